@@ -65,6 +65,12 @@ pub const MINER: &[u8] = b"miner";
 /// The seed of the referral account PDA.
 pub const REFERRAL: &[u8] = b"referral";
 
+/// The seed of the referral config account PDA.
+pub const REFERRAL_CONFIG: &[u8] = b"referral_config";
+
+/// The seed of the referral code account PDA.
+pub const REFERRAL_CODE: &[u8] = b"referral_code";
+
 /// The seed of the stake account PDA.
 pub const STAKE: &[u8] = b"stake";
 
@@ -77,6 +83,9 @@ pub const TREASURY: &[u8] = b"treasury";
 /// The seed of the pool account PDA.
 pub const POOL: &[u8] = b"pool";
 
+/// The seed of the stOIL mint PDA (liquid staking receipt token).
+pub const STOIL_MINT: &[u8] = b"stoil_mint";
+
 /// The seed of the well account PDA.
 pub const WELL: &[u8] = b"well";
 
@@ -86,7 +95,10 @@ pub const AUCTION: &[u8] = b"auction";
 /// The seed of the whitelist account PDA.
 pub const WHITELIST: &[u8] = b"whitelist";
 
-/// The seed of the rig account PDA (auction-based mining).
+/// The seed of the pending admin action account PDA.
+pub const PENDING_ADMIN_ACTION: &[u8] = b"pending_admin_action";
+
+/// The seed of the rig account PDA (miner progression).
 pub const RIG: &[u8] = b"rig";
 
 /// The seed of the micro account PDA (per-epoch auction state).
@@ -95,6 +107,9 @@ pub const MICRO: &[u8] = b"micro";
 /// The seed of the share account PDA (per-user, per-epoch auction contribution).
 pub const SHARE: &[u8] = b"share";
 
+/// The seed of the seeker account PDA.
+pub const SEEKER: &[u8] = b"seeker";
+
 /// Program id for const pda derivations
 const PROGRAM_ID: [u8; 32] = unsafe { *(&crate::id() as *const Pubkey as *const [u8; 32]) };
 
@@ -127,17 +142,119 @@ pub const DENOMINATOR_BPS: u64 = 10_000;
 /// The fee paid to bots if they checkpoint a user.
 pub const CHECKPOINT_FEE: u64 = 10_000; // 0.00001 SOL
 
-/// The fixed emission per round for block-based mining.
-pub const EMISSION_PER_ROUND: u64 = 200;
+/// The block-based mining emission schedule, as (first emission week, OIL per round) epochs.
+/// Like Bitcoin, each epoch halves the emission and runs twice as long as the previous one. The
+/// last epoch runs until `MAX_SUPPLY` is reached.
+pub const EMISSION_SCHEDULE: [(u64, u64); 7] = [
+    (0, ONE_OIL * 200),
+    (4, ONE_OIL * 100),
+    (12, ONE_OIL * 50),
+    (28, ONE_OIL * 25),
+    (60, ONE_OIL * 25 / 2),
+    (124, ONE_OIL * 25 / 4),
+    (252, ONE_OIL * 25 / 8),
+];
 
 /// The minimum cooldown period (in seconds) between auction OIL claims to prevent spam.
 pub const CLAIM_AUCTION_OIL_COOLDOWN_SECONDS: i64 = 10;
 
-/// The floor price for auction wells (in lamports).
-/// Price decays linearly from init_price down to this floor over auction_duration_seconds.
+/// The default floor price for auction wells (in lamports).
+/// Used when a well has no floor price configured in the auction account.
+/// Price decays from init_price down to the floor over auction_duration_seconds.
 /// Once price reaches floor, it stays at floor until someone bids.
 pub const AUCTION_FLOOR_PRICE: u64 = 10_000_000; // 0.01 SOL (testnet)
 
+/// The default multiplier applied to the winning price to set the next epoch's init_price (bps).
+pub const AUCTION_PRICE_MULTIPLIER_BPS: u64 = 20_000; // 2x
+
+/// The number of equal price drops for the stepwise auction curve.
+pub const AUCTION_PRICE_STEPS: u64 = 10;
+
+/// The number of times the price range halves over an auction for the exponential curve.
+pub const AUCTION_EXPONENTIAL_HALF_LIVES: u64 = 8;
+
+/// The default delay (in seconds) from schedule start to the first halving.
+pub const DEFAULT_FIRST_HALVING_DELAY_SECONDS: u64 = 14 * ONE_DAY as u64;
+
+/// The default period (in seconds) between subsequent halvings.
+pub const DEFAULT_HALVING_PERIOD_SECONDS: u64 = 28 * ONE_DAY as u64;
+
+/// The minimum delay to the first halving and period between halvings (in seconds), so a schedule
+/// cannot halve the mining rate many times within a few auctions.
+pub const MIN_HALVING_PERIOD_SECONDS: u64 = ONE_DAY as u64;
+
+/// The default mining rate reduction of the first halving (bps).
+pub const DEFAULT_FIRST_HALVING_DECAY_BPS: u64 = 5_000; // 50%
+
+/// The default mining rate reduction of each subsequent halving (bps).
+pub const DEFAULT_HALVING_DECAY_BPS: u64 = 2_500; // 25%
+
+/// Fixed-point scale of the halving rate multiplier (1x).
+pub const HALVING_RATE_SCALE: u64 = 1_000_000_000;
+
+/// The default maximum mining boost from staking (bps of a miner's split OIL weight).
+pub const DEFAULT_STAKE_BOOST_MAX_BPS: u64 = 5_000; // 1.5x
+
+/// The default share of the total stake score (bps) at which a miner reaches the maximum boost.
+pub const DEFAULT_STAKE_BOOST_SHARE_BPS: u64 = 100; // 1%
+
+/// The highest maximum mining boost the admin can configure (bps).
+pub const MAX_STAKE_BOOST_BPS: u64 = 10_000; // 2x
+
+/// The default refining fee charged on block-based OIL claims (bps of the unrefined OIL).
+pub const DEFAULT_REFINING_FEE_BPS: u64 = 1_000; // 10%
+
+/// The default refining fee charged on auction-based OIL claims (bps of the claimed OIL).
+pub const DEFAULT_AUCTION_REFINING_FEE_BPS: u64 = 1_000; // 10%
+
+/// The highest refining fee the admin can configure (bps).
+pub const MAX_REFINING_FEE_BPS: u64 = 5_000; // 50%
+
+/// The default period over which vested OIL claims unlock, fee-free (see `VestOIL`).
+pub const DEFAULT_VESTING_PERIOD: i64 = 30 * ONE_DAY;
+
+/// The default discount on the refining fee for Seeker miners (bps of the fee).
+pub const DEFAULT_SEEKER_REFINING_DISCOUNT_BPS: u64 = 5_000; // 50%
+
+/// The mint authority of Seeker genesis tokens.
+pub const SEEKER_MINT_AUTHORITY: Pubkey = pubkey!("GT2zuHVaZQYZSyQMgJPLzvkmyztfyXg2NJunqFp4p3A4");
+
+/// The token group of Seeker genesis tokens.
+pub const SEEKER_GROUP: Pubkey = pubkey!("GT22s89nU4iWFkNXj1Bw6uYhJJWDRPpShHt4Bk8f99Te");
+
+/// The XP earned per SOL deployed, bid or contributed.
+pub const XP_PER_SOL: u64 = 100;
+
+/// The XP earned per OIL of stake score held for a day.
+pub const XP_PER_STAKE_DAY: u64 = 1;
+
+/// The total XP needed to reach each rig level (index 0 is level 1).
+pub const RIG_LEVEL_XP: [u64; 10] = [100, 500, 1_500, 4_000, 10_000, 25_000, 60_000, 150_000, 400_000, 1_000_000];
+
+/// The checkpoint fee discount per rig level.
+pub const RIG_CHECKPOINT_DISCOUNT_BPS_PER_LEVEL: u64 = 500; // 50% at level 10
+
+/// The refining fee discount per rig level.
+pub const RIG_REFINING_DISCOUNT_BPS_PER_LEVEL: u64 = 250; // 25% at level 10
+
+/// The rig level that unlocks the Contrarian automation strategy.
+pub const CONTRARIAN_STRATEGY_LEVEL: u64 = 3;
+
+/// The default referral rate (bps of a referee's claim) when no referral config exists.
+pub const DEFAULT_REFERRAL_RATE_BPS: u64 = 50; // 0.5%
+
+/// The maximum combined referral and upline rate (bps of a referee's claim).
+pub const MAX_REFERRAL_RATE_BPS: u64 = 1_000; // 10%
+
+/// The number of weeks of referral stats kept in a referral account's ring buffer.
+pub const REFERRAL_STATS_WEEKS: usize = 4;
+
+/// The delay between queueing an admin action and executing it.
+pub const ADMIN_TIMELOCK_SECONDS: i64 = 2 * ONE_DAY;
+
+/// How long a queued admin action stays executable once its timelock has passed.
+pub const ADMIN_ACTION_WINDOW_SECONDS: i64 = 7 * ONE_DAY;
+
 /// The fee paid to the admin for each transaction.
 pub const ADMIN_FEE: u64 = 100; // 1%
 
@@ -147,8 +264,48 @@ pub const ADMIN_FEE_COLLECTOR: Pubkey = pubkey!("FEEFFuugN2rj9fcgrdoSfMdPFkRCfkp
 /// The swap program used for buybacks.
 pub const SWAP_PROGRAM: Pubkey = pubkey!("vnt1u7PzorND5JjweFWmDawKe2hLWoTwHU6QKz6XX98");
 
+/// The pool program holding the treasury's protocol-owned liquidity (the OIL/SOL pool lives on the
+/// same program as buybacks, but the two are wired separately).
+pub const LIQUIDITY_PROGRAM: Pubkey = pubkey!("vnt1u7PzorND5JjweFWmDawKe2hLWoTwHU6QKz6XX98");
+
 /// The address of the var account.
 pub const VAR_ADDRESS: Pubkey = pubkey!("DQGNTK6bcSMgDQ73b5Fdg6xUwVmvzxP18v4sVdFpEHXb");
 
+/// The number of protocol roles (see `Role`).
+pub const ROLE_COUNT: usize = 6;
+
+/// The maximum number of members of a role.
+pub const MAX_ROLE_MEMBERS: usize = 5;
+
+/// Pause flag for mining (Deploy, Automate other than closing, Checkpoint, Reset, ClaimSeeker).
+pub const PAUSE_MINING: u64 = 1 << 0;
+
+/// Pause flag for the auction (PlaceBid, Contribute, CheckpointAuction).
+pub const PAUSE_AUCTION: u64 = 1 << 1;
+
+/// Pause flag for staking deposits and stake XP (UpdateRig).
+pub const PAUSE_STAKING: u64 = 1 << 2;
+
+/// Pause flag for reward claims (SOL, OIL, auction, yield and referral claims, ReloadSOL).
+pub const PAUSE_CLAIMS: u64 = 1 << 3;
+
+/// Pause flag for referral setup (CreateReferral, CreateReferralCode, SetReferrer, SetReferralRebate).
+pub const PAUSE_REFERRALS: u64 = 1 << 4;
+
+/// Pause flag for withdrawals (stake, auction pool contributions and closing an automation). Kept
+/// separate from staking, auction and mining so users can still exit while those are paused.
+pub const PAUSE_WITHDRAWALS: u64 = 1 << 5;
+
+/// All pause flags.
+pub const PAUSE_ALL: u64 = (1 << 6) - 1;
+
+/// The offset of the layout version byte within an account's 8-byte discriminator. Steel only reads
+/// the first byte, so accounts created before versioning read as version 0.
+pub const ACCOUNT_VERSION_OFFSET: usize = 1;
+
+/// The liquidity manager before roles were introduced (seeded into the role table by MigrateAccount).
+pub const LIQ_MANAGER: Pubkey = pubkey!("DEvGq2WVuA3qkSCtwwuMYThY4onkJunEHSAxU5cieph8");
+
 /// The address which can call the bury and wrap instructions.
+/// Seeded as the treasury operator by MigrateAccount.
 pub const BURY_AUTHORITY: Pubkey = pubkey!("BoT3qYmE6xePWPU96Kf2QeuJr1pDgQ3gLWbA6kSyjzV");
@@ -8,6 +8,48 @@ pub enum OilError {
 
     #[error("Not authorized")]
     NotAuthorized = 1,
+
+    #[error("Price exceeds maximum")]
+    PriceExceedsMax = 2,
+
+    #[error("Bid expired")]
+    BidExpired = 3,
+
+    #[error("Access code exhausted")]
+    AccessCodeExhausted = 4,
+
+    #[error("Access code expired")]
+    AccessCodeExpired = 5,
+
+    #[error("Access code bound to another wallet")]
+    AccessCodeBound = 6,
+
+    #[error("Admin action is still timelocked")]
+    AdminActionTimelocked = 7,
+
+    #[error("Admin action does not match the queued action")]
+    AdminActionMismatch = 8,
+
+    #[error("Admin action execution window has passed")]
+    AdminActionExpired = 9,
+
+    #[error("Subsystem is paused")]
+    Paused = 10,
+
+    #[error("Liquidity operation changed balances unexpectedly")]
+    InvalidLiquidityChange = 11,
+
+    #[error("Liquidity operation exceeds the treasury's liquidity reserves")]
+    InsufficientLiquidity = 12,
+
+    #[error("Not a Seeker genesis token held by the miner")]
+    InvalidSeekerToken = 13,
+
+    #[error("Rig level too low")]
+    LevelTooLow = 14,
+
+    #[error("Output below the minimum")]
+    SlippageExceeded = 15,
 }
 
 error!(OilError);
@@ -10,6 +10,11 @@ pub enum OilEvent {
     JoinPool = 5,
     ClaimAuctionOIL = 6,
     ClaimAuctionSOL = 7,
+    AdminActionQueued = 8,
+    AdminActionExecuted = 9,
+    AdminActionCancelled = 10,
+    Liquidity = 11,
+    LevelUp = 12,
 }
 
 #[repr(C)]
@@ -53,6 +58,9 @@ pub struct ResetEvent {
 
     /// The amount of SOL in the gusher.
     pub gusher_sol: u64,
+
+    /// The emission week the round's OIL was minted in.
+    pub emission_week: u64,
 }
 
 #[repr(C)]
@@ -225,6 +233,81 @@ pub struct ClaimAuctionSOLEvent {
     pub ts: u64,
 }
 
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct AdminActionEvent {
+    /// The event discriminator (AdminActionQueued, AdminActionExecuted or AdminActionCancelled).
+    pub disc: u64,
+
+    /// The timelocked instruction (OilInstruction discriminator).
+    pub action: u64,
+
+    /// The admin that signed the instruction.
+    pub signer: Pubkey,
+
+    /// The account argument of the action (new admin, swap program or var address), if any.
+    pub target: Pubkey,
+
+    /// Hash of the action, its instruction data and its target.
+    pub args_hash: [u8; 32],
+
+    /// Timestamp from which the action can be executed.
+    pub executable_at: i64,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct LiquidityEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The liquidity operation (see `LiquidityAction`).
+    pub action: u64,
+
+    /// The change of the treasury's wrapped SOL balance.
+    pub sol_change: i64,
+
+    /// The change of the treasury's OIL balance.
+    pub oil_change: i64,
+
+    /// The change of the treasury's LP token balance.
+    pub lp_change: i64,
+
+    /// The LP tokens held by the treasury after the operation.
+    pub lp_tokens: u64,
+
+    /// The wrapped SOL held for liquidity after the operation.
+    pub liquidity_sol: u64,
+
+    /// The OIL held for liquidity after the operation.
+    pub liquidity_oil: u64,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct LevelUpEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The authority of the miner.
+    pub authority: Pubkey,
+
+    /// The new level of the miner's rig.
+    pub level: u64,
+
+    /// The rig's total XP.
+    pub xp: u64,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
 event!(ResetEvent);
 event!(BarrelEvent);
 event!(DeployEvent);
@@ -232,4 +315,7 @@ event!(LiqEvent);
 event!(BidEvent);
 event!(JoinAuctionPoolEvent);
 event!(ClaimAuctionOILEvent);
-event!(ClaimAuctionSOLEvent);
\ No newline at end of file
+event!(ClaimAuctionSOLEvent);
+event!(AdminActionEvent);
+event!(LiquidityEvent);
+event!(LevelUpEvent);
\ No newline at end of file
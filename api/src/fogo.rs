@@ -1,4 +1,4 @@
-use fogo_sessions_sdk::session::is_session;
+use fogo_sessions_sdk::session::{is_session, Session};
 use fogo_sessions_sdk::token::PROGRAM_SIGNER_SEED;
 use spl_token::instruction::close_account;
 use solana_program::program::invoke_signed as solana_invoke_signed;
@@ -26,6 +26,16 @@ pub fn validate_session(signer_info: &AccountInfo) -> Result<(), ProgramError> {
     Ok(())
 }
 
+/// Ensures the session signer was opened by `authority_info` and is live for this program.
+pub fn validate_session_user(signer_info: &AccountInfo, authority_info: &AccountInfo) -> Result<(), ProgramError> {
+    let user = Session::extract_user_from_signer_or_session(signer_info, &ID)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    if user != *authority_info.key {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    Ok(())
+}
+
 pub fn transfer_wrapped_sol_and_unwrap<'a>(
     signer_info: &'a AccountInfo<'a>,
     program_signer_info: &'a AccountInfo<'a>,
@@ -64,24 +74,34 @@ pub fn transfer_wrapped_sol_and_unwrap<'a>(
         &[&[PROGRAM_SIGNER_SEED, &[bump]]],
     )?;
     
+    close_wrapped_sol(destination_wrapped_sol_info, destination_pda_info, token_program, destination_seeds)
+}
+
+/// Closes a program PDA's wrapped SOL ATA, unwrapping its balance into the PDA.
+fn close_wrapped_sol<'a>(
+    wrapped_sol_info: &'a AccountInfo<'a>,
+    pda_info: &'a AccountInfo<'a>,
+    token_program: &'a AccountInfo<'a>,
+    seeds: &[&[u8]],
+) -> Result<(), ProgramError> {
     let close_ix = close_account(
         token_program.key,
-        destination_wrapped_sol_info.key,
-        destination_pda_info.key,
-        destination_pda_info.key,
+        wrapped_sol_info.key,
+        pda_info.key,
+        pda_info.key,
         &[],
     )?;
     
     invoke_signed(
         &close_ix,
         &[
-            destination_wrapped_sol_info.clone(),
-            destination_pda_info.clone(),
-            destination_pda_info.clone(),
+            wrapped_sol_info.clone(),
+            pda_info.clone(),
+            pda_info.clone(),
             token_program.clone(),
         ],
         &ID,
-        destination_seeds,
+        seeds,
     )?;
     
     Ok(())
@@ -162,3 +182,218 @@ pub fn transfer_token_with_program_signer<'a>(
     
     Ok(())
 }
+
+/// Wrapped SOL accounts a Fogo session pays with (sessions cannot move native SOL).
+pub struct WrappedSolAccounts<'a> {
+    /// The authority's wrapped SOL ATA.
+    pub user: &'a AccountInfo<'a>,
+    /// The wrapped SOL ATA of the program account being paid.
+    pub destination: &'a AccountInfo<'a>,
+    pub token_program: &'a AccountInfo<'a>,
+    pub mint: &'a AccountInfo<'a>,
+    pub associated_token_program: &'a AccountInfo<'a>,
+}
+
+impl<'a> WrappedSolAccounts<'a> {
+    /// Reads the accounts in instruction order: user, destination, token_program, mint, associated_token_program.
+    pub fn from_iter(accounts: &mut impl Iterator<Item = &'a AccountInfo<'a>>) -> Result<Self, ProgramError> {
+        let mut next = || accounts.next().ok_or(ProgramError::NotEnoughAccountKeys);
+        Ok(Self {
+            user: next()?,
+            destination: next()?,
+            token_program: next()?,
+            mint: next()?,
+            associated_token_program: next()?,
+        })
+    }
+}
+
+/// The user a user-facing instruction acts for, resolved once per instruction.
+///
+/// Instructions are written once against this context. A wallet signs for itself and pays in
+/// native SOL; a Fogo session signs for its authority, pays rent from a sponsor and moves funds
+/// as wrapped SOL through the program signer.
+pub struct UserContext<'a> {
+    /// The transaction signer (the wallet, a crank, or a session key).
+    pub signer: &'a AccountInfo<'a>,
+    /// The wallet the instruction acts for. Native SOL payouts go here.
+    pub authority: &'a AccountInfo<'a>,
+    /// The account paying rent for any account created by the instruction.
+    pub payer: &'a AccountInfo<'a>,
+    /// The program signer PDA (Fogo sessions only).
+    pub program_signer: Option<&'a AccountInfo<'a>>,
+}
+
+impl<'a> UserContext<'a> {
+    /// A wallet signing directly. `authority` may differ from the signer for permissionless
+    /// instructions (e.g. checkpointing another miner); use `assert_authority` where it may not.
+    pub fn wallet(
+        signer_info: &'a AccountInfo<'a>,
+        authority_info: &'a AccountInfo<'a>,
+        payer_info: &'a AccountInfo<'a>,
+    ) -> Result<Self, ProgramError> {
+        signer_info.is_signer()?;
+        Ok(Self {
+            signer: signer_info,
+            authority: authority_info,
+            payer: payer_info,
+            program_signer: None,
+        })
+    }
+
+    /// A Fogo session signing on behalf of `authority_info`. The session must belong to the authority.
+    pub fn session(
+        signer_info: &'a AccountInfo<'a>,
+        authority_info: &'a AccountInfo<'a>,
+        program_signer_info: &'a AccountInfo<'a>,
+        payer_info: &'a AccountInfo<'a>,
+    ) -> Result<Self, ProgramError> {
+        signer_info.is_signer()?;
+        validate_session(signer_info)?;
+        validate_session_user(signer_info, authority_info)?;
+        validate_program_signer(program_signer_info)?;
+        Ok(Self {
+            signer: signer_info,
+            authority: authority_info,
+            payer: payer_info,
+            program_signer: Some(program_signer_info),
+        })
+    }
+
+    /// The authority's address.
+    pub fn key(&self) -> Pubkey {
+        *self.authority.key
+    }
+
+    pub fn is_session(&self) -> bool {
+        self.program_signer.is_some()
+    }
+
+    /// The program signer PDA, failing for wallet signers.
+    pub fn program_signer(&self) -> Result<&'a AccountInfo<'a>, ProgramError> {
+        self.program_signer.ok_or(ProgramError::InvalidArgument)
+    }
+
+    /// Ensures the signer acts for the authority: wallets must be the authority, sessions
+    /// are bound to the authority's session on construction.
+    pub fn assert_authority(&self) -> Result<(), ProgramError> {
+        if !self.is_session() && self.signer.key != self.authority.key {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        Ok(())
+    }
+
+    /// Pays `amount` native SOL from the user into the program account `destination_info`.
+    ///
+    /// Wallets transfer natively. Sessions transfer wrapped SOL through the program signer into the
+    /// destination's wrapped SOL ATA and unwrap it into `destination_info` (signed with `destination_seeds`).
+    pub fn pay(
+        &self,
+        amount: u64,
+        destination_info: &'a AccountInfo<'a>,
+        destination_seeds: &[&[u8]],
+        system_program: &'a AccountInfo<'a>,
+        wrapped: Option<&WrappedSolAccounts<'a>>,
+    ) -> Result<(), ProgramError> {
+        if amount == 0 {
+            return Ok(());
+        }
+        let Some(program_signer_info) = self.program_signer else {
+            destination_info.collect(amount, self.signer)?;
+            return Ok(());
+        };
+
+        let wrapped = wrapped.ok_or(ProgramError::NotEnoughAccountKeys)?;
+        wrapped.token_program.is_program(&spl_token::ID)?;
+        wrapped.mint.has_address(&crate::consts::SOL_MINT)?;
+        wrapped.associated_token_program.is_program(&spl_associated_token_account::ID)?;
+        crate::utils::create_or_validate_wrapped_sol_ata(
+            wrapped.user,
+            self.authority,
+            wrapped.mint,
+            self.payer,
+            system_program,
+            wrapped.token_program,
+            wrapped.associated_token_program,
+            None,
+        )?;
+        crate::utils::create_or_validate_wrapped_sol_ata(
+            wrapped.destination,
+            destination_info,
+            wrapped.mint,
+            self.payer,
+            system_program,
+            wrapped.token_program,
+            wrapped.associated_token_program,
+            None,
+        )?;
+        transfer_wrapped_sol_and_unwrap(
+            self.signer,
+            program_signer_info,
+            self.payer,
+            amount,
+            wrapped.user,
+            wrapped.destination,
+            destination_info,
+            wrapped.mint,
+            wrapped.token_program,
+            destination_seeds,
+        )
+    }
+
+    /// Pays `amount` wrapped SOL from the authority's wrapped SOL ATA into the program account `destination_info`,
+    /// unwrapping it through the destination's wrapped SOL ATA (signed with `destination_seeds`).
+    pub fn pay_wrapped(
+        &self,
+        amount: u64,
+        user_wrapped_sol_info: &'a AccountInfo<'a>,
+        destination_wrapped_sol_info: &'a AccountInfo<'a>,
+        destination_info: &'a AccountInfo<'a>,
+        mint_info: &'a AccountInfo<'a>,
+        token_program: &'a AccountInfo<'a>,
+        destination_seeds: &[&[u8]],
+    ) -> Result<(), ProgramError> {
+        match self.program_signer {
+            Some(program_signer_info) => transfer_wrapped_sol_and_unwrap(
+                self.signer,
+                program_signer_info,
+                self.payer,
+                amount,
+                user_wrapped_sol_info,
+                destination_wrapped_sol_info,
+                destination_info,
+                mint_info,
+                token_program,
+                destination_seeds,
+            ),
+            None => {
+                transfer(self.signer, user_wrapped_sol_info, destination_wrapped_sol_info, token_program, amount)?;
+                close_wrapped_sol(destination_wrapped_sol_info, destination_info, token_program, destination_seeds)
+            }
+        }
+    }
+
+    /// Transfers `amount` tokens out of the authority's token account `sender_info`.
+    /// Wallets sign the transfer themselves; sessions transfer through the program signer.
+    pub fn transfer_token(
+        &self,
+        token_program: &'a AccountInfo<'a>,
+        sender_info: &'a AccountInfo<'a>,
+        mint_info: &'a AccountInfo<'a>,
+        destination_info: &'a AccountInfo<'a>,
+        amount: u64,
+    ) -> Result<(), ProgramError> {
+        match self.program_signer {
+            Some(program_signer_info) => transfer_token_with_program_signer(
+                token_program,
+                sender_info,
+                mint_info,
+                destination_info,
+                self.signer,
+                program_signer_info,
+                amount,
+            ),
+            None => transfer(self.signer, sender_info, destination_info, token_program, amount),
+        }
+    }
+}
@@ -0,0 +1,693 @@
+//! Instruction metadata used to generate the IDL.
+//!
+//! Account lists are declared next to their SDK builders in [`crate::sdk`] and argument lists are
+//! derived from the instruction structs in [`crate::instruction`], so the IDL cannot drift from the
+//! code that builds the instructions.
+
+use solana_program::pubkey::Pubkey;
+
+use crate::{instruction::*, sdk::*};
+
+/// An account passed to an instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IdlAccount {
+    pub name: &'static str,
+    pub is_writable: bool,
+    pub is_signer: bool,
+    /// Whether the account is only passed in some cases (e.g. a referral for new miners).
+    pub is_optional: bool,
+    /// The fixed address of the account (programs, sysvars and mints).
+    pub address: Option<Pubkey>,
+}
+
+impl IdlAccount {
+    /// A writable signer.
+    pub const fn signer(name: &'static str) -> Self {
+        Self { name, is_writable: true, is_signer: true, is_optional: false, address: None }
+    }
+
+    /// A writable account.
+    pub const fn writable(name: &'static str) -> Self {
+        Self { name, is_writable: true, is_signer: false, is_optional: false, address: None }
+    }
+
+    /// A read-only account.
+    pub const fn readonly(name: &'static str) -> Self {
+        Self { name, is_writable: false, is_signer: false, is_optional: false, address: None }
+    }
+
+    /// Pins the account to a fixed address.
+    pub const fn at(self, address: Pubkey) -> Self {
+        Self { address: Some(address), ..self }
+    }
+
+    /// Marks the account as optional.
+    pub const fn optional(self) -> Self {
+        Self { is_optional: true, ..self }
+    }
+}
+
+/// A field of an instruction's arguments.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IdlArg {
+    pub name: &'static str,
+    /// The Rust type of the field, as written in the struct.
+    pub ty: &'static str,
+    /// The size of the field in bytes.
+    pub size: usize,
+}
+
+/// Implemented by every instruction struct (see `instruction_args!`).
+pub trait IdlArgs {
+    const ARGS: &'static [IdlArg];
+}
+
+/// An instruction with its accounts and arguments.
+#[derive(Clone, Copy, Debug)]
+pub struct IdlInstruction {
+    pub instruction: OilInstruction,
+    pub docs: &'static str,
+    pub accounts: &'static [IdlAccount],
+    pub args: &'static [IdlArg],
+}
+
+impl IdlInstruction {
+    pub const fn new<T: IdlArgs>(instruction: OilInstruction, docs: &'static str, accounts: &'static [IdlAccount]) -> Self {
+        Self { instruction, docs, accounts, args: T::ARGS }
+    }
+}
+
+/// Every instruction, in the order of [`OilInstruction`].
+pub const INSTRUCTIONS: &[IdlInstruction] = &[
+    // Miner
+    IdlInstruction::new::<Automate>(OilInstruction::Automate, "Configures a new automation.", AUTOMATE_ACCOUNTS),
+    IdlInstruction::new::<Automate>(
+        OilInstruction::AutomateWithSession,
+        "Configures a new automation (Fogo session).",
+        AUTOMATE_WITH_SESSION_ACCOUNTS,
+    ),
+    IdlInstruction::new::<Initialize>(OilInstruction::Initialize, "Initializes program variables.", INITIALIZE_ACCOUNTS),
+    IdlInstruction::new::<Checkpoint>(
+        OilInstruction::Checkpoint,
+        "Checkpoints rewards from a prior round.",
+        CHECKPOINT_ACCOUNTS,
+    ),
+    IdlInstruction::new::<Checkpoint>(
+        OilInstruction::CheckpointWithSession,
+        "Checkpoints rewards from a prior round (Fogo session).",
+        CHECKPOINT_WITH_SESSION_ACCOUNTS,
+    ),
+    IdlInstruction::new::<ClaimSOL>(OilInstruction::ClaimSOL, "Claims SOL mining rewards.", CLAIM_SOL_ACCOUNTS),
+    IdlInstruction::new::<ClaimSOL>(
+        OilInstruction::ClaimSOLWithSession,
+        "Claims SOL mining rewards (Fogo session).",
+        CLAIM_SOL_WITH_SESSION_ACCOUNTS,
+    ),
+    IdlInstruction::new::<ClaimOIL>(OilInstruction::ClaimOIL, "Claims OIL mining rewards.", CLAIM_OIL_ACCOUNTS),
+    IdlInstruction::new::<ClaimOIL>(
+        OilInstruction::ClaimOILWithSession,
+        "Claims OIL mining rewards (Fogo session).",
+        CLAIM_OIL_WITH_SESSION_ACCOUNTS,
+    ),
+    IdlInstruction::new::<VestOIL>(
+        OilInstruction::VestOIL,
+        "Claims OIL mining rewards through a fee-free vesting schedule.",
+        CLAIM_OIL_ACCOUNTS,
+    ),
+    IdlInstruction::new::<VestOIL>(
+        OilInstruction::VestOILWithSession,
+        "Claims OIL mining rewards through a fee-free vesting schedule (Fogo session).",
+        CLAIM_OIL_WITH_SESSION_ACCOUNTS,
+    ),
+    IdlInstruction::new::<Close>(OilInstruction::Close, "Closes an account.", CLOSE_ACCOUNTS),
+    IdlInstruction::new::<Deploy>(OilInstruction::Deploy, "Deploys SOL to claim space on the board.", DEPLOY_ACCOUNTS),
+    IdlInstruction::new::<Deploy>(
+        OilInstruction::DeployWithSession,
+        "Deploys SOL to claim space on the board (Fogo session).",
+        DEPLOY_WITH_SESSION_ACCOUNTS,
+    ),
+    IdlInstruction::new::<Log>(OilInstruction::Log, "Logs non-truncatable event data.", LOG_ACCOUNTS),
+    IdlInstruction::new::<Reset>(OilInstruction::Reset, "Resets the board for a new round.", RESET_ACCOUNTS),
+    IdlInstruction::new::<ReloadSOL>(
+        OilInstruction::ReloadSOL,
+        "Reloads SOL mining rewards into automation.",
+        RELOAD_SOL_ACCOUNTS,
+    ),
+    IdlInstruction::new::<CreateReferral>(
+        OilInstruction::CreateReferral,
+        "Creates a referral account.",
+        CREATE_REFERRAL_ACCOUNTS,
+    ),
+    IdlInstruction::new::<CreateReferral>(
+        OilInstruction::CreateReferralWithSession,
+        "Creates a referral account (Fogo session).",
+        CREATE_REFERRAL_WITH_SESSION_ACCOUNTS,
+    ),
+    IdlInstruction::new::<ClaimReferral>(
+        OilInstruction::ClaimReferral,
+        "Claims referral rewards.",
+        CLAIM_REFERRAL_ACCOUNTS,
+    ),
+    IdlInstruction::new::<ClaimReferral>(
+        OilInstruction::ClaimReferralWithSession,
+        "Claims referral rewards (Fogo session).",
+        CLAIM_REFERRAL_WITH_SESSION_ACCOUNTS,
+    ),
+    IdlInstruction::new::<CreateReferralCode>(
+        OilInstruction::CreateReferralCode,
+        "Maps a referral code to a referral account.",
+        CREATE_REFERRAL_CODE_ACCOUNTS,
+    ),
+    IdlInstruction::new::<CreateReferralCode>(
+        OilInstruction::CreateReferralCodeWithSession,
+        "Maps a referral code to a referral account (Fogo session).",
+        CREATE_REFERRAL_CODE_WITH_SESSION_ACCOUNTS,
+    ),
+    IdlInstruction::new::<SetReferralRebate>(
+        OilInstruction::SetReferralRebate,
+        "Sets the share of referral rewards given back to referees.",
+        SET_REFERRAL_REBATE_ACCOUNTS,
+    ),
+    IdlInstruction::new::<SetReferralRebate>(
+        OilInstruction::SetReferralRebateWithSession,
+        "Sets the share of referral rewards given back to referees (Fogo session).",
+        SET_REFERRAL_REBATE_WITH_SESSION_ACCOUNTS,
+    ),
+    IdlInstruction::new::<SetReferrer>(
+        OilInstruction::SetReferrer,
+        "Attaches a referrer to a miner that has none.",
+        SET_REFERRER_ACCOUNTS,
+    ),
+    IdlInstruction::new::<SetReferrer>(
+        OilInstruction::SetReferrerWithSession,
+        "Attaches a referrer to a miner that has none (Fogo session).",
+        SET_REFERRER_WITH_SESSION_ACCOUNTS,
+    ),
+    IdlInstruction::new::<ClaimSeeker>(
+        OilInstruction::ClaimSeeker,
+        "Claims a Seeker genesis token for a miner.",
+        CLAIM_SEEKER_ACCOUNTS,
+    ),
+    IdlInstruction::new::<UpdateRig>(
+        OilInstruction::UpdateRig,
+        "Accrues stake XP on a miner's rig and syncs its level.",
+        UPDATE_RIG_ACCOUNTS,
+    ),
+    // Auction-based mining
+    IdlInstruction::new::<PlaceBid>(OilInstruction::PlaceBid, "Places a bid on an auction well.", PLACE_BID_ACCOUNTS),
+    IdlInstruction::new::<PlaceBid>(
+        OilInstruction::PlaceBidWithSession,
+        "Places a bid on an auction well (Fogo session).",
+        PLACE_BID_WITH_SESSION_ACCOUNTS,
+    ),
+    IdlInstruction::new::<ClaimAuctionOIL>(
+        OilInstruction::ClaimAuctionOIL,
+        "Claims OIL rewards from auction mining.",
+        CLAIM_AUCTION_OIL_ACCOUNTS,
+    ),
+    IdlInstruction::new::<ClaimAuctionOIL>(
+        OilInstruction::ClaimAuctionOILWithSession,
+        "Claims OIL rewards from auction mining (Fogo session).",
+        CLAIM_AUCTION_OIL_WITH_SESSION_ACCOUNTS,
+    ),
+    IdlInstruction::new::<ClaimAuctionSOL>(
+        OilInstruction::ClaimAuctionSOL,
+        "Claims SOL rewards from auction mining.",
+        CLAIM_AUCTION_SOL_ACCOUNTS,
+    ),
+    IdlInstruction::new::<ClaimAuctionSOL>(
+        OilInstruction::ClaimAuctionSOLWithSession,
+        "Claims SOL rewards from auction mining (Fogo session).",
+        CLAIM_AUCTION_SOL_WITH_SESSION_ACCOUNTS,
+    ),
+    IdlInstruction::new::<Contribute>(
+        OilInstruction::Contribute,
+        "Contributes SOL to the pool's bid on an auction well.",
+        CONTRIBUTE_ACCOUNTS,
+    ),
+    IdlInstruction::new::<Contribute>(
+        OilInstruction::ContributeWithSession,
+        "Contributes SOL to the pool's bid on an auction well (Fogo session).",
+        CONTRIBUTE_WITH_SESSION_ACCOUNTS,
+    ),
+    IdlInstruction::new::<CheckpointAuction>(
+        OilInstruction::CheckpointAuction,
+        "Checkpoints auction rewards from prior epochs.",
+        CHECKPOINT_AUCTION_ACCOUNTS,
+    ),
+    IdlInstruction::new::<CheckpointAuction>(
+        OilInstruction::CheckpointAuctionWithSession,
+        "Checkpoints auction rewards from prior epochs (Fogo session).",
+        CHECKPOINT_AUCTION_WITH_SESSION_ACCOUNTS,
+    ),
+    IdlInstruction::new::<WithdrawContribution>(
+        OilInstruction::WithdrawContribution,
+        "Withdraws an un-spent pool contribution.",
+        WITHDRAW_CONTRIBUTION_ACCOUNTS,
+    ),
+    IdlInstruction::new::<WithdrawContribution>(
+        OilInstruction::WithdrawContributionWithSession,
+        "Withdraws an un-spent pool contribution (Fogo session).",
+        WITHDRAW_CONTRIBUTION_WITH_SESSION_ACCOUNTS,
+    ),
+    // Staker
+    IdlInstruction::new::<Deposit>(OilInstruction::Deposit, "Deposits OIL into a stake account.", DEPOSIT_ACCOUNTS),
+    IdlInstruction::new::<Deposit>(
+        OilInstruction::DepositWithSession,
+        "Deposits OIL into a stake account (Fogo session).",
+        DEPOSIT_WITH_SESSION_ACCOUNTS,
+    ),
+    IdlInstruction::new::<Withdraw>(OilInstruction::Withdraw, "Withdraws OIL from a stake account.", WITHDRAW_ACCOUNTS),
+    IdlInstruction::new::<Withdraw>(
+        OilInstruction::WithdrawWithSession,
+        "Withdraws OIL from a stake account (Fogo session).",
+        WITHDRAW_WITH_SESSION_ACCOUNTS,
+    ),
+    IdlInstruction::new::<ClaimYield>(OilInstruction::ClaimYield, "Claims staking yield.", CLAIM_YIELD_ACCOUNTS),
+    IdlInstruction::new::<ClaimYield>(
+        OilInstruction::ClaimYieldWithSession,
+        "Claims staking yield (Fogo session).",
+        CLAIM_YIELD_WITH_SESSION_ACCOUNTS,
+    ),
+    IdlInstruction::new::<DepositLiquid>(
+        OilInstruction::DepositLiquid,
+        "Stakes OIL in the liquid stake for stOIL.",
+        DEPOSIT_LIQUID_ACCOUNTS,
+    ),
+    IdlInstruction::new::<DepositLiquid>(
+        OilInstruction::DepositLiquidWithSession,
+        "Stakes OIL in the liquid stake for stOIL (Fogo session).",
+        DEPOSIT_LIQUID_WITH_SESSION_ACCOUNTS,
+    ),
+    IdlInstruction::new::<RedeemLiquid>(
+        OilInstruction::RedeemLiquid,
+        "Burns stOIL for the OIL it backs.",
+        REDEEM_LIQUID_ACCOUNTS,
+    ),
+    IdlInstruction::new::<RedeemLiquid>(
+        OilInstruction::RedeemLiquidWithSession,
+        "Burns stOIL for the OIL it backs (Fogo session).",
+        REDEEM_LIQUID_WITH_SESSION_ACCOUNTS,
+    ),
+    // Admin
+    IdlInstruction::new::<Buyback>(OilInstruction::Buyback, "Executes a buyback transaction.", BUYBACK_ACCOUNTS),
+    IdlInstruction::new::<CompoundLiquid>(
+        OilInstruction::CompoundLiquid,
+        "Swaps the liquid stake's SOL rewards into staked OIL.",
+        COMPOUND_LIQUID_ACCOUNTS,
+    ),
+    IdlInstruction::new::<Wrap>(
+        OilInstruction::Wrap,
+        "Wraps SOL in the treasury for swap and liquidity transactions.",
+        WRAP_ACCOUNTS,
+    ),
+    IdlInstruction::new::<SetAdmin>(
+        OilInstruction::SetAdmin,
+        "Proposes a new admin authority (timelocked).",
+        SET_ADMIN_ACCOUNTS,
+    ),
+    IdlInstruction::new::<AcceptAdmin>(
+        OilInstruction::AcceptAdmin,
+        "Accepts a proposed admin handover.",
+        ACCEPT_ADMIN_ACCOUNTS,
+    ),
+    IdlInstruction::new::<CancelAdminAction>(
+        OilInstruction::CancelAdminAction,
+        "Cancels a queued admin action.",
+        CANCEL_ADMIN_ACTION_ACCOUNTS,
+    ),
+    IdlInstruction::new::<SetRole>(
+        OilInstruction::SetRole,
+        "Sets the M-of-N signers of a protocol role (timelocked).",
+        SET_ROLE_ACCOUNTS,
+    ),
+    IdlInstruction::new::<SetPause>(
+        OilInstruction::SetPause,
+        "Pauses or unpauses protocol subsystems (the guardian can only pause).",
+        SET_PAUSE_ACCOUNTS,
+    ),
+    IdlInstruction::new::<SetFeeCollector>(
+        OilInstruction::SetFeeCollector,
+        "Updates the fee collection address (timelocked).",
+        SET_FEE_COLLECTOR_ACCOUNTS,
+    ),
+    IdlInstruction::new::<SetSwapProgram>(
+        OilInstruction::SetSwapProgram,
+        "Updates the swap program address (timelocked).",
+        SET_SWAP_PROGRAM_ACCOUNTS,
+    ),
+    IdlInstruction::new::<SetVarAddress>(
+        OilInstruction::SetVarAddress,
+        "Updates the entropy variable address (timelocked).",
+        SET_VAR_ADDRESS_ACCOUNTS,
+    ),
+    IdlInstruction::new::<NewVar>(OilInstruction::NewVar, "Creates a new entropy variable.", NEW_VAR_ACCOUNTS),
+    IdlInstruction::new::<SetAdminFee>(
+        OilInstruction::SetAdminFee,
+        "Updates the admin fee rate (timelocked).",
+        SET_ADMIN_FEE_ACCOUNTS,
+    ),
+    IdlInstruction::new::<SetStakeBoost>(
+        OilInstruction::SetStakeBoost,
+        "Updates the stake-weighted mining boost (timelocked).",
+        SET_STAKE_BOOST_ACCOUNTS,
+    ),
+    IdlInstruction::new::<SetSeekerBonus>(
+        OilInstruction::SetSeekerBonus,
+        "Updates the Seeker miner bonus (timelocked).",
+        SET_SEEKER_BONUS_ACCOUNTS,
+    ),
+    IdlInstruction::new::<SetRefiningFee>(
+        OilInstruction::SetRefiningFee,
+        "Updates the refining fees and the vesting period (timelocked).",
+        SET_REFINING_FEE_ACCOUNTS,
+    ),
+    IdlInstruction::new::<MigrateAccount>(
+        OilInstruction::MigrateAccount,
+        "Upgrades any account to the current layout version of its type.",
+        MIGRATE_ACCOUNT_ACCOUNTS,
+    ),
+    IdlInstruction::new::<SetAuction>(
+        OilInstruction::SetAuction,
+        "Configures auction parameters (timelocked).",
+        SET_AUCTION_ACCOUNTS,
+    ),
+    IdlInstruction::new::<CreateWhitelist>(
+        OilInstruction::CreateWhitelist,
+        "Creates or updates a whitelist account (max uses, expiry, wallet binding).",
+        CREATE_WHITELIST_ACCOUNTS,
+    ),
+    IdlInstruction::new::<RevokeWhitelist>(
+        OilInstruction::RevokeWhitelist,
+        "Revokes a whitelist code and refunds its rent.",
+        REVOKE_WHITELIST_ACCOUNTS,
+    ),
+    IdlInstruction::new::<SetTgeTimestamp>(
+        OilInstruction::SetTgeTimestamp,
+        "Sets the token generation event timestamp (timelocked).",
+        SET_TGE_TIMESTAMP_ACCOUNTS,
+    ),
+    IdlInstruction::new::<Barrel>(OilInstruction::Barrel, "Burns leftover OIL held for liquidity.", BARREL_ACCOUNTS),
+    IdlInstruction::new::<AddLiquidity>(
+        OilInstruction::AddLiquidity,
+        "Deposits treasury SOL and OIL into the pool for protocol-owned LP tokens.",
+        LIQUIDITY_ACCOUNTS,
+    ),
+    IdlInstruction::new::<RemoveLiquidity>(
+        OilInstruction::RemoveLiquidity,
+        "Withdraws SOL and OIL from the treasury's pool position.",
+        LIQUIDITY_ACCOUNTS,
+    ),
+    IdlInstruction::new::<HarvestLiquidity>(
+        OilInstruction::HarvestLiquidity,
+        "Collects the fees earned by the treasury's pool position.",
+        LIQUIDITY_ACCOUNTS,
+    ),
+    IdlInstruction::new::<RebalanceLiquidity>(
+        OilInstruction::RebalanceLiquidity,
+        "Swaps between the SOL and OIL held for liquidity.",
+        LIQUIDITY_ACCOUNTS,
+    ),
+    IdlInstruction::new::<SetLpMint>(
+        OilInstruction::SetLpMint,
+        "Sets the LP token mint of the treasury's pool position (timelocked).",
+        SET_LP_MINT_ACCOUNTS,
+    ),
+    IdlInstruction::new::<ScheduleHalvings>(
+        OilInstruction::ScheduleHalvings,
+        "Schedules a change to the halving schedule.",
+        SCHEDULE_HALVINGS_ACCOUNTS,
+    ),
+    IdlInstruction::new::<SetReferralConfig>(
+        OilInstruction::SetReferralConfig,
+        "Configures referral tiers and the upline share.",
+        SET_REFERRAL_CONFIG_ACCOUNTS,
+    ),
+    IdlInstruction::new::<ClearReferrer>(
+        OilInstruction::ClearReferrer,
+        "Removes a miner's referrer.",
+        CLEAR_REFERRER_ACCOUNTS,
+    ),
+    // View
+    IdlInstruction::new::<GetPendingRewards>(
+        OilInstruction::GetPendingRewards,
+        "Returns a miner's pending rewards via return data.",
+        GET_PENDING_REWARDS_ACCOUNTS,
+    ),
+    IdlInstruction::new::<GetWellQuote>(
+        OilInstruction::GetWellQuote,
+        "Returns a well's current price and mining state via return data.",
+        GET_WELL_QUOTE_ACCOUNTS,
+    ),
+    IdlInstruction::new::<GetStakeInfo>(
+        OilInstruction::GetStakeInfo,
+        "Returns a stake account's lock and yield state via return data.",
+        GET_STAKE_INFO_ACCOUNTS,
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use steel::Instruction;
+
+    use super::*;
+    use crate::{consts::*, state::*};
+
+    /// Builds each instruction with every optional account included.
+    fn sample(instruction: OilInstruction) -> Instruction {
+        let signer = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let program_signer = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+        let some = Some(Pubkey::new_unique());
+        match instruction {
+            OilInstruction::Automate => automate(signer, authority, 1, 1, other, 1, 1, 0, false, some, false, true),
+            OilInstruction::AutomateWithSession => automate_with_session(
+                signer, authority, program_signer, payer, 1, 1, other, 1, 1, 0, false, some, false, true,
+            ),
+            OilInstruction::Initialize => initialize(signer, other, other, other, other, 0, 0, [0; 4], 0, [0; 4]),
+            OilInstruction::Checkpoint => checkpoint(signer, authority, 1),
+            OilInstruction::CheckpointWithSession => checkpoint_with_session(signer, authority, program_signer, 1),
+            OilInstruction::ClaimSOL => claim_sol(signer, some, some, some),
+            OilInstruction::ClaimSOLWithSession => {
+                claim_sol_with_session(signer, authority, program_signer, payer, some, some, some)
+            }
+            OilInstruction::ClaimOIL => claim_oil(signer, some, some, some, some, some),
+            OilInstruction::ClaimOILWithSession => {
+                claim_oil_with_session(signer, authority, program_signer, payer, some, some, some, some, some)
+            }
+            OilInstruction::VestOIL => vest_oil(signer, some, some, some, some, some),
+            OilInstruction::VestOILWithSession => {
+                vest_oil_with_session(signer, authority, program_signer, payer, some, some, some, some, some)
+            }
+            OilInstruction::Close => close(signer, 1, other),
+            OilInstruction::Deploy => deploy(signer, authority, 1, 1, [true; 25], some, false, Some([1; 32])),
+            OilInstruction::DeployWithSession => {
+                deploy_with_session(signer, authority, program_signer, payer, 1, 1, [true; 25], some, false, Some([1; 32]))
+            }
+            OilInstruction::Log => log(signer, &[]),
+            OilInstruction::Reset => reset(signer, other, 1, other, other),
+            OilInstruction::ReloadSOL => reload_sol(signer, authority, some, some, some),
+            OilInstruction::CreateReferral => create_referral(signer),
+            OilInstruction::CreateReferralWithSession => {
+                create_referral_with_session(signer, authority, program_signer, payer)
+            }
+            OilInstruction::ClaimReferral => claim_referral(signer),
+            OilInstruction::ClaimReferralWithSession => {
+                claim_referral_with_session(signer, authority, program_signer, payer)
+            }
+            OilInstruction::CreateReferralCode => create_referral_code(signer, [1; 32]),
+            OilInstruction::CreateReferralCodeWithSession => {
+                create_referral_code_with_session(signer, authority, program_signer, payer, [1; 32])
+            }
+            OilInstruction::SetReferralRebate => set_referral_rebate(signer, 1),
+            OilInstruction::SetReferralRebateWithSession => {
+                set_referral_rebate_with_session(signer, authority, program_signer, 1)
+            }
+            OilInstruction::SetReferrer => set_referrer(signer, other),
+            OilInstruction::SetReferrerWithSession => set_referrer_with_session(signer, authority, program_signer, other),
+            OilInstruction::ClaimSeeker => claim_seeker(signer, other),
+            OilInstruction::UpdateRig => update_rig(signer, authority),
+            OilInstruction::PlaceBid => place_bid(signer, authority, 0, other, some, 1, some, 1, None, Some([1; 32])),
+            OilInstruction::PlaceBidWithSession => {
+                place_bid_with_session(signer, authority, program_signer, payer, 0, other, some, 1, some, 1, None, Some([1; 32]))
+            }
+            OilInstruction::ClaimAuctionOIL => claim_auction_oil(signer, 0b1111, some, some, some, some, some),
+            OilInstruction::ClaimAuctionOILWithSession => claim_auction_oil_with_session(
+                signer, authority, program_signer, payer, 0b1111, some, some, some, some, some,
+            ),
+            OilInstruction::ClaimAuctionSOL => claim_auction_sol(signer, some, some, some),
+            OilInstruction::ClaimAuctionSOLWithSession => {
+                claim_auction_sol_with_session(signer, authority, program_signer, payer, some, some, some)
+            }
+            OilInstruction::Contribute => contribute(signer, authority, 0, 1, 1, 0, Some([1; 32])),
+            OilInstruction::ContributeWithSession => {
+                contribute_with_session(signer, authority, program_signer, payer, 0, 1, 1, 0, Some([1; 32]))
+            }
+            OilInstruction::CheckpointAuction => checkpoint_auction(signer, authority, [Some(1), None, None, None]),
+            OilInstruction::CheckpointAuctionWithSession => {
+                checkpoint_auction_with_session(signer, authority, program_signer, [Some(1), None, None, None])
+            }
+            OilInstruction::WithdrawContribution => withdraw_contribution(signer, authority, 0, 1, 1),
+            OilInstruction::WithdrawContributionWithSession => {
+                withdraw_contribution_with_session(signer, authority, program_signer, 0, 1, 1)
+            }
+            OilInstruction::Deposit => deposit(signer, payer, 1, 0, 0),
+            OilInstruction::DepositWithSession => {
+                deposit_with_session(signer, authority, program_signer, payer, 1, 0, 0)
+            }
+            OilInstruction::Withdraw => withdraw(signer, 1, 0),
+            OilInstruction::WithdrawWithSession => {
+                withdraw_with_session(signer, authority, program_signer, payer, 1, 0)
+            }
+            OilInstruction::ClaimYield => claim_yield(signer, 1, 0),
+            OilInstruction::ClaimYieldWithSession => {
+                claim_yield_with_session(signer, authority, program_signer, 1, 0)
+            }
+            OilInstruction::DepositLiquid => deposit_liquid(signer, 1, 1),
+            OilInstruction::DepositLiquidWithSession => {
+                deposit_liquid_with_session(signer, authority, program_signer, payer, 1, 1)
+            }
+            OilInstruction::RedeemLiquid => redeem_liquid(signer, 1),
+            OilInstruction::RedeemLiquidWithSession => {
+                redeem_liquid_with_session(signer, authority, program_signer, payer, 1)
+            }
+            OilInstruction::Buyback => buyback(signer, &[], &[]),
+            OilInstruction::CompoundLiquid => compound_liquid(signer, 1, &[], &[]),
+            OilInstruction::Wrap => wrap(signer, false, 1),
+            OilInstruction::SetAdmin => set_admin(signer, other),
+            OilInstruction::AcceptAdmin => accept_admin(signer),
+            OilInstruction::CancelAdminAction => cancel_admin_action(signer, OilInstruction::SetAdmin),
+            OilInstruction::SetRole => set_role(signer, Role::Guardian, 1, &[other]),
+            OilInstruction::SetPause => set_pause(signer, 0),
+            OilInstruction::SetFeeCollector => set_fee_collector(signer, other),
+            OilInstruction::SetSwapProgram => set_swap_program(signer, other),
+            OilInstruction::SetVarAddress => set_var_address(signer, other),
+            OilInstruction::NewVar => new_var(signer, other, 1, [0; 32], 1),
+            OilInstruction::SetAdminFee => set_admin_fee(signer, 1),
+            OilInstruction::SetStakeBoost => set_stake_boost(signer, 1, 1),
+            OilInstruction::SetSeekerBonus => set_seeker_bonus(signer, 1),
+            OilInstruction::SetRefiningFee => set_refining_fee(signer, 1, 1, 1),
+            OilInstruction::MigrateAccount => migrate_account(signer, other),
+            OilInstruction::SetAuction => set_auction(
+                signer,
+                1,
+                1,
+                [1; 4],
+                1,
+                [1; 4],
+                [PriceCurve::Linear; 4],
+                [0; 4],
+                [0; 4],
+                0,
+            ),
+            OilInstruction::CreateWhitelist => create_whitelist(signer, [1; 32], 0, 0, other),
+            OilInstruction::RevokeWhitelist => revoke_whitelist(signer, [1; 32]),
+            OilInstruction::SetTgeTimestamp => set_tge_timestamp(signer, 0),
+            OilInstruction::Barrel => barrel(signer, 0),
+            OilInstruction::AddLiquidity => add_liquidity(signer, other, &[], &[]),
+            OilInstruction::RemoveLiquidity => remove_liquidity(signer, other, &[], &[]),
+            OilInstruction::HarvestLiquidity => harvest_liquidity(signer, other, &[], &[]),
+            OilInstruction::RebalanceLiquidity => rebalance_liquidity(signer, other, &[], &[]),
+            OilInstruction::SetLpMint => set_lp_mint(signer, other),
+            OilInstruction::ScheduleHalvings => schedule_halvings(signer, 0, 1, 1, 1, 1),
+            OilInstruction::SetReferralConfig => set_referral_config(signer, [0; 4], [0; 4], 0),
+            OilInstruction::ClearReferrer => clear_referrer(signer, authority, other),
+            OilInstruction::GetPendingRewards => get_pending_rewards(authority),
+            OilInstruction::GetWellQuote => get_well_quote(0),
+            OilInstruction::GetStakeInfo => get_stake_info(authority, 0),
+        }
+    }
+
+    /// The address of singleton accounts, by the name they are declared with.
+    fn singleton_address(name: &str) -> Option<Pubkey> {
+        match name {
+            "board" => Some(board_pda().0),
+            "config" => Some(config_pda().0),
+            "treasury" => Some(TREASURY_ADDRESS),
+            "treasury_tokens" | "treasury_oil" => Some(treasury_tokens_address()),
+            "pool" | "staking_pool" => Some(pool_pda().0),
+            "pool_tokens" => Some(pool_tokens_address()),
+            "stoil_mint" => Some(stoil_mint_pda().0),
+            "auction" => Some(auction_pda().0),
+            "referral_config" => Some(referral_config_pda().0),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_every_instruction_is_declared_once() {
+        for discriminant in 0..=u8::MAX {
+            let Ok(instruction) = OilInstruction::try_from(discriminant) else {
+                continue;
+            };
+            let count = INSTRUCTIONS.iter().filter(|ix| ix.instruction == instruction).count();
+            assert_eq!(count, 1, "{:?} is declared {} times", instruction, count);
+        }
+    }
+
+    #[test]
+    fn test_accounts_match_sdk() {
+        for ix in INSTRUCTIONS {
+            let built = sample(ix.instruction);
+            assert_eq!(built.data[0], ix.instruction as u8, "{:?} discriminator", ix.instruction);
+            assert_eq!(built.accounts.len(), ix.accounts.len(), "{:?} account count", ix.instruction);
+            for (i, (meta, account)) in built.accounts.iter().zip(ix.accounts).enumerate() {
+                let at = format!("{:?} account {} ({})", ix.instruction, i, account.name);
+                assert_eq!(meta.is_signer, account.is_signer, "{} signer", at);
+                assert_eq!(meta.is_writable, account.is_writable, "{} writable", at);
+                if let Some(address) = account.address.or(singleton_address(account.name)) {
+                    assert_eq!(meta.pubkey, address, "{} address", at);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_args_match_struct_size() {
+        for ix in INSTRUCTIONS {
+            let size = ix.args.iter().map(|arg| arg.size).sum::<usize>();
+            let built = sample(ix.instruction);
+            if ix.instruction != OilInstruction::Log {
+                assert_eq!(built.data.len(), 1 + size, "{:?} args", ix.instruction);
+            }
+        }
+    }
+
+    /// Fails when api/idl.json is stale; regenerate it with `cargo run --bin generate-idl`.
+    #[test]
+    fn test_idl_json_matches_instructions() {
+        let idl: serde_json::Value = serde_json::from_str(include_str!("../idl.json")).unwrap();
+        let instructions = idl["instructions"].as_array().unwrap();
+        assert_eq!(instructions.len(), INSTRUCTIONS.len(), "idl.json instruction count");
+        for (ix, json) in INSTRUCTIONS.iter().zip(instructions) {
+            let name = format!("{:?}", ix.instruction);
+            assert!(same_name(&name, &json["name"]), "idl.json has {} for {}", json["name"], name);
+            assert_eq!(json["discriminant"]["value"], ix.instruction as u8, "{} discriminant", name);
+            assert_eq!(json["docs"][0], ix.docs, "{} docs", name);
+            let accounts = json["accounts"].as_array().unwrap();
+            assert_eq!(accounts.len(), ix.accounts.len(), "{} account count", name);
+            for (account, json) in ix.accounts.iter().zip(accounts) {
+                let at = format!("{} account {}", name, account.name);
+                assert!(same_name(account.name, &json["name"]), "{} name", at);
+                assert_eq!(json["isMut"], account.is_writable, "{} writable", at);
+                assert_eq!(json["isSigner"], account.is_signer, "{} signer", at);
+                assert_eq!(json["isOptional"].as_bool().unwrap_or(false), account.is_optional, "{} optional", at);
+                let address = account.address.map(|address| address.to_string());
+                assert_eq!(json["address"].as_str(), address.as_deref(), "{} address", at);
+            }
+            let args = json["args"].as_array().unwrap();
+            assert_eq!(args.len(), ix.args.len(), "{} arg count", name);
+            for (arg, json) in ix.args.iter().zip(args) {
+                assert_eq!(json["name"], arg.name, "{} arg", name);
+            }
+        }
+    }
+
+    /// Whether an IDL name is the camelCase form of a Rust name.
+    fn same_name(name: &str, json: &serde_json::Value) -> bool {
+        json.as_str()
+            .is_some_and(|json| json.to_lowercase() == name.replace('_', "").to_lowercase())
+    }
+}
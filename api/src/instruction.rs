@@ -1,6 +1,31 @@
 use steel::*;
 use bytemuck;
 
+use crate::consts::MAX_ROLE_MEMBERS;
+
+/// Declares the instruction argument structs, deriving their layout and the argument list used to
+/// generate the IDL (see [`crate::idl::IdlArgs`]).
+macro_rules! instruction_args {
+    ($($(#[$meta:meta])* pub struct $name:ident { $($(#[$field_meta:meta])* pub $field:ident: $ty:ty),* $(,)? })*) => {
+        $(
+            $(#[$meta])*
+            #[repr(C)]
+            #[derive(Clone, Copy, Debug, Pod, Zeroable)]
+            pub struct $name {
+                $($(#[$field_meta])* pub $field: $ty),*
+            }
+
+            impl crate::idl::IdlArgs for $name {
+                const ARGS: &'static [crate::idl::IdlArg] = &[$(crate::idl::IdlArg {
+                    name: stringify!($field),
+                    ty: stringify!($ty),
+                    size: core::mem::size_of::<$ty>(),
+                }),*];
+            }
+        )*
+    };
+}
+
 #[repr(u8)]
 #[derive(Clone, Copy, Debug, Eq, PartialEq, TryFromPrimitive)]
 pub enum OilInstruction {
@@ -14,6 +39,8 @@ pub enum OilInstruction {
     ClaimSOLWithSession = 44,
     ClaimOIL = 4,
     ClaimOILWithSession = 45,
+    VestOIL = 87,
+    VestOILWithSession = 88,
     Close = 5,
     Deploy = 6,
     DeployWithSession = 39,
@@ -24,6 +51,14 @@ pub enum OilInstruction {
     CreateReferralWithSession = 49,
     ClaimReferral = 28,
     ClaimReferralWithSession = 50,
+    CreateReferralCode = 64,
+    CreateReferralCodeWithSession = 65,
+    SetReferralRebate = 66,
+    SetReferralRebateWithSession = 67,
+    SetReferrer = 69,
+    SetReferrerWithSession = 70,
+    ClaimSeeker = 84,
+    UpdateRig = 86,
 
     // Auction-based mining
     PlaceBid = 29,
@@ -36,6 +71,8 @@ pub enum OilInstruction {
     ContributeWithSession = 54,
     CheckpointAuction = 55,
     CheckpointAuctionWithSession = 56,
+    WithdrawContribution = 57,
+    WithdrawContributionWithSession = 58,
 
     // Staker
     Deposit = 10,
@@ -44,251 +81,360 @@ pub enum OilInstruction {
     WithdrawWithSession = 47,
     ClaimYield = 12,
     ClaimYieldWithSession = 51,
+    DepositLiquid = 90,
+    DepositLiquidWithSession = 91,
+    RedeemLiquid = 92,
+    RedeemLiquidWithSession = 93,
 
     // Admin
     Buyback = 13,
+    CompoundLiquid = 94,
     Wrap = 14,
     SetAdmin = 16,
+    AcceptAdmin = 73,
+    CancelAdminAction = 74,
+    SetRole = 76,
+    SetPause = 77,
     SetFeeCollector = 17,
     SetSwapProgram = 18,
     SetVarAddress = 19,
     NewVar = 20,
     SetAdminFee = 21,
-    Migrate = 26,
+    SetStakeBoost = 83,
+    SetSeekerBonus = 85,
+    SetRefiningFee = 89,
+    MigrateAccount = 78,
     SetAuction = 33,
     CreateWhitelist = 34,
+    RevokeWhitelist = 72,
     SetTgeTimestamp = 35,
-    Liq = 37,
     Barrel = 38,
+    AddLiquidity = 79,
+    RemoveLiquidity = 80,
+    HarvestLiquidity = 81,
+    RebalanceLiquidity = 82,
+    SetLpMint = 95,
+    ScheduleHalvings = 62,
+    SetReferralConfig = 63,
+    ClearReferrer = 71,
+
+    // View (read-only, results via return data)
+    GetPendingRewards = 59,
+    GetWellQuote = 60,
+    GetStakeInfo = 61,
 }
 
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct Automate {
-    pub amount: [u8; 8],
-    pub deposit: [u8; 8],
-    pub fee: [u8; 8],
-    pub mask: [u8; 8],
-    pub strategy: u8,
-    pub reload: [u8; 8],
-    /// Optional referrer pubkey for new miners. Set to Pubkey::default() for no referrer.
-    pub referrer: [u8; 32],
-    /// Whether automated deployments should be pooled (1 = pooled, 0 = not pooled).
-    pub pooled: u8,
-}
-
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct ClaimSOL {}
-
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct ClaimOIL {}
-
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct Deploy {
-    pub amount: [u8; 8],
-    pub squares: [u8; 4],
-    /// Optional referrer pubkey. Set to Pubkey::default() for no referrer.
-    pub referrer: [u8; 32],
-    /// Whether this deploy is pooled. 0 = solo, 1 = pooled.
-    pub pooled: u8,
-}
-
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct Log {}
-
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct Reset {}
-
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct Close {}
-
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct SetAdmin {
-    pub admin: [u8; 32],
-}
-
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct SetFeeCollector {
-    pub fee_collector: [u8; 32],
-}
-
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct Buyback {}
-
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct Liq {}
-
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct Barrel {
-    pub amount: [u8; 8],
-}
-
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct Wrap {
-    /// 0 = use balance, 1 = use liquidity
-    pub use_liquidity: u8,
-    pub amount: [u8; 8],
-}
-
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct ReloadSOL {}
-
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct Deposit {
-    pub amount: [u8; 8],
-    pub lock_duration_days: [u8; 8],  // 0 = no lock, 1-730 days
-    pub stake_id: [u8; 8],  // Unique ID for this stake account (allows multiple stakes per user)
-}
-
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct Withdraw {
-    pub amount: [u8; 8],
-    pub stake_id: [u8; 8],
-}
-
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct ClaimYield {
-    pub amount: [u8; 8],
-}
-
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct Checkpoint {}
-
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct NewVar {
-    pub id: [u8; 8],
-    pub commit: [u8; 32],
-    pub samples: [u8; 8],
-}
-
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct SetAdminFee {
-    pub admin_fee: [u8; 8],
-}
-
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct SetSwapProgram {}
-
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct SetVarAddress {}
-
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct Migrate {}
-
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct CreateReferral {}
-
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct ClaimReferral {}
-
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct CreateWhitelist {
-    /// The code hash (first 32 bytes of keccak256 hash of the code string)
-    pub code_hash: [u8; 32],
-}
-
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct SetTgeTimestamp {
-    /// Unix timestamp for Token Generation Event (TGE).
-    /// If current time < tge_timestamp, pre-mine is active.
-    /// Set to 0 to disable pre-mine.
-    pub tge_timestamp: [u8; 8],
-}
-
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct Initialize {
-    pub barrel_authority: [u8; 32],
-    pub fee_collector: [u8; 32],
-    pub swap_program: [u8; 32],
-    pub var_address: [u8; 32],
-    pub admin_fee: [u8; 8],
-    // Auction configuration (optional - only used if auction accounts need initialization)
-    pub halving_period_seconds: [u8; 8],
-    pub base_mining_rates: [[u8; 8]; 4],  // 4 wells
-    pub auction_duration_seconds: [u8; 8],
-    pub starting_prices: [[u8; 8]; 4],  // 4 wells
-}
-
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct PlaceBid {
-    pub square_id: [u8; 8],
-    /// Optional referrer pubkey for new miners. Set to Pubkey::default() for no referrer.
-    pub referrer: [u8; 32],
-}
-
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct ClaimAuctionOIL {
-    /// Well IDs to claim OIL from (0-3), can claim multiple at once
-    /// Bitmask: bit 0 = well 0, bit 1 = well 1, etc.
-    pub well_mask: u8,
-}
+instruction_args! {
+    pub struct Automate {
+        pub amount: [u8; 8],
+        pub deposit: [u8; 8],
+        pub fee: [u8; 8],
+        pub mask: [u8; 8],
+        pub strategy: u8,
+        pub reload: [u8; 8],
+        /// Optional referrer pubkey for new miners. Set to Pubkey::default() for no referrer.
+        pub referrer: [u8; 32],
+        /// Whether automated deployments should be pooled (1 = pooled, 0 = not pooled).
+        pub pooled: u8,
+    }
 
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct ClaimAuctionSOL {
-    /// Reserved for future use (currently unused, but kept for consistency)
-    pub _reserved: u8,
-}
+    pub struct ClaimSOL {}
+
+    pub struct ClaimOIL {}
 
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct SetAuction {
-    pub halving_period_seconds: [u8; 8],
-    pub last_halving_time: [u8; 8],
-    pub base_mining_rates: [[u8; 8]; 4],  // 4 wells
-    pub auction_duration_seconds: [u8; 8],
-    pub starting_prices: [[u8; 8]; 4],  // 4 wells
-    pub well_id: [u8; 8],  // Well ID to update (0-3). If >= 4, only updates auction account.
-}
+    pub struct VestOIL {}
+
+    pub struct Deploy {
+        pub amount: [u8; 8],
+        pub squares: [u8; 4],
+        /// Optional referrer pubkey. Set to Pubkey::default() for no referrer.
+        pub referrer: [u8; 32],
+        /// Whether this deploy is pooled. 0 = solo, 1 = pooled.
+        pub pooled: u8,
+        /// Keccak hash of a premine access code (see `Whitelist`). Zeroes for none; required before the TGE.
+        pub access_code: [u8; 32],
+    }
+
+    pub struct Log {}
+
+    pub struct Reset {}
+
+    pub struct Close {}
+
+    pub struct SetAdmin {
+        pub admin: [u8; 32],
+    }
+
+    pub struct AcceptAdmin {}
+
+    pub struct SetRole {
+        /// The role to set (see `Role`).
+        pub role: u8,
+        /// Number of members that must sign (0 unassigns the role).
+        pub threshold: u8,
+        /// Keys holding the role (zeroed = empty slot).
+        pub members: [[u8; 32]; MAX_ROLE_MEMBERS],
+    }
+
+    pub struct SetPause {
+        /// Bitmap of paused subsystems (see the PAUSE_* flags).
+        pub paused: [u8; 8],
+    }
+
+    pub struct CancelAdminAction {
+        /// The queued instruction to cancel (OilInstruction discriminator).
+        pub action: u8,
+    }
+
+    pub struct SetFeeCollector {
+        pub fee_collector: [u8; 32],
+    }
 
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct Contribute {
-    /// Well ID to contribute to (0-3)
-    pub well_id: [u8; 8],
-    /// Amount to contribute (in lamports) - treated as maximum, may be less if pool becomes eligible
-    pub amount: [u8; 8],
-}
+    pub struct Buyback {}
+
+    pub struct CompoundLiquid {
+        /// The minimum OIL the swap must return.
+        pub min_oil_out: [u8; 8],
+    }
+
+    pub struct AddLiquidity {}
+
+    pub struct RemoveLiquidity {}
+
+    pub struct HarvestLiquidity {}
+
+    pub struct RebalanceLiquidity {}
+
+    pub struct SetLpMint {}
+
+    pub struct Barrel {
+        pub amount: [u8; 8],
+    }
 
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct CheckpointAuction {
-    /// Well mask: bit 0 = well 0, bit 1 = well 1, bit 2 = well 2, bit 3 = well 3
-    /// Allows checkpointing multiple wells in a single instruction
-    pub well_mask: u8,
-    /// Epoch IDs for each well (0-3), in order
-    /// If well_mask bit is set, corresponding epoch_id must be provided
-    /// If well_mask bit is not set, epoch_id is ignored
-    pub epoch_ids: [[u8; 8]; 4],
+    pub struct Wrap {
+        /// 0 = use balance, 1 = use liquidity
+        pub use_liquidity: u8,
+        pub amount: [u8; 8],
+    }
+
+    pub struct ReloadSOL {}
+
+    pub struct Deposit {
+        pub amount: [u8; 8],
+        pub lock_duration_days: [u8; 8],  // 0 = no lock, 1-730 days
+        pub stake_id: [u8; 8],  // Unique ID for this stake account (allows multiple stakes per user)
+    }
+
+    pub struct Withdraw {
+        pub amount: [u8; 8],
+        pub stake_id: [u8; 8],
+    }
+
+    pub struct ClaimYield {
+        pub amount: [u8; 8],
+    }
+
+    pub struct DepositLiquid {
+        /// The amount of OIL to stake for stOIL.
+        pub amount: [u8; 8],
+        /// The minimum stOIL to mint.
+        pub min_shares: [u8; 8],
+    }
+
+    pub struct RedeemLiquid {
+        /// The amount of stOIL to burn for OIL.
+        pub amount: [u8; 8],
+    }
+
+    pub struct Checkpoint {}
+
+    pub struct NewVar {
+        pub id: [u8; 8],
+        pub commit: [u8; 32],
+        pub samples: [u8; 8],
+    }
+
+    pub struct SetAdminFee {
+        pub admin_fee: [u8; 8],
+    }
+
+    pub struct SetStakeBoost {
+        /// Maximum mining boost (bps of a miner's split OIL weight)
+        pub max_bps: [u8; 8],
+        /// Share of the total stake score (bps) at which the maximum boost is reached (0 = no boost)
+        pub share_bps: [u8; 8],
+    }
+
+    pub struct SetSeekerBonus {
+        /// Discount on the refining fee for Seeker miners (bps of the fee)
+        pub refining_discount_bps: [u8; 8],
+    }
+
+    pub struct SetRefiningFee {
+        /// Refining fee on block-based OIL claims (bps of the unrefined OIL)
+        pub refining_fee_bps: [u8; 8],
+        /// Refining fee on auction-based OIL claims (bps of the claimed OIL)
+        pub auction_refining_fee_bps: [u8; 8],
+        /// Seconds over which vested OIL claims unlock
+        pub vesting_period: [u8; 8],
+    }
+
+    pub struct SetSwapProgram {}
+
+    pub struct SetVarAddress {}
+
+    pub struct MigrateAccount {}
+
+    pub struct CreateReferral {}
+
+    pub struct ClaimReferral {}
+
+    pub struct CreateReferralCode {
+        /// The code hash (first 32 bytes of keccak256 hash of the code string)
+        pub code_hash: [u8; 32],
+    }
+
+    pub struct SetReferralRebate {
+        /// Share of the referral bonus given back to referees (bps, max 10000).
+        pub rebate_bps: [u8; 8],
+    }
+
+    pub struct SetReferrer {
+        /// The referrer to attach to the miner.
+        pub referrer: [u8; 32],
+    }
+
+    pub struct ClearReferrer {}
+
+    pub struct ClaimSeeker {}
+
+    pub struct UpdateRig {}
+
+    pub struct SetReferralConfig {
+        /// Referred SOL volume (in lamports) at which each tier starts, ascending (tier 0 always starts at 0).
+        pub tier_thresholds: [[u8; 8]; 4],
+        /// Referral rate of each tier (bps of a referee's claim).
+        pub tier_rates_bps: [[u8; 8]; 4],
+        /// Share of a referee's claim paid to the referrer's own referrer (bps).
+        pub upline_share_bps: [u8; 8],
+    }
+
+    pub struct CreateWhitelist {
+        /// The code hash (first 32 bytes of keccak256 hash of the code string)
+        pub code_hash: [u8; 32],
+        /// Maximum number of uses (0 = unlimited).
+        pub max_uses: [u8; 8],
+        /// Unix timestamp at which the code expires (0 = never).
+        pub expires_at: [u8; 8],
+        /// Wallet the code is bound to (Pubkey::default() = any wallet).
+        pub bound_wallet: [u8; 32],
+    }
+
+    pub struct RevokeWhitelist {}
+
+    pub struct SetTgeTimestamp {
+        /// Unix timestamp for Token Generation Event (TGE).
+        /// If current time < tge_timestamp, pre-mine is active.
+        /// Set to 0 to disable pre-mine.
+        pub tge_timestamp: [u8; 8],
+    }
+
+    pub struct Initialize {
+        pub barrel_authority: [u8; 32],
+        pub fee_collector: [u8; 32],
+        pub swap_program: [u8; 32],
+        pub var_address: [u8; 32],
+        pub admin_fee: [u8; 8],
+        // Auction configuration (optional - only used if auction accounts need initialization)
+        pub halving_period_seconds: [u8; 8],
+        pub base_mining_rates: [[u8; 8]; 4],  // 4 wells
+        pub auction_duration_seconds: [u8; 8],
+        pub starting_prices: [[u8; 8]; 4],  // 4 wells
+    }
+
+    pub struct PlaceBid {
+        pub square_id: [u8; 8],
+        /// Optional referrer pubkey for new miners. Set to Pubkey::default() for no referrer.
+        pub referrer: [u8; 32],
+        /// Maximum bid amount (in lamports) the bidder is willing to pay.
+        pub max_price: [u8; 8],
+        /// Unix timestamp after which the bid is rejected. Set to 0 for no deadline.
+        pub expires_at: [u8; 8],
+        /// Keccak hash of a premine access code (see `Whitelist`). Zeroes for none; required before the TGE.
+        pub access_code: [u8; 32],
+    }
+
+    pub struct ClaimAuctionOIL {
+        /// Well IDs to claim OIL from (0-3), can claim multiple at once
+        /// Bitmask: bit 0 = well 0, bit 1 = well 1, etc.
+        pub well_mask: u8,
+    }
+
+    pub struct ClaimAuctionSOL {
+        /// Reserved for future use (currently unused, but kept for consistency)
+        pub _reserved: u8,
+    }
+
+    pub struct SetAuction {
+        pub halving_period_seconds: [u8; 8],
+        pub last_halving_time: [u8; 8],
+        pub base_mining_rates: [[u8; 8]; 4],  // 4 wells
+        pub auction_duration_seconds: [u8; 8],
+        pub starting_prices: [[u8; 8]; 4],  // 4 wells
+        pub well_id: [u8; 8],  // Well ID to update (0-3). If >= 4, only updates auction account.
+        pub floor_prices: [[u8; 8]; 4],  // 4 wells (0 = AUCTION_FLOOR_PRICE)
+        pub price_multipliers_bps: [[u8; 8]; 4],  // 4 wells (0 = AUCTION_PRICE_MULTIPLIER_BPS)
+        pub price_curves: [[u8; 8]; 4],  // 4 wells (see PriceCurve)
+    }
+
+    pub struct ScheduleHalvings {
+        /// Unix timestamp at which the new schedule takes effect (must be in the future). Set to 0 to cancel a pending change.
+        pub effective_time: [u8; 8],
+        /// Delay from the effective time to the first halving under the new schedule (in seconds)
+        pub first_halving_delay_seconds: [u8; 8],
+        /// Period between subsequent halvings (in seconds)
+        pub halving_period_seconds: [u8; 8],
+        /// Mining rate reduction of the very first halving (bps, only used if no halving has occurred yet)
+        pub first_halving_decay_bps: [u8; 8],
+        /// Mining rate reduction of each subsequent halving (bps)
+        pub halving_decay_bps: [u8; 8],
+    }
+
+    pub struct Contribute {
+        /// Well ID to contribute to (0-3)
+        pub well_id: [u8; 8],
+        /// Amount to contribute (in lamports) - treated as maximum, may be less if pool becomes eligible
+        pub amount: [u8; 8],
+        /// Highest bid price (in lamports) the pool may use this contribution for. Set to 0 for no limit.
+        pub max_price: [u8; 8],
+        /// Keccak hash of a premine access code (see `Whitelist`). Zeroes for none; required before the TGE.
+        pub access_code: [u8; 32],
+    }
+
+    pub struct WithdrawContribution {
+        /// Well ID to withdraw the contribution from (0-3)
+        pub well_id: [u8; 8],
+        /// Amount to withdraw (in lamports) - capped at the remaining contribution
+        pub amount: [u8; 8],
+    }
+
+    pub struct CheckpointAuction {
+        /// Well mask: bit 0 = well 0, bit 1 = well 1, bit 2 = well 2, bit 3 = well 3
+        /// Allows checkpointing multiple wells in a single instruction
+        pub well_mask: u8,
+        /// Epoch IDs for each well (0-3), in order
+        /// If well_mask bit is set, corresponding epoch_id must be provided
+        /// If well_mask bit is not set, epoch_id is ignored
+        pub epoch_ids: [[u8; 8]; 4],
+    }
+
+    pub struct GetPendingRewards {}
+
+    pub struct GetWellQuote {
+        pub well_id: [u8; 8],
+    }
+
+    pub struct GetStakeInfo {}
 }
 
 instruction!(OilInstruction, Automate);
@@ -296,32 +442,60 @@ instruction!(OilInstruction, Initialize);
 instruction!(OilInstruction, Checkpoint);
 instruction!(OilInstruction, ClaimSOL);
 instruction!(OilInstruction, ClaimOIL);
+instruction!(OilInstruction, VestOIL);
 instruction!(OilInstruction, ReloadSOL);
 instruction!(OilInstruction, Deploy);
 instruction!(OilInstruction, Log);
 instruction!(OilInstruction, Buyback);
+instruction!(OilInstruction, CompoundLiquid);
 instruction!(OilInstruction, Wrap);
 instruction!(OilInstruction, Reset);
 instruction!(OilInstruction, Close);
 instruction!(OilInstruction, SetAdmin);
+instruction!(OilInstruction, AcceptAdmin);
+instruction!(OilInstruction, CancelAdminAction);
+instruction!(OilInstruction, SetRole);
+instruction!(OilInstruction, SetPause);
 instruction!(OilInstruction, SetFeeCollector);
 instruction!(OilInstruction, Deposit);
 instruction!(OilInstruction, Withdraw);
 instruction!(OilInstruction, ClaimYield);
+instruction!(OilInstruction, DepositLiquid);
+instruction!(OilInstruction, RedeemLiquid);
 instruction!(OilInstruction, NewVar);
 instruction!(OilInstruction, SetAdminFee);
+instruction!(OilInstruction, SetStakeBoost);
+instruction!(OilInstruction, SetSeekerBonus);
+instruction!(OilInstruction, SetRefiningFee);
 instruction!(OilInstruction, SetSwapProgram);
 instruction!(OilInstruction, SetVarAddress);
-instruction!(OilInstruction, Migrate);
+instruction!(OilInstruction, MigrateAccount);
 instruction!(OilInstruction, CreateReferral);
 instruction!(OilInstruction, ClaimReferral);
+instruction!(OilInstruction, CreateReferralCode);
+instruction!(OilInstruction, SetReferralRebate);
+instruction!(OilInstruction, SetReferrer);
+instruction!(OilInstruction, ClaimSeeker);
+instruction!(OilInstruction, UpdateRig);
+instruction!(OilInstruction, ClearReferrer);
+instruction!(OilInstruction, SetReferralConfig);
 instruction!(OilInstruction, PlaceBid);
 instruction!(OilInstruction, ClaimAuctionOIL);
 instruction!(OilInstruction, ClaimAuctionSOL);
 instruction!(OilInstruction, SetAuction);
+instruction!(OilInstruction, ScheduleHalvings);
 instruction!(OilInstruction, CreateWhitelist);
+instruction!(OilInstruction, RevokeWhitelist);
 instruction!(OilInstruction, SetTgeTimestamp);
-instruction!(OilInstruction, Liq);
+instruction!(OilInstruction, AddLiquidity);
+instruction!(OilInstruction, RemoveLiquidity);
+instruction!(OilInstruction, HarvestLiquidity);
+instruction!(OilInstruction, RebalanceLiquidity);
+instruction!(OilInstruction, SetLpMint);
 instruction!(OilInstruction, Barrel);
 instruction!(OilInstruction, Contribute);
-instruction!(OilInstruction, CheckpointAuction);
\ No newline at end of file
+instruction!(OilInstruction, CheckpointAuction);
+instruction!(OilInstruction, WithdrawContribution);
+instruction!(OilInstruction, GetPendingRewards);
+instruction!(OilInstruction, GetWellQuote);
+instruction!(OilInstruction, GetStakeInfo);
@@ -2,10 +2,12 @@ pub mod consts;
 pub mod error;
 pub mod event;
 pub mod fogo;
+pub mod idl;
 pub mod instruction;
 pub mod sdk;
 pub mod state;
 pub mod utils;
+pub mod view;
 
 pub mod prelude {
     pub use crate::consts::*;
@@ -15,17 +17,18 @@ pub mod prelude {
     pub use crate::sdk::*;
     // Export state types explicitly to avoid ambiguous re-export warning
     pub use crate::state::{
-        Auction, Automation, AutomationStrategy, Board, Config, Micro, Miner, OilAccount, Pool, Referral, Round, Share, Stake, Treasury, Well,
-        Whitelist,
+        Auction, Automation, AutomationStrategy, Board, Config, HalvingSchedule, LiquidityAction, LiquidityChange, Micro, Miner, OilAccount, PendingAdminAction, Pool, PriceCurve, Referral,
+        ReferralAccounts, ReferralCode, ReferralConfig, ReferralSplit, ReferralWeekStats, Rig, Role, RoleSet, Round, Seeker, Share, Stake, Treasury, Well, Whitelist, XpSource,
     };
     // Re-export state module functions (PDAs, etc.)
     pub use crate::state::{
-        auction_pda, automation_pda, board_pda, config_pda, micro_pda, miner_pda, pool_pda, pool_tokens_address,
-        referral_pda, round_pda, share_pda, stake_pda, stake_pda_with_id, treasury_pda, treasury_tokens_address, well_pda,
+        account_version, auction_pda, automation_pda, board_pda, config_pda, micro_pda, miner_pda, pending_admin_action_pda, pool_pda, pool_tokens_address,
+        referral_code_pda, referral_config_pda, referral_pda, rig_checkpoint_fee, rig_level, rig_pda, round_pda, seeker_pda, sol_xp, share_pda, stake_pda, stake_pda_with_id, stoil_mint_pda, treasury_pda, treasury_tokens_address, well_pda,
         whitelist_pda,
     };
     // Re-export utils
     pub use crate::utils::*;
+    pub use crate::view::*;
 }
 
 use steel::*;
@@ -1,13 +1,19 @@
 use solana_program::pubkey::Pubkey;
-use spl_associated_token_account::get_associated_token_address;
+use spl_associated_token_account::{get_associated_token_address, get_associated_token_address_with_program_id};
 use steel::*;
 
 use crate::{
-    consts::{AUCTION, BOARD, MINT_ADDRESS, SOL_MINT, TREASURY_ADDRESS},
+    consts::{AUCTION, BOARD, EMISSION_SCHEDULE, MAX_ROLE_MEMBERS, MAX_SUPPLY, MINT_ADDRESS, SOL_MINT, TREASURY_ADDRESS},
+    idl::IdlAccount,
     instruction::{self, *},
     state::*,
+    utils::emission_per_round,
 };
 
+pub const LOG_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+];
+
 pub fn log(signer: Pubkey, msg: &[u8]) -> Instruction {
     let mut data = Log {}.to_bytes();
     data.extend_from_slice(msg);
@@ -31,46 +37,94 @@ pub fn auction_program_log(accounts: &[AccountInfo], msg: &[u8]) -> Result<(), P
     invoke_signed(&log(auction_address, msg), accounts, &crate::ID, &[AUCTION])
 }
 
-// let [signer_info, board_info, config_info, mint_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] = accounts else {
-// pub fn initialize(
-//     signer: Pubkey,
-//     barrel_authority: Pubkey,
-//     fee_collector: Pubkey,
-//     swap_program: Pubkey,
-//     var_address: Pubkey,
-//     admin_fee: u64,
-// ) -> Instruction {
-//     let board_address = board_pda().0;
-//     let config_address = config_pda().0;
-//     let mint_address = MINT_ADDRESS;
-//     let treasury_address = TREASURY_ADDRESS;
-//     let treasury_tokens_address = treasury_tokens_address();
-//     Instruction {
-//         program_id: crate::ID,
-//         accounts: vec![
-//             AccountMeta::new(signer, true),
-//             AccountMeta::new(board_address, false),
-//             AccountMeta::new(config_address, false),
-//             AccountMeta::new(mint_address, false),
-//             AccountMeta::new(treasury_address, false),
-//             AccountMeta::new(treasury_tokens_address, false),
-//             AccountMeta::new_readonly(system_program::ID, false),
-//             AccountMeta::new_readonly(spl_token::ID, false),
-//             AccountMeta::new_readonly(spl_associated_token_account::ID, false),
-//         ],
-//         data: Initialize {
-//             barrel_authority: barrel_authority.to_bytes(),
-//             fee_collector: fee_collector.to_bytes(),
-//             swap_program: swap_program.to_bytes(),
-//             var_address: var_address.to_bytes(),
-//             admin_fee: admin_fee.to_le_bytes(),
-//         }
-//         .to_bytes(),
-//     }
-// }
+pub const INITIALIZE_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("board"),
+    IdlAccount::writable("config"),
+    IdlAccount::readonly("mint").at(MINT_ADDRESS),
+    IdlAccount::writable("treasury"),
+    IdlAccount::writable("treasury_tokens"),
+    IdlAccount::writable("pool"),
+    IdlAccount::writable("pool_tokens"),
+    IdlAccount::writable("well_0"),
+    IdlAccount::writable("well_1"),
+    IdlAccount::writable("well_2"),
+    IdlAccount::writable("well_3"),
+    IdlAccount::writable("auction"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::readonly("token_program").at(spl_token::ID),
+    IdlAccount::readonly("associated_token_program").at(spl_associated_token_account::ID),
+];
+
+/// Initializes the board, config, treasury, staking pool, auction and wells.
+/// Admin-only instruction (the admin address bootstraps the config).
+pub fn initialize(
+    signer: Pubkey,
+    barrel_authority: Pubkey,
+    fee_collector: Pubkey,
+    swap_program: Pubkey,
+    var_address: Pubkey,
+    admin_fee: u64,
+    halving_period_seconds: u64,
+    base_mining_rates: [u64; 4],
+    auction_duration_seconds: u64,
+    starting_prices: [u64; 4],
+) -> Instruction {
+    let board_address = board_pda().0;
+    let config_address = config_pda().0;
+    let treasury_address = TREASURY_ADDRESS;
+    let pool_address = pool_pda().0;
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new(board_address, false),
+        AccountMeta::new(config_address, false),
+        AccountMeta::new_readonly(MINT_ADDRESS, false),
+        AccountMeta::new(treasury_address, false),
+        AccountMeta::new(treasury_tokens_address(), false),
+        AccountMeta::new(pool_address, false),
+        AccountMeta::new(pool_tokens_address(), false),
+    ];
+    for well_id in 0..4 {
+        accounts.push(AccountMeta::new(well_pda(well_id).0, false));
+    }
+    accounts.extend_from_slice(&[
+        AccountMeta::new(auction_pda().0, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(spl_token::ID, false),
+        AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+    ]);
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: Initialize {
+            barrel_authority: barrel_authority.to_bytes(),
+            fee_collector: fee_collector.to_bytes(),
+            swap_program: swap_program.to_bytes(),
+            var_address: var_address.to_bytes(),
+            admin_fee: admin_fee.to_le_bytes(),
+            halving_period_seconds: halving_period_seconds.to_le_bytes(),
+            base_mining_rates: base_mining_rates.map(u64::to_le_bytes),
+            auction_duration_seconds: auction_duration_seconds.to_le_bytes(),
+            starting_prices: starting_prices.map(u64::to_le_bytes),
+        }
+        .to_bytes(),
+    }
+}
 
 // let [signer_info, automation_info, executor_info, miner_info, system_program] = accounts else {
 
+pub const AUTOMATE_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("authority"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("automation"),
+    IdlAccount::writable("executor"),
+    IdlAccount::writable("miner"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::readonly("oil_program").at(crate::ID),
+    IdlAccount::writable("referral").optional(),
+];
+
 /// Set up automation for a miner. If the miner doesn't exist yet, pass a referrer to set it.
 /// If a referrer is provided and the miner is new, the referral account must be included.
 pub fn automate(
@@ -95,12 +149,12 @@ pub fn automate(
     let mut accounts = vec![
             AccountMeta::new(signer, true), // 0: signer (payer)
             AccountMeta::new(authority, false), // 1: authority (user's wallet)
-            AccountMeta::new(automation_address, false), // 2: automation
-            AccountMeta::new(executor, false), // 3: executor
-            AccountMeta::new(miner_address, false), // 4: miner
-            AccountMeta::new_readonly(system_program::ID, false), // 5: system_program
-            AccountMeta::new_readonly(crate::ID, false), // 6: oil_program
-            AccountMeta::new_readonly(config_address, false), // 7: config
+            AccountMeta::new_readonly(config_address, false), // 2: config
+            AccountMeta::new(automation_address, false), // 3: automation
+            AccountMeta::new(executor, false), // 4: executor
+            AccountMeta::new(miner_address, false), // 5: miner
+            AccountMeta::new_readonly(system_program::ID, false), // 6: system_program
+            AccountMeta::new_readonly(crate::ID, false), // 7: oil_program
     ];
     
     // Token accounts (user_wrapped_sol, automation_wrapped_sol, token_program, program_signer (optional), payer (optional), mint, ata_program)
@@ -129,22 +183,37 @@ pub fn automate(
     }
 }
 
-/// Claim SOL rewards with single-tier referral system.
+pub const CLAIM_SOL_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("miner"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::writable("miner_referrer").optional(),
+    IdlAccount::writable("referral_referrer").optional(),
+    IdlAccount::readonly("referral_config").optional(),
+    IdlAccount::writable("upline_referral").optional(),
+];
+
+/// Claim SOL rewards with tiered referral system.
 /// 
-/// If the miner has a referrer, 1.0% of the claim goes to the referrer.
+/// If the miner has a referrer, the referrer's tier rate (minus their rebate) goes to the referrer,
+/// and the upline share goes to the referrer's own referrer.
 /// 
 /// Account structure:
-/// - Base: signer, miner, system_program
-/// - If miner has referrer (required): [miner_referrer, referral_referrer]
+/// - Base: signer, config, miner, system_program
+/// - If miner has referrer (required): [miner_referrer, referral_referrer, referral_config, [upline_referral]]
 pub fn claim_sol(
     signer: Pubkey,
     referrer_miner: Option<Pubkey>, // Referrer's miner PDA (if miner has referrer)
     referrer_referral: Option<Pubkey>, // Referrer's referral PDA (if miner has referrer)
+    upline_referral: Option<Pubkey>, // Upline's referral PDA (if the referral config pays an upline share)
 ) -> Instruction {
+    let config_address = config_pda().0;
     let miner_address = miner_pda(signer).0;
     
     let mut accounts = vec![
         AccountMeta::new(signer, true),
+        AccountMeta::new_readonly(config_address, false),
         AccountMeta::new(miner_address, false),
         AccountMeta::new_readonly(system_program::ID, false),
     ];
@@ -153,6 +222,10 @@ pub fn claim_sol(
     if let (Some(miner_pubkey), Some(referral_pubkey)) = (referrer_miner, referrer_referral) {
         accounts.push(AccountMeta::new(miner_pubkey, false));
         accounts.push(AccountMeta::new(referral_pubkey, false));
+        accounts.push(AccountMeta::new_readonly(referral_config_pda().0, false));
+        if let Some(upline_pubkey) = upline_referral {
+            accounts.push(AccountMeta::new(upline_pubkey, false));
+        }
     }
     
     Instruction {
@@ -164,19 +237,43 @@ pub fn claim_sol(
 
 // let [signer_info, miner_info, mint_info, recipient_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
 
-/// Claim OIL rewards with single-tier referral system.
+pub const CLAIM_OIL_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("miner"),
+    IdlAccount::writable("mint").at(MINT_ADDRESS),
+    IdlAccount::writable("recipient"),
+    IdlAccount::writable("treasury"),
+    IdlAccount::writable("treasury_tokens"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::readonly("token_program").at(spl_token::ID),
+    IdlAccount::readonly("associated_token_program").at(spl_associated_token_account::ID),
+    IdlAccount::writable("miner_referrer").optional(),
+    IdlAccount::writable("referral_referrer").optional(),
+    IdlAccount::writable("referral_referrer_oil_ata").optional(),
+    IdlAccount::readonly("referral_config").optional(),
+    IdlAccount::writable("upline_referral").optional(),
+    IdlAccount::writable("upline_referral_oil_ata").optional(),
+];
+
+/// Claim OIL rewards with tiered referral system.
 /// 
-/// If the miner has a referrer, 1.0% of the claim goes to the referrer.
+/// If the miner has a referrer, the referrer's tier rate (minus their rebate) goes to the referrer,
+/// and the upline share goes to the referrer's own referrer.
 /// 
 /// Account structure:
-/// - Base: signer, miner, mint, recipient, treasury, treasury_tokens, system_program, token_program, associated_token_program
-/// - If miner has referrer (required): [miner_referrer, referral_referrer, referral_referrer_oil_ata]
+/// - Base: signer, config, miner, mint, recipient, treasury, treasury_tokens, system_program, token_program, associated_token_program
+/// - If miner has referrer (required): [miner_referrer, referral_referrer, referral_referrer_oil_ata, referral_config,
+///   [upline_referral, upline_referral_oil_ata]]
 pub fn claim_oil(
     signer: Pubkey,
     referrer_miner: Option<Pubkey>, // Referrer's miner PDA (if miner has referrer)
     referrer_referral: Option<Pubkey>, // Referrer's referral PDA (if miner has referrer)
     referrer_referral_oil_ata: Option<Pubkey>, // Referrer's referral OIL ATA (if miner has referrer)
+    upline_referral: Option<Pubkey>, // Upline's referral PDA (if the referral config pays an upline share)
+    upline_referral_oil_ata: Option<Pubkey>, // Upline's referral OIL ATA (if the referral config pays an upline share)
 ) -> Instruction {
+    let config_address = config_pda().0;
     let miner_address = miner_pda(signer).0;
     let treasury_address = treasury_pda().0;
     let treasury_tokens_address = get_associated_token_address(&treasury_address, &MINT_ADDRESS);
@@ -184,6 +281,7 @@ pub fn claim_oil(
     
     let mut accounts = vec![
         AccountMeta::new(signer, true),
+        AccountMeta::new_readonly(config_address, false),
         AccountMeta::new(miner_address, false),
         AccountMeta::new(MINT_ADDRESS, false),
         AccountMeta::new(recipient_address, false),
@@ -200,6 +298,11 @@ pub fn claim_oil(
         accounts.push(AccountMeta::new(miner_pubkey, false));
         accounts.push(AccountMeta::new(referral_pubkey, false));
         accounts.push(AccountMeta::new(oil_ata_pubkey, false));
+        accounts.push(AccountMeta::new_readonly(referral_config_pda().0, false));
+        if let (Some(upline_pubkey), Some(upline_oil_ata_pubkey)) = (upline_referral, upline_referral_oil_ata) {
+            accounts.push(AccountMeta::new(upline_pubkey, false));
+            accounts.push(AccountMeta::new(upline_oil_ata_pubkey, false));
+        }
     }
     
     Instruction {
@@ -209,6 +312,40 @@ pub fn claim_oil(
     }
 }
 
+/// Claim OIL through the vesting schedule instead of paying the refining fee. Unrefined OIL unlocks
+/// linearly over the configured vesting period; refined OIL and OIL unlocked so far are sent to the
+/// recipient (split with the referrer like `claim_oil`).
+///
+/// Takes the same accounts as `claim_oil`.
+pub fn vest_oil(
+    signer: Pubkey,
+    referrer_miner: Option<Pubkey>,
+    referrer_referral: Option<Pubkey>,
+    referrer_referral_oil_ata: Option<Pubkey>,
+    upline_referral: Option<Pubkey>,
+    upline_referral_oil_ata: Option<Pubkey>,
+) -> Instruction {
+    let mut ix = claim_oil(
+        signer,
+        referrer_miner,
+        referrer_referral,
+        referrer_referral_oil_ata,
+        upline_referral,
+        upline_referral_oil_ata,
+    );
+    ix.data = VestOIL {}.to_bytes();
+    ix
+}
+
+
+pub const CLOSE_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("board"),
+    IdlAccount::writable("rent_payer"),
+    IdlAccount::writable("round"),
+    IdlAccount::writable("treasury"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+];
 
 pub fn close(signer: Pubkey, round_id: u64, rent_payer: Pubkey) -> Instruction {
     let board_address = board_pda().0;
@@ -228,6 +365,24 @@ pub fn close(signer: Pubkey, round_id: u64, rent_payer: Pubkey) -> Instruction {
     }
 }
 
+pub const DEPLOY_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("authority"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("automation"),
+    IdlAccount::writable("board"),
+    IdlAccount::writable("miner"),
+    IdlAccount::writable("rig"),
+    IdlAccount::writable("round"),
+    IdlAccount::readonly("pool"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::readonly("oil_program").at(crate::ID),
+    IdlAccount::writable("referral").optional(),
+    IdlAccount::writable("whitelist").optional(),
+    IdlAccount::writable("var"),
+    IdlAccount::readonly("entropy_program").at(entropy_rng_api::ID),
+];
+
 /// Deploy SOL to prospect on squares.
 /// 
 /// This function uses native SOL transfers and is used for:
@@ -236,6 +391,7 @@ pub fn close(signer: Pubkey, round_id: u64, rent_payer: Pubkey) -> Instruction {
 /// 
 /// Pass a referrer pubkey for new miners to set up referral.
 /// Set `pooled` to true to join the mining pool (rewards shared proportionally).
+/// Before the TGE, `access_code` must be the hash of a usable premine access code.
 pub fn deploy(
     signer: Pubkey,
     authority: Pubkey,
@@ -244,10 +400,13 @@ pub fn deploy(
     squares: [bool; 25],
     referrer: Option<Pubkey>,
     pooled: bool,
+    access_code: Option<[u8; 32]>,
 ) -> Instruction {
+    let config_address = config_pda().0;
     let automation_address = automation_pda(authority).0;
     let board_address = board_pda().0;
     let miner_address = miner_pda(authority).0;
+    let rig_address = rig_pda(authority).0;
     let round_address = round_pda(round_id).0;
     let entropy_var_address = entropy_rng_api::state::var_pda(board_address, 0).0;
 
@@ -264,17 +423,20 @@ pub fn deploy(
     let has_referrer = referrer_pubkey != Pubkey::default() && referrer_pubkey != authority;
 
     // Build accounts list - must match program structure:
-    // Oil accounts: base (8) + optional referral (1) = 8-9
+    // Oil accounts: base (11) + optional referral (1) + optional whitelist (1) = 11-13
     // Entropy accounts: var + program = 2 (always exactly 2)
     let mut accounts = vec![
         AccountMeta::new(signer, true), // 0: signer
         AccountMeta::new(authority, false), // 1: authority
-        AccountMeta::new(automation_address, false), // 2: automation
-        AccountMeta::new(board_address, false), // 3: board
-        AccountMeta::new(miner_address, false), // 4: miner
-        AccountMeta::new(round_address, false), // 5: round
-        AccountMeta::new_readonly(system_program::ID, false), // 6: system_program
-        AccountMeta::new_readonly(crate::ID, false), // 7: oil_program
+        AccountMeta::new_readonly(config_address, false), // 2: config
+        AccountMeta::new(automation_address, false), // 3: automation
+        AccountMeta::new(board_address, false), // 4: board
+        AccountMeta::new(miner_address, false), // 5: miner
+        AccountMeta::new(rig_address, false), // 6: rig
+        AccountMeta::new(round_address, false), // 7: round
+        AccountMeta::new_readonly(pool_pda().0, false), // 8: pool
+        AccountMeta::new_readonly(system_program::ID, false), // 9: system_program
+        AccountMeta::new_readonly(crate::ID, false), // 10: oil_program
     ];
     
     // Add referral account if referrer is provided and not equal to authority (matches program logic)
@@ -282,6 +444,9 @@ pub fn deploy(
         let referral_address = referral_pda(referrer_pubkey).0;
         accounts.push(AccountMeta::new(referral_address, false)); // referral (optional, in oil_accounts)
     }
+    if let Some(code_hash) = access_code {
+        accounts.push(AccountMeta::new(Whitelist::pda(code_hash).0, false)); // whitelist (optional, in oil_accounts)
+    }
     
     // Entropy accounts (always exactly 2, come after all oil_accounts)
     accounts.push(AccountMeta::new(entropy_var_address, false)); // entropy_var
@@ -295,6 +460,7 @@ pub fn deploy(
             squares: mask.to_le_bytes(),
             referrer: referrer_bytes,
             pooled: if pooled { 1 } else { 0 },
+            access_code: access_code.unwrap_or_default(),
         }
         .to_bytes(),
     }
@@ -303,6 +469,14 @@ pub fn deploy(
 
 // let [pool, user_source_token, user_destination_token, a_vault, b_vault, a_token_vault, b_token_vault, a_vault_lp_mint, b_vault_lp_mint, a_vault_lp, b_vault_lp, protocol_token_fee, user_key, vault_program, token_program] =
 
+pub const WRAP_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("treasury"),
+    IdlAccount::writable("treasury_sol"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+];
+
 pub fn wrap(signer: Pubkey, use_liquidity: bool, amount: u64) -> Instruction {
     let config_address = config_pda().0;
     let treasury_address = TREASURY_ADDRESS;
@@ -325,15 +499,29 @@ pub fn wrap(signer: Pubkey, use_liquidity: bool, amount: u64) -> Instruction {
     }
 }
 
+pub const BUYBACK_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("board"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("mint").at(MINT_ADDRESS),
+    IdlAccount::writable("treasury"),
+    IdlAccount::writable("treasury_oil"),
+    IdlAccount::writable("treasury_sol"),
+    IdlAccount::readonly("token_program").at(spl_token::ID),
+    IdlAccount::readonly("oil_program").at(crate::ID),
+];
+
 pub fn buyback(signer: Pubkey, swap_accounts: &[AccountMeta], swap_data: &[u8]) -> Instruction {
     let board_address = board_pda().0;
     let mint_address = MINT_ADDRESS;
     let treasury_address = TREASURY_ADDRESS;
     let treasury_oil_address = get_associated_token_address(&treasury_address, &MINT_ADDRESS);
     let treasury_sol_address = get_associated_token_address(&treasury_address, &SOL_MINT);
+    let config_address = config_pda().0;
     let mut accounts = vec![
         AccountMeta::new(signer, true),
         AccountMeta::new(board_address, false),
+        AccountMeta::new_readonly(config_address, false),
         AccountMeta::new(mint_address, false),
         AccountMeta::new(treasury_address, false),
         AccountMeta::new(treasury_oil_address, false),
@@ -355,11 +543,77 @@ pub fn buyback(signer: Pubkey, swap_accounts: &[AccountMeta], swap_data: &[u8])
     }
 }
 
+pub const COMPOUND_LIQUID_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("mint").at(MINT_ADDRESS),
+    IdlAccount::writable("pool"),
+    IdlAccount::writable("pool_tokens"),
+    IdlAccount::writable("pool_sol"),
+    IdlAccount::readonly("sol_mint").at(SOL_MINT),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::readonly("token_program").at(spl_token::ID),
+    IdlAccount::readonly("associated_token_program").at(spl_associated_token_account::ID),
+];
+
+/// Swaps the SOL rewards earned by liquid stake into OIL and adds it to the liquid stake, raising
+/// the stOIL exchange rate. The swap accounts and data are passed through like `buyback`, with the
+/// pool as the swap authority, and the swap must return at least `min_oil_out` OIL.
+/// Treasury operator instruction.
+pub fn compound_liquid(
+    signer: Pubkey,
+    min_oil_out: u64,
+    swap_accounts: &[AccountMeta],
+    swap_data: &[u8],
+) -> Instruction {
+    let pool_address = pool_pda().0;
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new_readonly(config_pda().0, false),
+        AccountMeta::new(MINT_ADDRESS, false),
+        AccountMeta::new(pool_address, false),
+        AccountMeta::new(pool_tokens_address(), false),
+        AccountMeta::new(get_associated_token_address(&pool_address, &SOL_MINT), false),
+        AccountMeta::new_readonly(SOL_MINT, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(spl_token::ID, false),
+        AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+    ];
+    for account in swap_accounts.iter() {
+        let mut acc_clone = account.clone();
+        acc_clone.is_signer = false;
+        accounts.push(acc_clone);
+    }
+    let mut data = CompoundLiquid {
+        min_oil_out: min_oil_out.to_le_bytes(),
+    }
+    .to_bytes();
+    data.extend_from_slice(swap_data);
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data,
+    }
+}
+
+pub const BARREL_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("board"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("mint").at(MINT_ADDRESS),
+    IdlAccount::writable("treasury"),
+    IdlAccount::writable("treasury_oil"),
+    IdlAccount::readonly("token_program").at(spl_token::ID),
+    IdlAccount::readonly("oil_program").at(crate::ID),
+];
+
+/// Burns OIL held by the treasury for liquidity (0 = all).
+/// Liquidity manager instruction.
 pub fn barrel(signer: Pubkey, amount: u64) -> Instruction {
     let board_address = board_pda().0;
+    let config_address = config_pda().0;
     let mint_address = MINT_ADDRESS;
     let treasury_address = TREASURY_ADDRESS;
-    let sender_oil_address = get_associated_token_address(&signer, &MINT_ADDRESS);
     let treasury_oil_address = get_associated_token_address(&treasury_address, &MINT_ADDRESS);
     let data = Barrel {
         amount: amount.to_le_bytes(),
@@ -369,8 +623,8 @@ pub fn barrel(signer: Pubkey, amount: u64) -> Instruction {
         program_id: crate::ID,
         accounts: vec![
             AccountMeta::new(signer, true),
-            AccountMeta::new(sender_oil_address, false),
             AccountMeta::new(board_address, false),
+            AccountMeta::new_readonly(config_address, false),
             AccountMeta::new(mint_address, false),
             AccountMeta::new(treasury_address, false),
             AccountMeta::new(treasury_oil_address, false),
@@ -381,9 +635,110 @@ pub fn barrel(signer: Pubkey, amount: u64) -> Instruction {
     }
 }
 
+/// Deposits SOL and OIL held for liquidity into the pool through the swap program.
+/// Liquidity manager instruction.
+pub fn add_liquidity(signer: Pubkey, lp_mint: Pubkey, pool_accounts: &[AccountMeta], pool_data: &[u8]) -> Instruction {
+    liquidity(signer, lp_mint, pool_accounts, AddLiquidity {}.to_bytes(), pool_data)
+}
+
+/// Burns LP tokens held by the treasury for SOL and OIL held for liquidity.
+/// Liquidity manager instruction.
+pub fn remove_liquidity(signer: Pubkey, lp_mint: Pubkey, pool_accounts: &[AccountMeta], pool_data: &[u8]) -> Instruction {
+    liquidity(signer, lp_mint, pool_accounts, RemoveLiquidity {}.to_bytes(), pool_data)
+}
+
+/// Collects the pool fees earned by the treasury's position.
+/// Liquidity manager instruction.
+pub fn harvest_liquidity(signer: Pubkey, lp_mint: Pubkey, pool_accounts: &[AccountMeta], pool_data: &[u8]) -> Instruction {
+    liquidity(signer, lp_mint, pool_accounts, HarvestLiquidity {}.to_bytes(), pool_data)
+}
+
+/// Swaps between the SOL and OIL held for liquidity.
+/// Liquidity manager instruction.
+pub fn rebalance_liquidity(signer: Pubkey, lp_mint: Pubkey, pool_accounts: &[AccountMeta], pool_data: &[u8]) -> Instruction {
+    liquidity(signer, lp_mint, pool_accounts, RebalanceLiquidity {}.to_bytes(), pool_data)
+}
+
+pub const LIQUIDITY_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("board"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("mint").at(MINT_ADDRESS),
+    IdlAccount::readonly("lp_mint"),
+    IdlAccount::writable("treasury"),
+    IdlAccount::writable("treasury_oil"),
+    IdlAccount::writable("treasury_sol"),
+    IdlAccount::writable("treasury_lp"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::readonly("token_program").at(spl_token::ID),
+    IdlAccount::readonly("associated_token_program").at(spl_associated_token_account::ID),
+    IdlAccount::readonly("oil_program").at(crate::ID),
+];
+
+/// Builds a liquidity instruction forwarding `pool_data` to the liquidity program. The treasury's LP
+/// token account is created on first use, paid by the signer.
+/// Accounts: signer, board, config, mint, lp_mint, treasury, treasury_oil, treasury_sol, treasury_lp, system_program,
+/// token_program, associated_token_program, oil_program, pool accounts...
+fn liquidity(signer: Pubkey, lp_mint: Pubkey, pool_accounts: &[AccountMeta], mut data: Vec<u8>, pool_data: &[u8]) -> Instruction {
+    let board_address = board_pda().0;
+    let config_address = config_pda().0;
+    let treasury_address = TREASURY_ADDRESS;
+    let treasury_oil_address = get_associated_token_address(&treasury_address, &MINT_ADDRESS);
+    let treasury_sol_address = get_associated_token_address(&treasury_address, &SOL_MINT);
+    let treasury_lp_address = get_associated_token_address(&treasury_address, &lp_mint);
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new(board_address, false),
+        AccountMeta::new_readonly(config_address, false),
+        AccountMeta::new(MINT_ADDRESS, false),
+        AccountMeta::new_readonly(lp_mint, false),
+        AccountMeta::new(treasury_address, false),
+        AccountMeta::new(treasury_oil_address, false),
+        AccountMeta::new(treasury_sol_address, false),
+        AccountMeta::new(treasury_lp_address, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(spl_token::ID, false),
+        AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+    for account in pool_accounts.iter() {
+        let mut acc_clone = account.clone();
+        acc_clone.is_signer = false;
+        accounts.push(acc_clone);
+    }
+    data.extend_from_slice(pool_data);
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data,
+    }
+}
 
 // let [signer_info, board_info, config_info, fee_collector_info, mint_info, round_info, round_next_info, top_miner_info, treasury_info, treasury_tokens_info, system_program, token_program, oil_program, slot_hashes_sysvar] =
 
+pub const RESET_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("board"),
+    IdlAccount::writable("config"),
+    IdlAccount::writable("fee_collector"),
+    IdlAccount::writable("mint").at(MINT_ADDRESS),
+    IdlAccount::writable("round"),
+    IdlAccount::writable("round_next"),
+    IdlAccount::writable("top_miner"),
+    IdlAccount::writable("treasury"),
+    IdlAccount::writable("pool"),
+    IdlAccount::writable("treasury_tokens"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::readonly("token_program").at(spl_token::ID),
+    IdlAccount::readonly("oil_program").at(crate::ID),
+    IdlAccount::readonly("slot_hashes").at(sysvar::slot_hashes::ID),
+    IdlAccount::readonly("sol_mint").at(SOL_MINT),
+    IdlAccount::writable("var"),
+    IdlAccount::readonly("entropy_program").at(entropy_rng_api::ID),
+    IdlAccount::writable("mint_authority"),
+    IdlAccount::readonly("mint_program").at(oil_mint_api::ID),
+];
+
 pub fn reset(
     signer: Pubkey,
     fee_collector: Pubkey,
@@ -451,9 +806,47 @@ pub fn reset_with_miners(
     
     reset_instruction
 }
+
+/// Projects the OIL supply under the emission schedule, assuming `rounds_per_week` rounds mint
+/// every week. Returns (emission week, supply at the start of the week) at the current week, at
+/// the start of each later epoch, and at the week `MAX_SUPPLY` is reached.
+pub fn project_supply(supply: u64, emission_week: u64, rounds_per_week: u64) -> Vec<(u64, u64)> {
+    let mut week = emission_week;
+    let mut supply = supply.min(MAX_SUPPLY);
+    let mut points = vec![(week, supply)];
+    if rounds_per_week == 0 {
+        return points;
+    }
+    while supply < MAX_SUPPLY {
+        let weekly = emission_per_round(week).saturating_mul(rounds_per_week);
+        let weeks_to_cap = (MAX_SUPPLY - supply).div_ceil(weekly);
+        match EMISSION_SCHEDULE.iter().map(|(start_week, _)| *start_week).find(|start_week| *start_week > week) {
+            Some(next_epoch) if next_epoch - week < weeks_to_cap => {
+                supply += weekly * (next_epoch - week);
+                week = next_epoch;
+            }
+            _ => {
+                supply = MAX_SUPPLY;
+                week += weeks_to_cap;
+            }
+        }
+        points.push((week, supply));
+    }
+    points
+}
     
 // let [signer_info, automation_info, board_info, miner_info, round_info, treasury_info, system_program] =
 
+pub const CHECKPOINT_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("board"),
+    IdlAccount::writable("config"),
+    IdlAccount::writable("miner"),
+    IdlAccount::writable("round"),
+    IdlAccount::writable("treasury"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+];
+
 pub fn checkpoint(signer: Pubkey, authority: Pubkey, round_id: u64) -> Instruction {
     let miner_address = miner_pda(authority).0;
     let board_address = board_pda().0;
@@ -475,6 +868,28 @@ pub fn checkpoint(signer: Pubkey, authority: Pubkey, round_id: u64) -> Instructi
     }
 }
 
+/// Accounts appended to timelocked admin instructions: pending_admin_action, system_program, board, oil_program.
+fn admin_timelock_accounts(action: OilInstruction) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta::new(pending_admin_action_pda(action as u8).0, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(board_pda().0, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ]
+}
+
+pub const SET_ADMIN_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("config"),
+    IdlAccount::writable("pending_admin_action"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::writable("board"),
+    IdlAccount::readonly("oil_program").at(crate::ID),
+];
+
+/// Proposes a new admin. The new admin calls `accept_admin` once the timelock has passed.
+/// Admin-only instruction.
+/// Accounts: signer (admin), config, pending_admin_action, system_program, board, oil_program
 pub fn set_admin(signer: Pubkey, admin: Pubkey) -> Instruction {
     let config_address = config_pda().0;
     Instruction {
@@ -482,8 +897,10 @@ pub fn set_admin(signer: Pubkey, admin: Pubkey) -> Instruction {
         accounts: vec![
             AccountMeta::new(signer, true),
             AccountMeta::new(config_address, false),
-            AccountMeta::new_readonly(system_program::ID, false),
-        ],
+        ]
+        .into_iter()
+        .chain(admin_timelock_accounts(OilInstruction::SetAdmin))
+        .collect(),
         data: SetAdmin {
             admin: admin.to_bytes(),
         }
@@ -491,15 +908,149 @@ pub fn set_admin(signer: Pubkey, admin: Pubkey) -> Instruction {
     }
 }
 
-pub fn set_admin_fee(signer: Pubkey, admin_fee: u64) -> Instruction {
+pub const ACCEPT_ADMIN_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("config"),
+    IdlAccount::writable("pending_admin_action"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::writable("board"),
+    IdlAccount::readonly("oil_program").at(crate::ID),
+];
+
+/// Accepts a proposed admin handover once the timelock has passed.
+/// Must be signed by the proposed admin.
+/// Accounts: signer (new admin), config, pending_admin_action, system_program, board, oil_program
+pub fn accept_admin(signer: Pubkey) -> Instruction {
     let config_address = config_pda().0;
     Instruction {
         program_id: crate::ID,
         accounts: vec![
             AccountMeta::new(signer, true),
             AccountMeta::new(config_address, false),
-            AccountMeta::new_readonly(system_program::ID, false),
+        ]
+        .into_iter()
+        .chain(admin_timelock_accounts(OilInstruction::SetAdmin))
+        .collect(),
+        data: AcceptAdmin {}.to_bytes(),
+    }
+}
+
+pub const SET_ROLE_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("config"),
+    IdlAccount::writable("pending_admin_action"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::writable("board"),
+    IdlAccount::readonly("oil_program").at(crate::ID),
+];
+
+/// Sets the members and signing threshold of a role (0 unassigns the role, which then falls back
+/// to the admin). The first call queues the change, a second identical call applies it once the
+/// timelock has passed. Co-signers of an M-of-N role are appended as extra signer accounts.
+/// An admin role must keep the current admin as a member; the admin key itself only changes
+/// through `set_admin`/`accept_admin`.
+/// Admin-only instruction.
+/// Accounts: signer (admin), config, pending_admin_action, system_program, board, oil_program
+pub fn set_role(signer: Pubkey, role: Role, threshold: u8, members: &[Pubkey]) -> Instruction {
+    let config_address = config_pda().0;
+    let mut role_members = [[0u8; 32]; MAX_ROLE_MEMBERS];
+    for (slot, member) in role_members.iter_mut().zip(members) {
+        *slot = member.to_bytes();
+    }
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+        ]
+        .into_iter()
+        .chain(admin_timelock_accounts(OilInstruction::SetRole))
+        .collect(),
+        data: SetRole {
+            role: role as u8,
+            threshold,
+            members: role_members,
+        }
+        .to_bytes(),
+    }
+}
+
+pub const SET_PAUSE_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("config"),
+];
+
+/// Sets the bitmap of paused subsystems (see the `PAUSE_*` flags). The admin can pause and
+/// unpause, the guardian can only pause.
+/// Accounts: signer (admin or guardian), config
+pub fn set_pause(signer: Pubkey, paused: u64) -> Instruction {
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+        ],
+        data: SetPause {
+            paused: paused.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+pub const CANCEL_ADMIN_ACTION_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("pending_admin_action"),
+    IdlAccount::writable("board"),
+    IdlAccount::readonly("oil_program").at(crate::ID),
+];
+
+/// Cancels a queued admin action (e.g. `OilInstruction::SetAdmin`), refunding its rent.
+/// Admin-only instruction.
+/// Accounts: signer (admin), config, pending_admin_action, board, oil_program
+pub fn cancel_admin_action(signer: Pubkey, action: OilInstruction) -> Instruction {
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(pending_admin_action_pda(action as u8).0, false),
+            AccountMeta::new(board_pda().0, false),
+            AccountMeta::new_readonly(crate::ID, false),
         ],
+        data: CancelAdminAction {
+            action: action as u8,
+        }
+        .to_bytes(),
+    }
+}
+
+pub const SET_ADMIN_FEE_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("config"),
+    IdlAccount::writable("pending_admin_action"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::writable("board"),
+    IdlAccount::readonly("oil_program").at(crate::ID),
+];
+
+/// Sets the admin fee. The first call queues the change, a second identical call applies it
+/// once the timelock has passed.
+/// Admin-only instruction.
+/// Accounts: signer (admin), config, pending_admin_action, system_program, board, oil_program
+pub fn set_admin_fee(signer: Pubkey, admin_fee: u64) -> Instruction {
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+        ]
+        .into_iter()
+        .chain(admin_timelock_accounts(OilInstruction::SetAdminFee))
+        .collect(),
         data: SetAdminFee {
             admin_fee: admin_fee.to_le_bytes(),
         }
@@ -507,6 +1058,121 @@ pub fn set_admin_fee(signer: Pubkey, admin_fee: u64) -> Instruction {
     }
 }
 
+pub const SET_STAKE_BOOST_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("config"),
+    IdlAccount::writable("pending_admin_action"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::writable("board"),
+    IdlAccount::readonly("oil_program").at(crate::ID),
+];
+
+/// Sets the stake-weighted mining boost: up to `max_bps` at `share_bps` of the total stake score.
+/// The first call queues the change, a second identical call applies it once the timelock has passed.
+/// Admin-only instruction.
+/// Accounts: signer (admin), config, pending_admin_action, system_program, board, oil_program
+pub fn set_stake_boost(signer: Pubkey, max_bps: u64, share_bps: u64) -> Instruction {
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+        ]
+        .into_iter()
+        .chain(admin_timelock_accounts(OilInstruction::SetStakeBoost))
+        .collect(),
+        data: SetStakeBoost {
+            max_bps: max_bps.to_le_bytes(),
+            share_bps: share_bps.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+pub const SET_SEEKER_BONUS_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("config"),
+    IdlAccount::writable("pending_admin_action"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::writable("board"),
+    IdlAccount::readonly("oil_program").at(crate::ID),
+];
+
+/// Sets the Seeker bonus: a `refining_discount_bps` discount on the refining fee for Seeker miners.
+/// The first call queues the change, a second identical call applies it once the timelock has passed.
+/// Admin-only instruction.
+/// Accounts: signer (admin), config, pending_admin_action, system_program, board, oil_program
+pub fn set_seeker_bonus(signer: Pubkey, refining_discount_bps: u64) -> Instruction {
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+        ]
+        .into_iter()
+        .chain(admin_timelock_accounts(OilInstruction::SetSeekerBonus))
+        .collect(),
+        data: SetSeekerBonus {
+            refining_discount_bps: refining_discount_bps.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+pub const SET_REFINING_FEE_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("config"),
+    IdlAccount::writable("pending_admin_action"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::writable("board"),
+    IdlAccount::readonly("oil_program").at(crate::ID),
+];
+
+/// Sets the refining fees on block-based (`refining_fee_bps`) and auction-based
+/// (`auction_refining_fee_bps`) OIL claims, and the `vesting_period` (seconds) of fee-free vested claims.
+/// The first call queues the change, a second identical call applies it once the timelock has passed.
+/// Admin-only instruction.
+/// Accounts: signer (admin), config, pending_admin_action, system_program, board, oil_program
+pub fn set_refining_fee(
+    signer: Pubkey,
+    refining_fee_bps: u64,
+    auction_refining_fee_bps: u64,
+    vesting_period: i64,
+) -> Instruction {
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+        ]
+        .into_iter()
+        .chain(admin_timelock_accounts(OilInstruction::SetRefiningFee))
+        .collect(),
+        data: SetRefiningFee {
+            refining_fee_bps: refining_fee_bps.to_le_bytes(),
+            auction_refining_fee_bps: auction_refining_fee_bps.to_le_bytes(),
+            vesting_period: vesting_period.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+pub const SET_FEE_COLLECTOR_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("config"),
+    IdlAccount::writable("pending_admin_action"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::writable("board"),
+    IdlAccount::readonly("oil_program").at(crate::ID),
+];
+
+/// Sets the fee collector. The first call queues the change, a second identical call applies it
+/// once the timelock has passed.
+/// Admin-only instruction.
+/// Accounts: signer (admin), config, pending_admin_action, system_program, board, oil_program
 pub fn set_fee_collector(signer: Pubkey, fee_collector: Pubkey) -> Instruction {
     let config_address = config_pda().0;
     Instruction {
@@ -514,8 +1180,10 @@ pub fn set_fee_collector(signer: Pubkey, fee_collector: Pubkey) -> Instruction {
         accounts: vec![
             AccountMeta::new(signer, true),
             AccountMeta::new(config_address, false),
-            AccountMeta::new_readonly(system_program::ID, false),
-        ],
+        ]
+        .into_iter()
+        .chain(admin_timelock_accounts(OilInstruction::SetFeeCollector))
+        .collect(),
         data: SetFeeCollector {
             fee_collector: fee_collector.to_bytes(),
         }
@@ -523,10 +1191,21 @@ pub fn set_fee_collector(signer: Pubkey, fee_collector: Pubkey) -> Instruction {
     }
 }
 
+pub const SET_TGE_TIMESTAMP_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("config"),
+    IdlAccount::writable("pending_admin_action"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::writable("board"),
+    IdlAccount::readonly("oil_program").at(crate::ID),
+];
+
 /// Sets the TGE (Token Generation Event) timestamp.
 /// If current time < tge_timestamp, pre-mine is active.
 /// Set to 0 to disable pre-mine.
+/// The first call queues the change, a second identical call applies it once the timelock has passed.
 /// Admin-only instruction.
+/// Accounts: signer (admin), config, pending_admin_action, system_program, board, oil_program
 pub fn set_tge_timestamp(signer: Pubkey, tge_timestamp: i64) -> Instruction {
     let config_address = config_pda().0;
     Instruction {
@@ -534,8 +1213,10 @@ pub fn set_tge_timestamp(signer: Pubkey, tge_timestamp: i64) -> Instruction {
         accounts: vec![
             AccountMeta::new(signer, true),
             AccountMeta::new(config_address, false),
-            AccountMeta::new_readonly(system_program::ID, false),
-        ],
+        ]
+        .into_iter()
+        .chain(admin_timelock_accounts(OilInstruction::SetTgeTimestamp))
+        .collect(),
         data: SetTgeTimestamp {
             tge_timestamp: tge_timestamp.to_le_bytes(),
         }
@@ -543,6 +1224,21 @@ pub fn set_tge_timestamp(signer: Pubkey, tge_timestamp: i64) -> Instruction {
     }
 }
 
+pub const SET_AUCTION_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("auction"),
+    IdlAccount::writable("pending_admin_action"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::writable("board"),
+    IdlAccount::readonly("oil_program").at(crate::ID),
+];
+
+/// Configures auction parameters, including the per-well price curve, floor price and
+/// next-epoch price multiplier (0 = protocol default for floor price and multiplier).
+/// The first call queues the change, a second identical call applies it once the timelock has passed.
+/// Auction manager instruction.
+/// Accounts: signer (admin), config, auction, pending_admin_action, system_program, board, oil_program
 pub fn set_auction(
     signer: Pubkey,
     halving_period_seconds: u64,
@@ -550,6 +1246,9 @@ pub fn set_auction(
     base_mining_rates: [u64; 4],
     auction_duration_seconds: u64,
     starting_prices: [u64; 4],
+    price_curves: [PriceCurve; 4],
+    floor_prices: [u64; 4],
+    price_multipliers_bps: [u64; 4],
     _well_id: u64, // Kept for backwards compatibility, but not used (always updates auction only)
 ) -> Instruction {
     let config_address = config_pda().0;
@@ -561,7 +1260,10 @@ pub fn set_auction(
             AccountMeta::new(signer, true),
             AccountMeta::new_readonly(config_address, false),
             AccountMeta::new(auction_address, false),
-        ],
+        ]
+        .into_iter()
+        .chain(admin_timelock_accounts(OilInstruction::SetAuction))
+        .collect(),
         data: SetAuction {
             halving_period_seconds: halving_period_seconds.to_le_bytes(),
             last_halving_time: last_halving_time.to_le_bytes(),
@@ -579,36 +1281,131 @@ pub fn set_auction(
                 starting_prices[3].to_le_bytes(),
             ],
             well_id: 4u64.to_le_bytes(), // Always use 4 to indicate auction-only update
+            floor_prices: [
+                floor_prices[0].to_le_bytes(),
+                floor_prices[1].to_le_bytes(),
+                floor_prices[2].to_le_bytes(),
+                floor_prices[3].to_le_bytes(),
+            ],
+            price_multipliers_bps: [
+                price_multipliers_bps[0].to_le_bytes(),
+                price_multipliers_bps[1].to_le_bytes(),
+                price_multipliers_bps[2].to_le_bytes(),
+                price_multipliers_bps[3].to_le_bytes(),
+            ],
+            price_curves: [
+                (price_curves[0] as u64).to_le_bytes(),
+                (price_curves[1] as u64).to_le_bytes(),
+                (price_curves[2] as u64).to_le_bytes(),
+                (price_curves[3] as u64).to_le_bytes(),
+            ],
         }
         .to_bytes(),
     }
 }
 
-// let [signer_info, mint_info, sender_info, stake_info, stake_tokens_info, treasury_info, system_program, token_program, associated_token_program] =
-
-pub fn deposit(signer: Pubkey, authority: Pubkey, amount: u64, lock_duration_days: u64, stake_id: u64) -> Instruction {
-    let mint_address = MINT_ADDRESS;
-    let stake_address = stake_pda_with_id(authority, stake_id).0; // Derive from authority, not signer
-    let stake_tokens_address = get_associated_token_address(&stake_address, &MINT_ADDRESS);
-    let sender_address = get_associated_token_address(&authority, &MINT_ADDRESS); // Authority's ATA
-    let pool_address = pool_pda().0;
-    let pool_tokens_address = pool_tokens_address();
-    let miner_address = miner_pda(authority).0; // Derive from authority
-    Instruction {
-        program_id: crate::ID,
-        accounts: vec![
-            AccountMeta::new(signer, true), // payer (session payer or regular wallet, pays fees)
-            AccountMeta::new(authority, true), // authority (user's wallet, signs token transfer and used for PDA derivation)
-            AccountMeta::new(mint_address, false),
-            AccountMeta::new(sender_address, false),
-            AccountMeta::new(stake_address, false),
-            AccountMeta::new(stake_tokens_address, false),
-            AccountMeta::new(pool_address, false),
-            AccountMeta::new(pool_tokens_address, false),
-            AccountMeta::new(miner_address, false),
-            AccountMeta::new_readonly(system_program::ID, false),
-            AccountMeta::new_readonly(spl_token::ID, false),
-            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+pub const SCHEDULE_HALVINGS_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("auction"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::writable("well_0"),
+    IdlAccount::writable("well_1"),
+    IdlAccount::writable("well_2"),
+    IdlAccount::writable("well_3"),
+];
+
+/// Schedules a change to the halving schedule at `effective_time`, or cancels a pending
+/// change if `effective_time` is 0. Wells are checkpointed under the current schedule first.
+/// Auction manager instruction.
+/// Accounts: signer (admin), config, auction, system_program, well_0..well_3
+pub fn schedule_halvings(
+    signer: Pubkey,
+    effective_time: i64,
+    first_halving_delay_seconds: u64,
+    halving_period_seconds: u64,
+    first_halving_decay_bps: u64,
+    halving_decay_bps: u64,
+) -> Instruction {
+    let config_address = config_pda().0;
+    let auction_address = auction_pda().0;
+    
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new_readonly(config_address, false),
+        AccountMeta::new(auction_address, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+    for well_id in 0..4 {
+        accounts.push(AccountMeta::new(well_pda(well_id).0, false));
+    }
+    
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ScheduleHalvings {
+            effective_time: effective_time.to_le_bytes(),
+            first_halving_delay_seconds: first_halving_delay_seconds.to_le_bytes(),
+            halving_period_seconds: halving_period_seconds.to_le_bytes(),
+            first_halving_decay_bps: first_halving_decay_bps.to_le_bytes(),
+            halving_decay_bps: halving_decay_bps.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+/// Price of a well at a given unix timestamp, computed exactly as the program does
+/// (price curve, floor price and auction duration are read from the auction account).
+pub fn well_price(well: &Well, auction: &Auction, unix_timestamp: i64) -> u64 {
+    well.price_at(auction, unix_timestamp)
+}
+
+// let [signer_info, mint_info, sender_info, stake_info, stake_tokens_info, treasury_info, system_program, token_program, associated_token_program] =
+
+pub const DEPOSIT_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::signer("payer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("mint").at(MINT_ADDRESS),
+    IdlAccount::writable("sender"),
+    IdlAccount::writable("stake"),
+    IdlAccount::writable("stake_tokens"),
+    IdlAccount::writable("pool"),
+    IdlAccount::writable("pool_tokens"),
+    IdlAccount::writable("miner"),
+    IdlAccount::writable("rig"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::readonly("token_program").at(spl_token::ID),
+    IdlAccount::readonly("associated_token_program").at(spl_associated_token_account::ID),
+];
+
+pub fn deposit(signer: Pubkey, payer: Pubkey, amount: u64, lock_duration_days: u64, stake_id: u64) -> Instruction {
+    let config_address = config_pda().0;
+    let mint_address = MINT_ADDRESS;
+    let stake_address = stake_pda_with_id(signer, stake_id).0;
+    let stake_tokens_address = get_associated_token_address(&stake_address, &MINT_ADDRESS);
+    let sender_address = get_associated_token_address(&signer, &MINT_ADDRESS);
+    let pool_address = pool_pda().0;
+    let pool_tokens_address = pool_tokens_address();
+    let miner_address = miner_pda(signer).0;
+    let rig_address = rig_pda(signer).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true), // signer (owns the stake and the OIL being deposited)
+            AccountMeta::new(payer, true), // payer (pays rent for new accounts, may be the signer)
+            AccountMeta::new_readonly(config_address, false), // config
+            AccountMeta::new(mint_address, false),
+            AccountMeta::new(sender_address, false),
+            AccountMeta::new(stake_address, false),
+            AccountMeta::new(stake_tokens_address, false),
+            AccountMeta::new(pool_address, false),
+            AccountMeta::new(pool_tokens_address, false),
+            AccountMeta::new(miner_address, false),
+            AccountMeta::new(rig_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
         ],
         data: Deposit {
             amount: amount.to_le_bytes(),
@@ -621,21 +1418,41 @@ pub fn deposit(signer: Pubkey, authority: Pubkey, amount: u64, lock_duration_day
 
 // let [signer_info, mint_info, recipient_info, stake_info, stake_tokens_info, treasury_info, system_program, token_program, associated_token_program] =
 
-pub fn withdraw(signer: Pubkey, authority: Pubkey, amount: u64, stake_id: u64) -> Instruction {
-    let stake_address = stake_pda_with_id(authority, stake_id).0; // Derive from authority, not signer
+pub const WITHDRAW_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("mint").at(MINT_ADDRESS),
+    IdlAccount::writable("recipient"),
+    IdlAccount::writable("stake"),
+    IdlAccount::writable("stake_tokens"),
+    IdlAccount::writable("pool"),
+    IdlAccount::writable("pool_tokens"),
+    IdlAccount::writable("miner"),
+    IdlAccount::writable("rig"),
+    IdlAccount::writable("treasury"),
+    IdlAccount::writable("treasury_oil"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::readonly("token_program").at(spl_token::ID),
+    IdlAccount::readonly("associated_token_program").at(spl_associated_token_account::ID),
+];
+
+pub fn withdraw(signer: Pubkey, amount: u64, stake_id: u64) -> Instruction {
+    let config_address = config_pda().0;
+    let stake_address = stake_pda_with_id(signer, stake_id).0;
     let stake_tokens_address = get_associated_token_address(&stake_address, &MINT_ADDRESS);
     let mint_address = MINT_ADDRESS;
-    let recipient_address = get_associated_token_address(&authority, &MINT_ADDRESS); // Authority's ATA
+    let recipient_address = get_associated_token_address(&signer, &MINT_ADDRESS);
     let pool_address = pool_pda().0;
     let pool_tokens_address = pool_tokens_address();
-    let miner_address = miner_pda(authority).0; // Derive from authority
+    let miner_address = miner_pda(signer).0;
+    let rig_address = rig_pda(signer).0;
     let treasury_address = treasury_pda().0;
     let treasury_tokens_address = treasury_tokens_address();
     Instruction {
         program_id: crate::ID,
         accounts: vec![
-            AccountMeta::new(signer, true), // payer (session payer or regular wallet)
-            AccountMeta::new(authority, false), // authority (user's wallet, for PDA derivation)
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false), // config
             AccountMeta::new(mint_address, false),
             AccountMeta::new(recipient_address, false),
             AccountMeta::new(stake_address, false),
@@ -643,6 +1460,7 @@ pub fn withdraw(signer: Pubkey, authority: Pubkey, amount: u64, stake_id: u64) -
             AccountMeta::new(pool_address, false),
             AccountMeta::new(pool_tokens_address, false),
             AccountMeta::new(miner_address, false),
+            AccountMeta::new(rig_address, false),
             AccountMeta::new(treasury_address, false), // Treasury account (writable, signed by PDA)
             AccountMeta::new(treasury_tokens_address, false), // Treasury OIL token account (writable, signed by PDA)
             AccountMeta::new_readonly(system_program::ID, false),
@@ -657,26 +1475,136 @@ pub fn withdraw(signer: Pubkey, authority: Pubkey, amount: u64, stake_id: u64) -
     }
 }
 
+pub const DEPOSIT_LIQUID_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("mint").at(MINT_ADDRESS),
+    IdlAccount::writable("sender"),
+    IdlAccount::writable("pool"),
+    IdlAccount::writable("pool_tokens"),
+    IdlAccount::writable("stoil_mint"),
+    IdlAccount::writable("recipient"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::readonly("token_program").at(spl_token::ID),
+    IdlAccount::readonly("associated_token_program").at(spl_associated_token_account::ID),
+];
+
+/// Stakes `amount` OIL unlocked in the liquid stake, minting stOIL to the signer at the pool
+/// exchange rate. Fails unless it mints at least `min_shares` stOIL. The stOIL mint is created on
+/// the first deposit.
+pub fn deposit_liquid(signer: Pubkey, amount: u64, min_shares: u64) -> Instruction {
+    let config_address = config_pda().0;
+    let sender_address = get_associated_token_address(&signer, &MINT_ADDRESS);
+    let pool_address = pool_pda().0;
+    let pool_tokens_address = pool_tokens_address();
+    let stoil_mint_address = stoil_mint_pda().0;
+    let recipient_address = get_associated_token_address(&signer, &stoil_mint_address);
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(MINT_ADDRESS, false),
+            AccountMeta::new(sender_address, false),
+            AccountMeta::new(pool_address, false),
+            AccountMeta::new(pool_tokens_address, false),
+            AccountMeta::new(stoil_mint_address, false),
+            AccountMeta::new(recipient_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+        ],
+        data: DepositLiquid {
+            amount: amount.to_le_bytes(),
+            min_shares: min_shares.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+pub const REDEEM_LIQUID_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("mint").at(MINT_ADDRESS),
+    IdlAccount::writable("recipient"),
+    IdlAccount::writable("pool"),
+    IdlAccount::writable("pool_tokens"),
+    IdlAccount::writable("stoil_mint"),
+    IdlAccount::writable("sender"),
+    IdlAccount::writable("pool_stoil"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::readonly("token_program").at(spl_token::ID),
+    IdlAccount::readonly("associated_token_program").at(spl_associated_token_account::ID),
+];
+
+/// Burns `amount` stOIL, redeeming the OIL it backs from the pool to the signer.
+pub fn redeem_liquid(signer: Pubkey, amount: u64) -> Instruction {
+    let config_address = config_pda().0;
+    let recipient_address = get_associated_token_address(&signer, &MINT_ADDRESS);
+    let pool_address = pool_pda().0;
+    let pool_tokens_address = pool_tokens_address();
+    let stoil_mint_address = stoil_mint_pda().0;
+    let sender_address = get_associated_token_address(&signer, &stoil_mint_address);
+    let pool_stoil_address = get_associated_token_address(&pool_address, &stoil_mint_address);
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(MINT_ADDRESS, false),
+            AccountMeta::new(recipient_address, false),
+            AccountMeta::new(pool_address, false),
+            AccountMeta::new(pool_tokens_address, false),
+            AccountMeta::new(stoil_mint_address, false),
+            AccountMeta::new(sender_address, false),
+            AccountMeta::new(pool_stoil_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+        ],
+        data: RedeemLiquid {
+            amount: amount.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
 // let [signer_info, automation_info, miner_info, system_program] = accounts else {
 
-/// Reload SOL from miner account to automation balance with single-tier referral system.
+pub const RELOAD_SOL_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("automation"),
+    IdlAccount::writable("miner"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::writable("miner_referrer").optional(),
+    IdlAccount::writable("referral_referrer").optional(),
+    IdlAccount::readonly("referral_config").optional(),
+    IdlAccount::writable("upline_referral").optional(),
+];
+
+/// Reload SOL from miner account to automation balance with tiered referral system.
 /// 
-/// If the miner has a referrer, 1.0% of the claim goes to the referrer.
+/// If the miner has a referrer, the referrer's tier rate (minus their rebate) goes to the referrer,
+/// and the upline share goes to the referrer's own referrer.
 /// 
 /// Account structure:
-/// - Base: signer, automation, miner, system_program
-/// - If miner has referrer (required): [miner_referrer, referral_referrer]
+/// - Base: signer, config, automation, miner, system_program
+/// - If miner has referrer (required): [miner_referrer, referral_referrer, referral_config, [upline_referral]]
 pub fn reload_sol(
     signer: Pubkey,
     authority: Pubkey,
     referrer_miner: Option<Pubkey>,
     referrer_referral: Option<Pubkey>,
+    upline_referral: Option<Pubkey>, // Upline's referral PDA (if the referral config pays an upline share)
 ) -> Instruction {
+    let config_address = config_pda().0;
     let automation_address = automation_pda(authority).0;
     let miner_address = miner_pda(authority).0;
     
     let mut accounts = vec![
         AccountMeta::new(signer, true),
+        AccountMeta::new_readonly(config_address, false),
         AccountMeta::new(automation_address, false),
         AccountMeta::new(miner_address, false),
         AccountMeta::new_readonly(system_program::ID, false),
@@ -686,6 +1614,10 @@ pub fn reload_sol(
     if let (Some(miner_ref), Some(referral_ref)) = (referrer_miner, referrer_referral) {
         accounts.push(AccountMeta::new(miner_ref, false));
         accounts.push(AccountMeta::new(referral_ref, false));
+        accounts.push(AccountMeta::new_readonly(referral_config_pda().0, false));
+        if let Some(upline_pubkey) = upline_referral {
+            accounts.push(AccountMeta::new(upline_pubkey, false));
+        }
     }
     
     Instruction {
@@ -697,14 +1629,24 @@ pub fn reload_sol(
 
 // let [signer_info, mint_info, recipient_info, stake_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
 
+pub const CLAIM_YIELD_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("stake"),
+    IdlAccount::writable("pool"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+];
+
 /// Claim SOL yield from staking. Stakers earn SOL rewards (2% of round winnings), not OIL.
 pub fn claim_yield(signer: Pubkey, amount: u64, stake_id: u64) -> Instruction {
+    let config_address = config_pda().0;
     let stake_address = stake_pda_with_id(signer, stake_id).0;
     let pool_address = pool_pda().0;
     Instruction {
         program_id: crate::ID,
         accounts: vec![
             AccountMeta::new(signer, true), // signer and writable for receiving SOL
+            AccountMeta::new_readonly(config_address, false), // config
             AccountMeta::new(stake_address, false),
             AccountMeta::new(pool_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
@@ -716,6 +1658,16 @@ pub fn claim_yield(signer: Pubkey, amount: u64, stake_id: u64) -> Instruction {
     }
 }
 
+pub const NEW_VAR_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("board"),
+    IdlAccount::writable("config"),
+    IdlAccount::writable("provider"),
+    IdlAccount::writable("var"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::readonly("entropy_program").at(entropy_rng_api::ID),
+];
+
 pub fn new_var(
     signer: Pubkey,
     provider: Pubkey,
@@ -746,6 +1698,52 @@ pub fn new_var(
     }
 }
 
+pub const SET_LP_MINT_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("treasury"),
+    IdlAccount::readonly("lp_mint"),
+    IdlAccount::writable("pending_admin_action"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::writable("board"),
+    IdlAccount::readonly("oil_program").at(crate::ID),
+];
+
+/// Sets the LP token mint of the treasury's pool position. The first call queues the change, a
+/// second identical call applies it once the timelock has passed. Fails while the treasury holds LP tokens.
+/// Admin-only instruction.
+/// Accounts: signer (admin), config, treasury, lp_mint, pending_admin_action, system_program, board, oil_program
+pub fn set_lp_mint(signer: Pubkey, lp_mint: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(TREASURY_ADDRESS, false),
+            AccountMeta::new_readonly(lp_mint, false),
+        ]
+        .into_iter()
+        .chain(admin_timelock_accounts(OilInstruction::SetLpMint))
+        .collect(),
+        data: SetLpMint {}.to_bytes(),
+    }
+}
+
+pub const SET_SWAP_PROGRAM_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("config"),
+    IdlAccount::readonly("new_program"),
+    IdlAccount::writable("pending_admin_action"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::writable("board"),
+    IdlAccount::readonly("oil_program").at(crate::ID),
+];
+
+/// Sets the swap program. The first call queues the change, a second identical call applies it
+/// once the timelock has passed.
+/// Admin-only instruction.
+/// Accounts: signer (admin), config, new_program, pending_admin_action, system_program, board, oil_program
 pub fn set_swap_program(signer: Pubkey, new_program: Pubkey) -> Instruction {
     let config_address = config_pda().0;
     Instruction {
@@ -754,11 +1752,29 @@ pub fn set_swap_program(signer: Pubkey, new_program: Pubkey) -> Instruction {
             AccountMeta::new(signer, true),
             AccountMeta::new(config_address, false),
             AccountMeta::new_readonly(new_program, false),
-        ],
+        ]
+        .into_iter()
+        .chain(admin_timelock_accounts(OilInstruction::SetSwapProgram))
+        .collect(),
         data: SetSwapProgram {}.to_bytes(),
     }
 }
 
+pub const SET_VAR_ADDRESS_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("board"),
+    IdlAccount::writable("config"),
+    IdlAccount::writable("new_var_address"),
+    IdlAccount::writable("pending_admin_action"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::writable("board"),
+    IdlAccount::readonly("oil_program").at(crate::ID),
+];
+
+/// Sets the var address. The first call queues the change, a second identical call applies it
+/// once the timelock has passed.
+/// Admin-only instruction.
+/// Accounts: signer (admin), board, config, new_var_address, pending_admin_action, system_program, board, oil_program
 pub fn set_var_address(signer: Pubkey, new_var_address: Pubkey) -> Instruction {
     let board_address = board_pda().0;
     let config_address = config_pda().0;
@@ -769,37 +1785,53 @@ pub fn set_var_address(signer: Pubkey, new_var_address: Pubkey) -> Instruction {
             AccountMeta::new(board_address, false),
             AccountMeta::new(config_address, false),
             AccountMeta::new(new_var_address, false),
-        ],
+        ]
+        .into_iter()
+        .chain(admin_timelock_accounts(OilInstruction::SetVarAddress))
+        .collect(),
         data: SetVarAddress {}.to_bytes(),
     }
 }
 
-/// Migrate Miner: Extend Miner struct with 2 [u64; 4] arrays (current_epoch_id, checkpointed_epoch_id).
-/// This migration moves current_epoch_id and checkpointed_epoch_id from Rig to Miner.
-/// Must be called by the admin.
-/// Accounts: signer, config, miner, system_program
-pub fn migrate(signer: Pubkey, miner_authority: Pubkey) -> Instruction {
-    let config_address = config_pda().0;
-    let miner_address = miner_pda(miner_authority).0;
+pub const MIGRATE_ACCOUNT_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("account"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+];
+
+/// Migrate Account: Upgrades any program account to the current layout version of its type.
+/// Anyone can migrate an account; the signer pays the rent top-up.
+/// Accounts: signer, account, system_program
+pub fn migrate_account(signer: Pubkey, address: Pubkey) -> Instruction {
     Instruction {
         program_id: crate::ID,
         accounts: vec![
             AccountMeta::new(signer, true),
-            AccountMeta::new(config_address, false),
-            AccountMeta::new(miner_address, false),
+            AccountMeta::new(address, false),
             AccountMeta::new_readonly(system_program::ID, false),
         ],
-        data: Migrate {}.to_bytes(),
+        data: MigrateAccount {}.to_bytes(),
     }
 }
 
+pub const CREATE_REFERRAL_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::signer("payer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("referral"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+];
+
 /// Create a referral account to become a referrer.
 pub fn create_referral(signer: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
     let referral_address = referral_pda(signer).0;
     Instruction {
         program_id: crate::ID,
         accounts: vec![
             AccountMeta::new(signer, true),
+            AccountMeta::new(signer, true), // payer
+            AccountMeta::new_readonly(config_address, false),
             AccountMeta::new(referral_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
         ],
@@ -807,12 +1839,23 @@ pub fn create_referral(signer: Pubkey) -> Instruction {
     }
 }
 
-/// Creates a Whitelist account for a shared access code.
-/// Admin-only instruction.
+pub const CREATE_WHITELIST_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("whitelist"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+];
+
+/// Creates or updates a Whitelist account for a shared access code.
+/// `max_uses` of 0 is unlimited, `expires_at` of 0 never expires and a default `bound_wallet` allows any wallet.
+/// Whitelist issuer instruction.
 /// Accounts: signer (admin), config, whitelist, system_program
 pub fn create_whitelist(
     signer: Pubkey,
     code_hash: [u8; 32],
+    max_uses: u64,
+    expires_at: i64,
+    bound_wallet: Pubkey,
 ) -> Instruction {
     let config_address = config_pda().0;
     let (whitelist_address, _) = Whitelist::pda(code_hash);
@@ -826,28 +1869,274 @@ pub fn create_whitelist(
         ],
         data: CreateWhitelist {
             code_hash,
+            max_uses: max_uses.to_le_bytes(),
+            expires_at: expires_at.to_le_bytes(),
+            bound_wallet: bound_wallet.to_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+pub const REVOKE_WHITELIST_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("whitelist"),
+];
+
+/// Revokes an access code by closing its Whitelist account (rent is refunded to the admin).
+/// Whitelist issuer instruction.
+/// Accounts: signer (admin), config, whitelist
+pub fn revoke_whitelist(signer: Pubkey, code_hash: [u8; 32]) -> Instruction {
+    let config_address = config_pda().0;
+    let (whitelist_address, _) = Whitelist::pda(code_hash);
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true), // signer (admin)
+            AccountMeta::new_readonly(config_address, false), // config
+            AccountMeta::new(whitelist_address, false), // whitelist
+        ],
+        data: RevokeWhitelist {}.to_bytes(),
+    }
+}
+
+pub const SET_REFERRAL_CONFIG_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("referral_config"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+];
+
+/// Sets the referral tiers and upline share, creating the referral config if needed.
+/// Admin-only instruction.
+/// Accounts: signer (admin), config, referral_config, system_program
+pub fn set_referral_config(
+    signer: Pubkey,
+    tier_thresholds: [u64; 4],
+    tier_rates_bps: [u64; 4],
+    upline_share_bps: u64,
+) -> Instruction {
+    let config_address = config_pda().0;
+    let referral_config_address = referral_config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(referral_config_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: SetReferralConfig {
+            tier_thresholds: tier_thresholds.map(u64::to_le_bytes),
+            tier_rates_bps: tier_rates_bps.map(u64::to_le_bytes),
+            upline_share_bps: upline_share_bps.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+pub const CREATE_REFERRAL_CODE_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::readonly("referral"),
+    IdlAccount::writable("referral_code"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+];
+
+/// Creates a human-readable referral code that resolves to the signer's referral account.
+/// Accounts: signer, config, referral, referral_code, system_program
+pub fn create_referral_code(signer: Pubkey, code_hash: [u8; 32]) -> Instruction {
+    let config_address = config_pda().0;
+    let referral_address = referral_pda(signer).0;
+    let referral_code_address = referral_code_pda(code_hash).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new_readonly(referral_address, false),
+            AccountMeta::new(referral_code_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: CreateReferralCode { code_hash }.to_bytes(),
+    }
+}
+
+pub const SET_REFERRAL_REBATE_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("referral"),
+];
+
+/// Sets the share of the referral bonus the signer gives back to their referees (bps).
+/// Accounts: signer, config, referral
+pub fn set_referral_rebate(signer: Pubkey, rebate_bps: u64) -> Instruction {
+    let config_address = config_pda().0;
+    let referral_address = referral_pda(signer).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(referral_address, false),
+        ],
+        data: SetReferralRebate {
+            rebate_bps: rebate_bps.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+pub const SET_REFERRER_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("miner"),
+    IdlAccount::writable("referral"),
+];
+
+/// Attaches a referrer to the signer's existing miner (only if it has none).
+/// Accounts: signer, config, miner, referral
+pub fn set_referrer(signer: Pubkey, referrer: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
+    let miner_address = miner_pda(signer).0;
+    let referral_address = referral_pda(referrer).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(miner_address, false),
+            AccountMeta::new(referral_address, false),
+        ],
+        data: SetReferrer {
+            referrer: referrer.to_bytes(),
         }
         .to_bytes(),
     }
 }
 
+pub const CLAIM_SEEKER_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("miner"),
+    IdlAccount::readonly("mint"),
+    IdlAccount::readonly("token_account"),
+    IdlAccount::writable("seeker"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+];
+
+/// Marks the signer's miner as a Seeker miner by claiming the Seeker genesis token `mint`, held in
+/// the signer's token-2022 associated token account.
+/// Accounts: signer, config, miner, mint, token_account, seeker, system_program
+pub fn claim_seeker(signer: Pubkey, mint: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
+    let miner_address = miner_pda(signer).0;
+    let token_account_address = get_associated_token_address_with_program_id(&signer, &mint, &spl_token_2022::ID);
+    let seeker_address = seeker_pda(mint).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(miner_address, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(token_account_address, false),
+            AccountMeta::new(seeker_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: ClaimSeeker {}.to_bytes(),
+    }
+}
+
+pub const UPDATE_RIG_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("board"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("miner"),
+    IdlAccount::writable("rig"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::readonly("oil_program").at(crate::ID),
+];
+
+/// Accrues stake XP on `authority`'s rig and mirrors its level to the miner, creating the rig if
+/// needed (paid by the signer). Anyone can update a rig.
+/// Accounts: signer, board, config, miner, rig, system_program, oil_program
+pub fn update_rig(signer: Pubkey, authority: Pubkey) -> Instruction {
+    let board_address = board_pda().0;
+    let config_address = config_pda().0;
+    let miner_address = miner_pda(authority).0;
+    let rig_address = rig_pda(authority).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(miner_address, false),
+            AccountMeta::new(rig_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(crate::ID, false),
+        ],
+        data: UpdateRig {}.to_bytes(),
+    }
+}
+
+pub const CLEAR_REFERRER_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("miner"),
+    IdlAccount::writable("referral"),
+];
+
+/// Removes a miner's referrer so the miner can attach a new one.
+/// Admin-only instruction.
+/// Accounts: signer (admin), config, miner, referral
+pub fn clear_referrer(signer: Pubkey, miner_authority: Pubkey, referrer: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
+    let miner_address = miner_pda(miner_authority).0;
+    let referral_address = referral_pda(referrer).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(miner_address, false),
+            AccountMeta::new(referral_address, false),
+        ],
+        data: ClearReferrer {}.to_bytes(),
+    }
+}
+
+pub const CLAIM_REFERRAL_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("referral"),
+    IdlAccount::writable("referral_tokens"),
+    IdlAccount::writable("mint").at(MINT_ADDRESS),
+    IdlAccount::writable("recipient"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::readonly("token_program").at(spl_token::ID),
+    IdlAccount::readonly("associated_token_program").at(spl_associated_token_account::ID),
+];
+
 /// Claim pending referral rewards (both SOL and OIL).
 /// 
-/// Account structure (for Fogo sessions):
-/// - Base: signer (payer), authority (user's wallet), referral, referral_tokens, mint, recipient, system_program, token_program, associated_token_program
-pub fn claim_referral(signer: Pubkey, authority: Pubkey) -> Instruction {
-    let referral_address = referral_pda(authority).0;
+/// Account structure:
+/// - Base: signer, config, referral, referral_tokens, mint, recipient, system_program, token_program, associated_token_program
+pub fn claim_referral(signer: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
+    let referral_address = referral_pda(signer).0;
     let referral_oil_address = get_associated_token_address(&referral_address, &MINT_ADDRESS);
-    let recipient_oil_address = get_associated_token_address(&authority, &MINT_ADDRESS);
+    let recipient_oil_address = get_associated_token_address(&signer, &MINT_ADDRESS);
     Instruction {
         program_id: crate::ID,
         accounts: vec![
-            AccountMeta::new(signer, true), // 0: signer (payer)
-            AccountMeta::new(authority, false), // 1: authority (user's wallet, receives SOL)
+            AccountMeta::new(signer, true), // 0: signer (receives SOL)
+            AccountMeta::new_readonly(config_address, false), // 1: config
             AccountMeta::new(referral_address, false), // 2: referral
             AccountMeta::new(referral_oil_address, false), // 3: referral_tokens (Referral account's OIL ATA)
             AccountMeta::new(MINT_ADDRESS, false), // 4: mint
-            AccountMeta::new(recipient_oil_address, false), // 5: recipient (Recipient's OIL ATA - authority's wallet)
+            AccountMeta::new(recipient_oil_address, false), // 5: recipient (Recipient's OIL ATA)
             AccountMeta::new_readonly(system_program::ID, false), // 6: system_program
             AccountMeta::new_readonly(spl_token::ID, false), // 7: token_program
             AccountMeta::new_readonly(spl_associated_token_account::ID, false), // 8: associated_token_program
@@ -856,104 +2145,168 @@ pub fn claim_referral(signer: Pubkey, authority: Pubkey) -> Instruction {
     }
 }
 
+pub const PLACE_BID_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("authority"),
+    IdlAccount::writable("well"),
+    IdlAccount::writable("auction"),
+    IdlAccount::writable("treasury"),
+    IdlAccount::writable("treasury_tokens"),
+    IdlAccount::writable("mint").at(MINT_ADDRESS),
+    IdlAccount::writable("mint_authority"),
+    IdlAccount::readonly("mint_program").at(oil_mint_api::ID),
+    IdlAccount::writable("staking_pool"),
+    IdlAccount::writable("fee_collector"),
+    IdlAccount::readonly("config"),
+    IdlAccount::readonly("token_program").at(spl_token::ID),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::readonly("oil_program").at(crate::ID),
+    IdlAccount::writable("bidder_miner"),
+    IdlAccount::writable("previous_owner_miner"),
+    IdlAccount::writable("micro"),
+    IdlAccount::writable("rig"),
+    IdlAccount::writable("referral").optional(),
+    IdlAccount::writable("whitelist").optional(),
+];
+
 /// Direct solo bid on an auction well (seize ownership).
 /// The bid amount is calculated on-chain as current_price + 1 lamport.
 /// User must have enough SOL in their wallet to cover the bid.
+/// The bid fails if the bid amount exceeds `max_price` or if `expires_at` has passed.
+/// 
+/// `previous_owner` is the well's current bidder and `epoch_id` its current epoch (the micro account
+/// of that epoch is created when the pool held or funded the well).
 /// 
 /// Account structure:
-/// - Base: signer, authority, program_signer (optional), payer (optional), well, auction, treasury, treasury_tokens, mint, mint_authority, mint_program, staking_pool, fee_collector, config, token_program, system_program, oil_program
-/// - If previous owner exists (optional): [previous_owner_miner, previous_owner]
-/// - If referrer is provided (optional): [referral]
+/// - Base: signer, authority, well, auction, treasury, treasury_tokens, mint, mint_authority, mint_program, staking_pool,
+///   fee_collector, config, token_program, system_program, oil_program, bidder_miner, previous_owner_miner, micro, rig
+/// - If a referrer is provided (optional): [referral]
+/// - If an access code is provided (optional, required before the TGE): [whitelist]
 pub fn place_bid(
     signer: Pubkey,
     authority: Pubkey,
     square_id: u64,
     fee_collector: Pubkey,
-    previous_owner_miner: Option<Pubkey>, // Previous owner's miner PDA (if previous owner exists)
-    previous_owner: Option<Pubkey>, // Previous owner pubkey (if previous owner exists)
+    previous_owner: Option<Pubkey>, // Well's current bidder (if any)
+    epoch_id: u64, // Well's current epoch
     referrer: Option<Pubkey>, // Optional referrer pubkey for new miners
+    max_price: u64, // Maximum bid amount in lamports
+    expires_at: Option<i64>, // Optional unix timestamp deadline
+    access_code: Option<[u8; 32]>, // Premine access code hash
 ) -> Instruction {
-    let well_address = well_pda(square_id).0;
-    let auction_address = auction_pda().0;
-    let treasury_address = treasury_pda().0;
-    let treasury_tokens_address = get_associated_token_address(&treasury_address, &MINT_ADDRESS);
-    let staking_pool_address = pool_pda().0;
-    let config_address = config_pda().0;
-    let mint_authority_address = oil_mint_api::state::authority_pda().0;
-    let bidder_miner_address = miner_pda(authority).0;
-    
     let mut accounts = vec![
         AccountMeta::new(signer, true), // 0: signer
         AccountMeta::new(authority, false), // 1: authority
     ];
+    accounts.extend(place_bid_accounts(authority, square_id, fee_collector, previous_owner, epoch_id, referrer, access_code));
     
-    // Add program_signer and payer for Fogo sessions (these are added by the client, not here)
-    // For now, we'll add placeholders or the client will add them
-    
-    accounts.extend_from_slice(&[
-        AccountMeta::new(well_address, false), // well
-        AccountMeta::new(auction_address, false), // Must be writable for auction_program_log CPI
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: instruction::PlaceBid {
+            square_id: square_id.to_le_bytes(),
+            referrer: referrer.unwrap_or(Pubkey::default()).to_bytes(),
+            max_price: max_price.to_le_bytes(),
+            expires_at: expires_at.unwrap_or(0).to_le_bytes(),
+            access_code: access_code.unwrap_or_default(),
+        }
+        .to_bytes(),
+    }
+}
+
+/// Accounts shared by `place_bid` and `place_bid_with_session`, from the well to the optional whitelist.
+fn place_bid_accounts(
+    authority: Pubkey,
+    square_id: u64,
+    fee_collector: Pubkey,
+    previous_owner: Option<Pubkey>,
+    epoch_id: u64,
+    referrer: Option<Pubkey>,
+    access_code: Option<[u8; 32]>,
+) -> Vec<AccountMeta> {
+    let treasury_address = treasury_pda().0;
+    let bidder_miner_address = miner_pda(authority).0;
+    // The previous owner's miner is only read when the well has an owner
+    let previous_owner_miner_address = previous_owner.map_or(bidder_miner_address, |owner| miner_pda(owner).0);
+    let mut accounts = vec![
+        AccountMeta::new(well_pda(square_id).0, false), // well
+        AccountMeta::new(auction_pda().0, false), // Must be writable for auction_program_log CPI
         AccountMeta::new(treasury_address, false),
-        AccountMeta::new(treasury_tokens_address, false),
+        AccountMeta::new(get_associated_token_address(&treasury_address, &MINT_ADDRESS), false),
         AccountMeta::new(MINT_ADDRESS, false),
-        AccountMeta::new(mint_authority_address, false),
+        AccountMeta::new(oil_mint_api::state::authority_pda().0, false),
         AccountMeta::new_readonly(oil_mint_api::ID, false),
-        AccountMeta::new(staking_pool_address, false),
+        AccountMeta::new(pool_pda().0, false), // staking_pool
         AccountMeta::new(fee_collector, false),
-        AccountMeta::new_readonly(config_address, false),
+        AccountMeta::new_readonly(config_pda().0, false),
         AccountMeta::new_readonly(spl_token::ID, false),
         AccountMeta::new_readonly(system_program::ID, false),
         AccountMeta::new_readonly(crate::ID, false), // oil_program
         AccountMeta::new(bidder_miner_address, false), // bidder_miner
-    ]);
-    
-    // Add previous owner accounts if provided
-    if let (Some(miner_pubkey), Some(owner_pubkey)) = (previous_owner_miner, previous_owner) {
-        accounts.push(AccountMeta::new(miner_pubkey, false)); // previous_owner_miner
-        accounts.push(AccountMeta::new(owner_pubkey, false)); // previous_owner
-    }
+        AccountMeta::new(previous_owner_miner_address, false), // previous_owner_miner
+        AccountMeta::new(micro_pda(square_id, epoch_id).0, false), // micro
+        AccountMeta::new(rig_pda(authority).0, false), // rig
+    ];
     
     // Add referral account if referrer is provided
     if let Some(referrer_pubkey) = referrer {
-        let referral_address = referral_pda(referrer_pubkey).0;
-        accounts.push(AccountMeta::new(referral_address, false)); // referral
+        accounts.push(AccountMeta::new(referral_pda(referrer_pubkey).0, false)); // referral
     }
-    
-    // Note: Wrapped token accounts are added by the client:
-    // - user_wrapped_sol (source)
-    // - treasury_wrapped_sol (temp ATA for all wrapped SOL - will be closed to get native SOL)
-    // - token_program, native_mint, ata_program
-    // The program distributes native SOL from treasury to pool and fee_collector after closing the temp ATA.
-    
-    Instruction {
-        program_id: crate::ID,
-        accounts,
-        data: instruction::PlaceBid {
-            square_id: square_id.to_le_bytes(),
-            referrer: referrer.unwrap_or(Pubkey::default()).to_bytes(),
-        }
-        .to_bytes(),
+    if let Some(code_hash) = access_code {
+        accounts.push(AccountMeta::new(Whitelist::pda(code_hash).0, false)); // whitelist
     }
+    accounts
 }
 
+pub const CLAIM_AUCTION_OIL_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("miner"),
+    IdlAccount::writable("well_0"),
+    IdlAccount::writable("well_1"),
+    IdlAccount::writable("well_2"),
+    IdlAccount::writable("well_3"),
+    IdlAccount::writable("auction"),
+    IdlAccount::writable("treasury"),
+    IdlAccount::writable("treasury_tokens"),
+    IdlAccount::writable("mint").at(MINT_ADDRESS),
+    IdlAccount::writable("mint_authority"),
+    IdlAccount::readonly("mint_program").at(oil_mint_api::ID),
+    IdlAccount::writable("recipient"),
+    IdlAccount::readonly("token_program").at(spl_token::ID),
+    IdlAccount::readonly("associated_token_program").at(spl_associated_token_account::ID),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::readonly("oil_program").at(crate::ID),
+    IdlAccount::writable("miner_referrer").optional(),
+    IdlAccount::writable("referral_referrer").optional(),
+    IdlAccount::writable("referral_referrer_oil_ata").optional(),
+    IdlAccount::readonly("referral_config").optional(),
+    IdlAccount::writable("upline_referral").optional(),
+    IdlAccount::writable("upline_referral_oil_ata").optional(),
+];
+
 /// Claim auction-based OIL rewards
 /// - OIL rewards: from current ownership and previous ownership (pre-minted)
 /// 
 /// Account structure:
-/// - Base: signer, miner, well accounts (one per well in mask), auction pool accounts (optional, one per well), auction, treasury, treasury_tokens, mint, mint_authority, mint_program, recipient, token_program, associated_token_program, system_program, oil_program
+/// - Base: signer, config, miner, well accounts (one per well in mask), auction pool accounts (optional, one per well), auction, treasury, treasury_tokens, mint, mint_authority, mint_program, recipient, token_program, associated_token_program, system_program, oil_program
 /// - Bid accounts (one per well in mask, required for pool contributors): [bid_0, bid_1, bid_2, bid_3] (must include epoch_id in PDA)
 /// Claim auction-based OIL rewards
 /// 
 /// Account structure:
-/// - Base: signer, miner, well_0, well_1, well_2, well_3, auction, treasury, treasury_tokens, mint, mint_authority, mint_program, recipient, token_program, associated_token_program, system_program, oil_program
-/// - If miner has referrer (required): [miner_referrer, referral_referrer, referral_referrer_oil_ata]
+/// - Base: signer, config, miner, well_0, well_1, well_2, well_3, auction, treasury, treasury_tokens, mint, mint_authority, mint_program, recipient, token_program, associated_token_program, system_program, oil_program
+/// - If miner has referrer (required): [miner_referrer, referral_referrer, referral_referrer_oil_ata, referral_config,
+///   [upline_referral, upline_referral_oil_ata]]
 pub fn claim_auction_oil(
     signer: Pubkey,
     well_mask: u8, // Bitmask: bit 0 = well 0, bit 1 = well 1, etc.
     referrer_miner: Option<Pubkey>, // Referrer's miner PDA (if miner has referrer)
     referrer_referral: Option<Pubkey>, // Referrer's referral PDA (if miner has referrer)
     referrer_referral_oil_ata: Option<Pubkey>, // Referrer's referral OIL ATA (if miner has referrer)
+    upline_referral: Option<Pubkey>, // Upline's referral PDA (if the referral config pays an upline share)
+    upline_referral_oil_ata: Option<Pubkey>, // Upline's referral OIL ATA (if the referral config pays an upline share)
 ) -> Instruction {
+    let config_address = config_pda().0;
     let miner_address = miner_pda(signer).0;
     let well_0_address = well_pda(0).0;
     let well_1_address = well_pda(1).0;
@@ -967,6 +2320,7 @@ pub fn claim_auction_oil(
     
     let mut accounts = vec![
         AccountMeta::new(signer, true),
+        AccountMeta::new_readonly(config_address, false),
         AccountMeta::new(miner_address, false),
         AccountMeta::new(well_0_address, false),
         AccountMeta::new(well_1_address, false),
@@ -991,6 +2345,11 @@ pub fn claim_auction_oil(
         accounts.push(AccountMeta::new(miner_pubkey, false));
         accounts.push(AccountMeta::new(referral_pubkey, false));
         accounts.push(AccountMeta::new(oil_ata_pubkey, false));
+        accounts.push(AccountMeta::new_readonly(referral_config_pda().0, false));
+        if let (Some(upline_pubkey), Some(upline_oil_ata_pubkey)) = (upline_referral, upline_referral_oil_ata) {
+            accounts.push(AccountMeta::new(upline_pubkey, false));
+            accounts.push(AccountMeta::new(upline_oil_ata_pubkey, false));
+        }
     }
     
     Instruction {
@@ -1003,22 +2362,39 @@ pub fn claim_auction_oil(
     }
 }
 
+pub const CLAIM_AUCTION_SOL_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("miner"),
+    IdlAccount::writable("treasury"),
+    IdlAccount::writable("auction"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::readonly("oil_program").at(crate::ID),
+    IdlAccount::writable("miner_referrer").optional(),
+    IdlAccount::writable("referral_referrer").optional(),
+    IdlAccount::readonly("referral_config").optional(),
+    IdlAccount::writable("upline_referral").optional(),
+];
+
 /// Claim auction-based SOL rewards
 /// 
 /// Account structure:
-/// - Base: signer (writable), miner, treasury, auction, system_program, oil_program
-/// - If miner has referrer (required): [miner_referrer, referral_referrer]
+/// - Base: signer (writable), config, miner, treasury, auction, system_program, oil_program
+/// - If miner has referrer (required): [miner_referrer, referral_referrer, referral_config, [upline_referral]]
 pub fn claim_auction_sol(
     signer: Pubkey,
     referrer_miner: Option<Pubkey>, // Referrer's miner PDA (if miner has referrer)
     referrer_referral: Option<Pubkey>, // Referrer's referral PDA (if miner has referrer)
+    upline_referral: Option<Pubkey>, // Upline's referral PDA (if the referral config pays an upline share)
 ) -> Instruction {
+    let config_address = config_pda().0;
     let miner_address = miner_pda(signer).0;
     let (auction_address, _) = auction_pda();
     let treasury_address = treasury_pda().0;
     
     let mut accounts = vec![
         AccountMeta::new(signer, true), // signer and writable for receiving SOL
+        AccountMeta::new_readonly(config_address, false), // config
         AccountMeta::new(miner_address, false),
         AccountMeta::new(treasury_address, false),
         AccountMeta::new(auction_address, false),
@@ -1030,6 +2406,10 @@ pub fn claim_auction_sol(
     if let (Some(miner_pubkey), Some(referral_pubkey)) = (referrer_miner, referrer_referral) {
         accounts.push(AccountMeta::new(miner_pubkey, false));
         accounts.push(AccountMeta::new(referral_pubkey, false));
+        accounts.push(AccountMeta::new_readonly(referral_config_pda().0, false));
+        if let Some(upline_pubkey) = upline_referral {
+            accounts.push(AccountMeta::new(upline_pubkey, false));
+        }
     }
     
     Instruction {
@@ -1042,10 +2422,259 @@ pub fn claim_auction_sol(
     }
 }
 
+pub const WITHDRAW_CONTRIBUTION_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("authority"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("well"),
+    IdlAccount::writable("treasury"),
+    IdlAccount::writable("share"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+];
+
+/// Withdraw un-spent FOGO from a pool contribution before the pool bids
+pub fn withdraw_contribution(
+    signer: Pubkey,
+    authority: Pubkey,
+    well_id: u64,
+    epoch_id: u64,
+    amount: u64,
+) -> Instruction {
+    let config_address = config_pda().0;
+    let well_address = well_pda(well_id).0;
+    let treasury_address = treasury_pda().0;
+    let share_address = share_pda(authority, well_id, epoch_id).0;
+    
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(authority, false),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(well_address, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new(share_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: WithdrawContribution {
+            well_id: well_id.to_le_bytes(),
+            amount: amount.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+pub const CONTRIBUTE_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("authority"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("well"),
+    IdlAccount::writable("auction"),
+    IdlAccount::writable("treasury"),
+    IdlAccount::writable("miner"),
+    IdlAccount::writable("rig"),
+    IdlAccount::writable("share"),
+    IdlAccount::writable("treasury_wrapped_sol"),
+    IdlAccount::writable("user_wrapped_sol"),
+    IdlAccount::readonly("token_program").at(spl_token::ID),
+    IdlAccount::readonly("sol_mint").at(SOL_MINT),
+    IdlAccount::readonly("associated_token_program").at(spl_associated_token_account::ID),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::readonly("oil_program").at(crate::ID),
+    IdlAccount::writable("whitelist").optional(),
+];
+
+/// Contribute SOL to the pool's bid on a well for the current epoch.
+/// The contribution is only spent on bids up to `max_price` (0 = no limit), and is refunded if the
+/// pool does not win the epoch. Before the TGE, `access_code` must be the hash of a usable premine access code.
+pub fn contribute(
+    signer: Pubkey,
+    authority: Pubkey,
+    well_id: u64,
+    epoch_id: u64,
+    amount: u64,
+    max_price: u64,
+    access_code: Option<[u8; 32]>,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new(authority, false),
+    ];
+    accounts.extend(contribute_accounts(authority, well_id, epoch_id, access_code));
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: Contribute {
+            well_id: well_id.to_le_bytes(),
+            amount: amount.to_le_bytes(),
+            max_price: max_price.to_le_bytes(),
+            access_code: access_code.unwrap_or_default(),
+        }
+        .to_bytes(),
+    }
+}
+
+/// Accounts shared by `contribute` and `contribute_with_session`, from the config to the optional whitelist.
+fn contribute_accounts(authority: Pubkey, well_id: u64, epoch_id: u64, access_code: Option<[u8; 32]>) -> Vec<AccountMeta> {
+    let treasury_address = treasury_pda().0;
+    let mut accounts = vec![
+        AccountMeta::new_readonly(config_pda().0, false),
+        AccountMeta::new(well_pda(well_id).0, false),
+        AccountMeta::new(auction_pda().0, false),
+        AccountMeta::new(treasury_address, false),
+        AccountMeta::new(miner_pda(authority).0, false),
+        AccountMeta::new(rig_pda(authority).0, false),
+        AccountMeta::new(share_pda(authority, well_id, epoch_id).0, false),
+        AccountMeta::new(get_associated_token_address(&treasury_address, &SOL_MINT), false), // treasury_wrapped_sol
+        AccountMeta::new(get_associated_token_address(&authority, &SOL_MINT), false), // user_wrapped_sol
+        AccountMeta::new_readonly(spl_token::ID, false),
+        AccountMeta::new_readonly(SOL_MINT, false),
+        AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+    if let Some(code_hash) = access_code {
+        accounts.push(AccountMeta::new(Whitelist::pda(code_hash).0, false)); // whitelist
+    }
+    accounts
+}
+
+pub const CHECKPOINT_AUCTION_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("authority"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("miner"),
+    IdlAccount::writable("share"),
+    IdlAccount::readonly("micro"),
+    IdlAccount::readonly("well"),
+    IdlAccount::readonly("oil_program").at(crate::ID),
+];
+
+/// Checkpoint auction rewards from prior epochs, one epoch per well (`None` skips the well).
+/// 
+/// Account structure:
+/// - Base: signer, authority, config, miner, [share, micro, well for each well checkpointed], oil_program
+pub fn checkpoint_auction(signer: Pubkey, authority: Pubkey, epoch_ids: [Option<u64>; 4]) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new(authority, false),
+    ];
+    accounts.extend(checkpoint_auction_accounts(authority, epoch_ids));
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: checkpoint_auction_data(epoch_ids).to_bytes(),
+    }
+}
+
+/// Accounts shared by `checkpoint_auction` and `checkpoint_auction_with_session`, from the config to the oil program.
+fn checkpoint_auction_accounts(authority: Pubkey, epoch_ids: [Option<u64>; 4]) -> Vec<AccountMeta> {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(config_pda().0, false),
+        AccountMeta::new(miner_pda(authority).0, false),
+    ];
+    for (well_id, epoch_id) in epoch_ids.iter().enumerate() {
+        let Some(epoch_id) = *epoch_id else {
+            continue;
+        };
+        let well_id = well_id as u64;
+        accounts.push(AccountMeta::new(share_pda(authority, well_id, epoch_id).0, false));
+        accounts.push(AccountMeta::new_readonly(micro_pda(well_id, epoch_id).0, false));
+        accounts.push(AccountMeta::new_readonly(well_pda(well_id).0, false));
+    }
+    accounts.push(AccountMeta::new_readonly(crate::ID, false));
+    accounts
+}
+
+fn checkpoint_auction_data(epoch_ids: [Option<u64>; 4]) -> CheckpointAuction {
+    let mut well_mask = 0u8;
+    for (well_id, epoch_id) in epoch_ids.iter().enumerate() {
+        if epoch_id.is_some() {
+            well_mask |= 1 << well_id;
+        }
+    }
+    CheckpointAuction {
+        well_mask,
+        epoch_ids: epoch_ids.map(|epoch_id| epoch_id.unwrap_or(0).to_le_bytes()),
+    }
+}
+
+// ============================================================================
+// View SDK Functions (results are returned via return data)
+// ============================================================================
+
+pub const GET_PENDING_REWARDS_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::readonly("miner"),
+    IdlAccount::readonly("treasury"),
+    IdlAccount::readonly("config"),
+];
+
+/// Returns a miner's pending rewards as [`PendingRewards`](crate::view::PendingRewards).
+pub fn get_pending_rewards(authority: Pubkey) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new_readonly(miner_pda(authority).0, false),
+            AccountMeta::new_readonly(treasury_pda().0, false),
+            AccountMeta::new_readonly(config_pda().0, false),
+        ],
+        data: GetPendingRewards {}.to_bytes(),
+    }
+}
+
+pub const GET_WELL_QUOTE_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::readonly("well"),
+    IdlAccount::readonly("auction"),
+];
+
+/// Returns the current price and mining state of a well as [`WellQuote`](crate::view::WellQuote).
+pub fn get_well_quote(well_id: u64) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new_readonly(well_pda(well_id).0, false),
+            AccountMeta::new_readonly(auction_pda().0, false),
+        ],
+        data: GetWellQuote {
+            well_id: well_id.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+pub const GET_STAKE_INFO_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::readonly("stake"),
+    IdlAccount::readonly("pool"),
+];
+
+/// Returns a stake account's lock and yield state as [`StakeInfo`](crate::view::StakeInfo).
+pub fn get_stake_info(authority: Pubkey, stake_id: u64) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new_readonly(stake_pda_with_id(authority, stake_id).0, false),
+            AccountMeta::new_readonly(pool_pda().0, false),
+        ],
+        data: GetStakeInfo {}.to_bytes(),
+    }
+}
+
 // ============================================================================
 // FOGO Session SDK Functions
 // ============================================================================
 
+pub const CHECKPOINT_WITH_SESSION_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("authority"),
+    IdlAccount::readonly("program_signer"),
+    IdlAccount::writable("board"),
+    IdlAccount::writable("config"),
+    IdlAccount::writable("miner"),
+    IdlAccount::writable("round"),
+    IdlAccount::writable("treasury"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+];
+
 pub fn checkpoint_with_session(
     signer: Pubkey,
     authority: Pubkey,
@@ -1079,6 +2708,31 @@ pub fn checkpoint_with_session(
     }
 }
 
+pub const DEPLOY_WITH_SESSION_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("authority"),
+    IdlAccount::readonly("program_signer"),
+    IdlAccount::writable("payer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("automation"),
+    IdlAccount::writable("board"),
+    IdlAccount::writable("miner"),
+    IdlAccount::writable("rig"),
+    IdlAccount::writable("round"),
+    IdlAccount::readonly("pool"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::readonly("oil_program").at(crate::ID),
+    IdlAccount::writable("user_wrapped_sol"),
+    IdlAccount::writable("round_wrapped_sol"),
+    IdlAccount::readonly("token_program").at(spl_token::ID),
+    IdlAccount::readonly("sol_mint").at(SOL_MINT),
+    IdlAccount::readonly("associated_token_program").at(spl_associated_token_account::ID),
+    IdlAccount::writable("referral").optional(),
+    IdlAccount::writable("whitelist").optional(),
+    IdlAccount::writable("var"),
+    IdlAccount::readonly("entropy_program").at(entropy_rng_api::ID),
+];
+
 pub fn deploy_with_session(
     signer: Pubkey,
     authority: Pubkey,
@@ -1089,10 +2743,13 @@ pub fn deploy_with_session(
     squares: [bool; 25],
     referrer: Option<Pubkey>,
     pooled: bool,
+    access_code: Option<[u8; 32]>,
 ) -> Instruction {
+    let config_address = config_pda().0;
     let automation_address = automation_pda(authority).0;
     let board_address = board_pda().0;
     let miner_address = miner_pda(authority).0;
+    let rig_address = rig_pda(authority).0;
     let round_address = round_pda(round_id).0;
     let entropy_var_address = entropy_rng_api::state::var_pda(board_address, 0).0;
 
@@ -1114,10 +2771,13 @@ pub fn deploy_with_session(
         AccountMeta::new(authority, false),
         AccountMeta::new_readonly(program_signer, false),
         AccountMeta::new(payer, false),
+        AccountMeta::new_readonly(config_address, false),
         AccountMeta::new(automation_address, false),
         AccountMeta::new(board_address, false),
         AccountMeta::new(miner_address, false),
+        AccountMeta::new(rig_address, false),
         AccountMeta::new(round_address, false),
+        AccountMeta::new_readonly(pool_pda().0, false),
         AccountMeta::new_readonly(system_program::ID, false),
         AccountMeta::new_readonly(crate::ID, false),
         AccountMeta::new(user_wrapped_sol_ata, false),
@@ -1131,23 +2791,49 @@ pub fn deploy_with_session(
         let referral_address = referral_pda(referrer_pubkey).0;
         accounts.push(AccountMeta::new(referral_address, false));
     }
+    if let Some(code_hash) = access_code {
+        accounts.push(AccountMeta::new(Whitelist::pda(code_hash).0, false));
+    }
     
     accounts.push(AccountMeta::new(entropy_var_address, false));
     accounts.push(AccountMeta::new_readonly(entropy_rng_api::ID, false));
 
+    let mut data = Deploy {
+        amount: amount.to_le_bytes(),
+        squares: mask.to_le_bytes(),
+        referrer: referrer_pubkey.to_bytes(),
+        pooled: if pooled { 1 } else { 0 },
+        access_code: access_code.unwrap_or_default(),
+    }
+    .to_bytes();
+    data[0] = 39u8; // DeployWithSession = 39
+    
     Instruction {
         program_id: crate::ID,
         accounts,
-        data: Deploy {
-            amount: amount.to_le_bytes(),
-            squares: mask.to_le_bytes(),
-            referrer: referrer_pubkey.to_bytes(),
-            pooled: if pooled { 1 } else { 0 },
-        }
-        .to_bytes(),
+        data,
     }
 }
 
+pub const AUTOMATE_WITH_SESSION_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("authority"),
+    IdlAccount::readonly("program_signer"),
+    IdlAccount::writable("payer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("automation"),
+    IdlAccount::writable("executor"),
+    IdlAccount::writable("miner"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::readonly("oil_program").at(crate::ID),
+    IdlAccount::writable("user_wrapped_sol"),
+    IdlAccount::writable("automation_wrapped_sol"),
+    IdlAccount::readonly("token_program").at(spl_token::ID),
+    IdlAccount::readonly("sol_mint").at(SOL_MINT),
+    IdlAccount::readonly("associated_token_program").at(spl_associated_token_account::ID),
+    IdlAccount::writable("referral").optional(),
+];
+
 pub fn automate_with_session(
     signer: Pubkey,
     authority: Pubkey,
@@ -1164,6 +2850,7 @@ pub fn automate_with_session(
     pooled: bool,
     is_new_miner: bool,
 ) -> Instruction {
+    let config_address = config_pda().0;
     let automation_address = automation_pda(authority).0;
     let miner_address = miner_pda(authority).0;
     let referrer_pk = referrer.unwrap_or(Pubkey::default());
@@ -1175,6 +2862,7 @@ pub fn automate_with_session(
         AccountMeta::new(authority, false),
         AccountMeta::new_readonly(program_signer, false),
         AccountMeta::new(payer, false),
+        AccountMeta::new_readonly(config_address, false),
         AccountMeta::new(automation_address, false),
         AccountMeta::new(executor, false),
         AccountMeta::new(miner_address, false),
@@ -1192,23 +2880,57 @@ pub fn automate_with_session(
         accounts.push(AccountMeta::new(referral_address, false));
     }
     
+    let mut data = Automate {
+        amount: amount.to_le_bytes(),
+        deposit: deposit.to_le_bytes(),
+        fee: fee.to_le_bytes(),
+        mask: mask.to_le_bytes(),
+        strategy: strategy as u8,
+        reload: (reload as u64).to_le_bytes(),
+        referrer: referrer_pk.to_bytes(),
+        pooled: pooled as u8,
+    }
+    .to_bytes();
+    data[0] = 40u8; // AutomateWithSession = 40
+    
     Instruction {
         program_id: crate::ID,
         accounts,
-        data: Automate {
-            amount: amount.to_le_bytes(),
-            deposit: deposit.to_le_bytes(),
-            fee: fee.to_le_bytes(),
-            mask: mask.to_le_bytes(),
-            strategy: strategy as u8,
-            reload: (reload as u64).to_le_bytes(),
-            referrer: referrer_pk.to_bytes(),
-            pooled: pooled as u8,
-        }
-        .to_bytes(),
+        data,
     }
 }
 
+pub const PLACE_BID_WITH_SESSION_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("authority"),
+    IdlAccount::readonly("program_signer"),
+    IdlAccount::writable("payer"),
+    IdlAccount::writable("well"),
+    IdlAccount::writable("auction"),
+    IdlAccount::writable("treasury"),
+    IdlAccount::writable("treasury_tokens"),
+    IdlAccount::writable("mint").at(MINT_ADDRESS),
+    IdlAccount::writable("mint_authority"),
+    IdlAccount::readonly("mint_program").at(oil_mint_api::ID),
+    IdlAccount::writable("staking_pool"),
+    IdlAccount::writable("fee_collector"),
+    IdlAccount::readonly("config"),
+    IdlAccount::readonly("token_program").at(spl_token::ID),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::readonly("oil_program").at(crate::ID),
+    IdlAccount::writable("bidder_miner"),
+    IdlAccount::writable("previous_owner_miner"),
+    IdlAccount::writable("micro"),
+    IdlAccount::writable("rig"),
+    IdlAccount::writable("referral").optional(),
+    IdlAccount::writable("whitelist").optional(),
+    IdlAccount::writable("user_wrapped_sol"),
+    IdlAccount::writable("treasury_wrapped_sol"),
+    IdlAccount::readonly("token_program").at(spl_token::ID),
+    IdlAccount::readonly("sol_mint").at(SOL_MINT),
+    IdlAccount::readonly("associated_token_program").at(spl_associated_token_account::ID),
+];
+
 pub fn place_bid_with_session(
     signer: Pubkey,
     authority: Pubkey,
@@ -1216,171 +2938,371 @@ pub fn place_bid_with_session(
     payer: Pubkey,
     square_id: u64,
     fee_collector: Pubkey,
-    previous_owner_miner: Option<Pubkey>,
     previous_owner: Option<Pubkey>,
+    epoch_id: u64,
     referrer: Option<Pubkey>,
+    max_price: u64,
+    expires_at: Option<i64>,
+    access_code: Option<[u8; 32]>,
+) -> Instruction {
+    let treasury_address = treasury_pda().0;
+    let user_wrapped_sol_ata = get_associated_token_address(&authority, &SOL_MINT);
+    let treasury_wrapped_sol_ata = get_associated_token_address(&treasury_address, &SOL_MINT);
+    
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new(authority, false),
+        AccountMeta::new_readonly(program_signer, false),
+        AccountMeta::new(payer, false),
+    ];
+    accounts.extend(place_bid_accounts(authority, square_id, fee_collector, previous_owner, epoch_id, referrer, access_code));
+    accounts.extend_from_slice(&[
+        AccountMeta::new(user_wrapped_sol_ata, false),
+        AccountMeta::new(treasury_wrapped_sol_ata, false),
+        AccountMeta::new_readonly(spl_token::ID, false),
+        AccountMeta::new_readonly(SOL_MINT, false),
+        AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+    ]);
+    
+    let mut data = instruction::PlaceBid {
+        square_id: square_id.to_le_bytes(),
+        referrer: referrer.unwrap_or(Pubkey::default()).to_bytes(),
+        max_price: max_price.to_le_bytes(),
+        expires_at: expires_at.unwrap_or(0).to_le_bytes(),
+        access_code: access_code.unwrap_or_default(),
+    }
+    .to_bytes();
+    data[0] = 41u8; // PlaceBidWithSession = 41
+    
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data,
+    }
+}
+
+pub const CLAIM_AUCTION_OIL_WITH_SESSION_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("authority"),
+    IdlAccount::readonly("program_signer"),
+    IdlAccount::writable("payer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("miner"),
+    IdlAccount::writable("well_0"),
+    IdlAccount::writable("well_1"),
+    IdlAccount::writable("well_2"),
+    IdlAccount::writable("well_3"),
+    IdlAccount::writable("auction"),
+    IdlAccount::writable("treasury"),
+    IdlAccount::writable("treasury_tokens"),
+    IdlAccount::writable("mint").at(MINT_ADDRESS),
+    IdlAccount::writable("mint_authority"),
+    IdlAccount::readonly("mint_program").at(oil_mint_api::ID),
+    IdlAccount::writable("recipient"),
+    IdlAccount::readonly("token_program").at(spl_token::ID),
+    IdlAccount::readonly("associated_token_program").at(spl_associated_token_account::ID),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::readonly("oil_program").at(crate::ID),
+    IdlAccount::writable("miner_referrer").optional(),
+    IdlAccount::writable("referral_referrer").optional(),
+    IdlAccount::writable("referral_referrer_oil_ata").optional(),
+    IdlAccount::readonly("referral_config").optional(),
+    IdlAccount::writable("upline_referral").optional(),
+    IdlAccount::writable("upline_referral_oil_ata").optional(),
+];
+
+pub fn claim_auction_oil_with_session(
+    signer: Pubkey,
+    authority: Pubkey,
+    program_signer: Pubkey,
+    payer: Pubkey,
+    well_mask: u8,
+    referrer_miner: Option<Pubkey>,
+    referrer_referral: Option<Pubkey>,
+    referrer_referral_oil_ata: Option<Pubkey>,
+    upline_referral: Option<Pubkey>, // Upline's referral PDA (if the referral config pays an upline share)
+    upline_referral_oil_ata: Option<Pubkey>, // Upline's referral OIL ATA (if the referral config pays an upline share)
 ) -> Instruction {
-    let well_address = well_pda(square_id).0;
+    let config_address = config_pda().0;
+    let miner_address = miner_pda(authority).0;
+    let well_0_address = well_pda(0).0;
+    let well_1_address = well_pda(1).0;
+    let well_2_address = well_pda(2).0;
+    let well_3_address = well_pda(3).0;
     let auction_address = auction_pda().0;
     let treasury_address = treasury_pda().0;
     let treasury_tokens_address = get_associated_token_address(&treasury_address, &MINT_ADDRESS);
-    let staking_pool_address = pool_pda().0;
-    let config_address = config_pda().0;
+    let recipient_address = get_associated_token_address(&authority, &MINT_ADDRESS);
     let mint_authority_address = oil_mint_api::state::authority_pda().0;
-    let bidder_miner_address = miner_pda(authority).0;
-    let user_wrapped_sol_ata = get_associated_token_address(&authority, &SOL_MINT);
-    let treasury_wrapped_sol_ata = get_associated_token_address(&treasury_address, &SOL_MINT);
     
     let mut accounts = vec![
         AccountMeta::new(signer, true),
         AccountMeta::new(authority, false),
         AccountMeta::new_readonly(program_signer, false),
         AccountMeta::new(payer, false),
-        AccountMeta::new(well_address, false),
+        AccountMeta::new_readonly(config_address, false),
+        AccountMeta::new(miner_address, false),
+        AccountMeta::new(well_0_address, false),
+        AccountMeta::new(well_1_address, false),
+        AccountMeta::new(well_2_address, false),
+        AccountMeta::new(well_3_address, false),
         AccountMeta::new(auction_address, false),
         AccountMeta::new(treasury_address, false),
         AccountMeta::new(treasury_tokens_address, false),
         AccountMeta::new(MINT_ADDRESS, false),
         AccountMeta::new(mint_authority_address, false),
         AccountMeta::new_readonly(oil_mint_api::ID, false),
-        AccountMeta::new(staking_pool_address, false),
-        AccountMeta::new(fee_collector, false),
-        AccountMeta::new_readonly(config_address, false),
+        AccountMeta::new(recipient_address, false),
         AccountMeta::new_readonly(spl_token::ID, false),
+        AccountMeta::new_readonly(spl_associated_token_account::ID, false),
         AccountMeta::new_readonly(system_program::ID, false),
         AccountMeta::new_readonly(crate::ID, false),
-        AccountMeta::new(bidder_miner_address, false),
     ];
     
-    if let (Some(miner_pubkey), Some(owner_pubkey)) = (previous_owner_miner, previous_owner) {
+    if let (Some(miner_pubkey), Some(referral_pubkey), Some(oil_ata_pubkey)) = 
+        (referrer_miner, referrer_referral, referrer_referral_oil_ata) {
         accounts.push(AccountMeta::new(miner_pubkey, false));
-        accounts.push(AccountMeta::new(owner_pubkey, false));
+        accounts.push(AccountMeta::new(referral_pubkey, false));
+        accounts.push(AccountMeta::new(oil_ata_pubkey, false));
+        accounts.push(AccountMeta::new_readonly(referral_config_pda().0, false));
+        if let (Some(upline_pubkey), Some(upline_oil_ata_pubkey)) = (upline_referral, upline_referral_oil_ata) {
+            accounts.push(AccountMeta::new(upline_pubkey, false));
+            accounts.push(AccountMeta::new(upline_oil_ata_pubkey, false));
+        }
     }
     
-    if let Some(referrer_pubkey) = referrer {
-        let referral_address = referral_pda(referrer_pubkey).0;
-        accounts.push(AccountMeta::new(referral_address, false));
+    let mut data = ClaimAuctionOIL {
+        well_mask,
     }
-    
-    accounts.extend_from_slice(&[
-        AccountMeta::new(user_wrapped_sol_ata, false),
-        AccountMeta::new(treasury_wrapped_sol_ata, false),
-        AccountMeta::new_readonly(spl_token::ID, false),
-        AccountMeta::new_readonly(SOL_MINT, false),
-        AccountMeta::new_readonly(spl_associated_token_account::ID, false),
-    ]);
+    .to_bytes();
+    data[0] = 42u8; // ClaimAuctionOILWithSession = 42
     
     Instruction {
         program_id: crate::ID,
         accounts,
-        data: instruction::PlaceBid {
-            square_id: square_id.to_le_bytes(),
-            referrer: referrer.unwrap_or(Pubkey::default()).to_bytes(),
+        data,
+    }
+}
+
+pub const CLAIM_AUCTION_SOL_WITH_SESSION_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("authority"),
+    IdlAccount::readonly("program_signer"),
+    IdlAccount::writable("payer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("miner"),
+    IdlAccount::writable("treasury"),
+    IdlAccount::writable("auction"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::readonly("oil_program").at(crate::ID),
+    IdlAccount::writable("miner_referrer").optional(),
+    IdlAccount::writable("referral_referrer").optional(),
+    IdlAccount::readonly("referral_config").optional(),
+    IdlAccount::writable("upline_referral").optional(),
+];
+
+pub fn claim_auction_sol_with_session(
+    signer: Pubkey,
+    authority: Pubkey,
+    program_signer: Pubkey,
+    payer: Pubkey,
+    referrer_miner: Option<Pubkey>,
+    referrer_referral: Option<Pubkey>,
+    upline_referral: Option<Pubkey>, // Upline's referral PDA (if the referral config pays an upline share)
+) -> Instruction {
+    let config_address = config_pda().0;
+    let miner_address = miner_pda(authority).0;
+    let (auction_address, _) = auction_pda();
+    let treasury_address = treasury_pda().0;
+    
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new(authority, false),
+        AccountMeta::new_readonly(program_signer, false),
+        AccountMeta::new(payer, false),
+        AccountMeta::new_readonly(config_address, false),
+        AccountMeta::new(miner_address, false),
+        AccountMeta::new(treasury_address, false),
+        AccountMeta::new(auction_address, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+    
+    if let (Some(miner_pubkey), Some(referral_pubkey)) = (referrer_miner, referrer_referral) {
+        accounts.push(AccountMeta::new(miner_pubkey, false));
+        accounts.push(AccountMeta::new(referral_pubkey, false));
+        accounts.push(AccountMeta::new_readonly(referral_config_pda().0, false));
+        if let Some(upline_pubkey) = upline_referral {
+            accounts.push(AccountMeta::new(upline_pubkey, false));
         }
-        .to_bytes(),
+    }
+    
+    let mut data = ClaimAuctionSOL {
+        _reserved: 0,
+    }
+    .to_bytes();
+    data[0] = 43u8; // ClaimAuctionSOLWithSession = 43
+    
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data,
+    }
+}
+
+pub const WITHDRAW_CONTRIBUTION_WITH_SESSION_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("authority"),
+    IdlAccount::readonly("program_signer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("well"),
+    IdlAccount::writable("treasury"),
+    IdlAccount::writable("share"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+];
+
+pub fn withdraw_contribution_with_session(
+    signer: Pubkey,
+    authority: Pubkey,
+    program_signer: Pubkey,
+    well_id: u64,
+    epoch_id: u64,
+    amount: u64,
+) -> Instruction {
+    let config_address = config_pda().0;
+    let well_address = well_pda(well_id).0;
+    let treasury_address = treasury_pda().0;
+    let share_address = share_pda(authority, well_id, epoch_id).0;
+    
+    let mut data = WithdrawContribution {
+        well_id: well_id.to_le_bytes(),
+        amount: amount.to_le_bytes(),
+    }
+    .to_bytes();
+    data[0] = 58u8; // WithdrawContributionWithSession = 58
+    
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(authority, false),
+            AccountMeta::new_readonly(program_signer, false),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(well_address, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new(share_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
     }
 }
 
-pub fn claim_auction_oil_with_session(
+pub const CONTRIBUTE_WITH_SESSION_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("authority"),
+    IdlAccount::readonly("program_signer"),
+    IdlAccount::writable("payer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("well"),
+    IdlAccount::writable("auction"),
+    IdlAccount::writable("treasury"),
+    IdlAccount::writable("miner"),
+    IdlAccount::writable("rig"),
+    IdlAccount::writable("share"),
+    IdlAccount::writable("treasury_wrapped_sol"),
+    IdlAccount::writable("user_wrapped_sol"),
+    IdlAccount::readonly("token_program").at(spl_token::ID),
+    IdlAccount::readonly("sol_mint").at(SOL_MINT),
+    IdlAccount::readonly("associated_token_program").at(spl_associated_token_account::ID),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::readonly("oil_program").at(crate::ID),
+    IdlAccount::writable("whitelist").optional(),
+];
+
+pub fn contribute_with_session(
     signer: Pubkey,
     authority: Pubkey,
     program_signer: Pubkey,
     payer: Pubkey,
-    well_mask: u8,
-    referrer_miner: Option<Pubkey>,
-    referrer_referral: Option<Pubkey>,
-    referrer_referral_oil_ata: Option<Pubkey>,
+    well_id: u64,
+    epoch_id: u64,
+    amount: u64,
+    max_price: u64,
+    access_code: Option<[u8; 32]>,
 ) -> Instruction {
-    let miner_address = miner_pda(authority).0;
-    let well_0_address = well_pda(0).0;
-    let well_1_address = well_pda(1).0;
-    let well_2_address = well_pda(2).0;
-    let well_3_address = well_pda(3).0;
-    let auction_address = auction_pda().0;
-    let treasury_address = treasury_pda().0;
-    let treasury_tokens_address = get_associated_token_address(&treasury_address, &MINT_ADDRESS);
-    let recipient_address = get_associated_token_address(&authority, &MINT_ADDRESS);
-    let mint_authority_address = oil_mint_api::state::authority_pda().0;
-    
     let mut accounts = vec![
         AccountMeta::new(signer, true),
         AccountMeta::new(authority, false),
         AccountMeta::new_readonly(program_signer, false),
         AccountMeta::new(payer, false),
-        AccountMeta::new(miner_address, false),
-        AccountMeta::new(well_0_address, false),
-        AccountMeta::new(well_1_address, false),
-        AccountMeta::new(well_2_address, false),
-        AccountMeta::new(well_3_address, false),
-        AccountMeta::new(auction_address, false),
-        AccountMeta::new(treasury_address, false),
-        AccountMeta::new(treasury_tokens_address, false),
-        AccountMeta::new(MINT_ADDRESS, false),
-        AccountMeta::new(mint_authority_address, false),
-        AccountMeta::new_readonly(oil_mint_api::ID, false),
-        AccountMeta::new(recipient_address, false),
-        AccountMeta::new_readonly(spl_token::ID, false),
-        AccountMeta::new_readonly(spl_associated_token_account::ID, false),
-        AccountMeta::new_readonly(system_program::ID, false),
-        AccountMeta::new_readonly(crate::ID, false),
     ];
+    accounts.extend(contribute_accounts(authority, well_id, epoch_id, access_code));
     
-    if let (Some(miner_pubkey), Some(referral_pubkey), Some(oil_ata_pubkey)) = 
-        (referrer_miner, referrer_referral, referrer_referral_oil_ata) {
-        accounts.push(AccountMeta::new(miner_pubkey, false));
-        accounts.push(AccountMeta::new(referral_pubkey, false));
-        accounts.push(AccountMeta::new(oil_ata_pubkey, false));
+    let mut data = Contribute {
+        well_id: well_id.to_le_bytes(),
+        amount: amount.to_le_bytes(),
+        max_price: max_price.to_le_bytes(),
+        access_code: access_code.unwrap_or_default(),
     }
+    .to_bytes();
+    data[0] = 54u8; // ContributeWithSession = 54
     
     Instruction {
         program_id: crate::ID,
         accounts,
-        data: ClaimAuctionOIL {
-            well_mask,
-        }
-        .to_bytes(),
+        data,
     }
 }
 
-pub fn claim_auction_sol_with_session(
+pub const CHECKPOINT_AUCTION_WITH_SESSION_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("authority"),
+    IdlAccount::readonly("program_signer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("miner"),
+    IdlAccount::writable("share"),
+    IdlAccount::readonly("micro"),
+    IdlAccount::readonly("well"),
+    IdlAccount::readonly("oil_program").at(crate::ID),
+];
+
+pub fn checkpoint_auction_with_session(
     signer: Pubkey,
     authority: Pubkey,
     program_signer: Pubkey,
-    payer: Pubkey,
-    referrer_miner: Option<Pubkey>,
-    referrer_referral: Option<Pubkey>,
+    epoch_ids: [Option<u64>; 4],
 ) -> Instruction {
-    let miner_address = miner_pda(authority).0;
-    let (auction_address, _) = auction_pda();
-    let treasury_address = treasury_pda().0;
-    
     let mut accounts = vec![
         AccountMeta::new(signer, true),
         AccountMeta::new(authority, false),
         AccountMeta::new_readonly(program_signer, false),
-        AccountMeta::new(payer, false),
-        AccountMeta::new(miner_address, false),
-        AccountMeta::new(treasury_address, false),
-        AccountMeta::new(auction_address, false),
-        AccountMeta::new_readonly(system_program::ID, false),
-        AccountMeta::new_readonly(crate::ID, false),
     ];
+    accounts.extend(checkpoint_auction_accounts(authority, epoch_ids));
     
-    if let (Some(miner_pubkey), Some(referral_pubkey)) = (referrer_miner, referrer_referral) {
-        accounts.push(AccountMeta::new(miner_pubkey, false));
-        accounts.push(AccountMeta::new(referral_pubkey, false));
-    }
+    let mut data = checkpoint_auction_data(epoch_ids).to_bytes();
+    data[0] = 56u8; // CheckpointAuctionWithSession = 56
     
     Instruction {
         program_id: crate::ID,
         accounts,
-        data: ClaimAuctionSOL {
-            _reserved: 0,
-        }
-        .to_bytes(),
+        data,
     }
 }
 
+pub const CLAIM_SOL_WITH_SESSION_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("authority"),
+    IdlAccount::readonly("program_signer"),
+    IdlAccount::writable("payer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("miner"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::writable("miner_referrer").optional(),
+    IdlAccount::writable("referral_referrer").optional(),
+    IdlAccount::readonly("referral_config").optional(),
+    IdlAccount::writable("upline_referral").optional(),
+];
+
 pub fn claim_sol_with_session(
     signer: Pubkey,
     authority: Pubkey,
@@ -1388,7 +3310,9 @@ pub fn claim_sol_with_session(
     payer: Pubkey,
     referrer_miner: Option<Pubkey>,
     referrer_referral: Option<Pubkey>,
+    upline_referral: Option<Pubkey>, // Upline's referral PDA (if the referral config pays an upline share)
 ) -> Instruction {
+    let config_address = config_pda().0;
     let miner_address = miner_pda(authority).0;
     
     let mut accounts = vec![
@@ -1396,6 +3320,7 @@ pub fn claim_sol_with_session(
         AccountMeta::new(authority, false),
         AccountMeta::new_readonly(program_signer, false),
         AccountMeta::new(payer, false),
+        AccountMeta::new_readonly(config_address, false),
         AccountMeta::new(miner_address, false),
         AccountMeta::new_readonly(system_program::ID, false),
     ];
@@ -1403,15 +3328,44 @@ pub fn claim_sol_with_session(
     if let (Some(miner_pubkey), Some(referral_pubkey)) = (referrer_miner, referrer_referral) {
         accounts.push(AccountMeta::new(miner_pubkey, false));
         accounts.push(AccountMeta::new(referral_pubkey, false));
+        accounts.push(AccountMeta::new_readonly(referral_config_pda().0, false));
+        if let Some(upline_pubkey) = upline_referral {
+            accounts.push(AccountMeta::new(upline_pubkey, false));
+        }
     }
     
+    let mut data = ClaimSOL {}.to_bytes();
+    data[0] = 44u8; // ClaimSOLWithSession = 44
+    
     Instruction {
         program_id: crate::ID,
         accounts,
-        data: ClaimSOL {}.to_bytes(),
+        data,
     }
 }
 
+pub const CLAIM_OIL_WITH_SESSION_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("authority"),
+    IdlAccount::readonly("program_signer"),
+    IdlAccount::writable("payer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("miner"),
+    IdlAccount::writable("mint").at(MINT_ADDRESS),
+    IdlAccount::writable("recipient"),
+    IdlAccount::writable("treasury"),
+    IdlAccount::writable("treasury_tokens"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::readonly("token_program").at(spl_token::ID),
+    IdlAccount::readonly("associated_token_program").at(spl_associated_token_account::ID),
+    IdlAccount::writable("miner_referrer").optional(),
+    IdlAccount::writable("referral_referrer").optional(),
+    IdlAccount::writable("referral_referrer_oil_ata").optional(),
+    IdlAccount::readonly("referral_config").optional(),
+    IdlAccount::writable("upline_referral").optional(),
+    IdlAccount::writable("upline_referral_oil_ata").optional(),
+];
+
 pub fn claim_oil_with_session(
     signer: Pubkey,
     authority: Pubkey,
@@ -1420,7 +3374,10 @@ pub fn claim_oil_with_session(
     referrer_miner: Option<Pubkey>,
     referrer_referral: Option<Pubkey>,
     referrer_referral_oil_ata: Option<Pubkey>,
+    upline_referral: Option<Pubkey>, // Upline's referral PDA (if the referral config pays an upline share)
+    upline_referral_oil_ata: Option<Pubkey>, // Upline's referral OIL ATA (if the referral config pays an upline share)
 ) -> Instruction {
+    let config_address = config_pda().0;
     let miner_address = miner_pda(authority).0;
     let treasury_address = treasury_pda().0;
     let treasury_tokens_address = get_associated_token_address(&treasury_address, &MINT_ADDRESS);
@@ -1431,6 +3388,7 @@ pub fn claim_oil_with_session(
         AccountMeta::new(authority, false),
         AccountMeta::new_readonly(program_signer, false),
         AccountMeta::new(payer, false),
+        AccountMeta::new_readonly(config_address, false),
         AccountMeta::new(miner_address, false),
         AccountMeta::new(MINT_ADDRESS, false),
         AccountMeta::new(recipient_address, false),
@@ -1446,15 +3404,73 @@ pub fn claim_oil_with_session(
         accounts.push(AccountMeta::new(miner_pubkey, false));
         accounts.push(AccountMeta::new(referral_pubkey, false));
         accounts.push(AccountMeta::new(oil_ata_pubkey, false));
+        accounts.push(AccountMeta::new_readonly(referral_config_pda().0, false));
+        if let (Some(upline_pubkey), Some(upline_oil_ata_pubkey)) = (upline_referral, upline_referral_oil_ata) {
+            accounts.push(AccountMeta::new(upline_pubkey, false));
+            accounts.push(AccountMeta::new(upline_oil_ata_pubkey, false));
+        }
     }
     
+    let mut data = ClaimOIL {}.to_bytes();
+    data[0] = 45u8; // ClaimOILWithSession = 45
+    
     Instruction {
         program_id: crate::ID,
         accounts,
-        data: ClaimOIL {}.to_bytes(),
+        data,
     }
 }
 
+/// Takes the same accounts as `claim_oil_with_session`.
+pub fn vest_oil_with_session(
+    signer: Pubkey,
+    authority: Pubkey,
+    program_signer: Pubkey,
+    payer: Pubkey,
+    referrer_miner: Option<Pubkey>,
+    referrer_referral: Option<Pubkey>,
+    referrer_referral_oil_ata: Option<Pubkey>,
+    upline_referral: Option<Pubkey>,
+    upline_referral_oil_ata: Option<Pubkey>,
+) -> Instruction {
+    let mut ix = claim_oil_with_session(
+        signer,
+        authority,
+        program_signer,
+        payer,
+        referrer_miner,
+        referrer_referral,
+        referrer_referral_oil_ata,
+        upline_referral,
+        upline_referral_oil_ata,
+    );
+    let mut data = VestOIL {}.to_bytes();
+    data[0] = 88u8; // VestOILWithSession = 88
+    ix.data = data;
+    ix
+}
+
+pub const WITHDRAW_WITH_SESSION_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("authority"),
+    IdlAccount::readonly("program_signer"),
+    IdlAccount::writable("payer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("mint").at(MINT_ADDRESS),
+    IdlAccount::writable("recipient"),
+    IdlAccount::writable("stake"),
+    IdlAccount::writable("stake_tokens"),
+    IdlAccount::writable("pool"),
+    IdlAccount::writable("pool_tokens"),
+    IdlAccount::writable("miner"),
+    IdlAccount::writable("rig"),
+    IdlAccount::writable("treasury"),
+    IdlAccount::writable("treasury_oil"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::readonly("token_program").at(spl_token::ID),
+    IdlAccount::readonly("associated_token_program").at(spl_associated_token_account::ID),
+];
+
 pub fn withdraw_with_session(
     signer: Pubkey,
     authority: Pubkey,
@@ -1463,6 +3479,7 @@ pub fn withdraw_with_session(
     amount: u64,
     stake_id: u64,
 ) -> Instruction {
+    let config_address = config_pda().0;
     let stake_address = stake_pda_with_id(authority, stake_id).0;
     let stake_tokens_address = get_associated_token_address(&stake_address, &MINT_ADDRESS);
     let mint_address = MINT_ADDRESS;
@@ -1470,9 +3487,17 @@ pub fn withdraw_with_session(
     let pool_address = pool_pda().0;
     let pool_tokens_address = pool_tokens_address();
     let miner_address = miner_pda(authority).0;
+    let rig_address = rig_pda(authority).0;
     let treasury_address = treasury_pda().0;
     let treasury_tokens_address = treasury_tokens_address();
     
+    let mut data = Withdraw {
+        amount: amount.to_le_bytes(),
+        stake_id: stake_id.to_le_bytes(),
+    }
+    .to_bytes();
+    data[0] = 47u8; // WithdrawWithSession = 47
+    
     Instruction {
         program_id: crate::ID,
         accounts: vec![
@@ -1480,6 +3505,7 @@ pub fn withdraw_with_session(
             AccountMeta::new(authority, false),
             AccountMeta::new_readonly(program_signer, false),
             AccountMeta::new(payer, false),
+            AccountMeta::new_readonly(config_address, false),
             AccountMeta::new(mint_address, false),
             AccountMeta::new(recipient_address, false),
             AccountMeta::new(stake_address, false),
@@ -1487,20 +3513,36 @@ pub fn withdraw_with_session(
             AccountMeta::new(pool_address, false),
             AccountMeta::new(pool_tokens_address, false),
             AccountMeta::new(miner_address, false),
+            AccountMeta::new(rig_address, false),
             AccountMeta::new(treasury_address, false),
             AccountMeta::new(treasury_tokens_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
             AccountMeta::new_readonly(spl_token::ID, false),
             AccountMeta::new_readonly(spl_associated_token_account::ID, false),
         ],
-        data: Withdraw {
-            amount: amount.to_le_bytes(),
-            stake_id: stake_id.to_le_bytes(),
-        }
-        .to_bytes(),
+        data,
     }
 }
 
+pub const DEPOSIT_WITH_SESSION_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("authority"),
+    IdlAccount::readonly("program_signer"),
+    IdlAccount::writable("payer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("mint").at(MINT_ADDRESS),
+    IdlAccount::writable("sender"),
+    IdlAccount::writable("stake"),
+    IdlAccount::writable("stake_tokens"),
+    IdlAccount::writable("pool"),
+    IdlAccount::writable("pool_tokens"),
+    IdlAccount::writable("miner"),
+    IdlAccount::writable("rig"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::readonly("token_program").at(spl_token::ID),
+    IdlAccount::readonly("associated_token_program").at(spl_associated_token_account::ID),
+];
+
 pub fn deposit_with_session(
     signer: Pubkey,
     authority: Pubkey,
@@ -1510,6 +3552,7 @@ pub fn deposit_with_session(
     lock_duration_days: u64,
     stake_id: u64,
 ) -> Instruction {
+    let config_address = config_pda().0;
     let mint_address = MINT_ADDRESS;
     let stake_address = stake_pda_with_id(authority, stake_id).0;
     let stake_tokens_address = get_associated_token_address(&stake_address, &MINT_ADDRESS);
@@ -1517,6 +3560,7 @@ pub fn deposit_with_session(
     let pool_address = pool_pda().0;
     let pool_tokens_address = pool_tokens_address();
     let miner_address = miner_pda(authority).0;
+    let rig_address = rig_pda(authority).0;
     
     let mut data = Deposit {
         amount: amount.to_le_bytes(),
@@ -1533,13 +3577,76 @@ pub fn deposit_with_session(
             AccountMeta::new(authority, false), // 1: authority (user's wallet)
             AccountMeta::new_readonly(program_signer, false), // 2: program_signer
             AccountMeta::new(payer, false), // 3: payer (paymaster sponsor)
-            AccountMeta::new(mint_address, false), // 4: mint
-            AccountMeta::new(sender_address, false), // 5: sender (authority's OIL ATA)
-            AccountMeta::new(stake_address, false), // 6: stake
-            AccountMeta::new(stake_tokens_address, false), // 7: stake_tokens
-            AccountMeta::new(pool_address, false), // 8: pool
-            AccountMeta::new(pool_tokens_address, false), // 9: pool_tokens
-            AccountMeta::new(miner_address, false), // 10: miner
+            AccountMeta::new_readonly(config_address, false), // 4: config
+            AccountMeta::new(mint_address, false), // 5: mint
+            AccountMeta::new(sender_address, false), // 6: sender (authority's OIL ATA)
+            AccountMeta::new(stake_address, false), // 7: stake
+            AccountMeta::new(stake_tokens_address, false), // 8: stake_tokens
+            AccountMeta::new(pool_address, false), // 9: pool
+            AccountMeta::new(pool_tokens_address, false), // 10: pool_tokens
+            AccountMeta::new(miner_address, false), // 11: miner
+            AccountMeta::new(rig_address, false), // 12: rig
+            AccountMeta::new_readonly(system_program::ID, false), // 13: system_program
+            AccountMeta::new_readonly(spl_token::ID, false), // 14: token_program
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false), // 15: associated_token_program
+        ],
+        data,
+    }
+}
+
+pub const DEPOSIT_LIQUID_WITH_SESSION_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("authority"),
+    IdlAccount::readonly("program_signer"),
+    IdlAccount::writable("payer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("mint").at(MINT_ADDRESS),
+    IdlAccount::writable("sender"),
+    IdlAccount::writable("pool"),
+    IdlAccount::writable("pool_tokens"),
+    IdlAccount::writable("stoil_mint"),
+    IdlAccount::writable("recipient"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::readonly("token_program").at(spl_token::ID),
+    IdlAccount::readonly("associated_token_program").at(spl_associated_token_account::ID),
+];
+
+pub fn deposit_liquid_with_session(
+    signer: Pubkey,
+    authority: Pubkey,
+    program_signer: Pubkey,
+    payer: Pubkey,
+    amount: u64,
+    min_shares: u64,
+) -> Instruction {
+    let config_address = config_pda().0;
+    let sender_address = get_associated_token_address(&authority, &MINT_ADDRESS);
+    let pool_address = pool_pda().0;
+    let pool_tokens_address = pool_tokens_address();
+    let stoil_mint_address = stoil_mint_pda().0;
+    let recipient_address = get_associated_token_address(&authority, &stoil_mint_address);
+
+    let mut data = DepositLiquid {
+        amount: amount.to_le_bytes(),
+        min_shares: min_shares.to_le_bytes(),
+    }
+    .to_bytes();
+    data[0] = 91u8; // DepositLiquidWithSession = 91
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true), // 0: signer (session account)
+            AccountMeta::new(authority, false), // 1: authority (user's wallet)
+            AccountMeta::new_readonly(program_signer, false), // 2: program_signer
+            AccountMeta::new(payer, false), // 3: payer (paymaster sponsor)
+            AccountMeta::new_readonly(config_address, false), // 4: config
+            AccountMeta::new(MINT_ADDRESS, false), // 5: mint
+            AccountMeta::new(sender_address, false), // 6: sender (authority's OIL ATA)
+            AccountMeta::new(pool_address, false), // 7: pool
+            AccountMeta::new(pool_tokens_address, false), // 8: pool_tokens
+            AccountMeta::new(stoil_mint_address, false), // 9: stoil_mint
+            AccountMeta::new(recipient_address, false), // 10: recipient (authority's stOIL ATA)
             AccountMeta::new_readonly(system_program::ID, false), // 11: system_program
             AccountMeta::new_readonly(spl_token::ID, false), // 12: token_program
             AccountMeta::new_readonly(spl_associated_token_account::ID, false), // 13: associated_token_program
@@ -1548,6 +3655,78 @@ pub fn deposit_with_session(
     }
 }
 
+pub const REDEEM_LIQUID_WITH_SESSION_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("authority"),
+    IdlAccount::readonly("program_signer"),
+    IdlAccount::writable("payer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("mint").at(MINT_ADDRESS),
+    IdlAccount::writable("recipient"),
+    IdlAccount::writable("pool"),
+    IdlAccount::writable("pool_tokens"),
+    IdlAccount::writable("stoil_mint"),
+    IdlAccount::writable("sender"),
+    IdlAccount::writable("pool_stoil"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::readonly("token_program").at(spl_token::ID),
+    IdlAccount::readonly("associated_token_program").at(spl_associated_token_account::ID),
+];
+
+pub fn redeem_liquid_with_session(
+    signer: Pubkey,
+    authority: Pubkey,
+    program_signer: Pubkey,
+    payer: Pubkey,
+    amount: u64,
+) -> Instruction {
+    let config_address = config_pda().0;
+    let recipient_address = get_associated_token_address(&authority, &MINT_ADDRESS);
+    let pool_address = pool_pda().0;
+    let pool_tokens_address = pool_tokens_address();
+    let stoil_mint_address = stoil_mint_pda().0;
+    let sender_address = get_associated_token_address(&authority, &stoil_mint_address);
+    let pool_stoil_address = get_associated_token_address(&pool_address, &stoil_mint_address);
+
+    let mut data = RedeemLiquid {
+        amount: amount.to_le_bytes(),
+    }
+    .to_bytes();
+    data[0] = 93u8; // RedeemLiquidWithSession = 93
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true), // 0: signer (session account)
+            AccountMeta::new(authority, false), // 1: authority (user's wallet)
+            AccountMeta::new_readonly(program_signer, false), // 2: program_signer
+            AccountMeta::new(payer, false), // 3: payer (paymaster sponsor)
+            AccountMeta::new_readonly(config_address, false), // 4: config
+            AccountMeta::new(MINT_ADDRESS, false), // 5: mint
+            AccountMeta::new(recipient_address, false), // 6: recipient (authority's OIL ATA)
+            AccountMeta::new(pool_address, false), // 7: pool
+            AccountMeta::new(pool_tokens_address, false), // 8: pool_tokens
+            AccountMeta::new(stoil_mint_address, false), // 9: stoil_mint
+            AccountMeta::new(sender_address, false), // 10: sender (authority's stOIL ATA)
+            AccountMeta::new(pool_stoil_address, false), // 11: pool_stoil
+            AccountMeta::new_readonly(system_program::ID, false), // 12: system_program
+            AccountMeta::new_readonly(spl_token::ID, false), // 13: token_program
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false), // 14: associated_token_program
+        ],
+        data,
+    }
+}
+
+pub const CLAIM_YIELD_WITH_SESSION_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("authority"),
+    IdlAccount::readonly("program_signer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("stake"),
+    IdlAccount::writable("pool"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+];
+
 pub fn claim_yield_with_session(
     signer: Pubkey,
     authority: Pubkey,
@@ -1555,6 +3734,7 @@ pub fn claim_yield_with_session(
     amount: u64,
     stake_id: u64,
 ) -> Instruction {
+    let config_address = config_pda().0;
     let stake_address = stake_pda_with_id(authority, stake_id).0;
     let pool_address = pool_pda().0;
     
@@ -1568,22 +3748,34 @@ pub fn claim_yield_with_session(
         program_id: crate::ID,
         accounts: vec![
             AccountMeta::new(signer, true), // 0: signer (session account)
-            AccountMeta::new(authority, true), // 1: authority (user's wallet, writable for receiving SOL)
+            AccountMeta::new(authority, false), // 1: authority (user's wallet, writable for receiving SOL)
             AccountMeta::new_readonly(program_signer, false), // 2: program_signer
-            AccountMeta::new(stake_address, false), // 3: stake
-            AccountMeta::new(pool_address, false), // 4: pool
-            AccountMeta::new_readonly(system_program::ID, false), // 5: system_program
+            AccountMeta::new_readonly(config_address, false), // 3: config
+            AccountMeta::new(stake_address, false), // 4: stake
+            AccountMeta::new(pool_address, false), // 5: pool
+            AccountMeta::new_readonly(system_program::ID, false), // 6: system_program
         ],
         data,
     }
 }
 
+pub const CREATE_REFERRAL_WITH_SESSION_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("authority"),
+    IdlAccount::readonly("program_signer"),
+    IdlAccount::writable("payer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("referral"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+];
+
 pub fn create_referral_with_session(
     signer: Pubkey,
     authority: Pubkey,
     program_signer: Pubkey,
     payer: Pubkey,
 ) -> Instruction {
+    let config_address = config_pda().0;
     let referral_address = referral_pda(authority).0;
     
     // CreateReferralWithSession = 49
@@ -1596,19 +3788,36 @@ pub fn create_referral_with_session(
             AccountMeta::new(authority, false), // 1: authority (user's wallet)
             AccountMeta::new_readonly(program_signer, false), // 2: program_signer
             AccountMeta::new(payer, false), // 3: payer (paymaster sponsor)
-            AccountMeta::new(referral_address, false), // 4: referral
-            AccountMeta::new_readonly(system_program::ID, false), // 5: system_program
+            AccountMeta::new_readonly(config_address, false), // 4: config
+            AccountMeta::new(referral_address, false), // 5: referral
+            AccountMeta::new_readonly(system_program::ID, false), // 6: system_program
         ],
         data,
     }
 }
 
+pub const CLAIM_REFERRAL_WITH_SESSION_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("authority"),
+    IdlAccount::readonly("program_signer"),
+    IdlAccount::writable("payer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("referral"),
+    IdlAccount::writable("referral_tokens"),
+    IdlAccount::writable("mint").at(MINT_ADDRESS),
+    IdlAccount::writable("recipient"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+    IdlAccount::readonly("token_program").at(spl_token::ID),
+    IdlAccount::readonly("associated_token_program").at(spl_associated_token_account::ID),
+];
+
 pub fn claim_referral_with_session(
     signer: Pubkey,
     authority: Pubkey,
     program_signer: Pubkey,
     payer: Pubkey,
 ) -> Instruction {
+    let config_address = config_pda().0;
     let referral_address = referral_pda(authority).0;
     let referral_oil_address = get_associated_token_address(&referral_address, &MINT_ADDRESS);
     let recipient_oil_address = get_associated_token_address(&authority, &MINT_ADDRESS);
@@ -1623,14 +3832,179 @@ pub fn claim_referral_with_session(
             AccountMeta::new(authority, false), // 1: authority (user's wallet, receives SOL)
             AccountMeta::new_readonly(program_signer, false), // 2: program_signer
             AccountMeta::new(payer, false), // 3: payer (paymaster sponsor)
-            AccountMeta::new(referral_address, false), // 4: referral
-            AccountMeta::new(referral_oil_address, false), // 5: referral_tokens (Referral account's OIL ATA)
-            AccountMeta::new(MINT_ADDRESS, false), // 6: mint
-            AccountMeta::new(recipient_oil_address, false), // 7: recipient (Recipient's OIL ATA - authority's wallet)
-            AccountMeta::new_readonly(system_program::ID, false), // 8: system_program
-            AccountMeta::new_readonly(spl_token::ID, false), // 9: token_program
-            AccountMeta::new_readonly(spl_associated_token_account::ID, false), // 10: associated_token_program
+            AccountMeta::new_readonly(config_address, false), // 4: config
+            AccountMeta::new(referral_address, false), // 5: referral
+            AccountMeta::new(referral_oil_address, false), // 6: referral_tokens (Referral account's OIL ATA)
+            AccountMeta::new(MINT_ADDRESS, false), // 7: mint
+            AccountMeta::new(recipient_oil_address, false), // 8: recipient (Recipient's OIL ATA - authority's wallet)
+            AccountMeta::new_readonly(system_program::ID, false), // 9: system_program
+            AccountMeta::new_readonly(spl_token::ID, false), // 10: token_program
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false), // 11: associated_token_program
+        ],
+        data,
+    }
+}
+
+pub const CREATE_REFERRAL_CODE_WITH_SESSION_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("authority"),
+    IdlAccount::readonly("program_signer"),
+    IdlAccount::writable("payer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::readonly("referral"),
+    IdlAccount::writable("referral_code"),
+    IdlAccount::readonly("system_program").at(system_program::ID),
+];
+
+pub fn create_referral_code_with_session(
+    signer: Pubkey,
+    authority: Pubkey,
+    program_signer: Pubkey,
+    payer: Pubkey,
+    code_hash: [u8; 32],
+) -> Instruction {
+    let config_address = config_pda().0;
+    let referral_address = referral_pda(authority).0;
+    let referral_code_address = referral_code_pda(code_hash).0;
+    let mut data = CreateReferralCode { code_hash }.to_bytes();
+    data[0] = 65u8; // CreateReferralCodeWithSession = 65
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(authority, false),
+            AccountMeta::new_readonly(program_signer, false),
+            AccountMeta::new(payer, false),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new_readonly(referral_address, false),
+            AccountMeta::new(referral_code_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    }
+}
+
+pub const SET_REFERRAL_REBATE_WITH_SESSION_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("authority"),
+    IdlAccount::readonly("program_signer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("referral"),
+];
+
+pub fn set_referral_rebate_with_session(
+    signer: Pubkey,
+    authority: Pubkey,
+    program_signer: Pubkey,
+    rebate_bps: u64,
+) -> Instruction {
+    let config_address = config_pda().0;
+    let referral_address = referral_pda(authority).0;
+    let mut data = SetReferralRebate {
+        rebate_bps: rebate_bps.to_le_bytes(),
+    }
+    .to_bytes();
+    data[0] = 67u8; // SetReferralRebateWithSession = 67
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(authority, false),
+            AccountMeta::new_readonly(program_signer, false),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(referral_address, false),
+        ],
+        data,
+    }
+}
+
+pub const SET_REFERRER_WITH_SESSION_ACCOUNTS: &[IdlAccount] = &[
+    IdlAccount::signer("signer"),
+    IdlAccount::writable("authority"),
+    IdlAccount::readonly("program_signer"),
+    IdlAccount::readonly("config"),
+    IdlAccount::writable("miner"),
+    IdlAccount::writable("referral"),
+];
+
+pub fn set_referrer_with_session(
+    signer: Pubkey,
+    authority: Pubkey,
+    program_signer: Pubkey,
+    referrer: Pubkey,
+) -> Instruction {
+    let config_address = config_pda().0;
+    let miner_address = miner_pda(authority).0;
+    let referral_address = referral_pda(referrer).0;
+    let mut data = SetReferrer {
+        referrer: referrer.to_bytes(),
+    }
+    .to_bytes();
+    data[0] = 70u8; // SetReferrerWithSession = 70
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(authority, false),
+            AccountMeta::new_readonly(program_signer, false),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(miner_address, false),
+            AccountMeta::new(referral_address, false),
         ],
         data,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consts::ONE_OIL;
+
+    #[test]
+    fn test_project_supply() {
+        // No rounds, no emission
+        assert_eq!(project_supply(0, 0, 0), vec![(0, 0)]);
+
+        // From week 0, one point per epoch and one at the cap
+        let points = project_supply(0, 0, 1_000);
+        assert_eq!(points[0], (0, 0));
+        assert_eq!(points[1], (4, 4 * 1_000 * ONE_OIL * 200));
+        assert_eq!(points[2], (12, points[1].1 + 8 * 1_000 * ONE_OIL * 100));
+        assert_eq!(points.len(), EMISSION_SCHEDULE.len() + 1);
+        let (last_week, last_supply) = points[points.len() - 1];
+        assert_eq!(last_supply, MAX_SUPPLY);
+        assert!(last_week > EMISSION_SCHEDULE[EMISSION_SCHEDULE.len() - 1].0);
+        assert!(points.windows(2).all(|p| p[0].0 < p[1].0 && p[0].1 < p[1].1));
+
+        // Past the end of the schedule the last epoch runs up to the cap
+        let (final_week, final_amount) = EMISSION_SCHEDULE[EMISSION_SCHEDULE.len() - 1];
+        let supply = MAX_SUPPLY - 10 * final_amount - 1;
+        assert_eq!(
+            project_supply(supply, final_week + 100, 1),
+            vec![(final_week + 100, supply), (final_week + 111, MAX_SUPPLY)]
+        );
+
+        // A supply at or above the cap stays put
+        assert_eq!(project_supply(MAX_SUPPLY, 7, 1_000), vec![(7, MAX_SUPPLY)]);
+        assert_eq!(project_supply(u64::MAX, 7, 1_000), vec![(7, MAX_SUPPLY)]);
+    }
+
+    #[test]
+    fn test_liquid_stake_data() {
+        // Deposits carry the amount and the minimum stOIL to mint.
+        let ix = deposit_liquid(Pubkey::new_unique(), 5, 3);
+        let args = DepositLiquid::try_from_bytes(&ix.data[1..]).unwrap();
+        assert_eq!(ix.data[0], OilInstruction::DepositLiquid as u8);
+        assert_eq!((u64::from_le_bytes(args.amount), u64::from_le_bytes(args.min_shares)), (5, 3));
+
+        // Compounds carry the minimum OIL out, followed by the swap data passed to the swap program.
+        let ix = compound_liquid(Pubkey::new_unique(), 7, &[], &[1, 2, 3]);
+        let (args, swap_data) = ix.data[1..].split_at(std::mem::size_of::<CompoundLiquid>());
+        assert_eq!(ix.data[0], OilInstruction::CompoundLiquid as u8);
+        assert_eq!(u64::from_le_bytes(CompoundLiquid::try_from_bytes(args).unwrap().min_oil_out), 7);
+        assert_eq!(swap_data, &[1, 2, 3]);
+    }
+}
@@ -1,6 +1,11 @@
 use serde::{Deserialize, Serialize};
 use steel::*;
 
+use crate::consts::{
+    AUCTION_FLOOR_PRICE, AUCTION_PRICE_MULTIPLIER_BPS, DEFAULT_FIRST_HALVING_DECAY_BPS,
+    DEFAULT_FIRST_HALVING_DELAY_SECONDS, DEFAULT_HALVING_DECAY_BPS, DEFAULT_HALVING_PERIOD_SECONDS,
+    DENOMINATOR_BPS, HALVING_RATE_SCALE, MIN_HALVING_PERIOD_SECONDS,
+};
 use crate::state::auction_pda;
 use super::OilAccount;
 
@@ -11,7 +16,7 @@ pub struct Auction {
     /// Subsequent halving period in seconds (28 days = 2,419,200 seconds)
     pub halving_period_seconds: u64,
     
-    /// Timestamp of the last halving event (Unix timestamp in seconds)
+    /// Timestamp of the last halving event (Unix timestamp in seconds, schedule start if none yet)
     pub last_halving_time: u64,
     
     /// Base mining rates per well (OIL per second, in atomic units)
@@ -26,14 +31,169 @@ pub struct Auction {
     /// Buffer field (for future use)
     pub buffer_a: Numeric,
     
-    /// Number of halvings that have occurred (0 = none, 1 = first 50% halving, 2+ = 25% halvings)
+    /// Number of halvings that have occurred (0 = none, 1 = first halving, 2+ = subsequent halvings)
     pub halving_count: u64,
     
-    /// Buffer field (for future use)
-    pub buffer_c: u64,
+    /// Delay from the halving anchor to the first halving after it, in seconds
+    pub first_halving_delay_seconds: u64,
     
-    /// Buffer field (for future use)
-    pub buffer_d: u64,
+    /// Mining rate reduction of the very first halving (bps)
+    pub first_halving_decay_bps: u64,
+
+    /// Price curve per well (see `PriceCurve`, 0 = linear)
+    pub price_curves: [u64; 4],
+
+    /// Floor prices per well (in lamports, 0 = AUCTION_FLOOR_PRICE)
+    pub floor_prices: [u64; 4],
+
+    /// Multiplier applied to the winning price to set the next epoch's init_price (bps, 0 = AUCTION_PRICE_MULTIPLIER_BPS)
+    pub price_multipliers_bps: [u64; 4],
+
+    /// Mining rate reduction of each subsequent halving (bps)
+    pub halving_decay_bps: u64,
+
+    /// Start of the current halving schedule (0 = legacy schedule derived from last_halving_time)
+    pub halving_anchor_time: u64,
+
+    /// Number of halvings that occurred before the anchor
+    pub halving_anchor_count: u64,
+
+    /// Mining rate multiplier at the anchor (HALVING_RATE_SCALE = 1x)
+    pub halving_anchor_rate: u64,
+
+    /// Timestamp at which the pending halving schedule takes effect (0 = none)
+    pub pending_halving_effective_time: u64,
+
+    /// Pending delay from the effective time to the first halving under the new schedule, in seconds
+    pub pending_first_halving_delay_seconds: u64,
+
+    /// Pending period between halvings, in seconds
+    pub pending_halving_period_seconds: u64,
+
+    /// Pending reduction of the very first halving (bps, only used if no halving has occurred yet)
+    pub pending_first_halving_decay_bps: u64,
+
+    /// Pending reduction of each subsequent halving (bps)
+    pub pending_halving_decay_bps: u64,
+}
+
+/// Shape of the price decay from init_price down to the floor price over auction_duration_seconds.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, IntoPrimitive, TryFromPrimitive)]
+pub enum PriceCurve {
+    /// Price decays linearly to the floor.
+    Linear = 0,
+    /// Price halves every 1/AUCTION_EXPONENTIAL_HALF_LIVES of the auction, snapping to the floor at the end.
+    Exponential = 1,
+    /// Price drops in AUCTION_PRICE_STEPS equal steps.
+    Stepwise = 2,
+}
+
+impl PriceCurve {
+    pub fn from_u64(value: u64) -> Self {
+        Self::try_from(value as u8).unwrap_or(Self::Linear)
+    }
+}
+
+/// A halving schedule anchored at a point in time.
+///
+/// The n-th halving after the anchor occurs at `anchor_time + first_delay_seconds + (n - 1) * period_seconds`.
+/// Each halving multiplies the mining rate by `1 - decay`, using `first_decay_bps` for the very first
+/// halving of the protocol and `decay_bps` for every other one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HalvingSchedule {
+    pub anchor_time: u64,
+    pub anchor_count: u64,
+    pub anchor_rate: u64,
+    pub first_delay_seconds: u64,
+    pub period_seconds: u64,
+    pub first_decay_bps: u64,
+    pub decay_bps: u64,
+}
+
+impl HalvingSchedule {
+    /// Number of halvings since the anchor by a given timestamp.
+    fn halvings_since_anchor(&self, timestamp: u64) -> u64 {
+        let first_halving_time = self.anchor_time.saturating_add(self.first_delay_seconds);
+        if timestamp < first_halving_time {
+            return 0;
+        }
+        1 + (timestamp - first_halving_time) / self.period_seconds.max(1)
+    }
+
+    /// Timestamp of the n-th halving after the anchor (n >= 1).
+    fn halving_time(&self, n: u64) -> u64 {
+        self.anchor_time
+            .saturating_add(self.first_delay_seconds)
+            .saturating_add(n.saturating_sub(1).saturating_mul(self.period_seconds))
+    }
+
+    /// Number of halvings that had occurred by a given timestamp.
+    pub fn halving_count_at(&self, timestamp: u64) -> u64 {
+        self.anchor_count.saturating_add(self.halvings_since_anchor(timestamp))
+    }
+
+    /// Timestamp of the most recent halving at or before a given timestamp, if any since the anchor.
+    pub fn last_halving_at(&self, timestamp: u64) -> Option<u64> {
+        match self.halvings_since_anchor(timestamp) {
+            0 => None,
+            n => Some(self.halving_time(n)),
+        }
+    }
+
+    /// Timestamp of the first halving strictly after a given timestamp.
+    pub fn next_halving_after(&self, timestamp: u64) -> u64 {
+        self.halving_time(self.halvings_since_anchor(timestamp) + 1)
+    }
+
+    /// Mining rate multiplier at a given timestamp (HALVING_RATE_SCALE = 1x).
+    pub fn rate_at(&self, timestamp: u64) -> u64 {
+        let mut halvings = self.halvings_since_anchor(timestamp);
+        let mut rate = self.anchor_rate as u128;
+        if halvings > 0 && self.anchor_count == 0 {
+            rate = rate * (DENOMINATOR_BPS - self.first_decay_bps) as u128 / DENOMINATOR_BPS as u128;
+            halvings -= 1;
+        }
+        let factor = pow_scaled(
+            (DENOMINATOR_BPS - self.decay_bps) as u128 * HALVING_RATE_SCALE as u128 / DENOMINATOR_BPS as u128,
+            halvings,
+        );
+        (rate * factor / HALVING_RATE_SCALE as u128) as u64
+    }
+
+    /// Starts a new schedule at `timestamp`, carrying over the halvings and rate reached by then.
+    pub fn rebase(
+        &self,
+        timestamp: u64,
+        first_delay_seconds: u64,
+        period_seconds: u64,
+        first_decay_bps: u64,
+        decay_bps: u64,
+    ) -> Self {
+        Self {
+            anchor_time: timestamp,
+            anchor_count: self.halving_count_at(timestamp),
+            anchor_rate: self.rate_at(timestamp),
+            first_delay_seconds,
+            period_seconds,
+            first_decay_bps,
+            decay_bps,
+        }
+    }
+}
+
+/// Raises a HALVING_RATE_SCALE fixed-point factor (<= 1x) to the n-th power by squaring.
+fn pow_scaled(mut factor: u128, mut n: u64) -> u128 {
+    let scale = HALVING_RATE_SCALE as u128;
+    let mut result = scale;
+    while n > 0 && result > 0 {
+        if n & 1 == 1 {
+            result = result * factor / scale;
+        }
+        factor = factor * factor / scale;
+        n >>= 1;
+    }
+    result
 }
 
 impl Auction {
@@ -41,49 +201,198 @@ impl Auction {
         auction_pda()
     }
 
-    /// First halving period in seconds (14 days = 1,209,600 seconds)
-    /// This is a constant, not stored in the account
-    pub const FIRST_HALVING_PERIOD_SECONDS: u64 = 14 * 24 * 60 * 60;
+    /// Price curve configured for a well.
+    pub fn price_curve(&self, well_id: usize) -> PriceCurve {
+        PriceCurve::from_u64(self.price_curves[well_id])
+    }
 
-    /// Get the timestamp when the next halving should occur
-    pub fn next_halving_time(&self) -> u64 {
-        if self.halving_count == 0 {
-            // First halving is at FIRST_HALVING_PERIOD_SECONDS (14 days) after initialization
-            // last_halving_time is set to initialization time, so add 14 days
-            return self.last_halving_time + Self::FIRST_HALVING_PERIOD_SECONDS;
+    /// Floor price configured for a well, falling back to AUCTION_FLOOR_PRICE if unset.
+    pub fn floor_price(&self, well_id: usize) -> u64 {
+        match self.floor_prices[well_id] {
+            0 => AUCTION_FLOOR_PRICE,
+            floor_price => floor_price,
         }
-        
-        // After first halving, subsequent halvings are every halving_period_seconds (28 days)
-        self.last_halving_time + self.halving_period_seconds
     }
-    
-    /// Check if halving should be applied based on current time
-    /// Returns (halvings_to_apply, is_first_halving)
-    /// 
-    /// Schedule:
-    /// - First halving: 14 days after initialization (50% reduction)
-    /// - Subsequent halvings: Every 28 days after first halving (25% reduction each)
-    pub fn should_apply_halving(&self, current_time: u64) -> (u64, bool) {
-        // If no halvings have occurred yet, check for first halving (14 days)
-        if self.halving_count == 0 {
-            // last_halving_time is set to initialization time, so check if 14 days have passed
-            if current_time >= self.last_halving_time + Self::FIRST_HALVING_PERIOD_SECONDS {
-                return (1, true);
+
+    /// Next-epoch price multiplier configured for a well, falling back to AUCTION_PRICE_MULTIPLIER_BPS if unset.
+    pub fn price_multiplier_bps(&self, well_id: usize) -> u64 {
+        match self.price_multipliers_bps[well_id] {
+            0 => AUCTION_PRICE_MULTIPLIER_BPS,
+            multiplier => multiplier,
+        }
+    }
+
+    /// Starting price for the next epoch after a bid at `current_price`.
+    /// Bids at the floor restart from the configured starting price.
+    pub fn next_init_price(&self, well_id: usize, current_price: u64) -> u64 {
+        if current_price <= self.floor_price(well_id) {
+            return self.starting_prices[well_id];
+        }
+        let next_price = current_price as u128 * self.price_multiplier_bps(well_id) as u128 / DENOMINATOR_BPS as u128;
+        next_price.min(u64::MAX as u128) as u64
+    }
+
+    /// The halving schedule currently in effect, ignoring any pending change.
+    ///
+    /// Accounts created before configurable schedules (halving_anchor_time = 0) are anchored
+    /// at their initialization time with the default 14-day/50% then 28-day/25% schedule.
+    pub fn halving_schedule(&self) -> HalvingSchedule {
+        if self.halving_anchor_time > 0 {
+            return HalvingSchedule {
+                anchor_time: self.halving_anchor_time,
+                anchor_count: self.halving_anchor_count,
+                anchor_rate: self.halving_anchor_rate,
+                first_delay_seconds: self.first_halving_delay_seconds,
+                period_seconds: self.halving_period_seconds,
+                first_decay_bps: self.first_halving_decay_bps,
+                decay_bps: self.halving_decay_bps,
+            };
+        }
+
+        // Legacy: last_halving_time is the initialization time, or the time of the latest halving
+        let period_seconds = match self.halving_period_seconds {
+            0 => DEFAULT_HALVING_PERIOD_SECONDS,
+            period => period,
+        };
+        let anchor_time = if self.halving_count == 0 {
+            self.last_halving_time
+        } else {
+            self.last_halving_time
+                .saturating_sub((self.halving_count - 1) * period_seconds)
+                .saturating_sub(DEFAULT_FIRST_HALVING_DELAY_SECONDS)
+        };
+        HalvingSchedule {
+            anchor_time,
+            anchor_count: 0,
+            anchor_rate: HALVING_RATE_SCALE,
+            first_delay_seconds: DEFAULT_FIRST_HALVING_DELAY_SECONDS,
+            period_seconds,
+            first_decay_bps: DEFAULT_FIRST_HALVING_DECAY_BPS,
+            decay_bps: DEFAULT_HALVING_DECAY_BPS,
+        }
+    }
+
+    /// The halving schedule in effect at a given timestamp, including a pending change.
+    pub fn halving_schedule_at(&self, timestamp: u64) -> HalvingSchedule {
+        let schedule = self.halving_schedule();
+        if self.pending_halving_effective_time == 0 || timestamp < self.pending_halving_effective_time {
+            return schedule;
+        }
+        schedule.rebase(
+            self.pending_halving_effective_time,
+            self.pending_first_halving_delay_seconds,
+            self.pending_halving_period_seconds,
+            self.pending_first_halving_decay_bps,
+            self.pending_halving_decay_bps,
+        )
+    }
+
+    /// Number of halvings that had occurred by a given timestamp.
+    pub fn halving_count_at(&self, timestamp: u64) -> u64 {
+        self.halving_schedule_at(timestamp).halving_count_at(timestamp)
+    }
+
+    /// Mining rate of a well at a given timestamp (OIL per second, in atomic units).
+    pub fn mining_rate_at(&self, well_id: usize, timestamp: u64) -> u64 {
+        let rate = self.halving_schedule_at(timestamp).rate_at(timestamp);
+        (self.base_mining_rates[well_id] as u128 * rate as u128 / HALVING_RATE_SCALE as u128) as u64
+    }
+
+    /// Timestamp of the next change to the mining rate after a given timestamp
+    /// (a halving or a pending schedule taking effect).
+    pub fn next_rate_change_after(&self, timestamp: u64) -> u64 {
+        let next_halving_time = self.halving_schedule_at(timestamp).next_halving_after(timestamp);
+        if self.pending_halving_effective_time > timestamp {
+            return next_halving_time.min(self.pending_halving_effective_time);
+        }
+        next_halving_time
+    }
+
+    /// Get the timestamp when the next halving will occur
+    pub fn next_halving_time(&self, current_time: u64) -> u64 {
+        let mut timestamp = current_time;
+        loop {
+            let next = self.next_rate_change_after(timestamp);
+            if next <= timestamp || self.halving_count_at(next) > self.halving_count_at(timestamp) {
+                return next;
+            }
+            timestamp = next;
+        }
+    }
+
+    /// Brings the stored halving state up to date: materializes a legacy schedule and updates
+    /// halving_count and last_halving_time. A pending change stays pending until it is promoted.
+    pub fn sync_halvings(&mut self, current_time: u64) {
+        if self.halving_anchor_time == 0 {
+            let schedule = self.halving_schedule();
+            self.set_halving_schedule(&schedule);
+        }
+
+        let schedule = self.halving_schedule_at(current_time);
+        let halving_count = schedule.halving_count_at(current_time);
+        if halving_count > self.halving_count {
+            self.halving_count = halving_count;
+            // No halving since a pending change took effect: the latest one was under the current schedule
+            let last_halving_time = schedule.last_halving_at(current_time).or_else(|| {
+                let before_change = match self.pending_halving_effective_time {
+                    0 => current_time,
+                    effective_time => current_time.min(effective_time),
+                };
+                self.halving_schedule().last_halving_at(before_change)
+            });
+            if let Some(last_halving_time) = last_halving_time {
+                self.last_halving_time = last_halving_time;
             }
-            return (0, false);
-        }
-        
-        // After first halving, check for subsequent halvings (every 28 days)
-        if current_time < self.last_halving_time {
-            return (0, false); // Time hasn't reached last halving yet
-        }
-        
-        // Calculate how many halving periods have passed since last halving
-        let time_since_last_halving = current_time - self.last_halving_time;
-        let halvings_to_apply = time_since_last_halving / self.halving_period_seconds;
-        
-        // After first halving, all subsequent halvings are 25% reductions
-        (halvings_to_apply, false)
+        }
+    }
+
+    /// Makes a pending schedule that has taken effect the current one.
+    ///
+    /// Wells must have accumulated their OIL up to `current_time` beforehand, since mining before
+    /// the effective time can no longer be priced once the previous schedule is replaced.
+    pub fn promote_pending_halving_schedule(&mut self, current_time: u64) -> bool {
+        if self.pending_halving_effective_time == 0 || current_time < self.pending_halving_effective_time {
+            return false;
+        }
+        let schedule = self.halving_schedule_at(current_time);
+        self.set_halving_schedule(&schedule);
+        self.pending_halving_effective_time = 0;
+        self.pending_first_halving_delay_seconds = 0;
+        self.pending_halving_period_seconds = 0;
+        self.pending_first_halving_decay_bps = 0;
+        self.pending_halving_decay_bps = 0;
+        true
+    }
+
+    /// Checks a new halving schedule: the first delay and period must each last at least
+    /// `MIN_HALVING_PERIOD_SECONDS` and one auction, and the decays may not exceed 100%.
+    pub fn check_halving_schedule(
+        &self,
+        first_delay_seconds: u64,
+        period_seconds: u64,
+        first_decay_bps: u64,
+        decay_bps: u64,
+    ) -> Result<(), ProgramError> {
+        let min_seconds = MIN_HALVING_PERIOD_SECONDS.max(self.auction_duration_seconds);
+        if first_delay_seconds < min_seconds
+            || period_seconds < min_seconds
+            || first_decay_bps > DENOMINATOR_BPS
+            || decay_bps > DENOMINATOR_BPS
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(())
+    }
+
+    /// Stores a halving schedule as the one currently in effect.
+    pub fn set_halving_schedule(&mut self, schedule: &HalvingSchedule) {
+        self.halving_anchor_time = schedule.anchor_time;
+        self.halving_anchor_count = schedule.anchor_count;
+        self.halving_anchor_rate = schedule.anchor_rate;
+        self.first_halving_delay_seconds = schedule.first_delay_seconds;
+        self.halving_period_seconds = schedule.period_seconds;
+        self.first_halving_decay_bps = schedule.first_decay_bps;
+        self.halving_decay_bps = schedule.decay_bps;
     }
 }
 
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use steel::*;
 
+use crate::consts::{CONTRARIAN_STRATEGY_LEVEL, PAUSE_MINING, PAUSE_WITHDRAWALS};
 use crate::state::miner_pda;
 
 use super::OilAccount;
@@ -30,6 +31,7 @@ pub struct Automation {
     /// - Preferred: bit flags for which squares to deploy to
     /// - Random: first byte is number of squares to randomly select
     /// - Repeat: bit flags for which squares to deploy to (auto-updated after each deployment)
+    /// - Contrarian: first byte is number of squares with the least SOL deployed to select
     pub mask: u64,
 
     /// Whether or not to auto-reload SOL winnings into the automation balance.
@@ -45,18 +47,53 @@ pub enum AutomationStrategy {
     Random = 0,
     Preferred = 1,
     Repeat = 2,
+    Contrarian = 3,
 }
 
 impl AutomationStrategy {
     pub fn from_u64(value: u64) -> Self {
         Self::try_from(value as u8).unwrap()
     }
+
+    /// The rig level a miner needs to use this strategy.
+    pub fn min_level(self) -> u64 {
+        match self {
+            AutomationStrategy::Random | AutomationStrategy::Preferred | AutomationStrategy::Repeat => 0,
+            AutomationStrategy::Contrarian => CONTRARIAN_STRATEGY_LEVEL,
+        }
+    }
 }
 
 impl Automation {
     pub fn pda(&self) -> (Pubkey, u8) {
         miner_pda(self.authority)
     }
+
+    /// The pause flags that gate an Automate call with `executor`. Closing the automation (a default
+    /// executor) refunds its balance, so it is an exit and only stops with withdrawals.
+    pub fn pause_flags(executor: &Pubkey) -> u64 {
+        if *executor == Pubkey::default() {
+            PAUSE_WITHDRAWALS
+        } else {
+            PAUSE_MINING
+        }
+    }
 }
 
-account!(OilAccount, Automation);
\ No newline at end of file
+account!(OilAccount, Automation);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pause_flags() {
+        // Closing still works while mining is paused, and stops only with withdrawals.
+        let close = Automation::pause_flags(&Pubkey::default());
+        assert_eq!(close & PAUSE_MINING, 0);
+        assert_eq!(close, PAUSE_WITHDRAWALS);
+
+        // Opening or updating an automation stops with mining.
+        assert_eq!(Automation::pause_flags(&Pubkey::new_unique()), PAUSE_MINING);
+    }
+}
@@ -1,7 +1,9 @@
 use serde::{Deserialize, Serialize};
 use steel::*;
 
-use crate::state::config_pda;
+use crate::consts::{DENOMINATOR_BPS, MAX_ROLE_MEMBERS, ONE_WEEK, ROLE_COUNT};
+use crate::error::OilError;
+use crate::state::{config_pda, rig_refining_discount_bps, Miner};
 
 use super::OilAccount;
 
@@ -26,21 +28,310 @@ pub struct Config {
     /// Amount to pay to fee collector (bps)
     pub admin_fee: u64,
 
-    /// Current emission week (used for automatic weekly halving)
+    /// Current week of the emission schedule (see `EMISSION_SCHEDULE`), advanced by resets.
     pub emission_week: u64,
 
-    /// Timestamp when the last emission week was updated (for automatic weekly progression)
+    /// Start timestamp of the current emission week (0 = the schedule has not started).
     pub last_emission_week_update: u64,
 
     /// Timestamp for Token Generation Event (TGE). If current time < tge_timestamp, pre-mine is active.
     /// Set to 0 to disable pre-mine.
     pub tge_timestamp: i64,
+
+    /// The signer set of each role, indexed by `Role`.
+    pub roles: [RoleSet; ROLE_COUNT],
+
+    /// Bitmap of paused subsystems (see the PAUSE_* flags).
+    pub paused: u64,
+
+    /// Maximum mining boost from staking (bps of a miner's split OIL weight, 0 = no boost).
+    pub stake_boost_max_bps: u64,
+
+    /// Share of the total stake score (bps) at which a miner reaches the maximum boost.
+    pub stake_boost_share_bps: u64,
+
+    /// Discount on the refining fee for Seeker miners (bps of the fee).
+    pub seeker_refining_discount_bps: u64,
+
+    /// Refining fee charged on block-based OIL claims (bps of the unrefined OIL).
+    pub refining_fee_bps: u64,
+
+    /// Refining fee charged on auction-based OIL claims (bps of the claimed OIL).
+    pub auction_refining_fee_bps: u64,
+
+    /// Seconds over which vested OIL claims unlock linearly (see `Miner::vest_oil`).
+    pub vesting_period: i64,
+}
+
+/// Protocol roles, each held by an M-of-N set of signers.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, IntoPrimitive, TryFromPrimitive)]
+pub enum Role {
+    /// Manages the config, referrals and migrations.
+    Admin = 0,
+
+    /// Runs buybacks and wraps treasury SOL.
+    TreasuryOperator = 1,
+
+    /// Manages the treasury's pool position and barrels leftover liquidity OIL.
+    LiquidityManager = 2,
+
+    /// Configures the auction and the halving schedule.
+    AuctionManager = 3,
+
+    /// Creates and revokes whitelist codes.
+    WhitelistIssuer = 4,
+
+    /// Can pause subsystems in an emergency (but not unpause them).
+    Guardian = 5,
+}
+
+/// The members of a role and how many of them must sign.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct RoleSet {
+    /// Keys holding the role (Pubkey::default() = empty slot).
+    pub members: [Pubkey; MAX_ROLE_MEMBERS],
+
+    /// Number of members that must sign (0 = unassigned, the admin role acts instead).
+    pub threshold: u64,
+}
+
+impl RoleSet {
+    /// A role held by a single key.
+    pub fn single(member: Pubkey) -> Self {
+        let mut role_set = Self::zeroed();
+        role_set.members[0] = member;
+        role_set.threshold = 1;
+        role_set
+    }
+
+    pub fn is_member(&self, key: &Pubkey) -> bool {
+        *key != Pubkey::default() && self.members.contains(key)
+    }
+
+    /// Number of distinct members among `signers`.
+    pub fn approvals<'k>(&self, signers: impl Iterator<Item = &'k Pubkey>) -> u64 {
+        let mut approved = [false; MAX_ROLE_MEMBERS];
+        for signer in signers {
+            if *signer == Pubkey::default() {
+                continue;
+            }
+            if let Some(i) = self.members.iter().position(|m| m == signer) {
+                approved[i] = true;
+            }
+        }
+        approved.iter().filter(|a| **a).count() as u64
+    }
 }
 
 impl Config {
     pub fn pda() -> (Pubkey, u8) {
         config_pda()
     }
+
+    /// The signer set of `role`. Unassigned roles fall back to the admin role, and an
+    /// unassigned admin role to `admin`.
+    pub fn role(&self, role: Role) -> RoleSet {
+        let role_set = self.roles[role as usize];
+        if role_set.threshold > 0 {
+            role_set
+        } else if role != Role::Admin {
+            self.role(Role::Admin)
+        } else {
+            RoleSet::single(self.admin)
+        }
+    }
+
+    /// Checks that `signer` holds `role` and that enough members signed. Co-signers are
+    /// any other signer accounts in `accounts`.
+    pub fn check_role(&self, role: Role, signer: &AccountInfo, accounts: &[AccountInfo]) -> Result<(), ProgramError> {
+        let role_set = self.role(role);
+        if !signer.is_signer || !role_set.is_member(signer.key) {
+            return Err(OilError::NotAuthorized.into());
+        }
+        let signers = accounts.iter().filter(|a| a.is_signer).map(|a| a.key);
+        if role_set.approvals(signers) < role_set.threshold {
+            return Err(OilError::NotAuthorized.into());
+        }
+        Ok(())
+    }
+
+    /// Hands the admin over to `new_admin`. In an admin multisig the new admin takes the outgoing
+    /// admin's seat and the threshold is kept; an unassigned admin role keeps falling back to `admin`.
+    pub fn hand_over_admin(&mut self, new_admin: Pubkey) -> Result<(), ProgramError> {
+        let old_admin = self.admin;
+        let mut role_set = self.roles[Role::Admin as usize];
+        if role_set.threshold > 0 && old_admin != new_admin {
+            let old_seat = role_set.members.iter().position(|m| *m == old_admin && *m != Pubkey::default());
+            if role_set.is_member(&new_admin) {
+                // The new admin already holds a seat, so the outgoing admin's seat is freed
+                if let Some(i) = old_seat {
+                    role_set.members[i] = Pubkey::default();
+                }
+            } else {
+                let seat = old_seat
+                    .or_else(|| role_set.members.iter().position(|m| *m == Pubkey::default()))
+                    .ok_or(ProgramError::InvalidArgument)?;
+                role_set.members[seat] = new_admin;
+            }
+            let member_count = role_set.members.iter().filter(|m| **m != Pubkey::default()).count() as u64;
+            if member_count < role_set.threshold {
+                return Err(ProgramError::InvalidArgument);
+            }
+        }
+        self.roles[Role::Admin as usize] = role_set;
+        self.admin = new_admin;
+        Ok(())
+    }
+
+    /// Advances `emission_week` by the whole weeks elapsed since the current one started. The
+    /// first call starts week 0.
+    pub fn update_emission_week(&mut self, now: i64) {
+        let now = now.max(0) as u64;
+        if self.last_emission_week_update == 0 {
+            self.last_emission_week_update = now;
+            return;
+        }
+        let weeks = now.saturating_sub(self.last_emission_week_update) / ONE_WEEK as u64;
+        self.emission_week += weeks;
+        self.last_emission_week_update += weeks * ONE_WEEK as u64;
+    }
+
+    /// Mining boost (bps) of a miner holding `stake_score` out of `total_staked_score`. The boost
+    /// grows linearly with the miner's share of the stake, up to `stake_boost_max_bps`.
+    pub fn stake_boost_bps(&self, stake_score: u64, total_staked_score: u64) -> u64 {
+        if total_staked_score == 0 || self.stake_boost_share_bps == 0 {
+            return 0;
+        }
+        let share_bps = stake_score as u128 * DENOMINATOR_BPS as u128 / total_staked_score as u128;
+        let boost_bps = share_bps * self.stake_boost_max_bps as u128 / self.stake_boost_share_bps as u128;
+        boost_bps.min(self.stake_boost_max_bps as u128) as u64
+    }
+
+    /// Refining fee (bps of the unrefined OIL) charged when `miner` claims block-based OIL. Seeker
+    /// miners and rig levels each discount the fee.
+    pub fn refining_fee_bps(&self, miner: &Miner) -> u64 {
+        let mut discount_bps = rig_refining_discount_bps(miner.level);
+        if miner.is_seeker != 0 {
+            discount_bps = discount_bps.saturating_add(self.seeker_refining_discount_bps);
+        }
+        let discount_bps = discount_bps.min(DENOMINATOR_BPS);
+        self.refining_fee_bps * (DENOMINATOR_BPS - discount_bps) / DENOMINATOR_BPS
+    }
+
+    /// Fails with `OilError::Paused` if any subsystem in `flags` is paused.
+    pub fn check_unpaused(&self, flags: u64) -> Result<(), ProgramError> {
+        if self.paused & flags != 0 {
+            return Err(OilError::Paused.into());
+        }
+        Ok(())
+    }
 }
 
 account!(OilAccount, Config);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_role_fallback_and_approvals() {
+        let admin = Pubkey::new_unique();
+        let mut config = Config::zeroed();
+        config.admin = admin;
+        assert_eq!(config.role(Role::WhitelistIssuer), RoleSet::single(admin));
+
+        // 2-of-3 treasury operator
+        let (a, b, c) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let mut operators = RoleSet::zeroed();
+        operators.members[..3].copy_from_slice(&[a, b, c]);
+        operators.threshold = 2;
+        config.roles[Role::TreasuryOperator as usize] = operators;
+        let treasury_operator = config.role(Role::TreasuryOperator);
+        assert!(!treasury_operator.is_member(&admin));
+        assert!(!treasury_operator.is_member(&Pubkey::default()));
+        assert_eq!(treasury_operator.approvals([a, a, admin].iter()), 1);
+        assert_eq!(treasury_operator.approvals([a, c].iter()), 2);
+    }
+
+    #[test]
+    fn test_hand_over_admin() {
+        let (a, b, c, d) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+
+        // An unassigned admin role follows the admin key.
+        let mut config = Config::zeroed();
+        config.admin = a;
+        config.hand_over_admin(b).unwrap();
+        assert_eq!(config.admin, b);
+        assert_eq!(config.role(Role::Admin), RoleSet::single(b));
+
+        // A 2-of-3 admin multisig keeps its threshold, with the new admin in the outgoing admin's seat.
+        let mut admins = RoleSet::zeroed();
+        admins.members[..3].copy_from_slice(&[a, b, c]);
+        admins.threshold = 2;
+        config.admin = a;
+        config.roles[Role::Admin as usize] = admins;
+        config.hand_over_admin(d).unwrap();
+        assert_eq!(config.admin, d);
+        assert_eq!(&config.role(Role::Admin).members[..3], &[d, b, c]);
+        assert_eq!(config.role(Role::Admin).threshold, 2);
+
+        // Handing over to an existing member frees the outgoing admin's seat.
+        config.hand_over_admin(b).unwrap();
+        assert_eq!(&config.role(Role::Admin).members[..3], &[Pubkey::default(), b, c]);
+        assert_eq!(config.role(Role::Admin).threshold, 2);
+
+        // The threshold must stay reachable.
+        assert_eq!(config.hand_over_admin(c), Err(ProgramError::InvalidArgument));
+    }
+
+    #[test]
+    fn test_stake_boost() {
+        let mut config = Config::zeroed();
+        assert_eq!(config.stake_boost_bps(100, 1_000), 0);
+
+        // +50% at 1% of the total stake
+        config.stake_boost_max_bps = 5_000;
+        config.stake_boost_share_bps = 100;
+        assert_eq!(config.stake_boost_bps(0, 1_000), 0);
+        assert_eq!(config.stake_boost_bps(50, 10_000), 2_500);
+        assert_eq!(config.stake_boost_bps(500, 10_000), 5_000);
+        assert_eq!(config.stake_boost_bps(500, 0), 0);
+    }
+
+    #[test]
+    fn test_refining_fee() {
+        let mut config = Config::zeroed();
+        let mut miner = Miner::zeroed();
+        assert_eq!(config.refining_fee_bps(&miner), 0);
+        config.refining_fee_bps = 1_000;
+        assert_eq!(config.refining_fee_bps(&miner), 1_000);
+        miner.is_seeker = 1;
+        assert_eq!(config.refining_fee_bps(&miner), 1_000);
+        config.seeker_refining_discount_bps = 5_000;
+        assert_eq!(config.refining_fee_bps(&miner), 500);
+
+        // Rig levels stack with the Seeker discount.
+        miner.level = 4;
+        assert_eq!(config.refining_fee_bps(&miner), 400);
+        config.seeker_refining_discount_bps = 20_000;
+        assert_eq!(config.refining_fee_bps(&miner), 0);
+    }
+
+    #[test]
+    fn test_update_emission_week() {
+        let mut config = Config::zeroed();
+        let start = 1_000_000;
+        config.update_emission_week(start);
+        assert_eq!((config.emission_week, config.last_emission_week_update), (0, start as u64));
+
+        // Partial weeks do not advance the week
+        config.update_emission_week(start + ONE_WEEK - 1);
+        assert_eq!(config.emission_week, 0);
+
+        // Skipped weeks are caught up, keeping the week boundaries aligned to the start
+        config.update_emission_week(start + 3 * ONE_WEEK + 10);
+        assert_eq!((config.emission_week, config.last_emission_week_update), (3, (start + 3 * ONE_WEEK) as u64));
+    }
+}
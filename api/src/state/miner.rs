@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use steel::*;
 
+use crate::consts::DENOMINATOR_BPS;
 use crate::state::{miner_pda, Treasury};
 
 use super::OilAccount;
@@ -83,9 +84,11 @@ pub struct Miner {
     /// Total stake score across all stake accounts for this miner.
     pub total_stake_score: u64,
 
+    /// Whether this miner has claimed a Seeker genesis token (1 = seeker, see `ClaimSeeker`).
     pub is_seeker: u64,
-    /// XP system (future development)
-    pub buffer_a: u64,
+
+    /// The miner's progression level, mirrored from its rig (see `Rig`).
+    pub level: u64,
     
     /// Last epoch participated in per well (index = well_id, 0-3)
     /// Moved from Rig to Miner to free Rig for progression/Refinery mode
@@ -94,6 +97,19 @@ pub struct Miner {
     /// Last epoch checkpointed per well (index = well_id, 0-3)
     /// Moved from Rig to Miner to free Rig for progression/Refinery mode
     pub checkpointed_epoch_id: [u64; 4],
+
+    /// The stake boost (bps) snapshotted when the miner first deployed in `round_id` (see
+    /// `Config::stake_boost_bps`).
+    pub stake_boost_bps: u64,
+
+    /// OIL locked in the vesting schedule (see `Miner::vest_oil`).
+    pub vesting_oil: u64,
+
+    /// The last time OIL was released from the vesting schedule.
+    pub vesting_updated_at: i64,
+
+    /// The time the vesting schedule fully unlocks.
+    pub vesting_end_at: i64,
 }
 
 impl Miner {
@@ -116,7 +132,7 @@ impl Miner {
         self.round_id = 0;
         self.pooled_deployed = 0;
         self.is_seeker = 0;
-        self.buffer_a = 0;
+        self.level = 0;
         self.auction_rewards_oil = 0;
         self.auction_rewards_sol = 0;
         self.auction_rewards_factor = Numeric::ZERO;
@@ -131,9 +147,21 @@ impl Miner {
         self.total_stake_score = 0;
         self.current_epoch_id = [0; 4];
         self.checkpointed_epoch_id = [0; 4];
+        self.stake_boost_bps = 0;
+        self.vesting_oil = 0;
+        self.vesting_updated_at = 0;
+        self.vesting_end_at = 0;
+    }
+
+    /// `amount` weighted by the stake boost of the current round, for sharing split OIL.
+    pub fn boosted(&self, amount: u64) -> u64 {
+        (amount as u128 * (DENOMINATOR_BPS + self.stake_boost_bps) as u128 / DENOMINATOR_BPS as u128)
+            .min(u64::MAX as u128) as u64
     }
 
-    pub fn claim_oil(&mut self, clock: &Clock, treasury: &mut Treasury) -> u64 {
+    /// Claims refined and unrefined OIL, charging `refining_fee_bps` of the unrefined OIL as the
+    /// refining fee (see `Config::refining_fee_bps`).
+    pub fn claim_oil(&mut self, clock: &Clock, treasury: &mut Treasury, refining_fee_bps: u64) -> u64 {
         self.update_rewards(treasury);
         let refined_oil = self.block_refined_oil;
         let rewards_oil = self.block_rewards_oil;
@@ -141,11 +169,11 @@ impl Miner {
         self.block_refined_oil = 0;
         self.block_rewards_oil = 0;
 
-        // Charge a 10% fee and share with miners who haven't claimed yet.
+        // Charge the refining fee and share with miners who haven't claimed yet.
         // Check block_total_unclaimed BEFORE subtracting this miner's rewards_oil
         // to ensure fee is charged even if this is the only miner with unclaimed oil.
         if treasury.block_total_unclaimed > 0 {
-            let fee = rewards_oil / 10;
+            let fee = (rewards_oil as u128 * refining_fee_bps as u128 / DENOMINATOR_BPS as u128) as u64;
             amount -= fee;
             treasury.block_rewards_factor += Numeric::from_fraction(fee, treasury.block_total_unclaimed);
             treasury.block_total_refined += fee;
@@ -159,6 +187,48 @@ impl Miner {
         amount
     }
 
+    /// Claims OIL through the vesting schedule instead of paying the refining fee. Unrefined OIL is
+    /// locked and unlocks linearly over `vesting_period`, merged with any OIL still locked by
+    /// weighting the remaining durations. Returns the refined OIL plus the OIL unlocked so far.
+    pub fn vest_oil(&mut self, clock: &Clock, treasury: &mut Treasury, vesting_period: i64) -> u64 {
+        self.update_rewards(treasury);
+        let now = clock.unix_timestamp;
+        let refined_oil = self.block_refined_oil;
+        let rewards_oil = self.block_rewards_oil;
+        self.block_refined_oil = 0;
+        self.block_rewards_oil = 0;
+        treasury.block_total_unclaimed -= rewards_oil;
+        treasury.block_total_refined -= refined_oil;
+
+        // Release the unlocked OIL before extending the schedule.
+        let unlocked_oil = self.vested_oil(now);
+        self.vesting_oil -= unlocked_oil;
+        self.vesting_updated_at = now;
+
+        if rewards_oil > 0 {
+            let locked_duration = self.vesting_end_at.saturating_sub(now).max(0) as u128;
+            let vesting_oil = self.vesting_oil + rewards_oil;
+            let duration = (self.vesting_oil as u128 * locked_duration
+                + rewards_oil as u128 * vesting_period.max(0) as u128)
+                / vesting_oil as u128;
+            self.vesting_oil = vesting_oil;
+            self.vesting_end_at = now + duration as i64;
+        }
+        self.last_claim_block_oil_at = now;
+
+        refined_oil + unlocked_oil
+    }
+
+    /// The OIL in the vesting schedule unlocked at `now` and not yet released.
+    pub fn vested_oil(&self, now: i64) -> u64 {
+        if now >= self.vesting_end_at {
+            return self.vesting_oil;
+        }
+        let elapsed = now.saturating_sub(self.vesting_updated_at).max(0) as u128;
+        let duration = (self.vesting_end_at - self.vesting_updated_at).max(1) as u128;
+        (self.vesting_oil as u128 * elapsed / duration) as u64
+    }
+
     pub fn claim_sol(&mut self, clock: &Clock) -> u64 {
         let amount = self.block_rewards_sol;
         self.block_rewards_sol = 0;
@@ -198,4 +268,34 @@ impl Miner {
     }
 }
 
-account!(OilAccount, Miner);
\ No newline at end of file
+account!(OilAccount, Miner);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vest_oil() {
+        let mut miner = Miner::zeroed();
+        let mut treasury = Treasury::zeroed();
+        let mut clock = Clock::default();
+        clock.unix_timestamp = 1_000;
+
+        // Unrefined OIL is locked without a fee.
+        miner.block_rewards_oil = 1_000;
+        treasury.block_total_unclaimed = 5_000;
+        assert_eq!(miner.vest_oil(&clock, &mut treasury, 100), 0);
+        assert_eq!((miner.vesting_oil, miner.vesting_end_at), (1_000, 1_100));
+        assert_eq!(treasury.block_total_unclaimed, 4_000);
+
+        // Half way through, half of it has unlocked.
+        assert_eq!(miner.vested_oil(1_050), 500);
+        clock.unix_timestamp = 1_050;
+        miner.block_rewards_oil = 500;
+        assert_eq!(miner.vest_oil(&clock, &mut treasury, 100), 500);
+
+        // The rest merges with the new OIL: (500 * 50 + 500 * 100) / 1_000 = 75 seconds left.
+        assert_eq!((miner.vesting_oil, miner.vesting_end_at), (1_000, 1_125));
+        assert_eq!(miner.vested_oil(2_000), 1_000);
+    }
+}
@@ -4,9 +4,14 @@ mod board;
 mod config;
 mod micro;
 mod miner;
+mod pending_admin_action;
 mod pool;
 mod referral;
+mod referral_code;
+mod referral_config;
+mod rig;
 mod round;
+mod seeker;
 mod share;
 mod well;
 mod stake;
@@ -19,9 +24,14 @@ pub use board::*;
 pub use config::*;
 pub use micro::*;
 pub use miner::*;
+pub use pending_admin_action::*;
 pub use pool::*;
 pub use referral::*;
+pub use referral_code::*;
+pub use referral_config::*;
+pub use rig::*;
 pub use round::*;
+pub use seeker::*;
 pub use share::*;
 pub use well::*;
 pub use stake::*;
@@ -49,6 +59,42 @@ pub enum OilAccount {
     Whitelist = 117,
     Micro = 118,
     Share = 119,
+    ReferralConfig = 120,
+    ReferralCode = 121,
+    PendingAdminAction = 122,
+    Seeker = 123,
+    Rig = 124,
+}
+
+impl OilAccount {
+    /// The current layout version of this account type. Bump it when the layout changes and
+    /// register the upgrade in the program's `migrate_account` module.
+    pub fn version(self) -> u8 {
+        match self {
+            OilAccount::Automation
+            | OilAccount::Board
+            | OilAccount::Stake
+            | OilAccount::Referral
+            | OilAccount::Auction
+            | OilAccount::Well
+            | OilAccount::Whitelist
+            | OilAccount::Micro
+            | OilAccount::Share
+            | OilAccount::ReferralConfig
+            | OilAccount::ReferralCode
+            | OilAccount::PendingAdminAction
+            | OilAccount::Seeker
+            | OilAccount::Rig => 1,
+            OilAccount::Pool | OilAccount::Round | OilAccount::Treasury => 2,
+            OilAccount::Miner => 3,
+            OilAccount::Config => 4,
+        }
+    }
+}
+
+/// Returns the layout version stored in an account's discriminator (0 for legacy accounts).
+pub fn account_version(data: &[u8]) -> u8 {
+    data.get(ACCOUNT_VERSION_OFFSET).copied().unwrap_or(0)
 }
 
 pub fn automation_pda(authority: Pubkey) -> (Pubkey, u8) {
@@ -83,6 +129,18 @@ pub fn referral_pda(authority: Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[REFERRAL, &authority.to_bytes()], &crate::ID)
 }
 
+pub fn referral_config_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[REFERRAL_CONFIG], &crate::ID)
+}
+
+pub fn referral_code_pda(code_hash: [u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[REFERRAL_CODE, &code_hash], &crate::ID)
+}
+
+pub fn pending_admin_action_pda(action: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PENDING_ADMIN_ACTION, &[action]], &crate::ID)
+}
+
 pub fn treasury_pda() -> (Pubkey, u8) {
     Pubkey::find_program_address(&[TREASURY], &crate::ID)
 }
@@ -100,6 +158,10 @@ pub fn pool_tokens_address() -> Pubkey {
     spl_associated_token_account::get_associated_token_address(&pool_address, &MINT_ADDRESS)
 }
 
+pub fn stoil_mint_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[STOIL_MINT], &crate::ID)
+}
+
 pub fn auction_pda() -> (Pubkey, u8) {
     Pubkey::find_program_address(&[AUCTION], &crate::ID)
 }
@@ -116,6 +178,14 @@ pub fn micro_pda(well_id: u64, epoch_id: u64) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[MICRO, &well_id.to_le_bytes(), &epoch_id.to_le_bytes()], &crate::ID)
 }
 
+pub fn seeker_pda(mint: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SEEKER, &mint.to_bytes()], &crate::ID)
+}
+
+pub fn rig_pda(authority: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[RIG, &authority.to_bytes()], &crate::ID)
+}
+
 pub fn share_pda(authority: Pubkey, well_id: u64, epoch_id: u64) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[SHARE, &authority.to_bytes(), &well_id.to_le_bytes(), &epoch_id.to_le_bytes()], &crate::ID)
 }
\ No newline at end of file
@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+use solana_program::keccak;
+use steel::*;
+
+use crate::consts::ADMIN_ACTION_WINDOW_SECONDS;
+use crate::state::pending_admin_action_pda;
+
+use super::OilAccount;
+
+/// PendingAdminAction holds an admin action queued behind the timelock (one per action).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct PendingAdminAction {
+    /// The queued instruction (OilInstruction discriminator).
+    pub action: u64,
+
+    /// The admin that queued the action.
+    pub proposer: Pubkey,
+
+    /// The account argument of the action (new admin, swap program or var address), if any.
+    pub target: Pubkey,
+
+    /// Hash of the action, its instruction data and its target.
+    pub args_hash: [u8; 32],
+
+    /// Timestamp at which the action was queued.
+    pub queued_at: i64,
+
+    /// Timestamp from which the action can be executed.
+    pub executable_at: i64,
+}
+
+impl PendingAdminAction {
+    pub fn pda(action: u8) -> (Pubkey, u8) {
+        pending_admin_action_pda(action)
+    }
+
+    /// Hashes an admin action with its instruction data and target.
+    pub fn args_hash(action: u8, data: &[u8], target: &Pubkey) -> [u8; 32] {
+        keccak::hashv(&[&[action], data, target.as_ref()]).to_bytes()
+    }
+
+    /// Whether the timelock has passed.
+    pub fn is_ready(&self, unix_timestamp: i64) -> bool {
+        unix_timestamp >= self.executable_at
+    }
+
+    /// Whether the execution window has closed (the action can only be cancelled).
+    pub fn is_expired(&self, unix_timestamp: i64) -> bool {
+        unix_timestamp >= self.expires_at()
+    }
+
+    /// Timestamp at which the execution window closes.
+    pub fn expires_at(&self) -> i64 {
+        self.executable_at.saturating_add(ADMIN_ACTION_WINDOW_SECONDS)
+    }
+}
+
+account!(OilAccount, PendingAdminAction);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_execution_window() {
+        let mut pending = PendingAdminAction::zeroed();
+        pending.executable_at = 1_000;
+        assert!(!pending.is_ready(999));
+        assert!(pending.is_ready(1_000) && !pending.is_expired(1_000));
+        assert!(!pending.is_expired(1_000 + ADMIN_ACTION_WINDOW_SECONDS - 1));
+        assert!(pending.is_expired(1_000 + ADMIN_ACTION_WINDOW_SECONDS));
+    }
+}
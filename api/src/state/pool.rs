@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use steel::*;
 
+use crate::error::OilError;
+
 use super::OilAccount;
 
 /// Pool account holds all staking-related data and SOL rewards for stakers.
@@ -19,15 +21,152 @@ pub struct Pool {
     /// The current total amount of OIL staked (stakers earn SOL rewards, not OIL).
     pub total_staked: u64,
 
-    /// Buffer field (for future use, e.g., OIL rewards).
-    pub buffer_a: Numeric,
+    /// The stake rewards factor last time liquid stake rewards were updated.
+    pub liquid_rewards_factor: Numeric,
 
     /// Total amount of OIL burned from early withdrawal penalties (deflationary).
     pub total_burned_penalties: u64,
 
-    /// Buffer field (for future use).
-    pub buffer_c: u64,
+    /// The amount of OIL backing stOIL, including compounded rewards (staked unlocked).
+    pub liquid_staked: u64,
+
+    /// The SOL earned by liquid stake awaiting compounding into OIL (see `CompoundLiquid`).
+    pub liquid_rewards: u64,
+}
+
+impl Pool {
+    /// Accrues the SOL rewards earned by liquid stake since the last update.
+    pub fn update_liquid_rewards(&mut self) {
+        if self.stake_rewards_factor > self.liquid_rewards_factor {
+            let accumulated_rewards = self.stake_rewards_factor - self.liquid_rewards_factor;
+            let rewards = accumulated_rewards * Numeric::from_u64(self.liquid_staked);
+            self.liquid_rewards += rewards.to_u64();
+        }
+        self.liquid_rewards_factor = self.stake_rewards_factor;
+    }
+
+    /// Adds `amount` OIL to the liquid stake, returning the stOIL to mint given the current stOIL
+    /// `supply`. Fails unless it mints at least one and `min_shares` stOIL. Liquid stake is
+    /// unlocked, so its stake score equals its balance.
+    pub fn deposit_liquid(&mut self, amount: u64, supply: u64, min_shares: u64) -> Result<u64, OilError> {
+        self.update_liquid_rewards();
+        let shares = if supply == 0 || self.liquid_staked == 0 {
+            amount
+        } else {
+            (amount as u128 * supply as u128 / self.liquid_staked as u128) as u64
+        };
+        if shares == 0 {
+            return Err(OilError::AmountTooSmall);
+        }
+        if shares < min_shares {
+            return Err(OilError::SlippageExceeded);
+        }
+        self.add_liquid_stake(amount);
+        Ok(shares)
+    }
+
+    /// Removes the OIL backing `shares` stOIL out of the current stOIL `supply` from the liquid
+    /// stake, returning the OIL to redeem.
+    pub fn redeem_liquid(&mut self, shares: u64, supply: u64) -> u64 {
+        self.update_liquid_rewards();
+        if supply == 0 {
+            return 0;
+        }
+        let amount = (shares as u128 * self.liquid_staked as u128 / supply as u128) as u64;
+        self.liquid_staked -= amount;
+        self.total_staked -= amount;
+        self.total_staked_score = self.total_staked_score.saturating_sub(amount);
+        amount
+    }
+
+    /// Adds `amount` OIL swapped from liquid stake rewards to the liquid stake. Fails if the swap
+    /// returned less than `min_amount` OIL.
+    pub fn compound_liquid(&mut self, amount: u64, min_amount: u64) -> Result<(), OilError> {
+        if amount < min_amount {
+            return Err(OilError::SlippageExceeded);
+        }
+        self.add_liquid_stake(amount);
+        Ok(())
+    }
+
+    /// Adds `amount` OIL to the liquid stake without minting stOIL, raising the exchange rate.
+    pub fn add_liquid_stake(&mut self, amount: u64) {
+        self.liquid_staked += amount;
+        self.total_staked += amount;
+        self.total_staked_score = self.total_staked_score.saturating_add(amount);
+    }
 }
 
 account!(OilAccount, Pool);
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_liquid_stake() {
+        let mut pool = Pool::zeroed();
+
+        // The first deposit mints stOIL 1:1.
+        assert_eq!(pool.deposit_liquid(1_000, 0, 1_000), Ok(1_000));
+        assert_eq!((pool.total_staked, pool.total_staked_score), (1_000, 1_000));
+
+        // Liquid stake earns its share of SOL rewards.
+        pool.stake_rewards_factor = Numeric::from_fraction(2, 1);
+        pool.update_liquid_rewards();
+        assert_eq!(pool.liquid_rewards, 2_000);
+
+        // Compounded OIL raises the exchange rate for later deposits and redemptions.
+        pool.add_liquid_stake(1_000);
+        assert_eq!(pool.deposit_liquid(1_000, 1_000, 0), Ok(500));
+        assert_eq!(pool.redeem_liquid(500, 1_500), 1_000);
+        assert_eq!((pool.liquid_staked, pool.total_staked), (2_000, 2_000));
+    }
+
+    #[test]
+    fn test_deposit_liquid_min_shares() {
+        let mut pool = Pool::zeroed();
+        assert_eq!(pool.deposit_liquid(1_000, 0, 0), Ok(1_000));
+        pool.add_liquid_stake(1_000);
+
+        // A deposit too small to mint a share is rejected rather than kept.
+        assert_eq!(pool.deposit_liquid(1, 1_000, 0), Err(OilError::AmountTooSmall));
+
+        // So is one minting fewer shares than the depositor asked for.
+        assert_eq!(pool.deposit_liquid(1_000, 1_000, 501), Err(OilError::SlippageExceeded));
+        assert_eq!((pool.liquid_staked, pool.total_staked), (2_000, 2_000));
+        assert_eq!(pool.deposit_liquid(1_000, 1_000, 500), Ok(500));
+        assert_eq!((pool.liquid_staked, pool.total_staked), (3_000, 3_000));
+    }
+
+    #[test]
+    fn test_redeem_liquid() {
+        let mut pool = Pool::zeroed();
+        assert_eq!(pool.deposit_liquid(1_000, 0, 0), Ok(1_000));
+        pool.add_liquid_stake(500);
+
+        // stOIL redeems its share of the liquid stake, rounding down.
+        assert_eq!(pool.redeem_liquid(3, 1_000), 4);
+        assert_eq!(pool.redeem_liquid(997, 997), 1_496);
+        assert_eq!((pool.liquid_staked, pool.total_staked, pool.total_staked_score), (0, 0, 0));
+
+        // Nothing is redeemed without stOIL outstanding.
+        assert_eq!(pool.redeem_liquid(1_000, 0), 0);
+    }
+
+    #[test]
+    fn test_compound_liquid() {
+        let mut pool = Pool::zeroed();
+        assert_eq!(pool.deposit_liquid(1_000, 0, 0), Ok(1_000));
+
+        // A swap returning less than the minimum is rejected.
+        assert_eq!(pool.compound_liquid(99, 100), Err(OilError::SlippageExceeded));
+        assert_eq!(pool.liquid_staked, 1_000);
+
+        // Otherwise the OIL raises the exchange rate for stOIL holders.
+        assert_eq!(pool.compound_liquid(100, 100), Ok(()));
+        assert_eq!((pool.liquid_staked, pool.total_staked_score), (1_100, 1_100));
+        assert_eq!(pool.redeem_liquid(1_000, 1_000), 1_100);
+    }
+}
+
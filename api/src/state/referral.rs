@@ -2,10 +2,12 @@ use serde::{Deserialize, Serialize};
 use solana_program::pubkey::Pubkey;
 use solana_program::program_error::ProgramError;
 use solana_program::log::sol_log;
+use solana_program::native_token::lamports_to_sol;
+use spl_token::amount_to_ui_amount;
 use steel::*;
 
-use super::OilAccount;
-use crate::consts::REFERRAL;
+use super::{Miner, OilAccount, ReferralConfig, ReferralSplit};
+use crate::consts::{MINER, ONE_WEEK, REFERRAL, REFERRAL_CONFIG, REFERRAL_STATS_WEEKS, TOKEN_DECIMALS, TREASURY};
 
 /// Referral account tracks a referrer's stats and pending rewards.
 #[repr(C)]
@@ -28,6 +30,37 @@ pub struct Referral {
 
     /// Pending OIL rewards to claim.
     pub pending_oil: u64,
+
+    /// Total SOL claimed by referees (lifetime, sets the referral tier).
+    pub referred_volume_sol: u64,
+
+    /// Share of the referral bonus given back to referees (bps).
+    pub rebate_bps: u64,
+
+    /// Week (unix time / ONE_WEEK) of each weekly stats slot, ring-buffered by week % REFERRAL_STATS_WEEKS.
+    pub week_ids: [u64; REFERRAL_STATS_WEEKS],
+
+    /// Miners referred in each weekly stats slot.
+    pub week_referred: [u64; REFERRAL_STATS_WEEKS],
+
+    /// SOL earned in each weekly stats slot.
+    pub week_sol_earned: [u64; REFERRAL_STATS_WEEKS],
+
+    /// OIL earned in each weekly stats slot.
+    pub week_oil_earned: [u64; REFERRAL_STATS_WEEKS],
+}
+
+/// A referrer's stats for a single week.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ReferralWeekStats {
+    /// Miners referred during the week.
+    pub referred: u64,
+
+    /// SOL earned during the week.
+    pub sol_earned: u64,
+
+    /// OIL earned during the week.
+    pub oil_earned: u64,
 }
 
 impl Referral {
@@ -43,38 +76,48 @@ impl Referral {
         amount
     }
 
-    pub fn credit_sol_referral(&mut self, total_amount: u64) -> u64 {
-        // Calculate referral bonus (0.5% of total claim)
-        let referral_amount = if total_amount > 0 {
-            total_amount / 200 // 0.5% = 1/200
-        } else {
-            0
-        };
-
-        // Credit referral account
-        if referral_amount > 0 {
-            self.pending_sol += referral_amount;
-            self.total_sol_earned += referral_amount;
-        }
+    pub fn credit_sol(&mut self, amount: u64, week: u64) {
+        self.pending_sol += amount;
+        self.total_sol_earned += amount;
+        let slot = self.week_slot(week);
+        self.week_sol_earned[slot] += amount;
+    }
 
-        referral_amount
+    pub fn credit_oil(&mut self, amount: u64, week: u64) {
+        self.pending_oil += amount;
+        self.total_oil_earned += amount;
+        let slot = self.week_slot(week);
+        self.week_oil_earned[slot] += amount;
     }
 
-    pub fn credit_oil_referral(&mut self, total_amount: u64) -> u64 {
-        // Calculate referral bonus (0.5% of total claim)
-        let referral_amount = if total_amount > 0 {
-            total_amount / 200 // 0.5% = 1/200
-        } else {
-            0
-        };
+    /// The week number of a unix timestamp.
+    pub fn week(unix_timestamp: i64) -> u64 {
+        (unix_timestamp.max(0) / ONE_WEEK) as u64
+    }
 
-        // Credit referral account
-        if referral_amount > 0 {
-            self.pending_oil += referral_amount;
-            self.total_oil_earned += referral_amount;
+    /// Stats for `week`, if it is still held in the ring buffer.
+    pub fn week_stats(&self, week: u64) -> Option<ReferralWeekStats> {
+        let slot = (week % REFERRAL_STATS_WEEKS as u64) as usize;
+        if self.week_ids[slot] != week {
+            return None;
         }
+        Some(ReferralWeekStats {
+            referred: self.week_referred[slot],
+            sol_earned: self.week_sol_earned[slot],
+            oil_earned: self.week_oil_earned[slot],
+        })
+    }
 
-        referral_amount
+    /// The ring buffer slot of `week`, recycling the slot of an older week.
+    fn week_slot(&mut self, week: u64) -> usize {
+        let slot = (week % REFERRAL_STATS_WEEKS as u64) as usize;
+        if self.week_ids[slot] != week {
+            self.week_ids[slot] = week;
+            self.week_referred[slot] = 0;
+            self.week_sol_earned[slot] = 0;
+            self.week_oil_earned[slot] = 0;
+        }
+        slot
     }
 
     pub fn process_new_miner_referral<'a>(
@@ -103,10 +146,284 @@ impl Referral {
         // Increment total_referred
         let referral = referral_info.as_account_mut::<Referral>(&crate::ID)?;
         referral.total_referred += 1;
+        let slot = referral.week_slot(Self::week(Clock::get()?.unix_timestamp));
+        referral.week_referred[slot] += 1;
         sol_log(&format!("Referral: {} now has {} referrals", referrer, referral.total_referred));
         
         Ok(())
     }
 }
 
+/// Referral accounts of a referee's claim, in order:
+/// miner_referrer, referral_referrer, [referral_referrer_oil_ata], referral_config,
+/// [upline_referral, [upline_referral_oil_ata]]
+///
+/// The OIL ATAs are only present for OIL claims. The upline accounts are only present when
+/// the referrer has a referrer of their own and the config pays an upline share.
+pub struct ReferralAccounts<'a> {
+    /// The referrer's referral account.
+    pub referral_info: &'a AccountInfo<'a>,
+
+    /// The referrer's referral OIL ATA (OIL claims only).
+    pub referral_tokens_info: Option<&'a AccountInfo<'a>>,
+
+    /// The upline's referral account.
+    pub upline_info: Option<&'a AccountInfo<'a>>,
+
+    /// The upline's referral OIL ATA (OIL claims only).
+    pub upline_tokens_info: Option<&'a AccountInfo<'a>>,
+
+    /// The referrer.
+    pub referrer: Pubkey,
+
+    /// The referrer's own referrer (Pubkey::default() if none is paid).
+    pub upline: Pubkey,
+
+    config: ReferralConfig,
+}
+
+impl<'a> ReferralAccounts<'a> {
+    /// Loads and validates the referral accounts of `referee`'s claim.
+    pub fn load(
+        accounts: &'a [AccountInfo<'a>],
+        referrer: Pubkey,
+        referee: Pubkey,
+        with_tokens: bool,
+    ) -> Result<Self, ProgramError> {
+        let mut iter = accounts.iter();
+        let mut next = || iter.next().ok_or(ProgramError::NotEnoughAccountKeys);
+
+        // Validate referrer's miner account
+        let miner_referrer_info = next()?;
+        miner_referrer_info.has_seeds(&[MINER, &referrer.to_bytes()], &crate::ID)?;
+
+        // Validate referrer's referral account
+        let referral_info = next()?;
+        referral_info
+            .is_writable()?
+            .has_seeds(&[REFERRAL, &referrer.to_bytes()], &crate::ID)?;
+        let referral_tokens_info = if with_tokens { Some(next()?) } else { None };
+
+        // Load referral config (legacy flat rate until the admin creates one)
+        let config_info = next()?;
+        config_info.has_seeds(&[REFERRAL_CONFIG], &crate::ID)?;
+        let config = if config_info.data_is_empty() {
+            ReferralConfig::legacy()
+        } else {
+            *config_info.as_account::<ReferralConfig>(&crate::ID)?
+        };
+
+        // Pay the referrer's own referrer, unless it is the referee themselves
+        let mut upline = if miner_referrer_info.data_is_empty() || config.upline_share_bps == 0 {
+            Pubkey::default()
+        } else {
+            miner_referrer_info.as_account::<Miner>(&crate::ID)?.referrer
+        };
+        if upline == referee || upline == referrer {
+            upline = Pubkey::default();
+        }
+
+        let (upline_info, upline_tokens_info) = if upline != Pubkey::default() {
+            let upline_info = next()?;
+            upline_info
+                .is_writable()?
+                .has_seeds(&[REFERRAL, &upline.to_bytes()], &crate::ID)?;
+            let upline_tokens_info = if with_tokens { Some(next()?) } else { None };
+            (Some(upline_info), upline_tokens_info)
+        } else {
+            (None, None)
+        };
+
+        Ok(Self {
+            referral_info,
+            referral_tokens_info,
+            upline_info,
+            upline_tokens_info,
+            referrer,
+            upline,
+            config,
+        })
+    }
+
+    /// Credits the referrer and upline for a referee's SOL claim and records the referred volume.
+    pub fn credit_sol(&self, total_amount: u64, clock: &Clock) -> Result<ReferralSplit, ProgramError> {
+        let split = self.split(total_amount)?;
+        let week = Referral::week(clock.unix_timestamp);
+        let referral = self.referral_info.as_account_mut::<Referral>(&crate::ID)?;
+        referral.referred_volume_sol = referral.referred_volume_sol.saturating_add(total_amount);
+        referral.credit_sol(split.referrer_amount, week);
+        if let Some(upline_info) = self.upline_info {
+            upline_info
+                .as_account_mut::<Referral>(&crate::ID)?
+                .credit_sol(split.upline_amount, week);
+        }
+        Ok(split)
+    }
+
+    /// Credits the referrer and upline for a referee's OIL claim.
+    pub fn credit_oil(&self, total_amount: u64, clock: &Clock) -> Result<ReferralSplit, ProgramError> {
+        let split = self.split(total_amount)?;
+        let week = Referral::week(clock.unix_timestamp);
+        self.referral_info
+            .as_account_mut::<Referral>(&crate::ID)?
+            .credit_oil(split.referrer_amount, week);
+        if let Some(upline_info) = self.upline_info {
+            upline_info
+                .as_account_mut::<Referral>(&crate::ID)?
+                .credit_oil(split.upline_amount, week);
+        }
+        Ok(split)
+    }
+
+    /// Sends the referrer's and upline's SOL bonus from the claim's source account.
+    pub fn send_sol(&self, source_info: &AccountInfo<'a>, split: ReferralSplit) {
+        if split.referrer_amount > 0 {
+            source_info.send(split.referrer_amount, self.referral_info);
+            sol_log(&format!(
+                "Referral bonus: {} SOL to {}",
+                lamports_to_sol(split.referrer_amount),
+                self.referrer
+            ));
+        }
+        if split.rebate_amount > 0 {
+            sol_log(&format!("Referral rebate: {} SOL", lamports_to_sol(split.rebate_amount)));
+        }
+        if let Some(upline_info) = self.upline_info.filter(|_| split.upline_amount > 0) {
+            source_info.send(split.upline_amount, upline_info);
+            sol_log(&format!(
+                "Upline bonus: {} SOL to {}",
+                lamports_to_sol(split.upline_amount),
+                self.upline
+            ));
+        }
+    }
+
+    /// Sends the referrer's and upline's OIL bonus from the treasury, creating the referral ATAs if needed.
+    #[allow(clippy::too_many_arguments)]
+    pub fn send_oil(
+        &self,
+        treasury_info: &AccountInfo<'a>,
+        treasury_tokens_info: &AccountInfo<'a>,
+        payer_info: &AccountInfo<'a>,
+        mint_info: &AccountInfo<'a>,
+        system_program: &AccountInfo<'a>,
+        token_program: &AccountInfo<'a>,
+        associated_token_program: &AccountInfo<'a>,
+        split: ReferralSplit,
+    ) -> ProgramResult {
+        let payouts = [
+            (Some(self.referral_info), self.referral_tokens_info, split.referrer_amount, self.referrer, "Referral"),
+            (self.upline_info, self.upline_tokens_info, split.upline_amount, self.upline, "Upline"),
+        ];
+        for (referral_info, tokens_info, amount, recipient, label) in payouts {
+            let (Some(referral_info), Some(tokens_info)) = (referral_info, tokens_info) else {
+                continue;
+            };
+            if amount == 0 {
+                continue;
+            }
+
+            // Create referral OIL ATA if it doesn't exist
+            if tokens_info.data_is_empty() {
+                create_associated_token_account(
+                    payer_info,
+                    referral_info,
+                    tokens_info,
+                    mint_info,
+                    system_program,
+                    token_program,
+                    associated_token_program,
+                )?;
+            } else {
+                tokens_info.as_associated_token_account(referral_info.key, mint_info.key)?;
+            }
+
+            // Transfer OIL from treasury to referral account's OIL ATA
+            transfer_signed(
+                treasury_info,
+                treasury_tokens_info,
+                tokens_info,
+                token_program,
+                amount,
+                &[TREASURY],
+            )?;
+
+            sol_log(&format!(
+                "{} bonus: {} OIL to {}",
+                label,
+                amount_to_ui_amount(amount, TOKEN_DECIMALS),
+                recipient
+            ));
+        }
+        if split.rebate_amount > 0 {
+            sol_log(&format!(
+                "Referral rebate: {} OIL",
+                amount_to_ui_amount(split.rebate_amount, TOKEN_DECIMALS)
+            ));
+        }
+        Ok(())
+    }
+
+    fn split(&self, total_amount: u64) -> Result<ReferralSplit, ProgramError> {
+        let referral = self.referral_info.as_account::<Referral>(&crate::ID)?;
+        Ok(self.config.split(
+            total_amount,
+            referral.referred_volume_sol,
+            referral.rebate_bps,
+            self.upline_info.is_some(),
+        ))
+    }
+}
+
 account!(OilAccount, Referral);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_week_stats_wraparound() {
+        let mut referral = Referral::zeroed();
+        let weeks = REFERRAL_STATS_WEEKS as u64;
+
+        // Each week fills its own slot until the buffer wraps.
+        for week in 1..=weeks {
+            referral.credit_sol(week * 100, week);
+        }
+        for week in 1..=weeks {
+            assert_eq!(referral.week_stats(week).unwrap().sol_earned, week * 100);
+        }
+
+        // The next week recycles the slot of the oldest week.
+        referral.credit_oil(7, weeks + 1);
+        assert_eq!(referral.week_stats(1), None);
+        let stats = referral.week_stats(weeks + 1).unwrap();
+        assert_eq!((stats.referred, stats.sol_earned, stats.oil_earned), (0, 0, 7));
+        assert_eq!(referral.week_stats(2).unwrap().sol_earned, 200);
+
+        // Lifetime totals are unaffected by recycling.
+        assert_eq!(referral.total_oil_earned, 7);
+        assert_eq!(referral.total_sol_earned, (1..=weeks).map(|w| w * 100).sum::<u64>());
+    }
+
+    #[test]
+    fn test_week_stats_stale_slot_reset() {
+        let mut referral = Referral::zeroed();
+        let weeks = REFERRAL_STATS_WEEKS as u64;
+
+        let slot = referral.week_slot(3);
+        referral.week_referred[slot] += 2;
+        referral.credit_sol(50, 3);
+        referral.credit_oil(60, 3);
+
+        // A later week mapping to the same slot starts from zero rather than adding to stale stats.
+        let later = 3 + 2 * weeks;
+        referral.credit_sol(5, later);
+        assert_eq!(referral.week_stats(3), None);
+        let stats = referral.week_stats(later).unwrap();
+        assert_eq!((stats.referred, stats.sol_earned, stats.oil_earned), (0, 5, 0));
+
+        // Weeks never written read as empty, even when their slot is zeroed.
+        assert_eq!(referral.week_stats(later + 1), None);
+    }
+}
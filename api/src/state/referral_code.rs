@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+use solana_program::keccak;
+use steel::*;
+
+use crate::state::referral_code_pda;
+
+use super::OilAccount;
+
+/// ReferralCode maps a human-readable referral code to a referrer.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct ReferralCode {
+    /// The code hash (first 32 bytes of keccak256 hash of the code string)
+    pub code_hash: [u8; 32],
+
+    /// The referrer (wallet) this code resolves to.
+    pub authority: Pubkey,
+}
+
+impl ReferralCode {
+    pub fn pda(code_hash: [u8; 32]) -> (Pubkey, u8) {
+        referral_code_pda(code_hash)
+    }
+
+    /// Hashes a human-readable referral code.
+    pub fn hash(code: &str) -> [u8; 32] {
+        keccak::hash(code.as_bytes()).to_bytes()
+    }
+}
+
+account!(OilAccount, ReferralCode);
@@ -0,0 +1,125 @@
+use serde::{Deserialize, Serialize};
+use steel::*;
+
+use crate::consts::{DEFAULT_REFERRAL_RATE_BPS, DENOMINATOR_BPS};
+use crate::state::referral_config_pda;
+
+use super::OilAccount;
+
+/// ReferralConfig holds the referral tiers and upline share (singleton, set by admin).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct ReferralConfig {
+    /// Referred SOL volume (in lamports) at which each tier starts, ascending.
+    pub tier_thresholds: [u64; 4],
+
+    /// Referral rate of each tier (bps of a referee's claim).
+    pub tier_rates_bps: [u64; 4],
+
+    /// Share of a referee's claim paid to the referrer's own referrer (bps).
+    pub upline_share_bps: u64,
+
+    /// Buffer for future extensions.
+    pub buffer_a: u64,
+
+    /// Buffer for future extensions.
+    pub buffer_b: u64,
+
+    /// Buffer for future extensions.
+    pub buffer_c: u64,
+}
+
+/// How a referee's claim is split between the referrer, the upline and the referee.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ReferralSplit {
+    /// Amount credited to the referrer.
+    pub referrer_amount: u64,
+
+    /// Amount credited to the referrer's own referrer.
+    pub upline_amount: u64,
+
+    /// Amount of the referrer's share given back to the referee.
+    pub rebate_amount: u64,
+}
+
+impl ReferralSplit {
+    /// Total amount deducted from the referee's claim.
+    pub fn total(&self) -> u64 {
+        self.referrer_amount + self.upline_amount
+    }
+}
+
+impl ReferralConfig {
+    pub fn pda() -> (Pubkey, u8) {
+        referral_config_pda()
+    }
+
+    /// The config used until the admin creates one: a flat 0.5% rate and no upline share.
+    pub fn legacy() -> Self {
+        let mut config = Self::zeroed();
+        config.tier_rates_bps = [DEFAULT_REFERRAL_RATE_BPS; 4];
+        config
+    }
+
+    /// Referral rate (bps) of the highest tier reached by `referred_volume_sol`.
+    pub fn rate_bps(&self, referred_volume_sol: u64) -> u64 {
+        let mut rate = self.tier_rates_bps[0];
+        for i in 1..4 {
+            if self.tier_thresholds[i] == 0 || referred_volume_sol < self.tier_thresholds[i] {
+                break;
+            }
+            rate = self.tier_rates_bps[i];
+        }
+        rate
+    }
+
+    /// Splits a referee's claim of `total_amount`, given the referrer's volume and rebate.
+    pub fn split(&self, total_amount: u64, referred_volume_sol: u64, rebate_bps: u64, has_upline: bool) -> ReferralSplit {
+        let gross = bps_of(total_amount, self.rate_bps(referred_volume_sol));
+        let rebate_amount = bps_of(gross, rebate_bps.min(DENOMINATOR_BPS));
+        let upline_amount = if has_upline {
+            bps_of(total_amount, self.upline_share_bps)
+        } else {
+            0
+        };
+        ReferralSplit {
+            referrer_amount: gross - rebate_amount,
+            upline_amount,
+            rebate_amount,
+        }
+    }
+}
+
+fn bps_of(amount: u64, bps: u64) -> u64 {
+    (amount as u128 * bps as u128 / DENOMINATOR_BPS as u128) as u64
+}
+
+account!(OilAccount, ReferralConfig);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_legacy_split() {
+        let split = ReferralConfig::legacy().split(1_000_000, 0, 0, true);
+        assert_eq!(split, ReferralSplit { referrer_amount: 5_000, upline_amount: 0, rebate_amount: 0 });
+    }
+
+    #[test]
+    fn test_tiered_split() {
+        let mut config = ReferralConfig::zeroed();
+        config.tier_thresholds = [0, 1_000, 10_000, 0];
+        config.tier_rates_bps = [50, 100, 200, 500];
+        config.upline_share_bps = 20;
+        assert_eq!(config.rate_bps(999), 50);
+        assert_eq!(config.rate_bps(1_000), 100);
+        assert_eq!(config.rate_bps(u64::MAX), 200);
+
+        // A 25% rebate gives a quarter of the referrer's share back to the referee
+        let split = config.split(1_000_000, 10_000, 2_500, true);
+        assert_eq!(split, ReferralSplit { referrer_amount: 15_000, upline_amount: 2_000, rebate_amount: 5_000 });
+        assert_eq!(split.total(), 17_000);
+        assert_eq!(config.split(1_000_000, 10_000, 0, false).upline_amount, 0);
+    }
+}
@@ -0,0 +1,170 @@
+use serde::{Deserialize, Serialize};
+use solana_program::native_token::LAMPORTS_PER_SOL;
+use steel::*;
+
+use crate::consts::*;
+use crate::state::rig_pda;
+
+use super::OilAccount;
+
+/// Rig tracks a miner's progression: the XP earned from deploys, bids, contributions and staking,
+/// and the level it unlocks (mirrored to `Miner::level`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct Rig {
+    /// The authority of the miner this rig belongs to.
+    pub authority: Pubkey,
+
+    /// The total XP earned.
+    pub xp: u64,
+
+    /// The level reached with `xp` (see `RIG_LEVEL_XP`).
+    pub level: u64,
+
+    /// The XP earned from deploying SOL.
+    pub deploy_xp: u64,
+
+    /// The XP earned from bidding on auction wells.
+    pub bid_xp: u64,
+
+    /// The XP earned from contributing to the auction pool.
+    pub contribution_xp: u64,
+
+    /// The XP earned from staking OIL.
+    pub stake_xp: u64,
+
+    /// The miner's stake score when stake XP was last accrued.
+    pub stake_score: u64,
+
+    /// The last time stake XP was accrued.
+    pub last_stake_xp_at: i64,
+}
+
+/// The activity a rig earned XP from.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum XpSource {
+    Deploy,
+    Bid,
+    Contribution,
+    Stake,
+}
+
+impl Rig {
+    pub fn pda(&self) -> (Pubkey, u8) {
+        rig_pda(self.authority)
+    }
+
+    /// Credits `amount` XP from `source`, returning true if the rig leveled up.
+    pub fn add_xp(&mut self, source: XpSource, amount: u64) -> bool {
+        let source_xp = match source {
+            XpSource::Deploy => &mut self.deploy_xp,
+            XpSource::Bid => &mut self.bid_xp,
+            XpSource::Contribution => &mut self.contribution_xp,
+            XpSource::Stake => &mut self.stake_xp,
+        };
+        *source_xp = source_xp.saturating_add(amount);
+        self.xp = self.xp.saturating_add(amount);
+
+        let level = rig_level(self.xp);
+        let leveled_up = level > self.level;
+        self.level = level;
+        leveled_up
+    }
+
+    /// Credits XP for the stake held since stake XP was last accrued, returning true if the rig
+    /// leveled up. The lower of the previous and current stake score is used, so stake added just
+    /// before an update earns nothing for the time it was not held.
+    pub fn accrue_stake_xp(&mut self, stake_score: u64, now: i64) -> bool {
+        let elapsed = now.saturating_sub(self.last_stake_xp_at).max(0) as u128;
+        let score = self.stake_score.min(stake_score) as u128;
+        let xp = score * elapsed * XP_PER_STAKE_DAY as u128 / (ONE_OIL as u128 * ONE_DAY as u128);
+        self.stake_score = stake_score;
+        self.last_stake_xp_at = now;
+        self.add_xp(XpSource::Stake, xp.min(u64::MAX as u128) as u64)
+    }
+}
+
+/// The XP earned for deploying, bidding or contributing `lamports`.
+pub fn sol_xp(lamports: u64) -> u64 {
+    (lamports as u128 * XP_PER_SOL as u128 / LAMPORTS_PER_SOL as u128) as u64
+}
+
+/// The rig level reached with `xp`.
+pub fn rig_level(xp: u64) -> u64 {
+    RIG_LEVEL_XP.iter().take_while(|&&threshold| xp >= threshold).count() as u64
+}
+
+/// The checkpoint fee reserved for a miner at `level`.
+pub fn rig_checkpoint_fee(level: u64) -> u64 {
+    let discount_bps = level.saturating_mul(RIG_CHECKPOINT_DISCOUNT_BPS_PER_LEVEL).min(DENOMINATOR_BPS);
+    CHECKPOINT_FEE * (DENOMINATOR_BPS - discount_bps) / DENOMINATOR_BPS
+}
+
+/// The refining fee discount for a miner at `level` (see `Config::refining_fee_bps`).
+pub fn rig_refining_discount_bps(level: u64) -> u64 {
+    level.saturating_mul(RIG_REFINING_DISCOUNT_BPS_PER_LEVEL).min(DENOMINATOR_BPS)
+}
+
+account!(OilAccount, Rig);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rig_level() {
+        assert_eq!(rig_level(0), 0);
+        assert_eq!(rig_level(99), 0);
+        assert_eq!(rig_level(100), 1);
+        assert_eq!(rig_level(1_499), 2);
+        assert_eq!(rig_level(u64::MAX), RIG_LEVEL_XP.len() as u64);
+    }
+
+    #[test]
+    fn test_add_xp() {
+        let mut rig = Rig::zeroed();
+        assert!(!rig.add_xp(XpSource::Deploy, sol_xp(LAMPORTS_PER_SOL / 2)));
+        assert_eq!(rig.xp, 50);
+        assert!(rig.add_xp(XpSource::Bid, 450));
+        assert_eq!((rig.level, rig.deploy_xp, rig.bid_xp), (2, 50, 450));
+    }
+
+    #[test]
+    fn test_accrue_stake_xp() {
+        let mut rig = Rig::zeroed();
+
+        // The first update only records the stake score.
+        rig.accrue_stake_xp(ONE_OIL * 100, 1_000);
+        assert_eq!(rig.xp, 0);
+
+        // Stake added since the last update only counts from now on.
+        rig.accrue_stake_xp(ONE_OIL * 1_000, 1_000 + ONE_DAY);
+        assert_eq!(rig.stake_xp, 100);
+        rig.accrue_stake_xp(ONE_OIL * 1_000, 1_000 + 2 * ONE_DAY);
+        assert_eq!(rig.stake_xp, 1_100);
+    }
+
+    #[test]
+    fn test_accrue_stake_xp_withdraw_and_redeposit() {
+        let mut rig = Rig::zeroed();
+        rig.accrue_stake_xp(ONE_OIL * 1_000, 0);
+
+        // Withdrawing accrues the stake held until then, and records the lower score.
+        rig.accrue_stake_xp(0, ONE_DAY);
+        assert_eq!(rig.stake_xp, 1_000);
+
+        // Re-depositing a year later earns nothing for the year the stake was not held.
+        rig.accrue_stake_xp(ONE_OIL * 1_000, ONE_DAY + 365 * ONE_DAY);
+        assert_eq!(rig.stake_xp, 1_000);
+        rig.accrue_stake_xp(ONE_OIL * 1_000, 2 * ONE_DAY + 365 * ONE_DAY);
+        assert_eq!(rig.stake_xp, 2_000);
+    }
+
+    #[test]
+    fn test_perks() {
+        assert_eq!(rig_checkpoint_fee(0), CHECKPOINT_FEE);
+        assert_eq!(rig_checkpoint_fee(10), CHECKPOINT_FEE / 2);
+        assert_eq!(rig_checkpoint_fee(u64::MAX), 0);
+        assert_eq!(rig_refining_discount_bps(4), 1_000);
+    }
+}
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use steel::*;
 
-use crate::state::round_pda;
+use crate::state::{round_pda, Miner};
 
 use super::OilAccount;
 
@@ -64,6 +64,12 @@ pub struct Round {
 
     /// The cumulative SOL deployed by solo miners before pool deployed to each square.
     pub pool_cumulative: [u64; 25],
+
+    /// The solo SOL deployed in each square, weighted by each miner's stake boost (see `Miner::boosted`).
+    pub boosted_deployed: [u64; 25],
+
+    /// The pooled SOL deployed in the round, weighted by each miner's stake boost.
+    pub boosted_pooled: u64,
 }
 
 impl Round {
@@ -118,6 +124,35 @@ impl Round {
     pub fn did_hit_gusher_sol_only(&self, rng: u64) -> bool {
         rng.reverse_bits() % 625 == 0
     }
+
+    /// A solo miner's share of split OIL. Solo deployments on the winning square share their part of
+    /// the reward by stake-boosted weight (rounds without boost weights share it by amount).
+    pub fn split_oil(&self, winning_square: usize, miner: &Miner) -> u64 {
+        let total = self.deployed[winning_square];
+        let deployed = miner.deployed[winning_square];
+        let boosted_total = self.boosted_deployed[winning_square];
+        if total == 0 {
+            return 0;
+        }
+        if boosted_total == 0 {
+            return (self.top_miner_reward as u128 * deployed as u128 / total as u128) as u64;
+        }
+        let solo = total.saturating_sub(self.deployed_pooled[winning_square]);
+        let solo_reward = self.top_miner_reward as u128 * solo as u128 / total as u128;
+        (solo_reward * miner.boosted(deployed) as u128 / boosted_total as u128) as u64
+    }
+
+    /// A pool member's share of the pool's split OIL, by stake-boosted weight (rounds without boost
+    /// weights share it by amount).
+    pub fn pool_split_oil(&self, miner: &Miner) -> u64 {
+        if self.boosted_pooled == 0 {
+            if self.total_pooled == 0 {
+                return 0;
+            }
+            return (self.pool_rewards_oil as u128 * miner.pooled_deployed as u128 / self.total_pooled as u128) as u64;
+        }
+        (self.pool_rewards_oil as u128 * miner.boosted(miner.pooled_deployed) as u128 / self.boosted_pooled as u128) as u64
+    }
 }
 
 account!(OilAccount, Round);
@@ -135,12 +170,62 @@ mod tests {
         println!("Round account size: {} bytes", size_of_round);
         println!("Required rent: {} lamports ({} SOL)", required_rent, required_rent as f64 / 1_000_000_000.0);
         
-        // Verify the account size matches expected size (1008 bytes for v4 with pool_cumulative field)
-        // 8 bytes discriminator + 1000 bytes struct = 1008 bytes total
-        assert_eq!(size_of_round, 1008, "Round account size should be 1008 bytes (v4 with pool_cumulative, no padding)");
+        // Verify the account size matches expected size (1208 bytes with the stake boost weights)
+        // 8 bytes discriminator + 1200 bytes struct = 1208 bytes total
+        assert_eq!(size_of_round, 1208, "Round account size should be 1208 bytes (with boost weights, no padding)");
         
         // Verify rent is reasonable (should be > 0 and < 10 SOL for a 816 byte account)
         assert!(required_rent > 0, "Required rent should be greater than 0");
         assert!(required_rent < 10_000_000_000, "Required rent should be less than 10 SOL");
     }
-}
\ No newline at end of file
+    #[test]
+    fn test_split_oil() {
+        let mut round = Round::default();
+        round.top_miner_reward = 1_200;
+        round.deployed[0] = 400;
+        let (mut a, mut b) = (Miner::zeroed(), Miner::zeroed());
+        a.deployed[0] = 100;
+        b.deployed[0] = 300;
+
+        // Without boost weights the reward is shared by amount
+        assert_eq!(round.split_oil(0, &a), 300);
+        assert_eq!(round.split_oil(0, &b), 900);
+        assert_eq!(round.split_oil(1, &a), 0);
+
+        // Nobody boosted: the same shares
+        round.boosted_deployed[0] = a.boosted(100) + b.boosted(300);
+        assert_eq!(round.split_oil(0, &a), 300);
+        assert_eq!(round.split_oil(0, &b), 900);
+
+        // A 2x boost doubles a miner's weight, and the shares still add up to the reward
+        a.stake_boost_bps = 10_000;
+        round.boosted_deployed[0] = a.boosted(100) + b.boosted(300);
+        assert_eq!(round.split_oil(0, &a), 480);
+        assert_eq!(round.split_oil(0, &b), 720);
+
+        // Pooled SOL on the square keeps its part of the reward
+        round.deployed[0] = 600;
+        round.deployed_pooled[0] = 200;
+        assert_eq!(round.split_oil(0, &a) + round.split_oil(0, &b), 800);
+    }
+
+    #[test]
+    fn test_pool_split_oil() {
+        let mut round = Round::default();
+        round.pool_rewards_oil = 1_000;
+        let (mut a, mut b) = (Miner::zeroed(), Miner::zeroed());
+        a.pooled_deployed = 100;
+        b.pooled_deployed = 100;
+        assert_eq!(round.pool_split_oil(&a), 0);
+
+        // Without boost weights the reward is shared by amount
+        round.total_pooled = 200;
+        assert_eq!(round.pool_split_oil(&a), 500);
+
+        // A +50% boost
+        b.stake_boost_bps = 5_000;
+        round.boosted_pooled = a.boosted(100) + b.boosted(100);
+        assert_eq!(round.pool_split_oil(&a), 400);
+        assert_eq!(round.pool_split_oil(&b), 600);
+    }
+}
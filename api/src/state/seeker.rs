@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use steel::*;
+
+use crate::state::seeker_pda;
+
+use super::OilAccount;
+
+/// Seeker tracks whether a Seeker genesis token has been claimed, so each token can only mark one miner.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct Seeker {
+    /// The mint of the Seeker genesis token.
+    pub mint: Pubkey,
+
+    /// The authority of the miner that claimed the token.
+    pub authority: Pubkey,
+
+    /// The time the token was claimed.
+    pub claimed_at: i64,
+}
+
+impl Seeker {
+    pub fn pda(&self) -> (Pubkey, u8) {
+        seeker_pda(self.mint)
+    }
+}
+
+account!(OilAccount, Seeker);
@@ -30,14 +30,15 @@ pub struct Share {
     /// Amount of SOL refund claimed from this epoch
     pub claimed_sol: u64,
     
-    /// Buffer field for future extensions
-    pub buffer_a: u64,
+    /// Highest bid price (in lamports) the pool may use this contribution for (0 = no limit)
+    pub max_price: u64,
     
     /// Buffer field for future extensions
     pub buffer_b: u64,
     
-    /// Buffer field for future extensions
-    pub buffer_c: u64,
+    /// The highest contribution XP has been credited for, so withdrawing and contributing again
+    /// earns no extra XP.
+    pub max_contribution: u64,
 }
 
 impl Share {
@@ -53,9 +54,9 @@ impl Share {
         self.created_at = clock.unix_timestamp as u64;
         self.claimed_oil = 0;
         self.claimed_sol = 0;
-        self.buffer_a = 0;
+        self.max_price = 0;
         self.buffer_b = 0;
-        self.buffer_c = 0;
+        self.max_contribution = 0;
     }
 }
 
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use steel::*;
 
+use crate::error::OilError;
+
 use super::OilAccount;
 
 /// Treasury is a singleton account which is the mint authority for the OIL token and the authority of
@@ -48,12 +50,191 @@ pub struct Treasury {
 
     /// Auction-based mining: Total amount of FOGO pooled across all wells
     pub auction_total_pooled: u64,
+
+    /// Protocol-owned liquidity: The LP token mint of the treasury's pool position (set by the admin, see `SetLpMint`).
+    pub lp_mint: Pubkey,
+
+    /// Protocol-owned liquidity: The amount of LP tokens held by the treasury.
+    pub lp_tokens: u64,
+
+    /// Protocol-owned liquidity: The amount of wrapped SOL held for liquidity (excluded from buybacks).
+    pub liquidity_sol: u64,
+
+    /// Protocol-owned liquidity: The amount of OIL held for liquidity (kept apart from unclaimed rewards).
+    pub liquidity_oil: u64,
+
+    /// Protocol-owned liquidity: The total amount of SOL deposited into the pool.
+    pub lp_sol_deposited: u64,
+
+    /// Protocol-owned liquidity: The total amount of OIL deposited into the pool.
+    pub lp_oil_deposited: u64,
+
+    /// Protocol-owned liquidity: The total amount of SOL withdrawn from the pool.
+    pub lp_sol_withdrawn: u64,
+
+    /// Protocol-owned liquidity: The total amount of OIL withdrawn from the pool.
+    pub lp_oil_withdrawn: u64,
+
+    /// Protocol-owned liquidity: The total amount of SOL fees harvested from the pool.
+    pub lp_sol_harvested: u64,
+
+    /// Protocol-owned liquidity: The total amount of OIL fees harvested from the pool.
+    pub lp_oil_harvested: u64,
+}
+
+/// Protocol-owned liquidity operations.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, IntoPrimitive, TryFromPrimitive)]
+pub enum LiquidityAction {
+    /// Deposits SOL and OIL into the pool for LP tokens.
+    Add = 0,
+
+    /// Burns LP tokens for SOL and OIL.
+    Remove = 1,
+
+    /// Collects pool fees without touching the LP tokens.
+    Harvest = 2,
+
+    /// Swaps between the SOL and OIL held for liquidity.
+    Rebalance = 3,
+}
+
+/// The balance changes of the treasury's SOL, OIL and LP token accounts across a pool operation.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct LiquidityChange {
+    pub sol: i128,
+    pub oil: i128,
+    pub lp: i128,
 }
 
 impl Treasury {
     pub fn credit_auction_rewards_sol(&mut self, amount: u64) {
         self.auction_rewards_sol += amount;
     }
+
+    /// Checks the balance changes of a liquidity operation against its kind and the treasury's
+    /// liquidity reserves, and records them.
+    pub fn apply_liquidity_change(
+        &mut self,
+        action: LiquidityAction,
+        change: LiquidityChange,
+    ) -> Result<(), OilError> {
+        let valid = match action {
+            LiquidityAction::Add => change.sol <= 0 && change.oil <= 0 && change.lp > 0,
+            LiquidityAction::Remove => change.sol >= 0 && change.oil >= 0 && change.lp < 0,
+            LiquidityAction::Harvest => {
+                change.sol >= 0 && change.oil >= 0 && change.lp == 0 && (change.sol > 0 || change.oil > 0)
+            }
+            LiquidityAction::Rebalance => {
+                change.lp == 0 && ((change.sol < 0 && change.oil > 0) || (change.oil < 0 && change.sol > 0))
+            }
+        };
+        if !valid {
+            return Err(OilError::InvalidLiquidityChange);
+        }
+
+        // Only the SOL, OIL and LP tokens held for liquidity can be spent
+        let (sol_spent, sol_received) = split_change(change.sol);
+        let (oil_spent, oil_received) = split_change(change.oil);
+        let (lp_spent, lp_received) = split_change(change.lp);
+        if sol_spent > self.liquidity_sol || oil_spent > self.liquidity_oil || lp_spent > self.lp_tokens {
+            return Err(OilError::InsufficientLiquidity);
+        }
+        self.liquidity_sol = self.liquidity_sol - sol_spent + sol_received;
+        self.liquidity_oil = self.liquidity_oil - oil_spent + oil_received;
+        self.lp_tokens = self.lp_tokens - lp_spent + lp_received;
+
+        match action {
+            LiquidityAction::Add => {
+                self.lp_sol_deposited += sol_spent;
+                self.lp_oil_deposited += oil_spent;
+            }
+            LiquidityAction::Remove => {
+                self.lp_sol_withdrawn += sol_received;
+                self.lp_oil_withdrawn += oil_received;
+            }
+            LiquidityAction::Harvest => {
+                self.lp_sol_harvested += sol_received;
+                self.lp_oil_harvested += oil_received;
+            }
+            LiquidityAction::Rebalance => {}
+        }
+
+        Ok(())
+    }
+}
+
+/// Splits a balance change into the amounts spent and received.
+fn split_change(change: i128) -> (u64, u64) {
+    if change < 0 {
+        (change.unsigned_abs() as u64, 0)
+    } else {
+        (0, change as u64)
+    }
 }
 
-account!(OilAccount, Treasury);
\ No newline at end of file
+account!(OilAccount, Treasury);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn treasury() -> Treasury {
+        let mut treasury = Treasury::zeroed();
+        treasury.liquidity_sol = 1_000;
+        treasury.liquidity_oil = 500;
+        treasury
+    }
+
+    #[test]
+    fn test_liquidity_accounting() {
+        let mut treasury = treasury();
+
+        // Deposit, harvest, rebalance and withdraw
+        treasury
+            .apply_liquidity_change(LiquidityAction::Add, LiquidityChange { sol: -800, oil: -400, lp: 100 })
+            .unwrap();
+        treasury
+            .apply_liquidity_change(LiquidityAction::Harvest, LiquidityChange { sol: 10, oil: 5, lp: 0 })
+            .unwrap();
+        treasury
+            .apply_liquidity_change(LiquidityAction::Rebalance, LiquidityChange { sol: -100, oil: 50, lp: 0 })
+            .unwrap();
+        treasury
+            .apply_liquidity_change(LiquidityAction::Remove, LiquidityChange { sol: 400, oil: 200, lp: -50 })
+            .unwrap();
+
+        assert_eq!(treasury.lp_tokens, 50);
+        assert_eq!(treasury.liquidity_sol, 1_000 - 800 + 10 - 100 + 400);
+        assert_eq!(treasury.liquidity_oil, 500 - 400 + 5 + 50 + 200);
+        assert_eq!((treasury.lp_sol_deposited, treasury.lp_oil_deposited), (800, 400));
+        assert_eq!((treasury.lp_sol_harvested, treasury.lp_oil_harvested), (10, 5));
+        assert_eq!((treasury.lp_sol_withdrawn, treasury.lp_oil_withdrawn), (400, 200));
+    }
+
+    #[test]
+    fn test_liquidity_limits() {
+        let mut treasury = treasury();
+
+        // Spending beyond the liquidity reserves (e.g. unclaimed OIL rewards) fails
+        assert_eq!(
+            treasury.apply_liquidity_change(LiquidityAction::Add, LiquidityChange { sol: -800, oil: -600, lp: 100 }),
+            Err(OilError::InsufficientLiquidity)
+        );
+        assert_eq!(
+            treasury.apply_liquidity_change(LiquidityAction::Remove, LiquidityChange { sol: 1, oil: 1, lp: -1 }),
+            Err(OilError::InsufficientLiquidity)
+        );
+
+        // Balance changes must match the operation
+        assert_eq!(
+            treasury.apply_liquidity_change(LiquidityAction::Harvest, LiquidityChange { sol: 10, oil: 0, lp: 1 }),
+            Err(OilError::InvalidLiquidityChange)
+        );
+        assert_eq!(
+            treasury.apply_liquidity_change(LiquidityAction::Rebalance, LiquidityChange { sol: -10, oil: -10, lp: 0 }),
+            Err(OilError::InvalidLiquidityChange)
+        );
+        assert_eq!(treasury, self::treasury());
+    }
+}
\ No newline at end of file
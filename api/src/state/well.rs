@@ -1,9 +1,10 @@
 use serde::{Deserialize, Serialize};
 use steel::*;
 
+use crate::consts::{AUCTION_EXPONENTIAL_HALF_LIVES, AUCTION_PRICE_STEPS};
 use crate::state::well_pda;
 
-use super::{OilAccount, Auction};
+use super::{OilAccount, Auction, PriceCurve};
 
 /// Well account (one per well)
 #[repr(C)]
@@ -62,91 +63,56 @@ impl Well {
     }
 
     pub fn current_price(&self, auction: &Auction, clock: &Clock) -> u64 {
-        use crate::consts::AUCTION_FLOOR_PRICE;
-        
+        self.price_at(auction, clock.unix_timestamp)
+    }
+
+    /// Price of this well at a given unix timestamp, following the well's configured price curve.
+    pub fn price_at(&self, auction: &Auction, unix_timestamp: i64) -> u64 {
         // If well has no owner (never been bid on), show starting price
         use solana_program::pubkey::Pubkey;
         if self.current_bidder == Pubkey::default() {
             return self.init_price; // Return starting price for unowned wells
         }
         
-        let elapsed = clock.unix_timestamp.saturating_sub(self.epoch_start_time as i64);
-        let duration = auction.auction_duration_seconds as i64;
+        let well_id = self.well_id as usize;
+        let floor_price = auction.floor_price(well_id);
+        let elapsed = unix_timestamp.saturating_sub(self.epoch_start_time as i64).max(0) as u64;
+        let duration = auction.auction_duration_seconds;
         
         if elapsed >= duration {
-            return AUCTION_FLOOR_PRICE; // Auction expired, price is at floor
+            return floor_price; // Auction expired, price is at floor
         }
         
-        // Linear decay: price = floor + (init_price - floor) * (remaining / duration)
-        let remaining = duration - elapsed;
-        let price_range = self.init_price.saturating_sub(AUCTION_FLOOR_PRICE);
-        let decayed_amount = (price_range as u128 * remaining as u128 / duration as u128) as u64;
-        AUCTION_FLOOR_PRICE + decayed_amount
+        let price_range = self.init_price.saturating_sub(floor_price);
+        let decayed_amount = Self::decayed_range(auction.price_curve(well_id), price_range, elapsed, duration);
+        floor_price + decayed_amount
     }
 
-    /// Calculate the effective mining rate at a given point in time based on base rate and halvings
-    fn calculate_rate_at_time(&self, base_rate: u64, halving_count_at_time: u64) -> u64 {
-        if halving_count_at_time == 0 {
-            return base_rate;
-        }
-        
-        // Apply first halving (50% reduction)
-        let mut rate = base_rate / 2;
-        
-        // Apply subsequent halvings (25% reduction each)
-        for _ in 1..halving_count_at_time {
-            rate = (rate * 3) / 4;
-        }
-        
-        rate
-    }
-    
-    /// Calculate how many halvings had occurred by a given timestamp
-    /// 
-    /// Halving schedule:
-    /// - First halving: 14 days after initialization (at last_halving_time + FIRST_HALVING_PERIOD_SECONDS when halving_count = 0)
-    /// - Subsequent halvings: Every 28 days after the first halving
-    /// 
-    /// When halving_count > 0, last_halving_time is when the most recent halving occurred.
-    fn halving_count_at_time(auction: &Auction, timestamp: u64) -> u64 {
-        if auction.halving_count == 0 {
-            // No halvings have occurred yet
-            // last_halving_time is the initialization time
-            let first_halving_time = auction.last_halving_time + Auction::FIRST_HALVING_PERIOD_SECONDS;
-            if timestamp < first_halving_time {
-                return 0;
+    /// Portion of the price range still remaining after `elapsed` seconds (requires elapsed < duration).
+    fn decayed_range(curve: PriceCurve, price_range: u64, elapsed: u64, duration: u64) -> u64 {
+        let price_range = price_range as u128;
+        let elapsed = elapsed as u128;
+        let duration = duration as u128;
+        let remaining = match curve {
+            // price = floor + range * (remaining / duration)
+            PriceCurve::Linear => price_range * (duration - elapsed) / duration,
+            // price = floor + range * (steps_left / steps), dropping once per step
+            PriceCurve::Stepwise => {
+                let steps = AUCTION_PRICE_STEPS as u128;
+                let step = elapsed * steps / duration;
+                price_range * (steps - step) / steps
+            }
+            // price = floor + range / 2^(half_lives * elapsed / duration), interpolated within each half-life
+            PriceCurve::Exponential => {
+                let scaled = elapsed * AUCTION_EXPONENTIAL_HALF_LIVES as u128;
+                let halvings = (scaled / duration) as u32;
+                let fraction = scaled % duration;
+                let upper = price_range >> halvings;
+                let lower = price_range >> (halvings + 1);
+                upper - (upper - lower) * fraction / duration
             }
-            // First halving should have occurred but hasn't been applied yet
-            // Return 0 to be safe - it will be applied when someone interacts
-            return 0;
-        }
-        
-        // Calculate when the first halving occurred
-        // If halving_count = 1: first halving was at last_halving_time
-        // If halving_count = 2: first halving was 28 days before last_halving_time
-        // If halving_count = 3: first halving was 56 days before last_halving_time, etc.
-        let first_halving_time = if auction.halving_count == 1 {
-            auction.last_halving_time
-        } else {
-            // First halving was (halving_count - 1) * halving_period_seconds before the last halving
-            auction.last_halving_time.saturating_sub(
-                (auction.halving_count - 1) * auction.halving_period_seconds
-            )
         };
-        
-        if timestamp < first_halving_time {
-            return 0;
-        }
-        
-        // Calculate how many halvings occurred by this timestamp
-        // First halving is at first_halving_time, subsequent are every halving_period_seconds
-        let time_since_first = timestamp.saturating_sub(first_halving_time);
-        
-        // First halving counts as 1, then add subsequent halvings (every halving_period_seconds)
-        let halving_count = 1 + (time_since_first / auction.halving_period_seconds);
-        
-        // Cap at the actual halving_count (can't have more halvings than have occurred)
-        halving_count.min(auction.halving_count)
+        remaining as u64
     }
 
     pub fn update_accumulated_oil(&mut self, auction: &Auction, clock: &Clock) {
@@ -156,87 +122,23 @@ impl Well {
             return;
         }
         
-        let last_update = self.last_update_time as i64;
         let current_time = clock.unix_timestamp as u64;
-        let elapsed = clock.unix_timestamp.saturating_sub(last_update);
-        if elapsed <= 0 {
+        if current_time <= self.last_update_time {
             return;
         }
         
-        // Get base rate for this well (we need well_id, but we can derive it from self.well_id)
-        let base_rate = auction.base_mining_rates[self.well_id as usize];
-        
-        // Calculate halving counts at start and end of period
-        let halving_count_at_start = Self::halving_count_at_time(auction, last_update as u64);
-        let halving_count_at_end = Self::halving_count_at_time(auction, current_time);
-        
-        // Calculate rate at start of period
-        let rate_at_start = self.calculate_rate_at_time(base_rate, halving_count_at_start);
-        
-        // If no halving occurred during this period, use simple calculation
-        if halving_count_at_start == halving_count_at_end {
-            let oil_mined = rate_at_start.checked_mul(elapsed as u64).unwrap_or(0);
-            self.accumulated_oil = self.accumulated_oil
-                .checked_add(oil_mined)
-                .unwrap_or(u64::MAX);
-            self.lifetime_oil_mined = self.lifetime_oil_mined
-                .checked_add(oil_mined)
+        // Integrate the mining rate piecewise, splitting the period at each halving
+        let mut oil_mined = 0u64;
+        let mut segment_start = self.last_update_time;
+        while segment_start < current_time {
+            let segment_end = auction.next_rate_change_after(segment_start).min(current_time);
+            let segment_rate = auction.mining_rate_at(self.well_id as usize, segment_start);
+            oil_mined = oil_mined
+                .checked_add(segment_rate.checked_mul(segment_end - segment_start).unwrap_or(u64::MAX))
                 .unwrap_or(u64::MAX);
-            self.operator_total_oil_mined = self.operator_total_oil_mined
-                .checked_add(oil_mined)
-                .unwrap_or(u64::MAX);
-            self.last_update_time = current_time;
-            return;
-        }
-        
-        // Halving(s) occurred during this period - calculate in segments
-        // Calculate when first halving occurred (needed for segment calculation)
-        let first_halving_time = if auction.halving_count == 0 {
-            auction.last_halving_time + Auction::FIRST_HALVING_PERIOD_SECONDS
-        } else if auction.halving_count == 1 {
-            auction.last_halving_time
-        } else {
-            auction.last_halving_time.saturating_sub(
-                (auction.halving_count - 1) * auction.halving_period_seconds
-            )
-        };
-        
-        let mut total_oil = 0u64;
-        let mut segment_start = last_update as u64;
-        let mut current_halving_count = halving_count_at_start;
-        
-        while segment_start < current_time && current_halving_count < halving_count_at_end {
-            // Calculate when the next halving occurred
-            let next_halving_time = if current_halving_count == 0 {
-                first_halving_time
-            } else {
-                // Subsequent halvings are every halving_period_seconds after the first
-                first_halving_time + (current_halving_count as u64 * auction.halving_period_seconds)
-            };
-            
-            let segment_end = next_halving_time.min(current_time);
-            let segment_time = segment_end.saturating_sub(segment_start);
-            let segment_rate = self.calculate_rate_at_time(base_rate, current_halving_count);
-            
-            total_oil = total_oil.checked_add(
-                segment_rate.checked_mul(segment_time).unwrap_or(0)
-            ).unwrap_or(u64::MAX);
-            
             segment_start = segment_end;
-            current_halving_count += 1;
-        }
-        
-        // Calculate remaining time after all halvings in this period
-        if segment_start < current_time {
-            let remaining_time = current_time.saturating_sub(segment_start);
-            let final_rate = self.calculate_rate_at_time(base_rate, halving_count_at_end);
-            total_oil = total_oil.checked_add(
-                final_rate.checked_mul(remaining_time).unwrap_or(0)
-            ).unwrap_or(u64::MAX);
         }
         
-        let oil_mined = total_oil;
-        
         self.accumulated_oil = self.accumulated_oil
             .checked_add(oil_mined)
             .unwrap_or(u64::MAX);
@@ -253,70 +155,141 @@ impl Well {
         self.last_update_time = current_time;
     }
 
-    /// Apply all halvings that have already occurred (based on auction.halving_count)
-    /// This is used when resetting well.mps to base rate in a new epoch
-    /// 
-    /// Safety: This function assumes well.mps has just been reset to base rate.
-    /// It applies all halvings that have occurred according to auction.halving_count.
-    pub fn apply_existing_halvings(&mut self, auction: &Auction) {
-        if auction.halving_count == 0 {
-            // No halvings have occurred yet, keep base rate
-            self.halving_count = 0;
-            return;
-        }
-        
-        // Apply first halving (50% reduction)
-        self.mps = self.mps / 2;
-        
-        // Apply subsequent halvings (25% reduction each)
-        // auction.halving_count includes the first halving, so subtract 1 for subsequent halvings
-        for _ in 0..(auction.halving_count - 1) {
-            self.mps = (self.mps * 3) / 4; // 25% reduction (multiply by 0.75)
-        }
-        
-        // Sync well's halving_count to match auction's
-        self.halving_count = auction.halving_count;
-    }
-
+    /// Brings the auction's halving state up to date and sets this well's mps to the current rate.
     pub fn check_and_apply_halving(&mut self, auction: &mut Auction, clock: &Clock) {
-        // First, sync existing halvings if this well is behind
-        // This ensures all wells stay in sync with the global halving state
-        while self.halving_count < auction.halving_count {
-            if self.halving_count == 0 {
-                // Apply first halving (50% reduction)
-                self.mps = self.mps / 2;
-                self.halving_count = 1;
-            } else {
-                // Apply subsequent halvings (25% reduction each)
-                self.mps = (self.mps * 3) / 4;
-                self.halving_count += 1;
-            }
-        }
-        
-        // Then check if we should apply NEW halvings based on current time
         let current_time = clock.unix_timestamp as u64;
-        let (halvings_to_apply, is_first_halving) = auction.should_apply_halving(current_time);
-        
-        if halvings_to_apply > 0 {
-            if is_first_halving {
-                // First halving: 50% reduction
-                self.mps = self.mps / 2; // 50% reduction
-                self.halving_count += 1;
-                auction.halving_count += 1;
-                auction.last_halving_time = current_time;
-            } else {
-                // Subsequent halvings: 25% reduction each (multiply by 0.75)
-                for _ in 0..halvings_to_apply {
-                    self.mps = (self.mps * 3) / 4; // 25% reduction (multiply by 0.75)
-                    self.halving_count += 1;
-                    auction.halving_count += 1;
-                }
-                // Update auction last_halving_time to current time
-                auction.last_halving_time = current_time;
-            }
-        }
+        auction.sync_halvings(current_time);
+        self.mps = auction.mining_rate_at(self.well_id as usize, current_time);
+        self.halving_count = auction.halving_count;
     }
 }
 
 account!(OilAccount, Well);
 
+#[cfg(test)]
+mod tests {
+    use crate::consts::{
+        DEFAULT_FIRST_HALVING_DELAY_SECONDS, DEFAULT_HALVING_PERIOD_SECONDS, MIN_HALVING_PERIOD_SECONDS,
+    };
+
+    use super::*;
+
+    fn owned_well(init_price: u64) -> Well {
+        let mut well = Well::zeroed();
+        well.current_bidder = Pubkey::new_unique();
+        well.init_price = init_price;
+        well.epoch_start_time = 1_000;
+        well
+    }
+
+    fn auction_with_curve(curve: PriceCurve) -> Auction {
+        let mut auction = Auction::zeroed();
+        auction.auction_duration_seconds = 3_600;
+        auction.price_curves[0] = curve as u64;
+        auction.floor_prices[0] = 1_000;
+        auction
+    }
+
+    #[test]
+    fn test_price_curves_hit_init_and_floor() {
+        let well = owned_well(1_001_000);
+        for curve in [PriceCurve::Linear, PriceCurve::Exponential, PriceCurve::Stepwise] {
+            let auction = auction_with_curve(curve);
+            assert_eq!(well.price_at(&auction, 1_000), 1_001_000);
+            assert_eq!(well.price_at(&auction, 1_000 + 3_600), 1_000);
+            assert!(well.price_at(&auction, 1_000 + 1_800) < 1_001_000);
+            assert!(well.price_at(&auction, 1_000 + 3_599) >= 1_000);
+        }
+    }
+
+    #[test]
+    fn test_price_curve_shapes() {
+        let well = owned_well(1_001_000);
+        let halfway = 1_000 + 1_800;
+        assert_eq!(well.price_at(&auction_with_curve(PriceCurve::Linear), halfway), 501_000);
+        assert_eq!(well.price_at(&auction_with_curve(PriceCurve::Stepwise), halfway), 501_000);
+        assert_eq!(well.price_at(&auction_with_curve(PriceCurve::Stepwise), halfway - 1), 601_000);
+        // Four of eight half-lives have elapsed: 1/16 of the range remains.
+        assert_eq!(well.price_at(&auction_with_curve(PriceCurve::Exponential), halfway), 63_500);
+    }
+
+    fn legacy_auction() -> Auction {
+        let mut auction = Auction::zeroed();
+        auction.last_halving_time = 1_000;
+        auction.halving_period_seconds = DEFAULT_HALVING_PERIOD_SECONDS;
+        auction.base_mining_rates[0] = 1_000_000;
+        auction
+    }
+
+    #[test]
+    fn test_halving_schedule_rates() {
+        let auction = legacy_auction();
+        let first_halving = 1_000 + DEFAULT_FIRST_HALVING_DELAY_SECONDS;
+        assert_eq!(auction.mining_rate_at(0, first_halving - 1), 1_000_000);
+        assert_eq!(auction.mining_rate_at(0, first_halving), 500_000);
+        assert_eq!(auction.mining_rate_at(0, first_halving + DEFAULT_HALVING_PERIOD_SECONDS), 375_000);
+        assert_eq!(auction.mining_rate_at(0, first_halving + 2 * DEFAULT_HALVING_PERIOD_SECONDS), 281_250);
+        assert_eq!(auction.halving_count_at(first_halving + 2 * DEFAULT_HALVING_PERIOD_SECONDS), 3);
+    }
+
+    #[test]
+    fn test_accumulated_oil_spans_halving() {
+        let auction = legacy_auction();
+        let first_halving = 1_000 + DEFAULT_FIRST_HALVING_DELAY_SECONDS;
+        let mut well = owned_well(0);
+        well.last_update_time = first_halving - 10;
+        let clock = Clock { unix_timestamp: (first_halving + 10) as i64, ..Clock::default() };
+        well.update_accumulated_oil(&auction, &clock);
+        assert_eq!(well.accumulated_oil, 10 * 1_000_000 + 10 * 500_000);
+    }
+
+    #[test]
+    fn test_pending_halving_schedule() {
+        let mut auction = legacy_auction();
+        let effective_time = 1_000 + DEFAULT_FIRST_HALVING_DELAY_SECONDS;
+        auction.pending_halving_effective_time = effective_time;
+        auction.pending_first_halving_delay_seconds = 7 * 86_400;
+        auction.pending_halving_period_seconds = 7 * 86_400;
+        auction.pending_halving_decay_bps = 5_000;
+        assert_eq!(auction.mining_rate_at(0, effective_time - 1), 1_000_000);
+        assert_eq!(auction.mining_rate_at(0, effective_time), 500_000);
+        assert_eq!(auction.mining_rate_at(0, effective_time + 7 * 86_400), 250_000);
+        assert_eq!(auction.next_halving_time(effective_time), effective_time + 7 * 86_400);
+
+        // Promoting the pending schedule does not change any rate
+        auction.sync_halvings(effective_time + 1);
+        assert!(auction.promote_pending_halving_schedule(effective_time + 1));
+        assert_eq!(auction.mining_rate_at(0, effective_time + 7 * 86_400), 250_000);
+        assert_eq!(auction.halving_count_at(effective_time + 7 * 86_400), 2);
+    }
+
+    #[test]
+    fn test_check_halving_schedule() {
+        let mut auction = legacy_auction();
+        auction.auction_duration_seconds = 3_600;
+        let day = MIN_HALVING_PERIOD_SECONDS;
+        assert!(auction.check_halving_schedule(day, day, 5_000, 2_500).is_ok());
+
+        // Delays and periods shorter than a day, or decays above 100%, are rejected
+        assert_eq!(auction.check_halving_schedule(day - 1, day, 5_000, 2_500), Err(ProgramError::InvalidArgument));
+        assert_eq!(auction.check_halving_schedule(day, day - 1, 5_000, 2_500), Err(ProgramError::InvalidArgument));
+        assert_eq!(auction.check_halving_schedule(day, 0, 5_000, 2_500), Err(ProgramError::InvalidArgument));
+        assert_eq!(auction.check_halving_schedule(day, day, 10_001, 2_500), Err(ProgramError::InvalidArgument));
+        assert_eq!(auction.check_halving_schedule(day, day, 5_000, 10_001), Err(ProgramError::InvalidArgument));
+
+        // An auction longer than a day raises the minimum to one auction
+        auction.auction_duration_seconds = 2 * day;
+        assert_eq!(auction.check_halving_schedule(day, 2 * day, 5_000, 2_500), Err(ProgramError::InvalidArgument));
+        assert!(auction.check_halving_schedule(2 * day, 2 * day, 5_000, 2_500).is_ok());
+    }
+
+    #[test]
+    fn test_next_init_price() {
+        let mut auction = auction_with_curve(PriceCurve::Linear);
+        auction.starting_prices[0] = 50_000;
+        assert_eq!(auction.next_init_price(0, 1_000), 50_000);
+        assert_eq!(auction.next_init_price(0, 10_000), 20_000);
+        auction.price_multipliers_bps[0] = 15_000;
+        assert_eq!(auction.next_init_price(0, 10_000), 15_000);
+    }
+}
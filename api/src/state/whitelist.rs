@@ -4,6 +4,7 @@ use solana_program::log::sol_log;
 use steel::*;
 
 use crate::consts::WHITELIST;
+use crate::error::OilError;
 use super::OilAccount;
 
 /// Whitelist tracks access codes for pre-mine phase.
@@ -15,6 +16,15 @@ pub struct Whitelist {
     
     /// Number of times this code has been used (optional tracking)
     pub usage_count: u64,
+
+    /// Maximum number of uses (0 = unlimited)
+    pub max_uses: u64,
+
+    /// Unix timestamp at which the code expires (0 = never)
+    pub expires_at: i64,
+
+    /// Wallet the code is bound to (Pubkey::default() = any wallet)
+    pub bound_wallet: Pubkey,
 }
 
 impl Whitelist {
@@ -24,12 +34,28 @@ impl Whitelist {
         Pubkey::find_program_address(&[WHITELIST, &code_hash], &ID)
     }
 
+    /// Checks that `authority` may use this code now.
+    pub fn check_usable(&self, authority: Pubkey, unix_timestamp: i64) -> Result<(), OilError> {
+        if self.max_uses > 0 && self.usage_count >= self.max_uses {
+            return Err(OilError::AccessCodeExhausted);
+        }
+        if self.expires_at > 0 && unix_timestamp >= self.expires_at {
+            return Err(OilError::AccessCodeExpired);
+        }
+        if self.bound_wallet != Pubkey::default() && self.bound_wallet != authority {
+            return Err(OilError::AccessCodeBound);
+        }
+        Ok(())
+    }
+
     /// Validates and processes a premine access code for a new transaction.
     pub fn validate_premine_code<'a>(
         is_premine: bool,
         has_access_code: bool,
         access_code_hash: [u8; 32],
         whitelist_info_opt: Option<&AccountInfo<'a>>,
+        authority: Pubkey,
+        clock: &Clock,
     ) -> Result<(), ProgramError> {
         if is_premine {
             if !has_access_code {
@@ -49,18 +75,20 @@ impl Whitelist {
             }
             
             // Validate the code hash matches
-            let whitelist = whitelist_info.as_account::<Whitelist>(&crate::ID)?;
+            let whitelist = whitelist_info.as_account_mut::<Whitelist>(&crate::ID)?;
             if whitelist.code_hash != access_code_hash {
                 return Err(ProgramError::InvalidArgument); // Access code hash mismatch
             }
+
+            // Validate the code is not exhausted, expired or bound to another wallet
+            whitelist.check_usable(authority, clock.unix_timestamp)?;
             
-            // Increment usage count (optional tracking)
-            let whitelist_mut = whitelist_info.as_account_mut::<Whitelist>(&crate::ID)?;
-            whitelist_mut.usage_count = whitelist_mut.usage_count.saturating_add(1);
+            // Increment usage count
+            whitelist.usage_count = whitelist.usage_count.saturating_add(1);
             
             sol_log(&format!(
                 "Pre-mine: access code validated, usage_count={}",
-                whitelist_mut.usage_count
+                whitelist.usage_count
             ));
         } else if has_access_code {
             // Access code provided but not in pre-mine - ignore it (not an error)
@@ -72,3 +100,61 @@ impl Whitelist {
 }
 
 account!(OilAccount, Whitelist);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validate(whitelist: &mut Whitelist, authority: Pubkey, unix_timestamp: i64) -> Result<(), ProgramError> {
+        let key = Whitelist::pda(whitelist.code_hash).0;
+        let owner = crate::ID;
+        let mut lamports = 0;
+        let mut data = vec![0; 8 + std::mem::size_of::<Whitelist>()];
+        data[0] = Whitelist::discriminator();
+        data[8..].copy_from_slice(bytemuck::bytes_of(whitelist));
+        let info = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &owner, false, 0);
+        let clock = Clock { unix_timestamp, ..Clock::default() };
+        let result = Whitelist::validate_premine_code(true, true, whitelist.code_hash, Some(&info), authority, &clock);
+        *whitelist = *info.as_account::<Whitelist>(&crate::ID)?;
+        result
+    }
+
+    #[test]
+    fn test_validate_premine_code() {
+        let authority = Pubkey::new_unique();
+        let mut whitelist = Whitelist::zeroed();
+        whitelist.code_hash = [1; 32];
+        whitelist.max_uses = 2;
+        whitelist.expires_at = 1_000;
+
+        // Each use is counted until the code is exhausted.
+        assert_eq!(validate(&mut whitelist, authority, 0), Ok(()));
+        assert_eq!(validate(&mut whitelist, authority, 0), Ok(()));
+        assert_eq!(whitelist.usage_count, 2);
+        assert_eq!(validate(&mut whitelist, authority, 0), Err(OilError::AccessCodeExhausted.into()));
+        assert_eq!(whitelist.usage_count, 2);
+
+        // Expired codes are rejected from their expiry on.
+        whitelist.max_uses = 0;
+        assert_eq!(validate(&mut whitelist, authority, 999), Ok(()));
+        assert_eq!(validate(&mut whitelist, authority, 1_000), Err(OilError::AccessCodeExpired.into()));
+
+        // Bound codes only admit their wallet.
+        whitelist.expires_at = 0;
+        whitelist.bound_wallet = authority;
+        assert_eq!(validate(&mut whitelist, Pubkey::new_unique(), 0), Err(OilError::AccessCodeBound.into()));
+        assert_eq!(validate(&mut whitelist, authority, 0), Ok(()));
+        assert_eq!(whitelist.usage_count, 4);
+    }
+
+    #[test]
+    fn test_premine_requires_access_code() {
+        let clock = Clock::default();
+        let authority = Pubkey::new_unique();
+        assert_eq!(
+            Whitelist::validate_premine_code(true, false, [0; 32], None, authority, &clock),
+            Err(ProgramError::InvalidArgument)
+        );
+        assert_eq!(Whitelist::validate_premine_code(false, false, [0; 32], None, authority, &clock), Ok(()));
+    }
+}
@@ -3,7 +3,8 @@ use solana_program::program::invoke;
 use solana_program::sysvar::clock::Clock;
 use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
 use steel::*;
-use crate::state::Config;
+use crate::consts::{ACCOUNT_VERSION_OFFSET, EMISSION_SCHEDULE};
+use crate::state::{Config, OilAccount};
 
 /// Macro to extract accounts from an iterator with concise syntax.
 /// 
@@ -75,6 +76,23 @@ pub fn create_or_validate_wrapped_sol_ata<'a>(
     }
 }
 
+/// Creates a program account like `create_program_account`, stamping it with the current layout
+/// version of its type so `MigrateAccount` knows it needs no upgrade.
+pub fn create_versioned_account<'a, 'info, T: Discriminator + Pod>(
+    target_account: &'a AccountInfo<'info>,
+    system_program: &'a AccountInfo<'info>,
+    payer: &'a AccountInfo<'info>,
+    owner: &Pubkey,
+    seeds: &[&[u8]],
+) -> ProgramResult {
+    create_program_account::<T>(target_account, system_program, payer, owner, seeds)?;
+    let version = OilAccount::try_from(T::discriminator())
+        .map_err(|_| ProgramError::InvalidAccountData)?
+        .version();
+    target_account.try_borrow_mut_data()?[ACCOUNT_VERSION_OFFSET] = version;
+    Ok(())
+}
+
 /// Checks if pre-mine phase is currently active.
 /// 
 /// Pre-mine is active when:
@@ -86,6 +104,15 @@ pub fn is_premine_active(config: &Config, clock: &Clock) -> bool {
     config.tge_timestamp > 0 && clock.unix_timestamp < config.tge_timestamp
 }
 
+/// Returns the OIL minted per block-based mining round in an emission week (see `EMISSION_SCHEDULE`).
+pub fn emission_per_round(week: u64) -> u64 {
+    EMISSION_SCHEDULE
+        .iter()
+        .rev()
+        .find(|(start_week, _)| *start_week <= week)
+        .map_or(0, |(_, amount)| *amount)
+}
+
 /// Generates a random mask of 25 squares based on a random seed.
 /// 
 /// Uses reservoir sampling algorithm to randomly select `num_squares` squares
@@ -104,3 +131,27 @@ pub fn generate_random_mask(num_squares: u64, r: &[u8]) -> [bool; 25] {
     }
     new_mask
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consts::ONE_OIL;
+
+    #[test]
+    fn test_emission_per_round() {
+        // Week 0 opens the first epoch
+        assert_eq!(emission_per_round(0), ONE_OIL * 200);
+        assert_eq!(emission_per_round(3), ONE_OIL * 200);
+
+        // Each epoch starts on its first week
+        for (start_week, amount) in EMISSION_SCHEDULE {
+            assert_eq!(emission_per_round(start_week), amount);
+        }
+        assert_eq!(emission_per_round(251), ONE_OIL * 25 / 4);
+
+        // The last epoch runs on indefinitely
+        let (last_week, last_amount) = EMISSION_SCHEDULE[EMISSION_SCHEDULE.len() - 1];
+        assert_eq!(emission_per_round(last_week + 1_000), last_amount);
+        assert_eq!(emission_per_round(u64::MAX), last_amount);
+    }
+}
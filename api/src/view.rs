@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+use steel::*;
+
+/// Return data of the `GetPendingRewards` view instruction.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct PendingRewards {
+    /// The authority of the miner.
+    pub authority: Pubkey,
+
+    /// The SOL claimable from block-based mining.
+    pub block_rewards_sol: u64,
+
+    /// The unrefined OIL from block-based mining.
+    pub block_rewards_oil: u64,
+
+    /// The refined OIL from block-based mining, including refining not yet checkpointed.
+    pub block_refined_oil: u64,
+
+    /// The OIL received by claiming block-based rewards now (after the refining fee).
+    pub block_claimable_oil: u64,
+
+    /// The SOL claimable from auction-based mining.
+    pub auction_rewards_sol: u64,
+
+    /// The unrefined OIL from auction-based mining.
+    pub auction_rewards_oil: u64,
+
+    /// The refined OIL from auction-based mining, including refining not yet checkpointed.
+    pub auction_refined_oil: u64,
+
+    /// The timestamp of the view.
+    pub ts: i64,
+}
+
+/// Return data of the `GetWellQuote` view instruction.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct WellQuote {
+    /// The well ID (0-3).
+    pub well_id: u64,
+
+    /// The current epoch of the well.
+    pub epoch_id: u64,
+
+    /// The current owner of the well.
+    pub current_bidder: Pubkey,
+
+    /// The current auction price (in lamports).
+    pub current_price: u64,
+
+    /// The amount a bid placed now must pay (in lamports).
+    pub bid_amount: u64,
+
+    /// The OIL mined by the current owner and not yet paid out, including mining not yet checkpointed.
+    pub accumulated_oil: u64,
+
+    /// The current mining rate of the well (OIL per second, after halvings).
+    pub mps: u64,
+
+    /// The FOGO contributed to the pool for the current epoch.
+    pub total_contributed: u64,
+
+    /// The FOGO still needed for the pool to bid now.
+    pub pool_needed: u64,
+
+    /// The timestamp of the view.
+    pub ts: i64,
+}
+
+/// Return data of the `GetStakeInfo` view instruction.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct StakeInfo {
+    /// The authority of the stake account.
+    pub authority: Pubkey,
+
+    /// The amount of OIL staked.
+    pub balance: u64,
+
+    /// The stake score (balance weighted by the lock multiplier).
+    pub score: u64,
+
+    /// The lock duration in days (0 = no lock).
+    pub lock_duration_days: u64,
+
+    /// The timestamp at which the lock ends.
+    pub lock_ends_at: u64,
+
+    /// The seconds remaining until the lock ends.
+    pub remaining_lock_seconds: u64,
+
+    /// The penalty (in percent) charged when withdrawing now.
+    pub penalty_percent: u64,
+
+    /// The SOL yield claimable now, including yield not yet checkpointed.
+    pub pending_rewards: u64,
+
+    /// The total SOL yield earned by the stake account, including yield not yet checkpointed.
+    pub lifetime_rewards: u64,
+
+    /// The timestamp of the view.
+    pub ts: i64,
+}
+
+event!(PendingRewards);
+event!(WellQuote);
+event!(StakeInfo);
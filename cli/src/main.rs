@@ -4,27 +4,20 @@ use entropy_rng_api::prelude::*;
 // Jupiter swap imports removed - unused
 use oil_api::prelude::*;
 use oil_api::state::Share;
-use solana_account_decoder::UiAccountEncoding;
-use solana_client::{
-    client_error::{reqwest::StatusCode, ClientErrorKind},
-    nonblocking::rpc_client::RpcClient,
-    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
-    rpc_filter::{Memcmp, RpcFilterType},
-};
+use oil_client::{Confirmation, OilClient};
+use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::{
-    address_lookup_table::{state::AddressLookupTable, AddressLookupTableAccount},
-    compute_budget::ComputeBudgetInstruction,
-    message::{v0::Message, VersionedMessage},
     native_token::lamports_to_sol,
+    program_pack::Pack,
     pubkey::Pubkey,
     rent::Rent,
-    signature::{read_keypair_file, Signature, Signer},
-    transaction::{Transaction, VersionedTransaction},
+    signature::{read_keypair_file, Signer},
+    transaction::Transaction,
 };
 use solana_sdk::{keccak, pubkey};
 use spl_associated_token_account::get_associated_token_address;
 use spl_token::amount_to_ui_amount;
-use steel::{AccountDeserialize, AccountMeta, Clock, Discriminator, Instruction};
+use steel::{AccountDeserialize, AccountMeta, Clock, Instruction};
 
 #[tokio::main]
 async fn main() {
@@ -33,7 +26,8 @@ async fn main() {
         read_keypair_file(&std::env::var("KEYPAIR").expect("Missing KEYPAIR env var")).unwrap();
 
     // Build transaction
-    let rpc = RpcClient::new(std::env::var("RPC").expect("Missing RPC env var"));
+    let rpc = OilClient::new(RpcClient::new(std::env::var("RPC").expect("Missing RPC env var")))
+        .with_confirmation(Confirmation::BestEffort);
     match std::env::var("COMMAND")
         .expect("Missing COMMAND env var")
         .as_str()
@@ -59,6 +53,9 @@ async fn main() {
         "reset" => {
             reset(&rpc, &payer).await.unwrap();
         }
+        "supply_projection" => {
+            log_supply_projection(&rpc).await.unwrap();
+        }
         "treasury" => {
             log_treasury(&rpc).await.unwrap();
         }
@@ -86,6 +83,12 @@ async fn main() {
         "set_admin" => {
             set_admin(&rpc, &payer).await.unwrap();
         }
+        "accept_admin" => {
+            accept_admin(&rpc, &payer).await.unwrap();
+        }
+        "cancel_admin_action" => {
+            cancel_admin_action(&rpc, &payer).await.unwrap();
+        }
         "set_fee_collector" => {
             set_fee_collector(&rpc, &payer).await.unwrap();
         }
@@ -110,9 +113,21 @@ async fn main() {
         "set_admin_fee" => {
             set_admin_fee(&rpc, &payer).await.unwrap();
         }
+        "set_stake_boost" => {
+            set_stake_boost(&rpc, &payer).await.unwrap();
+        }
+        "set_seeker_bonus" => {
+            set_seeker_bonus(&rpc, &payer).await.unwrap();
+        }
+        "set_refining_fee" => {
+            set_refining_fee(&rpc, &payer).await.unwrap();
+        }
         "set_swap_program" => {
             set_swap_program(&rpc, &payer).await.unwrap();
         }
+        "set_lp_mint" => {
+            set_lp_mint(&rpc, &payer).await.unwrap();
+        }
         "set_var_address" => {
             set_var_address(&rpc, &payer).await.unwrap();
         }
@@ -131,6 +146,9 @@ async fn main() {
         "migrate" => {
             migrate(&rpc, &payer).await.unwrap();
         }
+        "set_pause" => {
+            set_pause(&rpc, &payer).await.unwrap();
+        }
         "set_tge_timestamp" => {
             set_tge_timestamp(&rpc, &payer).await.unwrap();
         }
@@ -140,9 +158,24 @@ async fn main() {
         "claim_referral" => {
             claim_referral_cmd(&rpc, &payer).await.unwrap();
         }
+        "claim_seeker" => {
+            claim_seeker(&rpc, &payer).await.unwrap();
+        }
+        "update_rig" => {
+            update_rig(&rpc, &payer).await.unwrap();
+        }
+        "deposit_liquid" => {
+            deposit_liquid(&rpc, &payer).await.unwrap();
+        }
+        "redeem_liquid" => {
+            redeem_liquid(&rpc, &payer).await.unwrap();
+        }
         "referral" => {
             log_referral(&rpc, &payer).await.unwrap();
         }
+        "referral_leaderboard" => {
+            log_referral_leaderboard(&rpc).await.unwrap();
+        }
         "well" => {
             log_well(&rpc).await.unwrap();
         }
@@ -155,6 +188,9 @@ async fn main() {
         "place_bid" => {
             place_bid(&rpc, &payer).await.unwrap();
         }
+        "quote" => {
+            log_well_quote(&rpc, &payer).await.unwrap();
+        }
         // "initialize" => {
         //     initialize(&rpc, &payer).await.unwrap();
         // }
@@ -163,7 +199,7 @@ async fn main() {
 }
 
 async fn lut(
-    rpc: &RpcClient,
+    rpc: &OilClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
     let recent_slot = rpc.get_slot().await? - 4;
@@ -217,7 +253,7 @@ async fn lut(
 }
 
 async fn set_admin_fee(
-    rpc: &RpcClient,
+    rpc: &OilClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
     let admin_fee = std::env::var("ADMIN_FEE").expect("Missing ADMIN_FEE env var");
@@ -227,8 +263,59 @@ async fn set_admin_fee(
     Ok(())
 }
 
+async fn set_stake_boost(
+    rpc: &OilClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let max_bps = std::env::var("MAX_BPS").expect("Missing MAX_BPS env var");
+    let max_bps = u64::from_str(&max_bps).expect("Invalid MAX_BPS");
+    let share_bps = std::env::var("SHARE_BPS").expect("Missing SHARE_BPS env var");
+    let share_bps = u64::from_str(&share_bps).expect("Invalid SHARE_BPS");
+    let ix = oil_api::sdk::set_stake_boost(payer.pubkey(), max_bps, share_bps);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn set_seeker_bonus(
+    rpc: &OilClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let refining_discount_bps = std::env::var("REFINING_DISCOUNT_BPS").expect("Missing REFINING_DISCOUNT_BPS env var");
+    let refining_discount_bps = u64::from_str(&refining_discount_bps).expect("Invalid REFINING_DISCOUNT_BPS");
+    let ix = oil_api::sdk::set_seeker_bonus(payer.pubkey(), refining_discount_bps);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn set_refining_fee(
+    rpc: &OilClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let refining_fee_bps = std::env::var("REFINING_FEE_BPS").expect("Missing REFINING_FEE_BPS env var");
+    let refining_fee_bps = u64::from_str(&refining_fee_bps).expect("Invalid REFINING_FEE_BPS");
+    let auction_refining_fee_bps =
+        std::env::var("AUCTION_REFINING_FEE_BPS").expect("Missing AUCTION_REFINING_FEE_BPS env var");
+    let auction_refining_fee_bps = u64::from_str(&auction_refining_fee_bps).expect("Invalid AUCTION_REFINING_FEE_BPS");
+    let vesting_period = std::env::var("VESTING_PERIOD").expect("Missing VESTING_PERIOD env var");
+    let vesting_period = i64::from_str(&vesting_period).expect("Invalid VESTING_PERIOD");
+    let ix = oil_api::sdk::set_refining_fee(payer.pubkey(), refining_fee_bps, auction_refining_fee_bps, vesting_period);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn set_lp_mint(
+    rpc: &OilClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let lp_mint = std::env::var("LP_MINT").expect("Missing LP_MINT env var");
+    let lp_mint = Pubkey::from_str(&lp_mint).expect("Invalid LP_MINT");
+    let ix = oil_api::sdk::set_lp_mint(payer.pubkey(), lp_mint);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
 async fn set_var_address(
-    rpc: &RpcClient,
+    rpc: &OilClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
     let new_var_address = std::env::var("VAR").expect("Missing VAR env var");
@@ -239,7 +326,7 @@ async fn set_var_address(
 }
 
 async fn new_var(
-    rpc: &RpcClient,
+    rpc: &OilClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
     let provider = std::env::var("PROVIDER").expect("Missing PROVIDER env var");
@@ -256,10 +343,10 @@ async fn new_var(
     Ok(())
 }
 
-async fn participating_miners(rpc: &RpcClient) -> Result<(), anyhow::Error> {
+async fn participating_miners(rpc: &OilClient) -> Result<(), anyhow::Error> {
     let round_id = std::env::var("ID").expect("Missing ID env var");
     let round_id = u64::from_str(&round_id).expect("Invalid ID");
-    let miners = get_miners_participating(rpc, round_id).await?;
+    let miners = rpc.miners_in_round(round_id).await?;
     for (i, (_address, miner)) in miners.iter().enumerate() {
         println!("{}: {}", i, miner.authority);
     }
@@ -267,13 +354,13 @@ async fn participating_miners(rpc: &RpcClient) -> Result<(), anyhow::Error> {
 }
 
 async fn log_stake(
-    rpc: &RpcClient,
+    rpc: &OilClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
     let authority = std::env::var("AUTHORITY").unwrap_or(payer.pubkey().to_string());
     let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
     let staker_address = oil_api::state::stake_pda(authority).0;
-    let stake = get_stake(rpc, authority).await?;
+    let stake = rpc.stake(authority).await?;
     println!("Stake");
     println!("  address: {}", staker_address);
     println!("  authority: {}", authority);
@@ -309,7 +396,7 @@ async fn log_stake(
 }
 
 async fn ata(
-    rpc: &RpcClient,
+    rpc: &OilClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
     let user = pubkey!("FgZFnb3bi7QexKCdXWPwWy91eocUD7JCFySHb83vLoPD");
@@ -328,7 +415,7 @@ async fn ata(
     Ok(())
 }
 
-async fn keys(rpc: &RpcClient, payer: &solana_sdk::signer::keypair::Keypair) -> Result<(), anyhow::Error> {
+async fn keys(rpc: &OilClient, payer: &solana_sdk::signer::keypair::Keypair) -> Result<(), anyhow::Error> {
     let treasury_address = oil_api::state::treasury_pda().0;
     let config_address = oil_api::state::config_pda().0;
     let board_address = oil_api::state::board_pda().0;
@@ -338,7 +425,7 @@ async fn keys(rpc: &RpcClient, payer: &solana_sdk::signer::keypair::Keypair) ->
     let miner_address = oil_api::state::miner_pda(signer_address).0;
     
     // Get current round ID from board
-    let board = get_board(rpc).await?;
+    let board = rpc.board().await?;
     let round = oil_api::state::round_pda(board.round_id).0;
     
     println!("Round: {}", round);
@@ -350,13 +437,13 @@ async fn keys(rpc: &RpcClient, payer: &solana_sdk::signer::keypair::Keypair) ->
 }
 
 async fn claim(
-    rpc: &RpcClient,
+    rpc: &OilClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
     // Get miner to check for referrer
-    let miner = get_miner(rpc, payer.pubkey()).await?;
+    let miner = rpc.miner(payer.pubkey()).await?;
     
-    // Get referrer accounts if miner has a referrer (single-tier system)
+    // Get referrer accounts if miner has a referrer
     let (referrer_miner, referrer_referral, referrer_referral_oil_ata) = if miner.referrer != Pubkey::default() {
         let referrer_miner_pda = oil_api::state::miner_pda(miner.referrer).0;
         let referrer_referral_pda = oil_api::state::referral_pda(miner.referrer).0;
@@ -365,42 +452,43 @@ async fn claim(
                 } else {
         (None, None, None)
     };
+
+    // Get upline accounts if the referral config pays the referrer's own referrer
+    let upline = get_referral_upline(rpc, payer.pubkey(), miner.referrer).await;
+    let (upline_referral, upline_referral_oil_ata) = match upline {
+        Some(upline) => {
+            let upline_referral_pda = oil_api::state::referral_pda(upline).0;
+            let upline_referral_oil_ata = spl_associated_token_account::get_associated_token_address(&upline_referral_pda, &oil_api::consts::MINT_ADDRESS);
+            (Some(upline_referral_pda), Some(upline_referral_oil_ata))
+        }
+        None => (None, None),
+    };
     
-    let ix_sol = oil_api::sdk::claim_sol(payer.pubkey(), referrer_miner, referrer_referral);
-    let ix_oil = oil_api::sdk::claim_oil(payer.pubkey(), referrer_miner, referrer_referral, referrer_referral_oil_ata);
+    let ix_sol = oil_api::sdk::claim_sol(payer.pubkey(), referrer_miner, referrer_referral, upline_referral);
+    // VEST=1 claims OIL through the vesting schedule instead of paying the refining fee.
+    let vest = std::env::var("VEST").map_or(false, |v| v == "1");
+    let claim_oil = if vest { oil_api::sdk::vest_oil } else { oil_api::sdk::claim_oil };
+    let ix_oil = claim_oil(
+        payer.pubkey(),
+        referrer_miner,
+        referrer_referral,
+        referrer_referral_oil_ata,
+        upline_referral,
+        upline_referral_oil_ata,
+    );
     submit_transaction(rpc, payer, &[ix_sol, ix_oil]).await?;
     Ok(())
 }
 
-#[allow(dead_code)]
-pub async fn get_address_lookup_table_accounts(
-    rpc_client: &RpcClient,
-    addresses: Vec<Pubkey>,
-) -> Result<Vec<AddressLookupTableAccount>, anyhow::Error> {
-    let mut accounts = Vec::new();
-    for key in addresses {
-        if let Ok(account) = rpc_client.get_account(&key).await {
-            if let Ok(address_lookup_table_account) = AddressLookupTable::deserialize(&account.data)
-            {
-                accounts.push(AddressLookupTableAccount {
-                    key,
-                    addresses: address_lookup_table_account.addresses.to_vec(),
-                });
-            }
-        }
-    }
-    Ok(accounts)
-}
-
 async fn reset(
-    rpc: &RpcClient,
+    rpc: &OilClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
-    let board = get_board(rpc).await?;
-    let config = get_config(rpc).await?;
+    let board = rpc.board().await?;
+    let config = rpc.config().await?;
     // Use var address from config (set via set_var_address)
     let var_address = config.var_address;
-    let var = get_var(rpc, var_address).await?;
+    let var = rpc.var(var_address).await?;
 
     println!("Var: {:?}", var);
 
@@ -446,15 +534,22 @@ async fn reset(
     Ok(())
 }
 
+/// The hash of the premine access code in the optional ACCESS_CODE env var.
+fn access_code() -> Option<[u8; 32]> {
+    std::env::var("ACCESS_CODE")
+        .ok()
+        .map(|code| keccak::hash(code.as_bytes()).to_bytes())
+}
+
 async fn deploy(
-    rpc: &RpcClient,
+    rpc: &OilClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
     let amount = std::env::var("AMOUNT").expect("Missing AMOUNT env var");
     let amount = u64::from_str(&amount).expect("Invalid AMOUNT");
     let square_id = std::env::var("SQUARE").expect("Missing SQUARE env var");
     let square_id = u64::from_str(&square_id).expect("Invalid SQUARE");
-    let board = get_board(rpc).await?;
+    let board = rpc.board().await?;
     let mut squares = [false; 25];
     squares[square_id as usize] = true;
     // Check for optional referrer env var.
@@ -473,19 +568,20 @@ async fn deploy(
         board.round_id,
         squares,
         referrer,
-        pooled
+        pooled,
+        access_code(),
     );
     submit_transaction(rpc, payer, &[ix]).await?;
     Ok(())
 }
 
 async fn deploy_all(
-    rpc: &RpcClient,
+    rpc: &OilClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
     let amount = std::env::var("AMOUNT").expect("Missing AMOUNT env var");
     let amount = u64::from_str(&amount).expect("Invalid AMOUNT");
-    let board = get_board(rpc).await?;
+    let board = rpc.board().await?;
     let squares = [true; 25];
     // Check for optional referrer env var.
     let referrer = std::env::var("REFERRER")
@@ -503,14 +599,15 @@ async fn deploy_all(
         board.round_id,
         squares,
         referrer,
-        pooled
+        pooled,
+        access_code(),
     );
     submit_transaction(rpc, payer, &[ix]).await?;
     Ok(())
 }
 
 // async fn initialize(
-//     rpc: &RpcClient,
+//     rpc: &OilClient,
 //     payer: &solana_sdk::signer::keypair::Keypair,
 // ) -> Result<(), anyhow::Error> {
 //     let barrel_authority = std::env::var("BURY_AUTHORITY")
@@ -547,16 +644,40 @@ async fn deploy_all(
 // }
 
 async fn set_admin(
-    rpc: &RpcClient,
+    rpc: &OilClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let admin = std::env::var("ADMIN").expect("Missing ADMIN env var");
+    let admin = Pubkey::from_str(&admin).expect("Invalid ADMIN");
+    let ix = oil_api::sdk::set_admin(payer.pubkey(), admin);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Proposed admin {} (accept after the timelock with COMMAND=accept_admin)", admin);
+    Ok(())
+}
+
+async fn accept_admin(
+    rpc: &OilClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
-    let ix = oil_api::sdk::set_admin(payer.pubkey(), payer.pubkey());
+    let ix = oil_api::sdk::accept_admin(payer.pubkey());
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn cancel_admin_action(
+    rpc: &OilClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let action = std::env::var("ACTION").expect("Missing ACTION env var");
+    let action = u8::from_str(&action).expect("Invalid ACTION (must be an instruction discriminator)");
+    let action = OilInstruction::try_from(action).expect("Unknown ACTION");
+    let ix = oil_api::sdk::cancel_admin_action(payer.pubkey(), action);
     submit_transaction(rpc, payer, &[ix]).await?;
     Ok(())
 }
 
 async fn set_swap_program(
-    rpc: &RpcClient,
+    rpc: &OilClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
     let swap_program = std::env::var("SWAP_PROGRAM").expect("Missing SWAP_PROGRAM env var");
@@ -567,7 +688,7 @@ async fn set_swap_program(
 }
 
 async fn set_fee_collector(
-    rpc: &RpcClient,
+    rpc: &OilClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
     let fee_collector = std::env::var("FEE_COLLECTOR").expect("Missing FEE_COLLECTOR env var");
@@ -578,7 +699,7 @@ async fn set_fee_collector(
 }
 
 async fn set_tge_timestamp(
-    rpc: &RpcClient,
+    rpc: &OilClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
     use std::time::{SystemTime, UNIX_EPOCH};
@@ -632,12 +753,12 @@ async fn set_tge_timestamp(
 }
 
 async fn checkpoint(
-    rpc: &RpcClient,
+    rpc: &OilClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
     let authority = std::env::var("AUTHORITY").unwrap_or(payer.pubkey().to_string());
     let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
-    let miner = get_miner(rpc, authority).await?;
+    let miner = rpc.miner(authority).await?;
     let steel_ix = oil_api::sdk::checkpoint(payer.pubkey(), authority, miner.round_id);
     
     // Convert steel::Instruction to solana_sdk::instruction::Instruction
@@ -656,18 +777,18 @@ async fn checkpoint(
 }
 
 async fn checkpoint_all(
-    rpc: &RpcClient,
+    rpc: &OilClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
-    let clock = get_clock(rpc).await?;
-    let miners = get_miners(rpc).await?;
+    let clock = rpc.clock().await?;
+    let miners = rpc.miners().await?;
     let mut expiry_slots = HashMap::new();
     let mut ixs: Vec<solana_sdk::instruction::Instruction> = vec![];
     for (i, (_address, miner)) in miners.iter().enumerate() {
         if miner.checkpoint_id < miner.round_id {
             // Log the expiry slot for the round.
             if !expiry_slots.contains_key(&miner.round_id) {
-                if let Ok(round) = get_round(rpc, miner.round_id).await {
+                if let Ok(round) = rpc.round(miner.round_id).await {
                     expiry_slots.insert(miner.round_id, round.expires_at);
                 }
             }
@@ -732,12 +853,12 @@ async fn checkpoint_all(
 }
 
 async fn close_all(
-    rpc: &RpcClient,
+    rpc: &OilClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
-    let rounds = get_rounds(rpc).await?;
+    let rounds = rpc.rounds().await?;
     let mut ixs = vec![];
-    let clock = get_clock(rpc).await?;
+    let clock = rpc.clock().await?;
     for (_i, (_address, round)) in rounds.iter().enumerate() {
         if clock.slot >= round.expires_at {
             ixs.push(oil_api::sdk::close(
@@ -760,7 +881,7 @@ async fn close_all(
     Ok(())
 }
 
-// async fn log_meteora_pool(rpc: &RpcClient) -> Result<(), anyhow::Error> {
+// async fn log_meteora_pool(rpc: &OilClient) -> Result<(), anyhow::Error> {
 //     let address = pubkey!("GgaDTFbqdgjoZz3FP7zrtofGwnRS4E6MCzmmD5Ni1Mxj");
 //     let pool = get_meteora_pool(rpc, address).await?;
 //     let vault_a = get_meteora_vault(rpc, pool.a_vault).await?;
@@ -800,11 +921,11 @@ async fn close_all(
 //     Ok(())
 // }
 
-async fn log_automation(rpc: &RpcClient) -> Result<(), anyhow::Error> {
+async fn log_automation(rpc: &OilClient) -> Result<(), anyhow::Error> {
     let authority = std::env::var("AUTHORITY").expect("Missing AUTHORITY env var");
     let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
     let address = automation_pda(authority).0;
-    let automation = get_automation(rpc, address).await?;
+    let automation = rpc.automation(authority).await?;
     let account_balance = rpc.get_balance(&address).await?;
     let size = 8 + std::mem::size_of::<Automation>();
     let required_rent = Rent::default().minimum_balance(size);
@@ -823,8 +944,9 @@ async fn log_automation(rpc: &RpcClient) -> Result<(), anyhow::Error> {
     Ok(())
 }
 
-async fn log_automations(rpc: &RpcClient) -> Result<(), anyhow::Error> {
-    let automations = get_automations(rpc).await?;
+async fn log_automations(rpc: &OilClient) -> Result<(), anyhow::Error> {
+    const REGOLITH_EXECUTOR: Pubkey = pubkey!("BoT3qYmE6xePWPU96Kf2QeuJr1pDgQ3gLWbA6kSyjzV");
+    let automations = rpc.automations(REGOLITH_EXECUTOR).await?;
     for (i, (address, automation)) in automations.iter().enumerate() {
         println!("[{}/{}] {}", i + 1, automations.len(), address);
         println!("  authority: {}", automation.authority);
@@ -838,11 +960,11 @@ async fn log_automations(rpc: &RpcClient) -> Result<(), anyhow::Error> {
     Ok(())
 }
 
-async fn log_treasury(rpc: &RpcClient) -> Result<(), anyhow::Error> {
+async fn log_treasury(rpc: &OilClient) -> Result<(), anyhow::Error> {
     let treasury_address = oil_api::state::treasury_pda().0;
     let treasury_pda = oil_api::state::treasury_pda();
     let account = rpc.get_account(&treasury_pda.0).await?;
-    let treasury = get_treasury(rpc).await?;
+    let treasury = rpc.treasury().await?;
     
     // Check account size to determine if migrated
     let expected_size = 8 + std::mem::size_of::<Treasury>();
@@ -908,11 +1030,33 @@ async fn log_treasury(rpc: &RpcClient) -> Result<(), anyhow::Error> {
         "  auction_total_pooled: {} SOL",
         lamports_to_sol(treasury.auction_total_pooled)
     );
+    println!("  lp_mint: {}", treasury.lp_mint);
+    println!("  lp_tokens: {}", treasury.lp_tokens);
+    println!("  liquidity_sol: {} SOL", lamports_to_sol(treasury.liquidity_sol));
+    println!(
+        "  liquidity_oil: {} OIL",
+        amount_to_ui_amount(treasury.liquidity_oil, TOKEN_DECIMALS)
+    );
+    println!(
+        "  lp deposited: {} SOL, {} OIL",
+        lamports_to_sol(treasury.lp_sol_deposited),
+        amount_to_ui_amount(treasury.lp_oil_deposited, TOKEN_DECIMALS)
+    );
+    println!(
+        "  lp withdrawn: {} SOL, {} OIL",
+        lamports_to_sol(treasury.lp_sol_withdrawn),
+        amount_to_ui_amount(treasury.lp_oil_withdrawn, TOKEN_DECIMALS)
+    );
+    println!(
+        "  lp harvested: {} SOL, {} OIL",
+        lamports_to_sol(treasury.lp_sol_harvested),
+        amount_to_ui_amount(treasury.lp_oil_harvested, TOKEN_DECIMALS)
+    );
     
     Ok(())
 }
 
-async fn log_pool(rpc: &RpcClient) -> Result<(), anyhow::Error> {
+async fn log_pool(rpc: &OilClient) -> Result<(), anyhow::Error> {
     let pool_address = oil_api::state::pool_pda().0;
     let pool_pda = oil_api::state::pool_pda();
     let account = rpc.get_account(&pool_pda.0).await?;
@@ -947,17 +1091,35 @@ async fn log_pool(rpc: &RpcClient) -> Result<(), anyhow::Error> {
         "  total_staked_score: {} OIL",
         amount_to_ui_amount(pool.total_staked_score, TOKEN_DECIMALS)
     );
+    println!(
+        "  liquid_staked: {} OIL (backing stOIL)",
+        amount_to_ui_amount(pool.liquid_staked, TOKEN_DECIMALS)
+    );
+    println!(
+        "  liquid_rewards: {} SOL (awaiting compounding)",
+        lamports_to_sol(pool.liquid_rewards)
+    );
+    if let Ok(stoil_mint) = rpc.get_account(&stoil_mint_pda().0).await {
+        let supply = spl_token::state::Mint::unpack(&stoil_mint.data)?.supply;
+        if supply > 0 {
+            println!(
+                "  stoil_supply: {} stOIL ({:.6} OIL per stOIL)",
+                amount_to_ui_amount(supply, TOKEN_DECIMALS),
+                pool.liquid_staked as f64 / supply as f64
+            );
+        }
+    }
     
     Ok(())
 }
 
-async fn log_round(rpc: &RpcClient) -> Result<(), anyhow::Error> {
+async fn log_round(rpc: &OilClient) -> Result<(), anyhow::Error> {
     let id = std::env::var("ID").expect("Missing ID env var");
     let id = u64::from_str(&id).expect("Invalid ID");
     let round_address = round_pda(id).0;
     let round_pda = oil_api::state::round_pda(id);
     let account = rpc.get_account(&round_pda.0).await?;
-    let round = get_round(rpc, id).await?;
+    let round = rpc.round(id).await?;
     let rng = round.rng();
     
     // Check account size to determine if migrated
@@ -999,7 +1161,7 @@ async fn log_round(rpc: &RpcClient) -> Result<(), anyhow::Error> {
     Ok(())
 }
 
-async fn inspect_round(rpc: &RpcClient) -> Result<(), anyhow::Error> {
+async fn inspect_round(rpc: &OilClient) -> Result<(), anyhow::Error> {
     let address_str = std::env::var("ADDRESS").expect("Missing ADDRESS env var");
     let address = Pubkey::from_str(&address_str).expect("Invalid address");
     
@@ -1051,218 +1213,127 @@ async fn inspect_round(rpc: &RpcClient) -> Result<(), anyhow::Error> {
     Ok(())
 }
 
-async fn verify_migration(rpc: &RpcClient) -> Result<(), anyhow::Error> {
+async fn verify_migration(rpc: &OilClient) -> Result<(), anyhow::Error> {
     println!("🔍 Verifying account migration status...\n");
-    
-    // Check Treasury
-    let treasury_pda = oil_api::state::treasury_pda();
-    let treasury_account = rpc.get_account(&treasury_pda.0).await?;
-    let expected_treasury_size = 8 + std::mem::size_of::<Treasury>();
-    let treasury_migrated = treasury_account.data.len() >= expected_treasury_size;
-    
-    println!("📊 Treasury Account:");
-    println!("   Address: {}", treasury_pda.0);
-    println!("   Current size: {} bytes", treasury_account.data.len());
-    println!("   Expected size: {} bytes", expected_treasury_size);
-    println!("   Status: {}", if treasury_migrated { "✅ Migrated" } else { "⚠️  Needs Migration" });
-    
-    if treasury_migrated {
-        let treasury = get_treasury(rpc).await?;
-        println!("   gusher_sol: {} SOL", lamports_to_sol(treasury.gusher_sol));
-    }
-    println!();
-    
-    // Check Board to get current round
-    let board = get_board(rpc).await?;
-    println!("📊 Board:");
-    println!("   Current round_id: {}", board.round_id);
-    println!();
-    
-    // Check current Round if it exists
-    let round_pda = oil_api::state::round_pda(board.round_id);
-    if let Ok(round_account) = rpc.get_account(&round_pda.0).await {
-        let expected_round_size = 8 + std::mem::size_of::<Round>();
-        let round_migrated = round_account.data.len() >= expected_round_size;
-        
-        println!("📊 Round {} Account:", board.round_id);
-        println!("   Address: {}", round_pda.0);
-        println!("   Current size: {} bytes", round_account.data.len());
-        println!("   Expected size: {} bytes", expected_round_size);
-        println!("   Status: {}", if round_migrated { "✅ Migrated" } else { "⚠️  Needs Migration" });
-        
-        if round_migrated {
-            let round = get_round(rpc, board.round_id).await?;
-            println!("   gusher_sol: {} SOL", lamports_to_sol(round.gusher_sol));
+
+    // Count accounts by type and whether they are at the current layout version
+    let accounts = rpc.raw_program_accounts(None).await?;
+    let mut counts: HashMap<u8, (usize, usize)> = HashMap::new();
+    for (_, data) in accounts.iter() {
+        let Some(discriminator) = data.first() else {
+            continue;
+        };
+        let entry = counts.entry(*discriminator).or_default();
+        entry.0 += 1;
+        if needs_migration(data) {
+            entry.1 += 1;
         }
-        println!();
-    } else {
-        println!("📊 Round {} Account:", board.round_id);
-        println!("   Status: ❌ Account not found (round not started yet)");
-        println!();
     }
-    
-    // Summary
+
+    let mut discriminators: Vec<u8> = counts.keys().copied().collect();
+    discriminators.sort();
+    let mut total_pending = 0;
     println!("📋 Migration Summary:");
-    if treasury_migrated {
-        println!("   ✅ Treasury: Migrated");
-    } else {
-        println!("   ⚠️  Treasury: Needs Migration");
-    }
-    
-    if let Ok(round_account) = rpc.get_account(&round_pda.0).await {
-        let expected_round_size = 8 + std::mem::size_of::<Round>();
-        let round_migrated = round_account.data.len() >= expected_round_size;
-        if round_migrated {
-            println!("   ✅ Round {}: Migrated", board.round_id);
+    for discriminator in discriminators {
+        let (total, pending) = counts[&discriminator];
+        total_pending += pending;
+        let Ok(kind) = OilAccount::try_from(discriminator) else {
+            println!("   ❓ Unknown discriminator {}: {} accounts", discriminator, total);
+            continue;
+        };
+        if pending == 0 {
+            println!("   ✅ {:?} (v{}): {} accounts migrated", kind, kind.version(), total);
         } else {
-            println!("   ⚠️  Round {}: Needs Migration", board.round_id);
+            println!("   ⚠️  {:?} (v{}): {} of {} accounts need migration", kind, kind.version(), pending, total);
         }
-    } else {
-        println!("   ℹ️  Round {}: Not started (no migration needed)", board.round_id);
     }
-    
+    println!("\n   {} of {} accounts need migration", total_pending, accounts.len());
+
     Ok(())
 }
 
-/// Migrate Miner: Extend Miner struct with 2 [u64; 4] arrays (current_epoch_id, checkpointed_epoch_id).
-/// Requires MINER_AUTHORITY environment variable (the wallet address of the miner to migrate).
-async fn migrate(
-    rpc: &RpcClient,
+async fn set_pause(
+    rpc: &OilClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
-    // Get miner authority from environment variable
-    let miner_authority_str = std::env::var("MINER_AUTHORITY")
-        .map_err(|_| anyhow::anyhow!("Missing MINER_AUTHORITY env var (wallet address of miner to migrate)"))?;
-    let miner_authority = Pubkey::from_str(&miner_authority_str)
-        .map_err(|e| anyhow::anyhow!("Invalid MINER_AUTHORITY: {}", e))?;
-    
-    let miner_address = oil_api::state::miner_pda(miner_authority).0;
-
-    println!("\n🔧 Miner Migration");
-    println!("   Miner Authority: {}", miner_authority);
-    println!("   Miner Account: {}", miner_address);
+    let paused = std::env::var("PAUSED").expect("Missing PAUSED env var");
+    let paused = u64::from_str(&paused).expect("Invalid PAUSED (must be a bitmap of PAUSE_* flags)");
+    let ix = oil_api::sdk::set_pause(payer.pubkey(), paused);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    log_config(rpc).await?;
+    Ok(())
+}
 
-    // Check if miner account exists
-    let miner_account = match rpc.get_account(&miner_address).await {
-        Ok(account) => account,
-        Err(_) => {
-            println!("   ⚠️  Miner account does not exist at {}", miner_address);
-            return Err(anyhow::anyhow!("Miner account not found"));
-        }
+/// Migrate Accounts: Upgrades every program account below the current layout version of its type,
+/// in batches. Optional ACCOUNT_TYPE env var (e.g. "Miner") restricts the scan to one account type.
+async fn migrate(
+    rpc: &OilClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    const BATCH_SIZE: usize = 8;
+
+    let kind = match std::env::var("ACCOUNT_TYPE") {
+        Ok(name) => Some(
+            (0..=u8::MAX)
+                .filter_map(|d| OilAccount::try_from(d).ok())
+                .find(|k| format!("{:?}", k) == name)
+                .ok_or_else(|| anyhow::anyhow!("Invalid ACCOUNT_TYPE: {}", name))?,
+        ),
+        Err(_) => None,
     };
-    
-    let expected_miner_size = 736; // 8 discriminator + 728 data bytes (adding 2 [u64; 4] = 64 bytes)
-    
-    println!("\n📊 Current State:");
-    println!("   Miner account size: {} bytes", miner_account.data.len());
-    println!("   Expected size: {} bytes", expected_miner_size);
-    
-    let is_migrated = miner_account.data.len() >= expected_miner_size;
-    
-    if is_migrated {
-        println!("   Status: ✅ Already migrated");
-        println!("\n   ✅ No migration needed. Miner already has current_epoch_id and checkpointed_epoch_id arrays.");
+
+    println!("\n🔧 Account Migration");
+    let accounts = rpc.raw_program_accounts(kind).await?;
+    let pending: Vec<Pubkey> = accounts
+        .iter()
+        .filter(|(_, data)| needs_migration(data))
+        .map(|(address, _)| *address)
+        .collect();
+    println!("   Scanned: {} accounts", accounts.len());
+    println!("   Needs migration: {} accounts", pending.len());
+    if pending.is_empty() {
+        println!("   ✅ No migration needed.");
         return Ok(());
     }
-    
-    // Verify current size matches expected old size
-    let expected_old_size = 672; // 8 discriminator + 664 data bytes
-    if miner_account.data.len() != expected_old_size {
-        println!("   ⚠️  Warning: Miner size is {} bytes, expected {} bytes", miner_account.data.len(), expected_old_size);
-        println!("   Continuing anyway - might be a different version");
-    }
-    
-    println!("   Status: ⚠️  Needs migration");
-    println!("\n   📦 Will extend Miner struct with 2 [u64; 4] arrays (current_epoch_id, checkpointed_epoch_id, 64 bytes total)");
-    
-    // Build migrate instruction
-    let migrate_ix = oil_api::sdk::migrate(payer.pubkey(), miner_authority);
-    
-    // Debug: Print instruction details
-    println!("\n   🔍 Instruction details:");
-    println!("      Program ID: {}", migrate_ix.program_id);
-    println!("      Accounts: {}", migrate_ix.accounts.len());
-    for (i, account) in migrate_ix.accounts.iter().enumerate() {
-        println!("        [{}] {} (signer: {}, writable: {})", 
-            i, account.pubkey, account.is_signer, account.is_writable);
-    }
-    println!("      Data length: {} bytes", migrate_ix.data.len());
-    println!("      Data (first 10 bytes): {:?}", &migrate_ix.data[..migrate_ix.data.len().min(10)]);
-    
-    // Simulate transaction first to catch errors
-    println!("\n   🔍 Simulating transaction...");
-    let blockhash = rpc.get_latest_blockhash().await?;
-    let mut all_instructions = vec![
-        ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
-        ComputeBudgetInstruction::set_compute_unit_price(1_000_000),
-    ];
-    // Convert steel::Instruction to solana_sdk::Instruction for simulation
-    let solana_ix = solana_sdk::instruction::Instruction {
-        program_id: migrate_ix.program_id,
-        accounts: migrate_ix.accounts.iter().map(|a| solana_sdk::instruction::AccountMeta {
-            pubkey: a.pubkey,
-            is_signer: a.is_signer,
-            is_writable: a.is_writable,
-        }).collect(),
-        data: migrate_ix.data.clone(),
-    };
-    all_instructions.push(solana_ix);
-    let transaction = Transaction::new_signed_with_payer(
-        &all_instructions,
-        Some(&payer.pubkey()),
-        &[payer],
-        blockhash,
-    );
-    
-    match rpc.simulate_transaction(&transaction).await {
-        Ok(sim_result) => {
-            if let Some(err) = sim_result.value.err {
-                println!("   ⚠️  Simulation error: {:?}", err);
-                if let Some(logs) = sim_result.value.logs {
-                    println!("   Logs:");
-                    for log in logs.iter().take(20) {
-                        println!("      {}", log);
-                    }
-                }
-                return Err(anyhow::anyhow!("Transaction simulation failed: {:?}", err));
+
+    let mut migrated = 0;
+    let mut failed = 0;
+    for batch in pending.chunks(BATCH_SIZE) {
+        let ixs: Vec<Instruction> = batch
+            .iter()
+            .map(|address| oil_api::sdk::migrate_account(payer.pubkey(), *address))
+            .collect();
+        match submit_transaction(rpc, payer, &ixs).await {
+            Ok(sig) => {
+                migrated += batch.len();
+                println!("   [{}/{}] ✅ {}", migrated + failed, pending.len(), sig);
+            }
+            Err(e) => {
+                failed += batch.len();
+                println!("   [{}/{}] ⚠️  Batch failed: {}", migrated + failed, pending.len(), e);
             }
-            println!("   ✅ Simulation successful");
-            println!("      Compute units used: {:?}", sim_result.value.units_consumed);
-        }
-        Err(e) => {
-            println!("   ⚠️  Simulation request failed: {:?}", e);
-            return Err(anyhow::anyhow!("Failed to simulate transaction: {}", e));
         }
     }
-    
-    // Submit transaction
-    println!("\n   📤 Submitting migration transaction...");
-    let sig = submit_transaction(rpc, payer, &[migrate_ix]).await?;
-    println!("   ✅ Migration transaction submitted: {}", sig);
-    
-    // Verify migration
-    println!("\n   🔍 Verifying migration...");
-    tokio::time::sleep(tokio::time::Duration::from_secs(2)).await; // Wait for confirmation
-    
-    let miner_account_after = rpc.get_account(&miner_address).await?;
-    let is_migrated_after = miner_account_after.data.len() >= expected_miner_size;
-    
-    println!("   Miner account size after: {} bytes", miner_account_after.data.len());
-    
-    if is_migrated_after {
-        println!("   ✅ Miner migration successful! current_epoch_id and checkpointed_epoch_id arrays are now available.");
-        println!("   Note: The new arrays will be initialized to all zeros.");
-    } else {
-        println!("   ⚠️  Migration may have failed. Miner size didn't increase as expected.");
-        println!("   Check transaction logs for details.");
+
+    println!("\n   Migrated: {} accounts", migrated);
+    if failed > 0 {
+        println!("   ⚠️  Failed: {} accounts (run again to retry)", failed);
     }
-    
+
     Ok(())
 }
 
+/// Whether an account is below the current layout version of its type.
+fn needs_migration(data: &[u8]) -> bool {
+    data.first()
+        .and_then(|d| OilAccount::try_from(*d).ok())
+        .map(|kind| account_version(data) < kind.version())
+        .unwrap_or(false)
+}
+
 /// Create a referral account to become a referrer.
 async fn create_referral(
-    rpc: &RpcClient,
+    rpc: &OilClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
     let referral_address = oil_api::state::referral_pda(payer.pubkey()).0;
@@ -1286,9 +1357,76 @@ async fn create_referral(
     Ok(())
 }
 
+/// Claim a Seeker genesis token for the payer's miner.
+async fn claim_seeker(
+    rpc: &OilClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let mint = std::env::var("MINT").expect("Missing MINT env var");
+    let mint = Pubkey::from_str(&mint).expect("Invalid MINT");
+    let seeker_address = oil_api::state::seeker_pda(mint).0;
+    
+    println!("📱 Claiming Seeker Token");
+    println!("   Mint: {}", mint);
+    println!("   Address: {}", seeker_address);
+    
+    // Check if already claimed.
+    if let Ok(_) = rpc.get_account(&seeker_address).await {
+        println!("   Status: ❌ Already claimed");
+        return Ok(());
+    }
+    
+    println!("\n📤 Submitting transaction...");
+    let ix = oil_api::sdk::claim_seeker(payer.pubkey(), mint);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    
+    println!("✅ Seeker token claimed!");
+    Ok(())
+}
+
+/// Stake OIL for stOIL, minting at least MIN_SHARES stOIL (default 1).
+async fn deposit_liquid(
+    rpc: &OilClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let amount = std::env::var("AMOUNT").expect("Missing AMOUNT env var");
+    let amount = u64::from_str(&amount).expect("Invalid AMOUNT");
+    let min_shares = std::env::var("MIN_SHARES").unwrap_or_else(|_| "1".to_string());
+    let min_shares = u64::from_str(&min_shares).expect("Invalid MIN_SHARES");
+    let ix = oil_api::sdk::deposit_liquid(payer.pubkey(), amount, min_shares);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn redeem_liquid(
+    rpc: &OilClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let amount = std::env::var("AMOUNT").expect("Missing AMOUNT env var");
+    let amount = u64::from_str(&amount).expect("Invalid AMOUNT");
+    let ix = oil_api::sdk::redeem_liquid(payer.pubkey(), amount);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+/// Accrue stake XP on a miner's rig (the payer's unless AUTHORITY is set).
+async fn update_rig(
+    rpc: &OilClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let authority = std::env::var("AUTHORITY").unwrap_or_else(|_| payer.pubkey().to_string());
+    let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
+    let ix = oil_api::sdk::update_rig(payer.pubkey(), authority);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    if let Some(rig) = rpc.rig(authority).await? {
+        println!("Rig level {} ({} XP)", rig.level, rig.xp);
+    }
+    Ok(())
+}
+
 /// Claim pending referral rewards.
 async fn claim_referral_cmd(
-    rpc: &RpcClient,
+    rpc: &OilClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
     let referral_address = oil_api::state::referral_pda(payer.pubkey()).0;
@@ -1307,8 +1445,7 @@ async fn claim_referral_cmd(
     }
     
     println!("\n📤 Submitting transaction...");
-    // For CLI, signer and authority are the same (regular wallet)
-    let ix = oil_api::sdk::claim_referral(payer.pubkey(), payer.pubkey());
+    let ix = oil_api::sdk::claim_referral(payer.pubkey());
     submit_transaction(rpc, payer, &[ix]).await?;
     
     println!("✅ Referral rewards claimed!");
@@ -1317,7 +1454,7 @@ async fn claim_referral_cmd(
 
 /// Show referral account info.
 async fn log_referral(
-    rpc: &RpcClient,
+    rpc: &OilClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
     let authority = std::env::var("AUTHORITY").unwrap_or(payer.pubkey().to_string());
@@ -1337,6 +1474,8 @@ async fn log_referral(
             println!("  total_oil_earned: {} OIL", amount_to_ui_amount(referral.total_oil_earned, TOKEN_DECIMALS));
             println!("  pending_sol: {} SOL", lamports_to_sol(referral.pending_sol));
             println!("  pending_oil: {} OIL", amount_to_ui_amount(referral.pending_oil, TOKEN_DECIMALS));
+            println!("  referred_volume_sol: {} SOL", lamports_to_sol(referral.referred_volume_sol));
+            println!("  rebate_bps: {}", referral.rebate_bps);
         }
         Err(e) => {
             println!("\n❌ Referral account not found");
@@ -1351,7 +1490,67 @@ async fn log_referral(
     Ok(())
 }
 
-async fn get_referral(rpc: &RpcClient, authority: Pubkey) -> Result<Referral, anyhow::Error> {
+/// Show the referral leaderboard for a week (WEEK env var, defaults to the current week).
+async fn log_referral_leaderboard(rpc: &OilClient) -> Result<(), anyhow::Error> {
+    let clock = rpc.clock().await?;
+    let current_week = Referral::week(clock.unix_timestamp);
+    let week = match std::env::var("WEEK") {
+        Ok(week) => u64::from_str(&week).expect("Invalid WEEK"),
+        Err(_) => current_week,
+    };
+    if week > current_week || current_week - week >= REFERRAL_STATS_WEEKS as u64 {
+        return Err(anyhow::anyhow!(
+            "Week {} is not available (current week: {}, stats are kept for {} weeks)",
+            week,
+            current_week,
+            REFERRAL_STATS_WEEKS
+        ));
+    }
+
+    // Rank referrers by SOL earned, then by miners referred
+    let referrals = rpc.referrals().await?;
+    let mut rankings: Vec<_> = referrals
+        .iter()
+        .filter_map(|(_, referral)| referral.week_stats(week).map(|stats| (referral.authority, stats)))
+        .collect();
+    rankings.sort_by(|(_, a), (_, b)| {
+        b.sol_earned.cmp(&a.sol_earned).then(b.referred.cmp(&a.referred))
+    });
+
+    println!("Referral Leaderboard (week {})", week);
+    if rankings.is_empty() {
+        println!("  No referral activity");
+    }
+    for (i, (authority, stats)) in rankings.iter().enumerate() {
+        println!(
+            "  #{:<3} {}  referred: {}  SOL: {}  OIL: {}",
+            i + 1,
+            authority,
+            stats.referred,
+            lamports_to_sol(stats.sol_earned),
+            amount_to_ui_amount(stats.oil_earned, TOKEN_DECIMALS)
+        );
+    }
+    Ok(())
+}
+
+/// Returns the referrer's own referrer if the referral config pays them an upline share.
+async fn get_referral_upline(rpc: &OilClient, authority: Pubkey, referrer: Pubkey) -> Option<Pubkey> {
+    if referrer == Pubkey::default() {
+        return None;
+    }
+    let config = rpc.referral_config().await.ok()??;
+    if config.upline_share_bps == 0 {
+        return None;
+    }
+    let upline = rpc.miner(referrer).await.ok()?.referrer;
+    if upline == Pubkey::default() || upline == authority {
+        return None;
+    }
+    Some(upline)
+}
+
+async fn get_referral(rpc: &OilClient, authority: Pubkey) -> Result<Referral, anyhow::Error> {
     let referral_address = oil_api::state::referral_pda(authority).0;
     let account = rpc.get_account(&referral_address).await
         .map_err(|e| anyhow::anyhow!("Failed to fetch referral account at {}: {}. Make sure you're using the correct AUTHORITY (the pubkey that created the referral account).", referral_address, e))?;
@@ -1372,7 +1571,7 @@ async fn get_referral(rpc: &RpcClient, authority: Pubkey) -> Result<Referral, an
 }
 
 async fn log_miner(
-    rpc: &RpcClient,
+    rpc: &OilClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
     let authority = std::env::var("MINER_AUTHORITY")
@@ -1380,7 +1579,7 @@ async fn log_miner(
         .unwrap_or_else(|_| payer.pubkey().to_string());
     let authority = Pubkey::from_str(&authority).map_err(|e| anyhow::anyhow!("Invalid authority pubkey: {}", e))?;
     let miner_address = oil_api::state::miner_pda(authority).0;
-    let miner = get_miner(&rpc, authority).await?;
+    let miner = rpc.miner(authority).await?;
     println!("Miner");
     println!("  address: {}", miner_address);
     println!("  authority: {}", authority);
@@ -1421,6 +1620,39 @@ async fn log_miner(
         println!("  pooled_deployed: (none)");
     }
     println!("  total_stake_score: {}", miner.total_stake_score);
+    let config = rpc.config().await?;
+    let pool = rpc.pool().await?;
+    let boost_bps = config.stake_boost_bps(miner.total_stake_score, pool.total_staked_score);
+    println!(
+        "  stake_boost: {:.2}x split OIL weight in round {} ({:.2}x at the next round, {} bps max)",
+        (DENOMINATOR_BPS + miner.stake_boost_bps) as f64 / DENOMINATOR_BPS as f64,
+        miner.round_id,
+        (DENOMINATOR_BPS + boost_bps) as f64 / DENOMINATOR_BPS as f64,
+        config.stake_boost_max_bps
+    );
+    println!("  is_seeker: {}", miner.is_seeker != 0);
+    match rpc.rig(authority).await? {
+        Some(rig) => {
+            println!("  level: {} ({} XP)", rig.level, rig.xp);
+            println!(
+                "  xp: {} deploy, {} bid, {} contribution, {} stake",
+                rig.deploy_xp, rig.bid_xp, rig.contribution_xp, rig.stake_xp
+            );
+            if let Some(next) = RIG_LEVEL_XP.get(rig.level as usize) {
+                println!("  next_level: {} XP", next);
+            }
+        }
+        None => println!("  level: 0 (no rig yet)"),
+    }
+    if miner.vesting_oil > 0 {
+        let clock = rpc.clock().await?;
+        println!(
+            "  vesting_oil: {} OIL ({} OIL unlocked, fully unlocks at {})",
+            amount_to_ui_amount(miner.vesting_oil, TOKEN_DECIMALS),
+            amount_to_ui_amount(miner.vested_oil(clock.unix_timestamp), TOKEN_DECIMALS),
+            miner.vesting_end_at
+        );
+    }
     
     println!("\nAuction-based mining (from Miner account)");
     println!(
@@ -1447,8 +1679,8 @@ async fn log_miner(
     Ok(())
 }
 
-async fn log_clock(rpc: &RpcClient) -> Result<(), anyhow::Error> {
-    let clock = get_clock(&rpc).await?;
+async fn log_clock(rpc: &OilClient) -> Result<(), anyhow::Error> {
+    let clock = rpc.clock().await?;
     println!("Clock");
     println!("  slot: {}", clock.slot);
     println!("  epoch_start_timestamp: {}", clock.epoch_start_timestamp);
@@ -1458,8 +1690,26 @@ async fn log_clock(rpc: &RpcClient) -> Result<(), anyhow::Error> {
     Ok(())
 }
 
-async fn log_config(rpc: &RpcClient) -> Result<(), anyhow::Error> {
-    let config = get_config(&rpc).await?;
+async fn log_supply_projection(rpc: &OilClient) -> Result<(), anyhow::Error> {
+    let rounds_per_week = std::env::var("ROUNDS_PER_WEEK").expect("Missing ROUNDS_PER_WEEK env var");
+    let rounds_per_week = u64::from_str(&rounds_per_week).expect("Invalid ROUNDS_PER_WEEK");
+    let config = rpc.config().await?;
+    let mint = rpc.get_account(&MINT_ADDRESS).await?;
+    let supply = spl_token::state::Mint::unpack(&mint.data)?.supply;
+    println!("Supply projection ({} rounds per week)", rounds_per_week);
+    for (week, supply) in project_supply(supply, config.emission_week, rounds_per_week) {
+        println!(
+            "  week {}: {} OIL ({} OIL per round)",
+            week,
+            amount_to_ui_amount(supply, TOKEN_DECIMALS),
+            amount_to_ui_amount(emission_per_round(week), TOKEN_DECIMALS)
+        );
+    }
+    Ok(())
+}
+
+async fn log_config(rpc: &OilClient) -> Result<(), anyhow::Error> {
+    let config = rpc.config().await?;
     println!("Config");
     println!("  admin: {}", config.admin);
     println!("  barrel_authority: {}", config.barrel_authority);
@@ -1470,6 +1720,23 @@ async fn log_config(rpc: &RpcClient) -> Result<(), anyhow::Error> {
     println!("  emission_week: {}", config.emission_week);
     println!("  last_emission_week_update: {}", config.last_emission_week_update);
     println!("  tge_timestamp: {}", config.tge_timestamp);
+    println!("  stake_boost_max_bps: {}", config.stake_boost_max_bps);
+    println!("  stake_boost_share_bps: {}", config.stake_boost_share_bps);
+    println!("  seeker_refining_discount_bps: {}", config.seeker_refining_discount_bps);
+    println!("  refining_fee_bps: {}", config.refining_fee_bps);
+    println!("  auction_refining_fee_bps: {}", config.auction_refining_fee_bps);
+    println!("  vesting_period: {}s", config.vesting_period);
+    for role in [Role::Admin, Role::TreasuryOperator, Role::LiquidityManager, Role::AuctionManager, Role::WhitelistIssuer, Role::Guardian] {
+        let role_set = config.role(role);
+        let members: Vec<String> = role_set
+            .members
+            .iter()
+            .filter(|m| **m != Pubkey::default())
+            .map(|m| m.to_string())
+            .collect();
+        println!("  {:?}: {}-of-{} [{}]", role, role_set.threshold, members.len(), members.join(", "));
+    }
+    println!("  paused: {:#08b}", config.paused);
     if config.tge_timestamp > 0 {
         use std::time::{SystemTime, UNIX_EPOCH};
         let now = SystemTime::now()
@@ -1490,14 +1757,17 @@ async fn log_config(rpc: &RpcClient) -> Result<(), anyhow::Error> {
     Ok(())
 }
 
-async fn log_well(rpc: &RpcClient) -> Result<(), anyhow::Error> {
+async fn log_well(rpc: &OilClient) -> Result<(), anyhow::Error> {
     println!("Well (Auction Wells)");
     println!("\n  Well Details (0-3):");
     
+    let auction = rpc.auction().await?;
+    let clock = rpc.clock().await?;
+    
     for well_id in 0..4 {
         let (well_address, _) = oil_api::state::well_pda(well_id);
         
-        match get_well(rpc, well_id).await {
+        match rpc.well(well_id).await {
             Ok(well) => {
                 println!("    Well {}:", well_id);
                 println!("      address: {}", well_address);
@@ -1505,6 +1775,10 @@ async fn log_well(rpc: &RpcClient) -> Result<(), anyhow::Error> {
                 println!("      epoch_id: {}", well.epoch_id);
                 println!("      current_bidder: {}", well.current_bidder);
                 println!("      init_price: {} SOL", lamports_to_sol(well.init_price));
+                println!("      current_price: {} SOL", lamports_to_sol(oil_api::sdk::well_price(&well, &auction, clock.unix_timestamp)));
+                println!("      price_curve: {:?}", auction.price_curve(well_id as usize));
+                println!("      floor_price: {} SOL", lamports_to_sol(auction.floor_price(well_id as usize)));
+                println!("      price_multiplier: {}x", auction.price_multiplier_bps(well_id as usize) as f64 / DENOMINATOR_BPS as f64);
                 println!("      mps: {} OIL/s", amount_to_ui_amount(well.mps, TOKEN_DECIMALS));
                 println!("      epoch_start_time: {}", well.epoch_start_time);
                 println!("      accumulated_oil: {} OIL", amount_to_ui_amount(well.accumulated_oil, TOKEN_DECIMALS));
@@ -1523,7 +1797,7 @@ async fn log_well(rpc: &RpcClient) -> Result<(), anyhow::Error> {
 }
 
 async fn log_bid(
-    rpc: &RpcClient,
+    rpc: &OilClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
     let authority = std::env::var("AUTHORITY").unwrap_or(payer.pubkey().to_string());
@@ -1540,7 +1814,7 @@ async fn log_bid(
     
     let (share_address, _) = oil_api::state::share_pda(authority, well_id, epoch_id);
     
-    match get_share(rpc, authority, well_id, epoch_id).await {
+    match rpc.share(authority, well_id, epoch_id).await {
         Ok(share) => {
             println!("Share (Auction Pool Contribution)");
             println!("  address: {}", share_address);
@@ -1548,6 +1822,7 @@ async fn log_bid(
             println!("  well_id: {}", share.well_id);
             println!("  epoch_id: {}", share.epoch_id);
             println!("  contribution: {} SOL", lamports_to_sol(share.contribution));
+            println!("  max_price: {} SOL", lamports_to_sol(share.max_price));
             println!("  created_at: {}", share.created_at);
             println!("  claimed_oil: {} OIL", share.claimed_oil);
             println!("  claimed_sol: {} SOL", lamports_to_sol(share.claimed_sol));
@@ -1563,20 +1838,42 @@ async fn log_bid(
     Ok(())
 }
 
-async fn log_auction(rpc: &RpcClient) -> Result<(), anyhow::Error> {
+async fn log_auction(rpc: &OilClient) -> Result<(), anyhow::Error> {
     let auction_address = oil_api::state::auction_pda().0;
     
-    match get_auction(rpc).await {
+    match rpc.auction().await {
         Ok(auction) => {
             println!("Auction (Configuration)");
             println!("  address: {}", auction_address);
             
             // Time-based halving information
+            let schedule = auction.halving_schedule();
             println!("\n  Time-Based Halving:");
-            println!("    halving_period_seconds: {} ({} days)", 
-                auction.halving_period_seconds,
-                auction.halving_period_seconds / (24 * 60 * 60)
+            println!("    anchor_time: {} (halvings before: {}, rate: {}x)",
+                schedule.anchor_time,
+                schedule.anchor_count,
+                schedule.anchor_rate as f64 / HALVING_RATE_SCALE as f64
+            );
+            println!("    first_halving_delay_seconds: {} ({} days, -{}%)",
+                schedule.first_delay_seconds,
+                schedule.first_delay_seconds / (24 * 60 * 60),
+                schedule.first_decay_bps as f64 / 100.0
+            );
+            println!("    halving_period_seconds: {} ({} days, -{}%)", 
+                schedule.period_seconds,
+                schedule.period_seconds / (24 * 60 * 60),
+                schedule.decay_bps as f64 / 100.0
             );
+            println!("    halving_count: {}", auction.halving_count);
+            if auction.pending_halving_effective_time > 0 {
+                println!("    pending schedule from {}: first delay {}s (-{}%), period {}s (-{}%)",
+                    auction.pending_halving_effective_time,
+                    auction.pending_first_halving_delay_seconds,
+                    auction.pending_first_halving_decay_bps as f64 / 100.0,
+                    auction.pending_halving_period_seconds,
+                    auction.pending_halving_decay_bps as f64 / 100.0
+                );
+            }
             println!("    last_halving_time: {}", auction.last_halving_time);
             if auction.last_halving_time > 0 {
                 println!("      (Unix timestamp: {})", auction.last_halving_time);
@@ -1584,17 +1881,16 @@ async fn log_auction(rpc: &RpcClient) -> Result<(), anyhow::Error> {
                 println!("      (Not set - will use initialization time)");
             }
             
-            let next_halving_time = auction.next_halving_time();
-            println!("    next_halving_time: {}", next_halving_time);
-            if next_halving_time > 0 {
-                println!("      (Unix timestamp: {})", next_halving_time);
-            }
-            
             // Calculate time remaining until next halving
             let current_time = std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap()
                 .as_secs();
+            let next_halving_time = auction.next_halving_time(current_time);
+            println!("    next_halving_time: {}", next_halving_time);
+            if next_halving_time > 0 {
+                println!("      (Unix timestamp: {})", next_halving_time);
+            }
             let time_remaining = if next_halving_time > current_time {
                 next_halving_time - current_time
             } else {
@@ -1634,10 +1930,22 @@ async fn log_auction(rpc: &RpcClient) -> Result<(), anyhow::Error> {
                     lamports_to_sol(auction.starting_prices[i])
                 );
             }
-            
-            // Note about repurposed fields (for reference)
-            println!("\n  Note: halving_period_seconds and last_halving_time are repurposed fields");
-            println!("    (kept for account layout compatibility, values may be 0)");
+            println!("\n  Price Curves:");
+            for i in 0..4 {
+                println!("    Well {}: {:?}, floor {} SOL, next epoch {}x", 
+                    i, 
+                    auction.price_curve(i),
+                    lamports_to_sol(auction.floor_price(i)),
+                    auction.price_multiplier_bps(i) as f64 / DENOMINATOR_BPS as f64
+                );
+            }
+            println!("\n  Current Mining Rates (OIL/s):");
+            for i in 0..4 {
+                println!("    Well {}: {} OIL/s", 
+                    i, 
+                    amount_to_ui_amount(auction.mining_rate_at(i, current_time), TOKEN_DECIMALS)
+                );
+            }
         }
         Err(e) => {
             println!("Auction account not found (not initialized yet)");
@@ -1647,8 +1955,45 @@ async fn log_auction(rpc: &RpcClient) -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+async fn log_well_quote(
+    rpc: &OilClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let well_id = std::env::var("WELL_ID")
+        .ok()
+        .and_then(|s| u64::from_str(&s).ok())
+        .unwrap_or(0);
+    
+    // Simulate the view instruction and decode its return data
+    let ix = oil_api::sdk::get_well_quote(well_id);
+    let blockhash = rpc.get_latest_blockhash().await?;
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&payer.pubkey()), &[payer], blockhash);
+    let sim = rpc.simulate_transaction(&tx).await?;
+    if let Some(err) = sim.value.err {
+        return Err(anyhow::anyhow!("Quote simulation failed: {:?}", err));
+    }
+    let return_data = sim.value.return_data
+        .ok_or_else(|| anyhow::anyhow!("Quote returned no data"))?;
+    let bytes = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, &return_data.data.0)?;
+    let quote = bytemuck::try_from_bytes::<WellQuote>(&bytes)
+        .map_err(|e| anyhow::anyhow!("Invalid quote data: {:?}", e))?;
+    
+    println!("Well Quote");
+    println!("  well_id: {}", quote.well_id);
+    println!("  epoch_id: {}", quote.epoch_id);
+    println!("  current_bidder: {}", quote.current_bidder);
+    println!("  current_price: {} SOL", lamports_to_sol(quote.current_price));
+    println!("  bid_amount: {} SOL", lamports_to_sol(quote.bid_amount));
+    println!("  accumulated_oil: {} OIL", amount_to_ui_amount(quote.accumulated_oil, TOKEN_DECIMALS));
+    println!("  mps: {} OIL/s", amount_to_ui_amount(quote.mps, TOKEN_DECIMALS));
+    println!("  total_contributed: {} SOL", lamports_to_sol(quote.total_contributed));
+    println!("  pool_needed: {} SOL", lamports_to_sol(quote.pool_needed));
+    println!("  ts: {}", quote.ts);
+    Ok(())
+}
+
 async fn place_bid(
-    rpc: &RpcClient,
+    rpc: &OilClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
     let well_id = std::env::var("WELL_ID")
@@ -1658,17 +2003,27 @@ async fn place_bid(
         .unwrap_or(0);
     
     // Get config to fetch fee_collector
-    let config = get_config(rpc).await?;
+    let config = rpc.config().await?;
     
     // Get well to find current_bidder and epoch_id
-    let well = get_well(rpc, well_id).await?;
+    let well = rpc.well(well_id).await?;
     
-    // Derive previous owner miner if previous owner exists
-    let (previous_owner_miner, previous_owner) = if well.current_bidder != Pubkey::default() {
-        let (miner_pda, _) = oil_api::state::miner_pda(well.current_bidder);
-        (Some(miner_pda), Some(well.current_bidder))
+    // Slippage protection: cap the bid at the current bid amount plus SLIPPAGE_BPS (default 1%)
+    let auction = rpc.auction().await?;
+    let clock = rpc.clock().await?;
+    let slippage_bps = std::env::var("SLIPPAGE_BPS")
+        .ok()
+        .and_then(|s| u64::from_str(&s).ok())
+        .unwrap_or(100);
+    let bid_amount = oil_api::sdk::well_price(&well, &auction, clock.unix_timestamp) + 1;
+    let max_price = bid_amount + bid_amount * slippage_bps / DENOMINATOR_BPS;
+    let expires_at = clock.unix_timestamp + ONE_MINUTE;
+    
+    // The previous owner's miner is credited with the bid if the well has an owner
+    let previous_owner = if well.current_bidder != Pubkey::default() {
+        Some(well.current_bidder)
     } else {
-        (None, None)
+        None
     };
     
     println!("Placing bid on Well {}", well_id);
@@ -1677,6 +2032,7 @@ async fn place_bid(
     if let Some(prev_owner) = previous_owner {
         println!("  Previous owner: {}", prev_owner);
     }
+    println!("  Bid amount: {} SOL (max {} SOL)", lamports_to_sol(bid_amount), lamports_to_sol(max_price));
     
     // Build and submit transaction
     let ix = oil_api::sdk::place_bid(
@@ -1684,9 +2040,12 @@ async fn place_bid(
         payer.pubkey(),        // authority (same as signer for CLI)
         well_id,               // square_id
         config.fee_collector,  // fee_collector
-        previous_owner_miner, // previous_owner_miner
         previous_owner,        // previous_owner
+        well.epoch_id,         // epoch_id
         None,                  // referrer (no referrer for CLI bids)
+        max_price,             // max_price
+        Some(expires_at),      // expires_at
+        access_code(),         // access_code (required before the TGE)
     );
     
     submit_transaction(rpc, payer, &[ix]).await?;
@@ -1694,12 +2053,12 @@ async fn place_bid(
     Ok(())
 }
 
-async fn log_var(rpc: &RpcClient) -> Result<(), anyhow::Error> {
-    let config = get_config(&rpc).await?;
-    let clock = get_clock(&rpc).await?;
+async fn log_var(rpc: &OilClient) -> Result<(), anyhow::Error> {
+    let config = rpc.config().await?;
+    let clock = rpc.clock().await?;
     let var_address = config.var_address;
     
-    match get_var(&rpc, var_address).await {
+    match rpc.var(var_address).await {
         Ok(var) => {
             println!("Var");
             println!("  address: {}", var_address);
@@ -1731,9 +2090,9 @@ async fn log_var(rpc: &RpcClient) -> Result<(), anyhow::Error> {
     Ok(())
 }
 
-async fn log_board(rpc: &RpcClient) -> Result<(), anyhow::Error> {
-    let board = get_board(&rpc).await?;
-    let clock = get_clock(&rpc).await?;
+async fn log_board(rpc: &OilClient) -> Result<(), anyhow::Error> {
+    let board = rpc.board().await?;
+    let clock = rpc.clock().await?;
     print_board(board, &clock);
     Ok(())
 }
@@ -1750,141 +2109,18 @@ fn print_board(board: Board, clock: &Clock) {
     );
 }
 
-async fn get_automation(rpc: &RpcClient, address: Pubkey) -> Result<Automation, anyhow::Error> {
-    let account = rpc.get_account(&address).await?;
-    let automation = Automation::try_from_bytes(&account.data)?;
-    Ok(*automation)
-}
-
-async fn get_automations(rpc: &RpcClient) -> Result<Vec<(Pubkey, Automation)>, anyhow::Error> {
-    const REGOLITH_EXECUTOR: Pubkey = pubkey!("BoT3qYmE6xePWPU96Kf2QeuJr1pDgQ3gLWbA6kSyjzV");
-    let filter = RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
-        56,
-        &REGOLITH_EXECUTOR.to_bytes(),
-    ));
-    let automations = get_program_accounts::<Automation>(rpc, oil_api::ID, vec![filter]).await?;
-    Ok(automations)
-}
-
-// async fn get_meteora_pool(rpc: &RpcClient, address: Pubkey) -> Result<Pool, anyhow::Error> {
+// async fn get_meteora_pool(rpc: &OilClient, address: Pubkey) -> Result<Pool, anyhow::Error> {
 //     let data = rpc.get_account_data(&address).await?;
 //     let pool = Pool::from_bytes(&data)?;
 //     Ok(pool)
 // }
 
-// async fn get_meteora_vault(rpc: &RpcClient, address: Pubkey) -> Result<Vault, anyhow::Error> {
+// async fn get_meteora_vault(rpc: &OilClient, address: Pubkey) -> Result<Vault, anyhow::Error> {
 //     let data = rpc.get_account_data(&address).await?;
 //     let vault = Vault::from_bytes(&data)?;
 //     Ok(vault)
 // }
 
-async fn get_board(rpc: &RpcClient) -> Result<Board, anyhow::Error> {
-    let board_pda = oil_api::state::board_pda();
-    let account = rpc.get_account(&board_pda.0).await?;
-    let board = Board::try_from_bytes(&account.data)?;
-    Ok(*board)
-}
-
-async fn get_var(rpc: &RpcClient, address: Pubkey) -> Result<Var, anyhow::Error> {
-    let account = rpc.get_account(&address).await?;
-    let var = Var::try_from_bytes(&account.data)?;
-    Ok(*var)
-}
-
-async fn get_round(rpc: &RpcClient, id: u64) -> Result<Round, anyhow::Error> {
-    let round_pda = oil_api::state::round_pda(id);
-    let account = rpc.get_account(&round_pda.0).await?;
-    let round = Round::try_from_bytes(&account.data)?;
-    Ok(*round)
-}
-
-async fn get_treasury(rpc: &RpcClient) -> Result<Treasury, anyhow::Error> {
-    let treasury_pda = oil_api::state::treasury_pda();
-    let account = rpc.get_account(&treasury_pda.0).await?;
-    let treasury = Treasury::try_from_bytes(&account.data)?;
-    Ok(*treasury)
-}
-
-async fn get_config(rpc: &RpcClient) -> Result<Config, anyhow::Error> {
-    let config_pda = oil_api::state::config_pda();
-    let account = rpc.get_account(&config_pda.0).await?;
-    let config = Config::try_from_bytes(&account.data)?;
-    Ok(*config)
-}
-
-async fn get_miner(rpc: &RpcClient, authority: Pubkey) -> Result<Miner, anyhow::Error> {
-    let miner_pda = oil_api::state::miner_pda(authority);
-    let account = rpc.get_account(&miner_pda.0)
-        .await
-        .map_err(|e| anyhow::anyhow!("Miner account not found for authority {} (address: {}): {}", authority, miner_pda.0, e))?;
-    let miner = Miner::try_from_bytes(&account.data)
-        .map_err(|e| anyhow::anyhow!("Failed to deserialize miner account: {}", e))?;
-    Ok(*miner)
-}
-
-async fn get_clock(rpc: &RpcClient) -> Result<Clock, anyhow::Error> {
-    let data = rpc.get_account_data(&solana_sdk::sysvar::clock::ID).await?;
-    let clock = bincode::deserialize::<Clock>(&data)?;
-    Ok(clock)
-}
-
-async fn get_stake(rpc: &RpcClient, authority: Pubkey) -> Result<Stake, anyhow::Error> {
-    let stake_pda = oil_api::state::stake_pda(authority);
-    let account = rpc.get_account(&stake_pda.0).await?;
-    let stake = Stake::try_from_bytes(&account.data)?;
-    Ok(*stake)
-}
-
-async fn get_well(rpc: &RpcClient, well_id: u64) -> Result<Well, anyhow::Error> {
-    let (well_pda, _) = oil_api::state::well_pda(well_id);
-    let account = rpc.get_account(&well_pda).await?;
-    if account.data.is_empty() {
-        return Err(anyhow::anyhow!("Well account not found (not initialized yet)"));
-    }
-    let well = Well::try_from_bytes(&account.data)?;
-    Ok(*well)
-}
-
-async fn get_share(rpc: &RpcClient, authority: Pubkey, well_id: u64, epoch_id: u64) -> Result<Share, anyhow::Error> {
-    let (share_pda, _) = oil_api::state::share_pda(authority, well_id, epoch_id);
-    let account = rpc.get_account(&share_pda).await?;
-    if account.data.is_empty() {
-        return Err(anyhow::anyhow!("Share account not found"));
-    }
-    let share = Share::try_from_bytes(&account.data)?;
-    Ok(*share)
-}
-
-async fn get_auction(rpc: &RpcClient) -> Result<Auction, anyhow::Error> {
-    let auction_pda = oil_api::state::auction_pda();
-    let account = rpc.get_account(&auction_pda.0).await?;
-    if account.data.is_empty() {
-        return Err(anyhow::anyhow!("Auction account not found (not initialized yet)"));
-    }
-    let auction = Auction::try_from_bytes(&account.data)?;
-    Ok(*auction)
-}
-
-async fn get_rounds(rpc: &RpcClient) -> Result<Vec<(Pubkey, Round)>, anyhow::Error> {
-    let rounds = get_program_accounts::<Round>(rpc, oil_api::ID, vec![]).await?;
-    Ok(rounds)
-}
-
-#[allow(dead_code)]
-async fn get_miners(rpc: &RpcClient) -> Result<Vec<(Pubkey, Miner)>, anyhow::Error> {
-    let miners = get_program_accounts::<Miner>(rpc, oil_api::ID, vec![]).await?;
-    Ok(miners)
-}
-
-async fn get_miners_participating(
-    rpc: &RpcClient,
-    round_id: u64,
-) -> Result<Vec<(Pubkey, Miner)>, anyhow::Error> {
-    let filter = RpcFilterType::Memcmp(Memcmp::new_base58_encoded(512, &round_id.to_le_bytes()));
-    let miners = get_program_accounts::<Miner>(rpc, oil_api::ID, vec![filter]).await?;
-    Ok(miners)
-}
-
 // fn get_winning_square(slot_hash: &[u8]) -> u64 {
 //     // Use slot hash to generate a random u64
 //     let r1 = u64::from_le_bytes(slot_hash[0..8].try_into().unwrap());
@@ -1896,148 +2132,12 @@ async fn get_miners_participating(
 //     r % 25
 // }
 
-#[allow(dead_code)]
-async fn simulate_transaction(
-    rpc: &RpcClient,
-    payer: &solana_sdk::signer::keypair::Keypair,
-    instructions: &[solana_sdk::instruction::Instruction],
-) {
-    let blockhash = rpc.get_latest_blockhash().await.unwrap();
-    let x = rpc
-        .simulate_transaction(&Transaction::new_signed_with_payer(
-            instructions,
-            Some(&payer.pubkey()),
-            &[payer],
-            blockhash,
-        ))
-        .await;
-    println!("Simulation result: {:?}", x);
-}
-
-#[allow(dead_code)]
-async fn simulate_transaction_with_address_lookup_tables(
-    rpc: &RpcClient,
-    payer: &solana_sdk::signer::keypair::Keypair,
-    instructions: &[solana_sdk::instruction::Instruction],
-    address_lookup_table_accounts: Vec<AddressLookupTableAccount>,
-) {
-    let blockhash = rpc.get_latest_blockhash().await.unwrap();
-    let tx = VersionedTransaction {
-        signatures: vec![Signature::default()],
-        message: VersionedMessage::V0(
-            Message::try_compile(
-                &payer.pubkey(),
-                instructions,
-                &address_lookup_table_accounts,
-                blockhash,
-            )
-            .unwrap(),
-        ),
-    };
-    let s = tx.sanitize();
-    println!("Sanitize result: {:?}", s);
-    s.unwrap();
-    let x = rpc.simulate_transaction(&tx).await;
-    println!("Simulation result: {:?}", x);
-}
-
-#[allow(unused)]
-async fn submit_transaction_batches(
-    rpc: &RpcClient,
-    payer: &solana_sdk::signer::keypair::Keypair,
-    mut ixs: Vec<solana_sdk::instruction::Instruction>,
-    batch_size: usize,
-) -> Result<(), anyhow::Error> {
-    // Batch and submit the instructions.
-    while !ixs.is_empty() {
-        let batch = ixs
-            .drain(..std::cmp::min(batch_size, ixs.len()))
-            .collect::<Vec<Instruction>>();
-        submit_transaction_no_confirm(rpc, payer, &batch).await?;
-    }
-    Ok(())
-}
-
-#[allow(unused)]
-async fn simulate_transaction_batches(
-    rpc: &RpcClient,
-    payer: &solana_sdk::signer::keypair::Keypair,
-    mut ixs: Vec<solana_sdk::instruction::Instruction>,
-    batch_size: usize,
-) -> Result<(), anyhow::Error> {
-    // Batch and submit the instructions.
-    while !ixs.is_empty() {
-        let batch = ixs
-            .drain(..std::cmp::min(batch_size, ixs.len()))
-            .collect::<Vec<Instruction>>();
-        simulate_transaction(rpc, payer, &batch).await;
-    }
-    Ok(())
-}
-
 async fn submit_transaction(
-    rpc: &RpcClient,
-    payer: &solana_sdk::signer::keypair::Keypair,
-    instructions: &[solana_sdk::instruction::Instruction],
-) -> Result<solana_sdk::signature::Signature, anyhow::Error> {
-    let blockhash = rpc.get_latest_blockhash().await?;
-    let mut all_instructions = vec![
-        ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
-        ComputeBudgetInstruction::set_compute_unit_price(1_000_000),
-    ];
-    all_instructions.extend_from_slice(instructions);
-    let transaction = Transaction::new_signed_with_payer(
-        &all_instructions,
-        Some(&payer.pubkey()),
-        &[payer],
-        blockhash,
-    );
-
-    // Try send_and_confirm_transaction first, fall back to send_transaction if not supported
-    match rpc.send_and_confirm_transaction(&transaction).await {
-        Ok(signature) => {
-            println!("Transaction submitted: {:?}", signature);
-            Ok(signature)
-        }
-        Err(e) => {
-            // If RPC doesn't support sendAndConfirmTransaction, try send_transaction instead
-            if let solana_client::client_error::ClientErrorKind::RpcError(rpc_err) = &e.kind {
-                // Check if error message contains "UNKNOWN" or error code is -32601 (method not found)
-                let error_str = format!("{:?}", rpc_err);
-                if error_str.contains("UNKNOWN") || error_str.contains("-32601") {
-                    // Method not found - try send_transaction instead
-                    println!("RPC doesn't support sendAndConfirmTransaction, using send_transaction instead...");
-                    let signature = rpc.send_transaction(&transaction).await?;
-                    println!("Transaction submitted (not confirmed): {:?}", signature);
-                    println!("Please check the transaction status manually");
-                    return Ok(signature);
-                }
-            }
-            println!("Error submitting transaction: {:?}", e);
-            Err(e.into())
-        }
-    }
-}
-
-async fn submit_transaction_no_confirm(
-    rpc: &RpcClient,
+    rpc: &OilClient,
     payer: &solana_sdk::signer::keypair::Keypair,
     instructions: &[solana_sdk::instruction::Instruction],
 ) -> Result<solana_sdk::signature::Signature, anyhow::Error> {
-    let blockhash = rpc.get_latest_blockhash().await?;
-    let mut all_instructions = vec![
-        ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
-        ComputeBudgetInstruction::set_compute_unit_price(1_000_000),
-    ];
-    all_instructions.extend_from_slice(instructions);
-    let transaction = Transaction::new_signed_with_payer(
-        &all_instructions,
-        Some(&payer.pubkey()),
-        &[payer],
-        blockhash,
-    );
-
-    match rpc.send_transaction(&transaction).await {
+    match rpc.submit(payer, instructions).await {
         Ok(signature) => {
             println!("Transaction submitted: {:?}", signature);
             Ok(signature)
@@ -2048,61 +2148,3 @@ async fn submit_transaction_no_confirm(
         }
     }
 }
-
-pub async fn get_program_accounts<T>(
-    client: &RpcClient,
-    program_id: Pubkey,
-    filters: Vec<RpcFilterType>,
-) -> Result<Vec<(Pubkey, T)>, anyhow::Error>
-where
-    T: AccountDeserialize + Discriminator + Clone,
-{
-    let mut all_filters = vec![RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
-        0,
-        &T::discriminator().to_le_bytes(),
-    ))];
-    all_filters.extend(filters);
-    let result = client
-        .get_program_accounts_with_config(
-            &program_id,
-            RpcProgramAccountsConfig {
-                filters: Some(all_filters),
-                account_config: RpcAccountInfoConfig {
-                    encoding: Some(UiAccountEncoding::Base64),
-                    ..Default::default()
-                },
-                ..Default::default()
-            },
-        )
-        .await;
-
-    match result {
-        Ok(accounts) => {
-            let accounts = accounts
-                .into_iter()
-                .filter_map(|(pubkey, account)| {
-                    if let Ok(account) = T::try_from_bytes(&account.data) {
-                        Some((pubkey, account.clone()))
-                    } else {
-                        None
-                    }
-                })
-                .collect();
-            Ok(accounts)
-        }
-        Err(err) => match err.kind {
-            ClientErrorKind::Reqwest(err) => {
-                if let Some(status_code) = err.status() {
-                    if status_code == StatusCode::GONE {
-                        panic!(
-                                "\n{} Your RPC provider does not support the getProgramAccounts endpoint, needed to execute this command. Please use a different RPC provider.\n",
-                                "ERROR"
-                            );
-                    }
-                }
-                return Err(anyhow::anyhow!("Failed to get program accounts: {}", err));
-            }
-            _ => return Err(anyhow::anyhow!("Failed to get program accounts: {}", err)),
-        },
-    }
-}
@@ -0,0 +1,315 @@
+use std::ops::Deref;
+
+use entropy_rng_api::state::Var;
+use oil_api::prelude::*;
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::{
+    nonblocking::rpc_client::RpcClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use solana_sdk::{
+    address_lookup_table::{state::AddressLookupTable, AddressLookupTableAccount},
+    pubkey::Pubkey,
+};
+use steel::{AccountDeserialize, Clock, Discriminator};
+
+use crate::{ClientError, Confirmation, PriorityFee};
+
+/// Maximum number of accounts per `getMultipleAccounts` request.
+const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+
+/// Typed async client for the OIL program.
+///
+/// Derefs to the underlying `RpcClient` for anything the client does not cover.
+pub struct OilClient {
+    rpc: RpcClient,
+    pub(crate) compute_unit_limit: u32,
+    pub(crate) priority_fee: PriorityFee,
+    pub(crate) confirmation: Confirmation,
+    pub(crate) lookup_tables: Vec<AddressLookupTableAccount>,
+}
+
+impl OilClient {
+    pub fn new(rpc: RpcClient) -> Self {
+        Self {
+            rpc,
+            compute_unit_limit: 1_400_000,
+            priority_fee: PriorityFee::Fixed(1_000_000),
+            confirmation: Confirmation::default(),
+            lookup_tables: vec![],
+        }
+    }
+
+    /// Sets the compute unit limit requested by every transaction.
+    pub fn with_compute_unit_limit(mut self, compute_unit_limit: u32) -> Self {
+        self.compute_unit_limit = compute_unit_limit;
+        self
+    }
+
+    /// Sets the priority fee paid by every transaction.
+    pub fn with_priority_fee(mut self, priority_fee: PriorityFee) -> Self {
+        self.priority_fee = priority_fee;
+        self
+    }
+
+    /// Sets how long transaction submission waits for confirmation.
+    pub fn with_confirmation(mut self, confirmation: Confirmation) -> Self {
+        self.confirmation = confirmation;
+        self
+    }
+
+    /// Compiles every transaction against the given address lookup tables.
+    pub fn with_lookup_tables(mut self, lookup_tables: Vec<AddressLookupTableAccount>) -> Self {
+        self.lookup_tables = lookup_tables;
+        self
+    }
+
+    pub fn rpc(&self) -> &RpcClient {
+        &self.rpc
+    }
+
+    /// Fetches and deserializes an account.
+    pub async fn account<T>(&self, address: Pubkey) -> Result<T, ClientError>
+    where
+        T: AccountDeserialize + Clone,
+    {
+        self.optional_account(address)
+            .await?
+            .ok_or(ClientError::AccountNotFound(address))
+    }
+
+    /// Fetches and deserializes an account, returning `None` if it does not exist.
+    pub async fn optional_account<T>(&self, address: Pubkey) -> Result<Option<T>, ClientError>
+    where
+        T: AccountDeserialize + Clone,
+    {
+        let account = self
+            .rpc
+            .get_account_with_commitment(&address, self.rpc.commitment())
+            .await?
+            .value;
+        match account {
+            Some(account) if !account.data.is_empty() => Ok(Some(deserialize(address, &account.data)?)),
+            _ => Ok(None),
+        }
+    }
+
+    /// Fetches and deserializes many accounts, batching `getMultipleAccounts` requests. Accounts
+    /// that do not exist are returned as `None`.
+    pub async fn multiple_accounts<T>(&self, addresses: &[Pubkey]) -> Result<Vec<Option<T>>, ClientError>
+    where
+        T: AccountDeserialize + Clone,
+    {
+        let mut result = Vec::with_capacity(addresses.len());
+        for chunk in addresses.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            let accounts = self.rpc.get_multiple_accounts(chunk).await?;
+            for (address, account) in chunk.iter().zip(accounts) {
+                match account {
+                    Some(account) if !account.data.is_empty() => {
+                        result.push(Some(deserialize(*address, &account.data)?))
+                    }
+                    _ => result.push(None),
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Fetches every program account of type `T` matching the filters. Accounts that fail to
+    /// deserialize (e.g. legacy layouts awaiting migration) are skipped.
+    pub async fn program_accounts<T>(
+        &self,
+        filters: Vec<RpcFilterType>,
+    ) -> Result<Vec<(Pubkey, T)>, ClientError>
+    where
+        T: AccountDeserialize + Discriminator + Clone,
+    {
+        let mut all_filters = vec![RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+            0,
+            &T::discriminator().to_le_bytes(),
+        ))];
+        all_filters.extend(filters);
+        let accounts = self.program_accounts_with_filters(all_filters).await?;
+        Ok(accounts
+            .into_iter()
+            .filter_map(|(address, data)| {
+                T::try_from_bytes(&data)
+                    .ok()
+                    .map(|account| (address, account.clone()))
+            })
+            .collect())
+    }
+
+    /// Fetches the raw data of every program account (optionally of one type) without
+    /// deserializing it, so accounts with legacy layouts are included.
+    pub async fn raw_program_accounts(
+        &self,
+        kind: Option<OilAccount>,
+    ) -> Result<Vec<(Pubkey, Vec<u8>)>, ClientError> {
+        let filters = kind
+            .map(|kind| vec![RpcFilterType::Memcmp(Memcmp::new_base58_encoded(0, &[kind as u8]))])
+            .unwrap_or_default();
+        self.program_accounts_with_filters(filters).await
+    }
+
+    async fn program_accounts_with_filters(
+        &self,
+        filters: Vec<RpcFilterType>,
+    ) -> Result<Vec<(Pubkey, Vec<u8>)>, ClientError> {
+        let accounts = self
+            .rpc
+            .get_program_accounts_with_config(
+                &oil_api::ID,
+                RpcProgramAccountsConfig {
+                    filters: (!filters.is_empty()).then_some(filters),
+                    account_config: RpcAccountInfoConfig {
+                        encoding: Some(UiAccountEncoding::Base64),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            )
+            .await?;
+        Ok(accounts
+            .into_iter()
+            .map(|(address, account)| (address, account.data))
+            .collect())
+    }
+
+    /// Fetches the given address lookup tables, skipping any that cannot be loaded.
+    pub async fn lookup_tables(
+        &self,
+        addresses: &[Pubkey],
+    ) -> Result<Vec<AddressLookupTableAccount>, ClientError> {
+        let accounts = self.rpc.get_multiple_accounts(addresses).await?;
+        Ok(addresses
+            .iter()
+            .zip(accounts)
+            .filter_map(|(key, account)| {
+                let table = AddressLookupTable::deserialize(&account?.data).ok()?;
+                Some(AddressLookupTableAccount {
+                    key: *key,
+                    addresses: table.addresses.to_vec(),
+                })
+            })
+            .collect())
+    }
+
+    pub async fn clock(&self) -> Result<Clock, ClientError> {
+        let data = self.rpc.get_account_data(&solana_sdk::sysvar::clock::ID).await?;
+        Ok(bincode::deserialize::<Clock>(&data)?)
+    }
+
+    pub async fn board(&self) -> Result<Board, ClientError> {
+        self.account(board_pda().0).await
+    }
+
+    pub async fn config(&self) -> Result<Config, ClientError> {
+        self.account(config_pda().0).await
+    }
+
+    pub async fn treasury(&self) -> Result<Treasury, ClientError> {
+        self.account(treasury_pda().0).await
+    }
+
+    pub async fn pool(&self) -> Result<Pool, ClientError> {
+        self.account(pool_pda().0).await
+    }
+
+    pub async fn auction(&self) -> Result<Auction, ClientError> {
+        self.account(auction_pda().0).await
+    }
+
+    pub async fn var(&self, address: Pubkey) -> Result<Var, ClientError> {
+        let account = self.rpc.get_account(&address).await?;
+        Ok(*Var::try_from_bytes(&account.data).map_err(|_| ClientError::InvalidAccount(address))?)
+    }
+
+    pub async fn round(&self, id: u64) -> Result<Round, ClientError> {
+        self.account(round_pda(id).0).await
+    }
+
+    pub async fn miner(&self, authority: Pubkey) -> Result<Miner, ClientError> {
+        self.account(miner_pda(authority).0).await
+    }
+
+    /// Returns a miner's rig, or `None` until it first earns XP.
+    pub async fn rig(&self, authority: Pubkey) -> Result<Option<Rig>, ClientError> {
+        self.optional_account(rig_pda(authority).0).await
+    }
+
+    pub async fn automation(&self, authority: Pubkey) -> Result<Automation, ClientError> {
+        self.account(automation_pda(authority).0).await
+    }
+
+    pub async fn stake(&self, authority: Pubkey) -> Result<Stake, ClientError> {
+        self.account(stake_pda(authority).0).await
+    }
+
+    pub async fn well(&self, well_id: u64) -> Result<Well, ClientError> {
+        self.account(well_pda(well_id).0).await
+    }
+
+    pub async fn share(&self, authority: Pubkey, well_id: u64, epoch_id: u64) -> Result<Share, ClientError> {
+        self.account(share_pda(authority, well_id, epoch_id).0).await
+    }
+
+    pub async fn referral(&self, authority: Pubkey) -> Result<Referral, ClientError> {
+        self.account(referral_pda(authority).0).await
+    }
+
+    /// Returns the referral config, or `None` until the admin creates one.
+    pub async fn referral_config(&self) -> Result<Option<ReferralConfig>, ClientError> {
+        self.optional_account(referral_config_pda().0).await
+    }
+
+    pub async fn rounds(&self) -> Result<Vec<(Pubkey, Round)>, ClientError> {
+        self.program_accounts(vec![]).await
+    }
+
+    pub async fn miners(&self) -> Result<Vec<(Pubkey, Miner)>, ClientError> {
+        self.program_accounts(vec![]).await
+    }
+
+    /// Returns the miners whose last deploy was in the given round.
+    pub async fn miners_in_round(&self, round_id: u64) -> Result<Vec<(Pubkey, Miner)>, ClientError> {
+        let offset = std::mem::offset_of!(Miner, round_id);
+        self.program_accounts(vec![memcmp(offset, &round_id.to_le_bytes())])
+            .await
+    }
+
+    /// Returns the automations that name `executor` as their executor.
+    pub async fn automations(&self, executor: Pubkey) -> Result<Vec<(Pubkey, Automation)>, ClientError> {
+        let offset = std::mem::offset_of!(Automation, executor);
+        self.program_accounts(vec![memcmp(offset, &executor.to_bytes())])
+            .await
+    }
+
+    pub async fn referrals(&self) -> Result<Vec<(Pubkey, Referral)>, ClientError> {
+        self.program_accounts(vec![]).await
+    }
+}
+
+impl Deref for OilClient {
+    type Target = RpcClient;
+
+    fn deref(&self) -> &RpcClient {
+        &self.rpc
+    }
+}
+
+/// Filters program accounts on bytes at `offset` into the account struct (after the discriminator),
+/// e.g. `memcmp(std::mem::offset_of!(Miner, round_id), &round_id.to_le_bytes())`.
+pub fn memcmp(offset: usize, bytes: &[u8]) -> RpcFilterType {
+    RpcFilterType::Memcmp(Memcmp::new_base58_encoded(8 + offset, bytes))
+}
+
+fn deserialize<T>(address: Pubkey, data: &[u8]) -> Result<T, ClientError>
+where
+    T: AccountDeserialize + Clone,
+{
+    T::try_from_bytes(data)
+        .map(|account| account.clone())
+        .map_err(|_| ClientError::InvalidAccount(address))
+}
@@ -0,0 +1,28 @@
+use solana_sdk::{
+    message::CompileError, pubkey::Pubkey, signer::SignerError, transaction::TransactionError,
+};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ClientError {
+    #[error("RPC error: {0}")]
+    Rpc(#[from] solana_client::client_error::ClientError),
+
+    #[error("Account {0} not found")]
+    AccountNotFound(Pubkey),
+
+    #[error("Account {0} could not be deserialized")]
+    InvalidAccount(Pubkey),
+
+    #[error("Invalid sysvar data: {0}")]
+    InvalidSysvar(#[from] bincode::Error),
+
+    #[error("Failed to compile transaction: {0}")]
+    Compile(#[from] CompileError),
+
+    #[error("Failed to sign transaction: {0}")]
+    Sign(#[from] SignerError),
+
+    #[error("Transaction simulation failed: {0}")]
+    Simulation(TransactionError),
+}
@@ -0,0 +1,16 @@
+//! Typed async client for the OIL program, built on the SDK in `oil-api`.
+//!
+//! ```ignore
+//! let client = OilClient::new(RpcClient::new(url)).with_priority_fee(PriorityFee::Recent { max: 100_000 });
+//! let board = client.board().await?;
+//! let ix = oil_api::sdk::checkpoint(payer.pubkey(), authority, board.round_id - 1);
+//! client.submit(&payer, &[ix]).await?;
+//! ```
+
+mod client;
+mod error;
+mod transaction;
+
+pub use client::*;
+pub use error::*;
+pub use transaction::*;
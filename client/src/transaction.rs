@@ -0,0 +1,164 @@
+use solana_client::{client_error::ClientErrorKind, rpc_response::RpcSimulateTransactionResult};
+use solana_sdk::{
+    compute_budget::ComputeBudgetInstruction,
+    instruction::Instruction,
+    message::{v0, VersionedMessage},
+    pubkey::Pubkey,
+    signature::{Keypair, Signature, Signer},
+    transaction::VersionedTransaction,
+};
+
+use crate::{ClientError, OilClient};
+
+/// How transactions set their compute unit price.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PriorityFee {
+    /// A fixed compute unit price, in micro-lamports.
+    Fixed(u64),
+
+    /// The median fee recently paid to write the transaction's accounts, capped at `max`
+    /// micro-lamports per compute unit.
+    Recent { max: u64 },
+}
+
+/// How long transaction submission waits before returning.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Confirmation {
+    /// Return as soon as the RPC accepts the transaction.
+    Sent,
+
+    /// Wait until the transaction reaches the client's commitment level.
+    #[default]
+    Confirmed,
+
+    /// Like `Confirmed`, but fall back to `Sent` on RPCs that cannot confirm transactions.
+    BestEffort,
+}
+
+impl OilClient {
+    /// Signs and submits a transaction with the client's compute budget, lookup tables and
+    /// confirmation strategy.
+    pub async fn submit(&self, payer: &Keypair, instructions: &[Instruction]) -> Result<Signature, ClientError> {
+        let transaction = self.transaction(payer, instructions).await?;
+        match self.confirmation {
+            Confirmation::Sent => Ok(self.send_transaction(&transaction).await?),
+            Confirmation::Confirmed => Ok(self.send_and_confirm_transaction(&transaction).await?),
+            Confirmation::BestEffort => match self.send_and_confirm_transaction(&transaction).await {
+                Ok(signature) => Ok(signature),
+                Err(err) if is_method_not_found(&err.kind) => Ok(self.send_transaction(&transaction).await?),
+                Err(err) => Err(err.into()),
+            },
+        }
+    }
+
+    /// Submits instructions in transactions of at most `batch_size` instructions, returning the
+    /// result of each batch. A failed batch does not stop the remaining ones.
+    pub async fn submit_batches(
+        &self,
+        payer: &Keypair,
+        instructions: &[Instruction],
+        batch_size: usize,
+    ) -> Vec<Result<Signature, ClientError>> {
+        let mut results = vec![];
+        for batch in instructions.chunks(batch_size.max(1)) {
+            results.push(self.submit(payer, batch).await);
+        }
+        results
+    }
+
+    /// Simulates a transaction with the client's compute budget and lookup tables.
+    pub async fn simulate(
+        &self,
+        payer: &Keypair,
+        instructions: &[Instruction],
+    ) -> Result<RpcSimulateTransactionResult, ClientError> {
+        let transaction = self.transaction(payer, instructions).await?;
+        let result = self.simulate_transaction(&transaction).await?.value;
+        match result.err {
+            Some(err) => Err(ClientError::Simulation(err)),
+            None => Ok(result),
+        }
+    }
+
+    /// Builds and signs a transaction, prepending the compute budget instructions. Transactions are
+    /// compiled to v0 messages when the client has lookup tables.
+    pub async fn transaction(
+        &self,
+        payer: &Keypair,
+        instructions: &[Instruction],
+    ) -> Result<VersionedTransaction, ClientError> {
+        let compute_unit_price = self.compute_unit_price(instructions).await?;
+        let mut all_instructions = vec![
+            ComputeBudgetInstruction::set_compute_unit_limit(self.compute_unit_limit),
+            ComputeBudgetInstruction::set_compute_unit_price(compute_unit_price),
+        ];
+        all_instructions.extend_from_slice(instructions);
+
+        let blockhash = self.get_latest_blockhash().await?;
+        let message = if self.lookup_tables.is_empty() {
+            VersionedMessage::Legacy(solana_sdk::message::Message::new_with_blockhash(
+                &all_instructions,
+                Some(&payer.pubkey()),
+                &blockhash,
+            ))
+        } else {
+            VersionedMessage::V0(v0::Message::try_compile(
+                &payer.pubkey(),
+                &all_instructions,
+                &self.lookup_tables,
+                blockhash,
+            )?)
+        };
+        Ok(VersionedTransaction::try_new(message, &[payer])?)
+    }
+
+    async fn compute_unit_price(&self, instructions: &[Instruction]) -> Result<u64, ClientError> {
+        match self.priority_fee {
+            PriorityFee::Fixed(price) => Ok(price),
+            PriorityFee::Recent { max } => {
+                let mut writable: Vec<Pubkey> = instructions
+                    .iter()
+                    .flat_map(|ix| ix.accounts.iter())
+                    .filter(|meta| meta.is_writable)
+                    .map(|meta| meta.pubkey)
+                    .collect();
+                writable.sort();
+                writable.dedup();
+                let fees = self.get_recent_prioritization_fees(&writable).await?;
+                let fees = fees.into_iter().map(|fee| fee.prioritization_fee).collect();
+                Ok(median(fees).min(max))
+            }
+        }
+    }
+}
+
+fn is_method_not_found(kind: &ClientErrorKind) -> bool {
+    match kind {
+        ClientErrorKind::RpcError(err) => {
+            let err = format!("{:?}", err);
+            err.contains("UNKNOWN") || err.contains("-32601")
+        }
+        _ => false,
+    }
+}
+
+fn median(mut values: Vec<u64>) -> u64 {
+    if values.is_empty() {
+        return 0;
+    }
+    values.sort_unstable();
+    values[values.len() / 2]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_median() {
+        assert_eq!(median(vec![]), 0);
+        assert_eq!(median(vec![5]), 5);
+        assert_eq!(median(vec![9, 1, 5]), 5);
+        assert_eq!(median(vec![0, 0, 0, 100, 200, 300]), 100);
+    }
+}
@@ -0,0 +1,227 @@
+use oil_api::prelude::*;
+use solana_sdk::{
+    instruction::Instruction, native_token::lamports_to_sol, pubkey::Pubkey, signature::Signer,
+};
+
+use crate::{
+    ledger::{Ledger, Skip},
+    Executor, COMPUTE_UNIT_LIMIT,
+};
+
+/// Lamports charged per transaction signature.
+const SIGNATURE_FEE: u64 = 5_000;
+
+/// Deploys for an automation in the given round, after checkpointing its miner and reloading its
+/// SOL winnings if needed.
+///
+/// Records the fee earned and the costs paid in the ledger, or why the automation was skipped.
+/// Automations are re-read before every step, so a retry never deploys twice in the same round.
+pub async fn execute(
+    executor: &Executor,
+    ledger: &mut Ledger,
+    authority: Pubkey,
+    round_id: u64,
+    miner_rent: u64,
+) -> Result<(), anyhow::Error> {
+    let client = &executor.client;
+    let signer = executor.payer.pubkey();
+    let automation_address = automation_pda(authority).0;
+    let Some(automation) = client.optional_account::<Automation>(automation_address).await? else {
+        ledger.skipped(Skip::Closed);
+        return Ok(());
+    };
+    let miner = client.optional_account::<Miner>(miner_pda(authority).0).await?;
+
+    // Skip miners that have already deployed this round.
+    if let Some(miner) = &miner {
+        if miner.round_id == round_id && miner.deployed.iter().any(|d| *d > 0) {
+            ledger.skipped(Skip::Deployed);
+            return Ok(());
+        }
+    }
+
+    // Skip automations whose fee does not cover the cost of executing them.
+    let needs_settle = miner
+        .as_ref()
+        .is_some_and(|m| m.round_id < round_id && m.checkpoint_id < m.round_id);
+    let cost = execution_cost(
+        miner.as_ref(),
+        needs_settle,
+        miner_rent,
+        executor.settings.compute_unit_price,
+    );
+    if automation.fee < cost.saturating_add(executor.settings.min_profit) {
+        ledger.skipped(Skip::Unprofitable);
+        return Ok(());
+    }
+
+    // Checkpoint the miner's last round, then reload its SOL winnings into the automation.
+    if let Some(miner) = miner.as_ref().filter(|_| needs_settle) {
+        let mut ixs = vec![oil_api::sdk::checkpoint(signer, authority, miner.round_id)];
+        if automation.reload > 0 {
+            ixs.push(reload_instruction(executor, authority, miner).await?);
+        }
+        let sig = client.submit(&executor.payer, &ixs).await?;
+        println!("Settled {}: {}", authority, sig);
+        ledger.paid(transaction_cost(executor.settings.compute_unit_price));
+    }
+
+    // Skip automations that cannot fund a full round: they would deploy to only some squares
+    // and close mid-round.
+    let Some(automation) = client.optional_account::<Automation>(automation_address).await? else {
+        ledger.skipped(Skip::Closed);
+        return Ok(());
+    };
+    let squares = squares_per_round(&automation);
+    if squares == 0 {
+        ledger.skipped(Skip::NoSquares);
+        return Ok(());
+    }
+    let round_cost = automation
+        .amount
+        .saturating_mul(squares)
+        .saturating_add(automation.fee);
+    if automation.balance < round_cost {
+        ledger.skipped(Skip::Underfunded);
+        return Ok(());
+    }
+
+    // The program selects the squares and amount from the automation.
+    let deploy_ix = oil_api::sdk::deploy(
+        signer,
+        authority,
+        automation.amount,
+        round_id,
+        [false; 25],
+        None,
+        automation.pooled > 0,
+        None,
+    );
+    let sig = client.submit(&executor.payer, &[deploy_ix]).await?;
+    println!(
+        "Deployed {} SOL x {} squares for {}: {}",
+        lamports_to_sol(automation.amount),
+        squares,
+        authority,
+        sig
+    );
+    ledger.paid(execution_cost(
+        miner.as_ref(),
+        false,
+        miner_rent,
+        executor.settings.compute_unit_price,
+    ));
+    ledger.deployed(automation.fee);
+    Ok(())
+}
+
+/// Builds the reload of a miner's SOL winnings, with the referral accounts its referrer requires.
+async fn reload_instruction(
+    executor: &Executor,
+    authority: Pubkey,
+    miner: &Miner,
+) -> Result<Instruction, anyhow::Error> {
+    let signer = executor.payer.pubkey();
+    if miner.referrer == Pubkey::default() {
+        return Ok(oil_api::sdk::reload_sol(signer, authority, None, None, None));
+    }
+
+    // The referrer's own referrer is paid an upline share when the referral config has one.
+    let referrer = miner.referrer;
+    let upline_share = executor
+        .client
+        .referral_config()
+        .await?
+        .map_or(0, |config| config.upline_share_bps);
+    let upline = if upline_share > 0 {
+        executor
+            .client
+            .optional_account::<Miner>(miner_pda(referrer).0)
+            .await?
+            .map(|m| m.referrer)
+            .filter(|upline| {
+                *upline != Pubkey::default() && *upline != authority && *upline != referrer
+            })
+    } else {
+        None
+    };
+    Ok(oil_api::sdk::reload_sol(
+        signer,
+        authority,
+        Some(miner_pda(referrer).0),
+        Some(referral_pda(referrer).0),
+        upline.map(|upline| referral_pda(upline).0),
+    ))
+}
+
+/// Returns the number of squares an automation deploys to each round.
+pub fn squares_per_round(automation: &Automation) -> u64 {
+    match AutomationStrategy::from_u64(automation.strategy) {
+        AutomationStrategy::Random | AutomationStrategy::Contrarian => (automation.mask & 0xFF).min(25),
+        AutomationStrategy::Preferred | AutomationStrategy::Repeat => {
+            (automation.mask & ((1 << 25) - 1)).count_ones() as u64
+        }
+    }
+}
+
+/// Estimates what executing an automation costs the executor: transaction fees, plus the
+/// checkpoint fee and miner rent the executor pays up front for a new miner.
+pub fn execution_cost(
+    miner: Option<&Miner>,
+    needs_settle: bool,
+    miner_rent: u64,
+    compute_unit_price: u64,
+) -> u64 {
+    let transactions = if needs_settle { 2 } else { 1 };
+    let mut cost = transactions * transaction_cost(compute_unit_price);
+    match miner {
+        None => cost += miner_rent + CHECKPOINT_FEE,
+        Some(miner) if miner.checkpoint_fee == 0 => cost += rig_checkpoint_fee(miner.level),
+        Some(_) => {}
+    }
+    cost
+}
+
+/// Returns the fee of a transaction: the signature fee plus its priority fee.
+fn transaction_cost(compute_unit_price: u64) -> u64 {
+    SIGNATURE_FEE + (COMPUTE_UNIT_LIMIT as u64 * compute_unit_price).div_ceil(1_000_000)
+}
+
+#[cfg(test)]
+mod tests {
+    use steel::Zeroable;
+
+    use super::*;
+
+    #[test]
+    fn test_squares_per_round() {
+        let mut automation = Automation::zeroed();
+        automation.strategy = AutomationStrategy::Preferred as u64;
+        automation.mask = 0b1011 | (1 << 30);
+        assert_eq!(squares_per_round(&automation), 3);
+
+        automation.strategy = AutomationStrategy::Random as u64;
+        automation.mask = 40;
+        assert_eq!(squares_per_round(&automation), 25);
+    }
+
+    #[test]
+    fn test_execution_cost() {
+        let base = transaction_cost(10_000);
+        assert_eq!(base, SIGNATURE_FEE + 4_000);
+
+        // A new miner costs its rent and the checkpoint fee.
+        assert_eq!(execution_cost(None, false, 1_000, 10_000), base + 1_000 + CHECKPOINT_FEE);
+
+        // An existing miner only needs its checkpoint fee topped up once it has been paid out.
+        let mut miner = Miner::zeroed();
+        miner.checkpoint_fee = CHECKPOINT_FEE;
+        assert_eq!(execution_cost(Some(&miner), false, 1_000, 10_000), base);
+        miner.checkpoint_fee = 0;
+        assert_eq!(execution_cost(Some(&miner), true, 1_000, 10_000), 2 * base + CHECKPOINT_FEE);
+
+        // Higher rig levels reserve a smaller checkpoint fee.
+        miner.level = 10;
+        assert_eq!(execution_cost(Some(&miner), false, 1_000, 10_000), base + CHECKPOINT_FEE / 2);
+    }
+}
@@ -0,0 +1,104 @@
+use std::fmt;
+
+use solana_sdk::native_token::lamports_to_sol;
+
+/// Why an automation was not executed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Skip {
+    /// The automation has been closed.
+    Closed,
+
+    /// The miner has already deployed this round.
+    Deployed,
+
+    /// The automation's fee does not cover the cost of executing it.
+    Unprofitable,
+
+    /// The automation cannot fund a full round, and would close mid-round.
+    Underfunded,
+
+    /// The automation's mask selects no squares.
+    NoSquares,
+}
+
+/// Tracks the profitability of the executor.
+#[derive(Clone, Debug, Default)]
+pub struct Ledger {
+    /// Automations deployed for.
+    pub deploys: u64,
+
+    /// Automations that failed to execute.
+    pub failures: u64,
+
+    /// Automations skipped because they were closed or had already deployed.
+    pub skipped_done: u64,
+
+    /// Automations skipped because their fee does not cover the cost of executing them.
+    pub skipped_unprofitable: u64,
+
+    /// Automations skipped because they cannot fund a full round, or select no squares.
+    pub skipped_underfunded: u64,
+
+    /// Fees earned from automations, in lamports.
+    pub fees_earned: u64,
+
+    /// Transaction fees, checkpoint fees and miner rent paid, in lamports.
+    pub costs_paid: u64,
+}
+
+impl Ledger {
+    pub fn deployed(&mut self, fee: u64) {
+        self.deploys += 1;
+        self.fees_earned += fee;
+    }
+
+    pub fn paid(&mut self, cost: u64) {
+        self.costs_paid += cost;
+    }
+
+    pub fn failed(&mut self) {
+        self.failures += 1;
+    }
+
+    pub fn skipped(&mut self, skip: Skip) {
+        match skip {
+            Skip::Closed | Skip::Deployed => self.skipped_done += 1,
+            Skip::Unprofitable => self.skipped_unprofitable += 1,
+            Skip::Underfunded | Skip::NoSquares => self.skipped_underfunded += 1,
+        }
+    }
+
+    /// Net profit in lamports (negative when costs exceed fees).
+    pub fn profit(&self) -> i128 {
+        self.fees_earned as i128 - self.costs_paid as i128
+    }
+
+    pub fn merge(&mut self, other: &Ledger) {
+        self.deploys += other.deploys;
+        self.failures += other.failures;
+        self.skipped_done += other.skipped_done;
+        self.skipped_unprofitable += other.skipped_unprofitable;
+        self.skipped_underfunded += other.skipped_underfunded;
+        self.fees_earned += other.fees_earned;
+        self.costs_paid += other.costs_paid;
+    }
+}
+
+impl fmt::Display for Ledger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let profit = self.profit();
+        write!(
+            f,
+            "{} deployed, {} failed, {} unprofitable, {} underfunded, {} done; fees {} SOL, costs {} SOL, profit {}{} SOL",
+            self.deploys,
+            self.failures,
+            self.skipped_unprofitable,
+            self.skipped_underfunded,
+            self.skipped_done,
+            lamports_to_sol(self.fees_earned),
+            lamports_to_sol(self.costs_paid),
+            if profit < 0 { "-" } else { "" },
+            lamports_to_sol(profit.unsigned_abs() as u64),
+        )
+    }
+}
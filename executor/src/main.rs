@@ -0,0 +1,206 @@
+mod execute;
+mod ledger;
+
+use std::{collections::VecDeque, str::FromStr, time::Duration};
+
+use ledger::Ledger;
+use oil_api::prelude::*;
+use oil_client::{OilClient, PriorityFee};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+};
+use steel::Clock;
+
+/// Attempts per automation and round before it is counted as failed.
+const MAX_ATTEMPTS: u32 = 3;
+
+/// Compute unit limit of every executor transaction (a deploy, or a checkpoint and reload).
+pub const COMPUTE_UNIT_LIMIT: u32 = 400_000;
+
+/// Executes the automations that name this executor: at the start of each round it checkpoints
+/// each automation's miner, reloads its SOL winnings when `reload` is set, and deploys for it,
+/// collecting the automation fee.
+///
+/// Configured through env vars:
+/// - RPC, KEYPAIR (required, KEYPAIR being the executor named by the automations)
+/// - POLL_INTERVAL_MS: how often the board is polled for a new round (default 1000)
+/// - PRIORITY_FEE: compute unit price in micro-lamports (default 10000)
+/// - MIN_PROFIT: lamports an automation must net above its costs to be executed (default 0)
+/// - ONCE: execute the current round once and exit
+#[tokio::main]
+async fn main() -> Result<(), anyhow::Error> {
+    let executor = Executor::from_env()?;
+    println!("Executor: {}", executor.payer.pubkey());
+
+    let mut round = RoundState::default();
+    let mut total = Ledger::default();
+    loop {
+        match executor.tick(&mut round, &mut total).await {
+            Ok(()) => {}
+            Err(err) => eprintln!("Error: {:#}", err),
+        }
+
+        if executor.settings.once && round.round_id.is_some() && round.pending.is_empty() {
+            round.finish(&mut total);
+            return Ok(());
+        }
+
+        tokio::select! {
+            _ = tokio::time::sleep(executor.settings.poll_interval) => {}
+            _ = tokio::signal::ctrl_c() => {
+                round.finish(&mut total);
+                println!("Shutting down");
+                return Ok(());
+            }
+        }
+    }
+}
+
+pub struct Settings {
+    pub poll_interval: Duration,
+    pub compute_unit_price: u64,
+    pub min_profit: u64,
+    pub once: bool,
+}
+
+impl Settings {
+    fn from_env() -> Result<Self, anyhow::Error> {
+        Ok(Self {
+            poll_interval: Duration::from_millis(env_or("POLL_INTERVAL_MS", 1_000)?),
+            compute_unit_price: env_or("PRIORITY_FEE", 10_000)?,
+            min_profit: env_or("MIN_PROFIT", 0)?,
+            once: std::env::var("ONCE")
+                .map(|s| s == "1" || s.to_lowercase() == "true")
+                .unwrap_or(false),
+        })
+    }
+}
+
+/// Reads a numeric env var, falling back to a default when unset.
+fn env_or(name: &str, default: u64) -> Result<u64, anyhow::Error> {
+    match std::env::var(name) {
+        Ok(value) => u64::from_str(&value).map_err(|_| anyhow::anyhow!("Invalid {}", name)),
+        Err(_) => Ok(default),
+    }
+}
+
+/// The automations left to execute in the current round.
+#[derive(Default)]
+struct RoundState {
+    round_id: Option<u64>,
+    pending: VecDeque<(Pubkey, u32)>,
+    miner_rent: u64,
+    ledger: Ledger,
+    finished: bool,
+}
+
+impl RoundState {
+    /// Counts the automations still pending as failed, and logs the round's profitability.
+    fn finish(&mut self, total: &mut Ledger) {
+        let Some(round_id) = self.round_id.filter(|_| !self.finished) else {
+            return;
+        };
+        self.finished = true;
+        for _ in self.pending.drain(..) {
+            self.ledger.failed();
+        }
+        total.merge(&self.ledger);
+        println!("Round {}: {}", round_id, self.ledger);
+        println!("Total: {}", total);
+    }
+}
+
+pub struct Executor {
+    pub client: OilClient,
+    pub payer: Keypair,
+    pub settings: Settings,
+}
+
+impl Executor {
+    fn from_env() -> Result<Self, anyhow::Error> {
+        let keypair_path = std::env::var("KEYPAIR").map_err(|_| anyhow::anyhow!("Missing KEYPAIR env var"))?;
+        let payer = read_keypair_file(&keypair_path)
+            .map_err(|err| anyhow::anyhow!("Failed to read keypair {}: {}", keypair_path, err))?;
+        let rpc_url = std::env::var("RPC").map_err(|_| anyhow::anyhow!("Missing RPC env var"))?;
+        let settings = Settings::from_env()?;
+        let client = OilClient::new(RpcClient::new(rpc_url))
+            .with_compute_unit_limit(COMPUTE_UNIT_LIMIT)
+            .with_priority_fee(PriorityFee::Fixed(settings.compute_unit_price));
+        Ok(Self {
+            client,
+            payer,
+            settings,
+        })
+    }
+
+    /// Starts a new round once the board has been reset, then executes each pending automation
+    /// while the round is open. Failed automations are retried on the next tick.
+    async fn tick(&self, round: &mut RoundState, total: &mut Ledger) -> Result<(), anyhow::Error> {
+        let board = self.client.board().await?;
+        if round.round_id != Some(board.round_id) {
+            round.finish(total);
+            let mut automations = self.client.automations(self.payer.pubkey()).await?;
+            // Deploys need an access code before the TGE, which automations do not hold.
+            let config = self.client.config().await?;
+            let clock = self.client.clock().await?;
+            if oil_api::utils::is_premine_active(&config, &clock) {
+                println!("Round {}: pre-mine active, not executing automations", board.round_id);
+                automations.clear();
+            }
+            let miner_rent = self
+                .client
+                .get_minimum_balance_for_rent_exemption(8 + std::mem::size_of::<Miner>())
+                .await?;
+            println!("Round {}: {} automations", board.round_id, automations.len());
+            *round = RoundState {
+                round_id: Some(board.round_id),
+                pending: automations
+                    .into_iter()
+                    .map(|(_, automation)| (automation.authority, 0))
+                    .collect(),
+                miner_rent,
+                ledger: Ledger::default(),
+                finished: false,
+            };
+        }
+
+        for _ in 0..round.pending.len() {
+            // Stop once the round has ended; whatever is left counts as failed.
+            let board = self.client.board().await?;
+            let clock = self.client.clock().await?;
+            if board.round_id != round.round_id.unwrap_or_default() || !is_open(&board, &clock) {
+                round.finish(total);
+                return Ok(());
+            }
+
+            let Some((authority, attempts)) = round.pending.pop_front() else {
+                break;
+            };
+            let result = execute::execute(
+                self,
+                &mut round.ledger,
+                authority,
+                board.round_id,
+                round.miner_rent,
+            )
+            .await;
+            if let Err(err) = result {
+                eprintln!("Failed to execute {}: {:#}", authority, err);
+                if attempts + 1 < MAX_ATTEMPTS {
+                    round.pending.push_back((authority, attempts + 1));
+                } else {
+                    round.ledger.failed();
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Whether the board accepts deploys: the round is waiting for its first deploy, or has not ended.
+fn is_open(board: &Board, clock: &Clock) -> bool {
+    board.end_slot == u64::MAX || (clock.slot >= board.start_slot && clock.slot < board.end_slot)
+}
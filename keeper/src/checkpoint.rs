@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+
+use oil_api::prelude::*;
+use solana_sdk::{instruction::Instruction, signature::Signer};
+
+use crate::Keeper;
+
+/// Each checkpoint uses ~200k CU, so at most 6 fit under the 1.4M CU limit.
+const MAX_BATCH_SIZE: usize = 6;
+
+/// Checkpoints miners whose last round is inside the bot-fee window (the last `TWELVE_HOURS_SLOTS`
+/// before the round expires), collecting their `checkpoint_fee`.
+///
+/// Miners are re-read on every sweep, so a miner that has since been checkpointed is skipped.
+/// Failed batches are retried on the next sweep.
+pub async fn crank(keeper: &Keeper) -> Result<(), anyhow::Error> {
+    let client = &keeper.client;
+    let payer = keeper.payer.pubkey();
+    let board = client.board().await?;
+    let clock = client.clock().await?;
+    let miners = client.miners().await?;
+
+    // Collect the miners in their bot-fee window, with the fee each one pays.
+    let mut expiry_slots: HashMap<u64, Option<u64>> = HashMap::new();
+    let mut checkpoints: Vec<(Instruction, u64)> = vec![];
+    for (_address, miner) in miners.iter() {
+        // Skip miners already checkpointed, miners in the current round, and miners with no fee.
+        if miner.checkpoint_id >= miner.round_id
+            || miner.round_id >= board.round_id
+            || miner.checkpoint_fee == 0
+        {
+            continue;
+        }
+
+        // Look up the expiry slot of the miner's round (closed rounds cannot be checkpointed).
+        if !expiry_slots.contains_key(&miner.round_id) {
+            let round = client
+                .optional_account::<Round>(round_pda(miner.round_id).0)
+                .await?;
+            expiry_slots.insert(miner.round_id, round.map(|r| r.expires_at));
+        }
+        let Some(Some(expires_at)) = expiry_slots.get(&miner.round_id) else {
+            continue;
+        };
+
+        // The fee is only paid to bots between the start of the window and the round's expiry.
+        if clock.slot < expires_at.saturating_sub(TWELVE_HOURS_SLOTS) || clock.slot >= *expires_at {
+            continue;
+        }
+        checkpoints.push((
+            oil_api::sdk::checkpoint(payer, miner.authority, miner.round_id),
+            miner.checkpoint_fee,
+        ));
+    }
+
+    // Batch and submit the instructions, continuing past failed batches.
+    let total = checkpoints.len();
+    let mut failed = 0;
+    for batch in checkpoints.chunks(MAX_BATCH_SIZE) {
+        let ixs = batch.iter().map(|(ix, _)| ix.clone()).collect::<Vec<_>>();
+        match client.submit(&keeper.payer, &ixs).await {
+            Ok(sig) => {
+                let fees = batch.iter().map(|(_, fee)| fee).sum::<u64>();
+                println!("Checkpointed {} miners ({} lamports): {}", batch.len(), fees, sig);
+                keeper.update_status(|s| {
+                    s.checkpoints += batch.len() as u64;
+                    s.checkpoint_fees += fees;
+                    s.checkpoint.last_signature = Some(sig.to_string());
+                });
+            }
+            Err(err) => {
+                eprintln!("Error submitting checkpoint batch: {:#}", err);
+                failed += batch.len();
+            }
+        }
+    }
+    if failed > 0 {
+        return Err(anyhow::anyhow!("{} of {} checkpoints failed", failed, total));
+    }
+
+    Ok(())
+}
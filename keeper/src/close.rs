@@ -0,0 +1,46 @@
+use solana_sdk::signature::Signer;
+
+use crate::Keeper;
+
+/// Rounds closed per transaction.
+const MAX_BATCH_SIZE: usize = 12;
+
+/// Closes expired rounds, returning their rent to the rent payer.
+///
+/// Rounds are re-read on every sweep, so a round that has since been closed is skipped. Failed
+/// batches are retried on the next sweep.
+pub async fn crank(keeper: &Keeper) -> Result<(), anyhow::Error> {
+    let client = &keeper.client;
+    let payer = keeper.payer.pubkey();
+    let clock = client.clock().await?;
+    let rounds = client.rounds().await?;
+
+    let ixs = rounds
+        .iter()
+        .filter(|(_, round)| round.expires_at < clock.slot)
+        .map(|(_, round)| oil_api::sdk::close(payer, round.id, round.rent_payer))
+        .collect::<Vec<_>>();
+
+    // Batch and submit the instructions, continuing past failed batches.
+    let mut failed = 0;
+    for batch in ixs.chunks(MAX_BATCH_SIZE) {
+        match client.submit(&keeper.payer, batch).await {
+            Ok(sig) => {
+                println!("Closed {} rounds: {}", batch.len(), sig);
+                keeper.update_status(|s| {
+                    s.closes += batch.len() as u64;
+                    s.close.last_signature = Some(sig.to_string());
+                });
+            }
+            Err(err) => {
+                eprintln!("Error submitting close batch: {:#}", err);
+                failed += batch.len();
+            }
+        }
+    }
+    if failed > 0 {
+        return Err(anyhow::anyhow!("{} of {} closes failed", failed, ixs.len()));
+    }
+
+    Ok(())
+}
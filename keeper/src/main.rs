@@ -0,0 +1,221 @@
+mod checkpoint;
+mod close;
+mod reset;
+mod status;
+
+use std::{
+    str::FromStr,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use oil_client::OilClient;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::signature::{read_keypair_file, Keypair, Signer};
+use status::Status;
+
+/// Delay before retrying a job after its first failure. Doubles on every consecutive failure.
+const BASE_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Long-running keeper that cranks the game: resets the board once a round's intermission has
+/// passed, checkpoints miners inside the bot-fee window, and closes expired rounds.
+///
+/// Configured through env vars:
+/// - RPC, KEYPAIR (required)
+/// - POLL_INTERVAL_MS: how often the board is polled for a reset (default 2000)
+/// - SWEEP_INTERVAL_SECS: how often miners and rounds are scanned for checkpoints and closes (default 60)
+/// - MAX_BACKOFF_SECS: cap on the retry delay of a failing job (default 60)
+/// - STATUS_ADDR: address of the status endpoint (default 127.0.0.1:3030)
+/// - ENTROPY_API: base URL of the entropy seed API
+/// - ONCE: run every job a single time and exit (e.g. from cron)
+#[tokio::main]
+async fn main() -> Result<(), anyhow::Error> {
+    let keeper = Keeper::from_env()?;
+    println!("Keeper: {}", keeper.payer.pubkey());
+
+    if keeper.settings.once {
+        let mut failed = false;
+        for job in [Job::Reset, Job::Checkpoint, Job::Close] {
+            failed |= keeper.run(job).await.is_err();
+        }
+        if failed {
+            return Err(anyhow::anyhow!("One or more jobs failed"));
+        }
+        return Ok(());
+    }
+
+    tokio::spawn(status::serve(
+        keeper.settings.status_addr.clone(),
+        keeper.status.clone(),
+    ));
+
+    let mut reset = Schedule::new(keeper.settings.poll_interval);
+    let mut checkpoint = Schedule::new(keeper.settings.sweep_interval);
+    let mut close = Schedule::new(keeper.settings.sweep_interval);
+    loop {
+        for (job, schedule) in [
+            (Job::Reset, &mut reset),
+            (Job::Checkpoint, &mut checkpoint),
+            (Job::Close, &mut close),
+        ] {
+            if !schedule.is_due(Instant::now()) {
+                continue;
+            }
+            match keeper.run(job).await {
+                Ok(()) => schedule.succeeded(Instant::now()),
+                Err(_) => {
+                    let delay = schedule.failed(Instant::now(), keeper.settings.max_backoff);
+                    println!("Retrying {:?} in {:?}", job, delay);
+                }
+            }
+        }
+
+        tokio::select! {
+            _ = tokio::time::sleep(keeper.settings.poll_interval) => {}
+            _ = tokio::signal::ctrl_c() => {
+                println!("Shutting down");
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Job {
+    Reset,
+    Checkpoint,
+    Close,
+}
+
+pub struct Settings {
+    pub poll_interval: Duration,
+    pub sweep_interval: Duration,
+    pub max_backoff: Duration,
+    pub status_addr: String,
+    pub entropy_api: String,
+    pub once: bool,
+}
+
+impl Settings {
+    fn from_env() -> Result<Self, anyhow::Error> {
+        Ok(Self {
+            poll_interval: Duration::from_millis(env_or("POLL_INTERVAL_MS", 2_000)?),
+            sweep_interval: Duration::from_secs(env_or("SWEEP_INTERVAL_SECS", 60)?),
+            max_backoff: Duration::from_secs(env_or("MAX_BACKOFF_SECS", 60)?),
+            status_addr: std::env::var("STATUS_ADDR").unwrap_or("127.0.0.1:3030".to_string()),
+            entropy_api: std::env::var("ENTROPY_API")
+                .unwrap_or("https://entropy-rng-api.up.railway.app".to_string()),
+            once: std::env::var("ONCE")
+                .map(|s| s == "1" || s.to_lowercase() == "true")
+                .unwrap_or(false),
+        })
+    }
+}
+
+/// Reads a numeric env var, falling back to a default when unset.
+fn env_or(name: &str, default: u64) -> Result<u64, anyhow::Error> {
+    match std::env::var(name) {
+        Ok(value) => u64::from_str(&value).map_err(|_| anyhow::anyhow!("Invalid {}", name)),
+        Err(_) => Ok(default),
+    }
+}
+
+pub struct Keeper {
+    pub client: OilClient,
+    pub payer: Keypair,
+    pub http: reqwest::Client,
+    pub settings: Settings,
+    pub status: Arc<Mutex<Status>>,
+}
+
+impl Keeper {
+    fn from_env() -> Result<Self, anyhow::Error> {
+        let keypair_path = std::env::var("KEYPAIR").map_err(|_| anyhow::anyhow!("Missing KEYPAIR env var"))?;
+        let payer = read_keypair_file(&keypair_path)
+            .map_err(|err| anyhow::anyhow!("Failed to read keypair {}: {}", keypair_path, err))?;
+        let rpc_url = std::env::var("RPC").map_err(|_| anyhow::anyhow!("Missing RPC env var"))?;
+        let status = Status {
+            keeper: payer.pubkey().to_string(),
+            started_at: unix_timestamp(),
+            ..Default::default()
+        };
+        Ok(Self {
+            client: OilClient::new(RpcClient::new(rpc_url)),
+            payer,
+            http: reqwest::Client::new(),
+            settings: Settings::from_env()?,
+            status: Arc::new(Mutex::new(status)),
+        })
+    }
+
+    /// Runs a job once, recording its outcome in the status.
+    async fn run(&self, job: Job) -> Result<(), anyhow::Error> {
+        let result = match job {
+            Job::Reset => reset::crank(self).await,
+            Job::Checkpoint => checkpoint::crank(self).await,
+            Job::Close => close::crank(self).await,
+        };
+        let mut status = self.status.lock().unwrap();
+        let job_status = match job {
+            Job::Reset => &mut status.reset,
+            Job::Checkpoint => &mut status.checkpoint,
+            Job::Close => &mut status.close,
+        };
+        match &result {
+            Ok(()) => job_status.succeeded(unix_timestamp()),
+            Err(err) => {
+                eprintln!("{:?} failed: {:#}", job, err);
+                job_status.failed(err);
+            }
+        }
+        result
+    }
+
+    /// Applies an update to the status.
+    pub fn update_status(&self, f: impl FnOnce(&mut Status)) {
+        f(&mut self.status.lock().unwrap());
+    }
+}
+
+/// When a job is next due, backing off exponentially while it fails.
+struct Schedule {
+    interval: Duration,
+    failures: u32,
+    next_run: Instant,
+}
+
+impl Schedule {
+    fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            failures: 0,
+            next_run: Instant::now(),
+        }
+    }
+
+    fn is_due(&self, now: Instant) -> bool {
+        now >= self.next_run
+    }
+
+    fn succeeded(&mut self, now: Instant) {
+        self.failures = 0;
+        self.next_run = now + self.interval;
+    }
+
+    /// Schedules a retry and returns its delay.
+    fn failed(&mut self, now: Instant, max_backoff: Duration) -> Duration {
+        let delay = BASE_BACKOFF
+            .saturating_mul(1 << self.failures.min(16))
+            .min(max_backoff);
+        self.failures += 1;
+        self.next_run = now + delay;
+        delay
+    }
+}
+
+fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
@@ -0,0 +1,163 @@
+use oil_api::prelude::*;
+use solana_sdk::{pubkey::Pubkey, signature::Signer};
+
+use crate::Keeper;
+
+/// Resets the board once the round has ended and the intermission has passed.
+///
+/// The entropy var is revealed in its own transaction, so the winning square (and with it the top
+/// miner) is known before the reset is submitted. Every step re-reads on-chain state first, so a
+/// retry or a competing keeper never submits work that has already landed.
+pub async fn crank(keeper: &Keeper) -> Result<(), anyhow::Error> {
+    let client = &keeper.client;
+    let payer = keeper.payer.pubkey();
+    let board = client.board().await?;
+    let clock = client.clock().await?;
+    keeper.update_status(|s| {
+        s.slot = clock.slot;
+        s.round_id = board.round_id;
+        s.end_slot = board.end_slot;
+    });
+
+    // Wait for the round to end and the intermission to pass.
+    if board.end_slot == u64::MAX || clock.slot < board.end_slot.saturating_add(INTERMISSION_SLOTS) {
+        return Ok(());
+    }
+
+    // Reveal the round's random variable, unless a previous attempt already did.
+    let config = client.config().await?;
+    let var_address = config.var_address;
+    let mut var = client.var(var_address).await?;
+    if var.value == [0; 32] {
+        let url = format!("{}/var/{}/seed", keeper.settings.entropy_api, var_address);
+        let response = keeper
+            .http
+            .get(url)
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<entropy_types::response::GetSeedResponse>()
+            .await?;
+        let sample_ix = entropy_rng_api::sdk::sample(payer, var_address);
+        let reveal_ix = entropy_rng_api::sdk::reveal(payer, var_address, response.seed);
+        let sig = client.submit(&keeper.payer, &[sample_ix, reveal_ix]).await?;
+        println!("Revealed var for round {}: {}", board.round_id, sig);
+        var = client.var(var_address).await?;
+        if var.value == [0; 32] {
+            return Err(anyhow::anyhow!("Var {} was not revealed", var_address));
+        }
+    }
+
+    // Find the top miner from the revealed value.
+    let round = client.round(board.round_id).await?;
+    let miners = client.miners_in_round(round.id).await?;
+    let miners = miners.into_iter().map(|(_, miner)| miner).collect::<Vec<_>>();
+    let top_miner = find_top_miner(&round, var.value, &miners).unwrap_or_default();
+
+    // Skip if the round was reset while the var was being revealed.
+    if client.board().await?.round_id != board.round_id {
+        return Ok(());
+    }
+    let reset_ix = oil_api::sdk::reset(
+        payer,
+        config.fee_collector,
+        board.round_id,
+        top_miner,
+        var_address,
+    );
+    match client.submit(&keeper.payer, &[reset_ix]).await {
+        Ok(sig) => {
+            println!("Reset round {} (top miner {}): {}", board.round_id, top_miner, sig);
+            keeper.update_status(|s| {
+                s.resets += 1;
+                s.reset.last_signature = Some(sig.to_string());
+            });
+            Ok(())
+        }
+        Err(err) => {
+            // Another keeper may have landed the reset first.
+            if client.board().await?.round_id != board.round_id {
+                return Ok(());
+            }
+            Err(err.into())
+        }
+    }
+}
+
+/// Returns the authority of the miner whose deployment covers the top miner sample, or `None` if
+/// the reward is split or the sample falls in the mining pool's range.
+pub fn find_top_miner(round: &Round, value: [u8; 32], miners: &[Miner]) -> Option<Pubkey> {
+    let mut round = *round;
+    round.slot_hash = value;
+    let r = round.rng()?;
+    if round.is_split_reward(r) {
+        return None;
+    }
+    let winning_square = round.winning_square(r);
+    let sample = round.top_miner_sample(r, winning_square);
+    miners
+        .iter()
+        .filter(|miner| miner.round_id == round.id)
+        .find(|miner| {
+            sample >= miner.cumulative[winning_square]
+                && sample < miner.cumulative[winning_square] + miner.deployed[winning_square]
+        })
+        .map(|miner| miner.authority)
+}
+
+#[cfg(test)]
+mod tests {
+    use steel::Zeroable;
+
+    use super::*;
+
+    fn miner(authority: Pubkey, round_id: u64, square: usize, cumulative: u64, deployed: u64) -> Miner {
+        let mut miner = Miner::zeroed();
+        miner.authority = authority;
+        miner.round_id = round_id;
+        miner.cumulative[square] = cumulative;
+        miner.deployed[square] = deployed;
+        miner
+    }
+
+    #[test]
+    fn test_find_top_miner() {
+        // Pick a value that does not split the reward.
+        let mut round = Round {
+            id: 7,
+            ..Default::default()
+        };
+        let value = (1..=u8::MAX)
+            .map(|b| {
+                let mut value = [0; 32];
+                value[0] = b;
+                value
+            })
+            .find(|value| {
+                let mut round = round;
+                round.slot_hash = *value;
+                !round.is_split_reward(round.rng().unwrap())
+            })
+            .unwrap();
+        let r = value[0] as u64;
+        let square = (r % 25) as usize;
+        round.deployed[square] = 100;
+        let sample = r.reverse_bits() % 100;
+
+        // Split the square between two miners, with a stale miner from an earlier round.
+        let (a, b, stale) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let miners = [
+            miner(stale, 6, square, 0, 100),
+            miner(a, 7, square, 0, 50),
+            miner(b, 7, square, 50, 50),
+        ];
+        let expected = if sample < 50 { a } else { b };
+        assert_eq!(find_top_miner(&round, value, &miners), Some(expected));
+
+        // No miner covers the sample when the pool holds the whole square.
+        assert_eq!(find_top_miner(&round, value, &miners[..1]), None);
+
+        // An unrevealed value has no top miner.
+        assert_eq!(find_top_miner(&round, [0; 32], &miners), None);
+    }
+}
@@ -0,0 +1,123 @@
+use std::sync::{Arc, Mutex};
+
+use serde::Serialize;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+
+/// Consecutive failures after which a job reports the keeper as unhealthy.
+const UNHEALTHY_FAILURES: u32 = 5;
+
+/// Snapshot of the keeper, served as JSON by the status endpoint.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct Status {
+    /// The keeper's signer.
+    pub keeper: String,
+
+    /// Unix timestamp at which the keeper started.
+    pub started_at: u64,
+
+    /// The last slot observed.
+    pub slot: u64,
+
+    /// The current round, as of the last observed board.
+    pub round_id: u64,
+
+    /// The slot at which the current round ends (u64::MAX until the first deploy).
+    pub end_slot: u64,
+
+    /// Rounds reset by this keeper.
+    pub resets: u64,
+
+    /// Miners checkpointed by this keeper.
+    pub checkpoints: u64,
+
+    /// Checkpoint fees earned by this keeper, in lamports.
+    pub checkpoint_fees: u64,
+
+    /// Rounds closed by this keeper.
+    pub closes: u64,
+
+    pub reset: JobStatus,
+    pub checkpoint: JobStatus,
+    pub close: JobStatus,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct JobStatus {
+    /// Number of times the job has run.
+    pub runs: u64,
+
+    /// Number of runs that failed.
+    pub failures: u64,
+
+    /// Failures since the last successful run.
+    pub consecutive_failures: u32,
+
+    /// Unix timestamp of the last successful run.
+    pub last_success_at: Option<u64>,
+
+    /// The error of the last failed run.
+    pub last_error: Option<String>,
+
+    /// The last transaction submitted by the job.
+    pub last_signature: Option<String>,
+}
+
+impl JobStatus {
+    pub fn succeeded(&mut self, now: u64) {
+        self.runs += 1;
+        self.consecutive_failures = 0;
+        self.last_success_at = Some(now);
+    }
+
+    pub fn failed(&mut self, error: &anyhow::Error) {
+        self.runs += 1;
+        self.failures += 1;
+        self.consecutive_failures += 1;
+        self.last_error = Some(format!("{:#}", error));
+    }
+}
+
+impl Status {
+    /// Whether every job has succeeded recently.
+    pub fn is_healthy(&self) -> bool {
+        [&self.reset, &self.checkpoint, &self.close]
+            .iter()
+            .all(|job| job.consecutive_failures < UNHEALTHY_FAILURES)
+    }
+}
+
+/// Serves the keeper status as JSON over HTTP, on any path. Responds with 503 while unhealthy.
+pub async fn serve(address: String, status: Arc<Mutex<Status>>) -> Result<(), anyhow::Error> {
+    let listener = TcpListener::bind(&address).await?;
+    println!("Status endpoint listening on http://{}", address);
+    loop {
+        let (mut stream, _) = listener.accept().await?;
+        let status = status.clone();
+        tokio::spawn(async move {
+            // The request itself is ignored, but must be read before responding.
+            let mut request = [0u8; 1024];
+            if stream.read(&mut request).await.is_err() {
+                return;
+            }
+            let (code, body) = {
+                let status = status.lock().unwrap();
+                let code = if status.is_healthy() {
+                    "200 OK"
+                } else {
+                    "503 Service Unavailable"
+                };
+                (code, serde_json::to_string(&*status).unwrap_or_default())
+            };
+            let response = format!(
+                "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                code,
+                body.len(),
+                body
+            );
+            let _ = stream.write_all(response.as_bytes()).await;
+        });
+    }
+}
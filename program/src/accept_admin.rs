@@ -0,0 +1,30 @@
+use oil_api::prelude::*;
+use steel::*;
+
+use crate::admin_timelock::execute_admin_action;
+
+/// Accepts a proposed admin handover, once the timelock has passed.
+pub fn process_accept_admin(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let [signer_info, config_info, timelock_accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .is_writable()?
+        .as_account_mut::<Config>(&oil_api::ID)?;
+
+    // Consume the proposal (SetAdmin data is the new admin's key).
+    execute_admin_action(
+        signer_info,
+        timelock_accounts,
+        OilInstruction::SetAdmin,
+        signer_info.key.as_ref(),
+        *signer_info.key,
+    )?;
+
+    // Set admin (an admin multisig keeps its other members and threshold).
+    config.hand_over_admin(*signer_info.key)?;
+
+    Ok(())
+}
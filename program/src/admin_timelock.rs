@@ -0,0 +1,137 @@
+use oil_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+/// Queues an admin action behind the timelock on its first call, and consumes it once the delay
+/// has passed. Returns whether the action should be applied now.
+///
+/// Accounts: pending_admin_action, system_program, board, oil_program
+pub fn admin_timelock<'a>(
+    signer_info: &AccountInfo<'a>,
+    accounts: &[AccountInfo<'a>],
+    action: OilInstruction,
+    data: &[u8],
+    target: Pubkey,
+) -> Result<bool, ProgramError> {
+    let [pending_info, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    if pending_info.data_is_empty() {
+        queue_admin_action(signer_info, accounts, action, data, target)?;
+        Ok(false)
+    } else {
+        execute_admin_action(signer_info, accounts, action, data, target)?;
+        Ok(true)
+    }
+}
+
+/// Queues an admin action, executable after `ADMIN_TIMELOCK_SECONDS`.
+///
+/// Accounts: pending_admin_action, system_program, board, oil_program
+pub fn queue_admin_action<'a>(
+    signer_info: &AccountInfo<'a>,
+    accounts: &[AccountInfo<'a>],
+    action: OilInstruction,
+    data: &[u8],
+    target: Pubkey,
+) -> ProgramResult {
+    let [pending_info, system_program, board_info, oil_program, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let action = action as u8;
+    pending_info
+        .is_writable()?
+        .has_seeds(&[PENDING_ADMIN_ACTION, &[action]], &oil_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+    board_info.is_writable()?.has_seeds(&[BOARD], &oil_api::ID)?;
+    oil_program.is_program(&oil_api::ID)?;
+
+    // Only one action of each kind can be pending (cancel it to queue another).
+    if !pending_info.data_is_empty() {
+        return Err(OilError::AdminActionMismatch.into());
+    }
+
+    let clock = Clock::get()?;
+    create_versioned_account::<PendingAdminAction>(
+        pending_info,
+        system_program,
+        signer_info,
+        &oil_api::ID,
+        &[PENDING_ADMIN_ACTION, &[action]],
+    )?;
+    let pending = pending_info.as_account_mut::<PendingAdminAction>(&oil_api::ID)?;
+    pending.action = action as u64;
+    pending.proposer = *signer_info.key;
+    pending.target = target;
+    pending.args_hash = PendingAdminAction::args_hash(action, data, &target);
+    pending.queued_at = clock.unix_timestamp;
+    pending.executable_at = clock.unix_timestamp + ADMIN_TIMELOCK_SECONDS;
+
+    sol_log(&format!(
+        "Admin action {} queued, executable from {} until {}",
+        action,
+        pending.executable_at,
+        pending.expires_at()
+    ));
+    let event = admin_action_event(OilEvent::AdminActionQueued, pending, signer_info.key, &clock);
+    program_log(&[board_info.clone(), oil_program.clone()], &event.to_bytes())?;
+
+    Ok(())
+}
+
+/// Consumes a queued admin action once the timelock has passed and before its execution window
+/// closes, refunding its rent to the signer. Expired actions must be cancelled and queued again.
+///
+/// Accounts: pending_admin_action, system_program, board, oil_program
+pub fn execute_admin_action<'a>(
+    signer_info: &AccountInfo<'a>,
+    accounts: &[AccountInfo<'a>],
+    action: OilInstruction,
+    data: &[u8],
+    target: Pubkey,
+) -> ProgramResult {
+    let [pending_info, _system_program, board_info, oil_program, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let action = action as u8;
+    pending_info
+        .is_writable()?
+        .has_seeds(&[PENDING_ADMIN_ACTION, &[action]], &oil_api::ID)?;
+    board_info.is_writable()?.has_seeds(&[BOARD], &oil_api::ID)?;
+    oil_program.is_program(&oil_api::ID)?;
+
+    let clock = Clock::get()?;
+    let pending = pending_info.as_account::<PendingAdminAction>(&oil_api::ID)?;
+    if pending.args_hash != PendingAdminAction::args_hash(action, data, &target) {
+        return Err(OilError::AdminActionMismatch.into());
+    }
+    if !pending.is_ready(clock.unix_timestamp) {
+        return Err(OilError::AdminActionTimelocked.into());
+    }
+    if pending.is_expired(clock.unix_timestamp) {
+        return Err(OilError::AdminActionExpired.into());
+    }
+    let event = admin_action_event(OilEvent::AdminActionExecuted, pending, signer_info.key, &clock);
+    pending_info.close(signer_info)?;
+    program_log(&[board_info.clone(), oil_program.clone()], &event.to_bytes())?;
+
+    Ok(())
+}
+
+/// Builds the event logged when an admin action is queued, executed or cancelled.
+pub fn admin_action_event(
+    event: OilEvent,
+    pending: &PendingAdminAction,
+    signer: &Pubkey,
+    clock: &Clock,
+) -> AdminActionEvent {
+    AdminActionEvent {
+        disc: event as u64,
+        action: pending.action,
+        signer: *signer,
+        target: pending.target,
+        args_hash: pending.args_hash,
+        executable_at: pending.executable_at,
+        ts: clock.unix_timestamp,
+    }
+}
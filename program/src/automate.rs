@@ -1,9 +1,36 @@
 use oil_api::prelude::*;
+use oil_api::fogo::{UserContext, WrappedSolAccounts};
 use solana_program::pubkey::Pubkey;
 use steel::*;
 
+use crate::pause::check_unpaused;
+
 /// Sets the executor.
-pub fn process_automate(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+pub fn process_automate<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, authority, config, automation, executor, miner, system_program, oil_program, referral (optional)
+    let [signer_info, authority_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    automate(UserContext::wallet(signer_info, authority_info, signer_info)?, config_info, accounts, data)
+}
+
+/// Sets the executor (FOGO session).
+pub fn process_automate_with_session<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, authority, program_signer, payer, config, automation, executor, miner, system_program, oil_program,
+    // user_wrapped_sol, automation_wrapped_sol, token_program, sol_mint, associated_token_program, referral (optional)
+    let [signer_info, authority_info, program_signer_info, payer_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let user = UserContext::session(signer_info, authority_info, program_signer_info, payer_info)?;
+    automate(user, config_info, accounts, data)
+}
+
+fn automate<'a>(
+    user: UserContext<'a>,
+    config_info: &'a AccountInfo<'a>,
+    accounts: &'a [AccountInfo<'a>],
+    data: &[u8],
+) -> ProgramResult {
     // Parse data.
     let args = Automate::try_from_bytes(data)?;
     let amount = u64::from_le_bytes(args.amount);
@@ -16,31 +43,40 @@ pub fn process_automate(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
     let pooled = args.pooled != 0;
     
     let has_referral = referrer != Pubkey::default();
-    let expected_len = 7 + if has_referral { 1 } else { 0 };
+    let wrapped_accounts_count = if user.is_session() { 5 } else { 0 };
+    let expected_len = 5 + wrapped_accounts_count + if has_referral { 1 } else { 0 };
     
-            if accounts.len() < expected_len {
-                return Err(ProgramError::NotEnoughAccountKeys);
-            }
+    if accounts.len() < expected_len {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
     
-            let mut accounts_iter = accounts.iter();
-    oil_api::extract_accounts!(accounts_iter, [s, a, aut, e, m, sp, op]);
-            let ref_info = if has_referral { accounts_iter.next() } else { None };
-    let (signer_info, authority_info, automation_info, executor_info, miner_info, system_program, oil_program,
-         referral_info_opt) = (s, a, aut, e, m, sp, op, ref_info);
+    let mut accounts_iter = accounts.iter();
+    oil_api::extract_accounts!(accounts_iter, [aut, e, m, sp, op]);
+    // Sessions pay with wrapped SOL (accounts precede the optional referral)
+    let wrapped = if user.is_session() {
+        Some(WrappedSolAccounts::from_iter(&mut accounts_iter)?)
+    } else {
+        None
+    };
+    let ref_info = if has_referral { accounts_iter.next() } else { None };
+    let (automation_info, executor_info, miner_info, system_program, oil_program, referral_info_opt) =
+        (aut, e, m, sp, op, ref_info);
+
+    // Closing refunds the automation balance, so a mining pause must not block it
+    check_unpaused(config_info, Automation::pause_flags(executor_info.key))?;
     
-    signer_info.is_signer()?;
     automation_info.is_writable()?;
     system_program.is_program(&system_program::ID)?;
     oil_program.is_program(&oil_api::ID)?;
     
-    let authority = *authority_info.key;
+    let authority = user.key();
     
     let is_new_miner = miner_info.data_is_empty();
     let miner = if is_new_miner {
-        create_program_account::<Miner>(
+        create_versioned_account::<Miner>(
             miner_info,
             system_program,
-            signer_info,
+            user.payer,
             &oil_api::ID,
             &[MINER, &authority.to_bytes()],
         )?;
@@ -78,16 +114,16 @@ pub fn process_automate(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
                     OilError::NotAuthorized.into(),
                 )?;
 
-            automation_info.close(authority_info)?;
+            automation_info.close(user.authority)?;
         }
         return Ok(());
     }
 
     let automation = if automation_info.data_is_empty() {
-        create_program_account::<Automation>(
+        create_versioned_account::<Automation>(
             automation_info,
             system_program,
-            signer_info,
+            user.payer,
             &oil_api::ID,
             &[AUTOMATION, &authority.to_bytes()],
         )?;
@@ -104,6 +140,11 @@ pub fn process_automate(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
             )?
     };
 
+    // Some strategies are unlocked by the miner's rig level.
+    if miner.level < strategy.min_level() {
+        return Err(OilError::LevelTooLow.into());
+    }
+
     // Set strategy and mask.
     automation.amount = amount;
     automation.balance += deposit;
@@ -114,12 +155,23 @@ pub fn process_automate(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
     automation.reload = reload as u64;
     automation.pooled = pooled as u64;
 
-    if miner.checkpoint_fee == 0 {
-        miner.checkpoint_fee = CHECKPOINT_FEE;
-            miner_info.collect(CHECKPOINT_FEE, signer_info)?;
-        }
-
-        automation_info.collect(deposit, signer_info)?;
+    // Fund the automation with the deposit, plus the miner's checkpoint fee if not yet paid.
+    let checkpoint_fee = if miner.checkpoint_fee == 0 {
+        miner.checkpoint_fee = rig_checkpoint_fee(miner.level);
+        miner.checkpoint_fee
+    } else {
+        0
+    };
+    user.pay(
+        deposit + checkpoint_fee,
+        automation_info,
+        &[AUTOMATION, &authority.to_bytes()],
+        system_program,
+        wrapped.as_ref(),
+    )?;
+    if checkpoint_fee > 0 {
+        automation_info.send(checkpoint_fee, miner_info);
+    }
 
     Ok(())
 }
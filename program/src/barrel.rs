@@ -1,12 +1,9 @@
 use oil_api::prelude::*;
 use solana_program::log::sol_log;
-use solana_program::pubkey;
 use spl_token::amount_to_ui_amount;
 use steel::*;
 
-const LIQ_MANAGER: Pubkey = pubkey!("DEvGq2WVuA3qkSCtwwuMYThY4onkJunEHSAxU5cieph8");
-
-/// Barrel (burn) leftover OIL from the liq manager.
+/// Barrel (burn) leftover OIL held by the treasury for liquidity.
 /// Burns 100% of the OIL.
 pub fn process_barrel(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
     // Parse data.
@@ -14,15 +11,15 @@ pub fn process_barrel(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
     let requested_amount = u64::from_le_bytes(args.amount);
 
     // Load accounts.
-    let [signer_info, sender_info, board_info, mint_info, treasury_info, treasury_oil_info, token_program, oil_program] =
+    let [signer_info, board_info, config_info, mint_info, treasury_info, treasury_oil_info, token_program, oil_program, ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
-    signer_info.is_signer()?.has_address(&LIQ_MANAGER)?;
-    let sender = sender_info
-        .is_writable()?
-        .as_associated_token_account(signer_info.key, &MINT_ADDRESS)?;
+    signer_info.is_signer()?;
+    config_info
+        .as_account::<Config>(&oil_api::ID)?
+        .check_role(Role::LiquidityManager, signer_info, accounts)?;
     board_info.as_account_mut::<Board>(&oil_api::ID)?;
     mint_info.has_address(&MINT_ADDRESS)?; // Verify mint address
     let treasury = treasury_info.as_account_mut::<Treasury>(&oil_api::ID)?;
@@ -32,21 +29,12 @@ pub fn process_barrel(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
 
     // Determine amount to barrel (use all if amount is 0, otherwise use requested amount)
     let amount = if requested_amount == 0 {
-        sender.amount()
+        treasury.liquidity_oil
     } else {
-        sender.amount().min(requested_amount)
+        treasury.liquidity_oil.min(requested_amount)
     };
     assert!(amount > 0, "No OIL to barrel");
 
-    // Transfer OIL from sender (LIQ_MANAGER) to treasury.
-    transfer(
-        signer_info,
-        sender_info,
-        treasury_oil_info,
-        token_program,
-        amount,
-    )?;
-
     // Burn all OIL (no sharing with stakers).
     let burn_amount = amount;
     burn_signed(
@@ -66,7 +54,8 @@ pub fn process_barrel(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
         .as_str(),
     );
 
-    // Update total_barrelled
+    // Update liquidity OIL and total_barrelled
+    treasury.liquidity_oil -= burn_amount;
     treasury.total_barrelled = treasury.total_barrelled.saturating_add(burn_amount);
 
     // Emit event.
@@ -77,7 +66,7 @@ pub fn process_barrel(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
             disc: 1,
             oil_barreled: burn_amount,
             oil_shared: 0, // No sharing with stakers
-            sol_amount: 0, // No SOL involved in barrel from liq manager
+            sol_amount: 0, // No SOL involved in barrel of liquidity OIL
             new_circulating_supply: mint.supply(),
             ts: Clock::get()?.unix_timestamp,
         }
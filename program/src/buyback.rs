@@ -8,12 +8,15 @@ use steel::*;
 pub fn process_buyback(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
     // Load accounts.
     let (oil_accounts, swap_accounts) = accounts.split_at(9);
-    let [signer_info, board_info, _config_info, mint_info, treasury_info, treasury_oil_info, treasury_sol_info, token_program, oil_program] =
+    let [signer_info, board_info, config_info, mint_info, treasury_info, treasury_oil_info, treasury_sol_info, token_program, oil_program] =
         oil_accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
-    signer_info.is_signer()?.has_address(&BURY_AUTHORITY)?;
+    signer_info.is_signer()?;
+    config_info
+        .as_account::<Config>(&oil_api::ID)?
+        .check_role(Role::TreasuryOperator, signer_info, accounts)?;
     board_info.as_account_mut::<Board>(&oil_api::ID)?;
     let oil_mint = mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
     let treasury = treasury_info.as_account_mut::<Treasury>(&oil_api::ID)?;
@@ -31,7 +34,10 @@ pub fn process_buyback(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
         treasury_sol_info.as_associated_token_account(treasury_info.key, &SOL_MINT)?;
     let pre_swap_oil_balance = treasury_oil.amount();
     let pre_swap_sol_balance = treasury_sol.amount();
-    assert!(pre_swap_sol_balance > 0);
+
+    // Wrapped SOL held for liquidity is not swapped.
+    let swap_sol_amount = pre_swap_sol_balance.saturating_sub(treasury.liquidity_sol);
+    assert!(swap_sol_amount > 0);
 
     // Record pre-swap mint supply.
     let pre_swap_mint_supply = oil_mint.supply();
@@ -100,12 +106,12 @@ pub fn process_buyback(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
     let post_swap_oil_balance = treasury_oil.amount();
     let post_swap_sol_balance = treasury_sol.amount();
     let total_oil = post_swap_oil_balance - pre_swap_oil_balance;
-    assert_eq!(post_swap_sol_balance, 0);
+    assert_eq!(post_swap_sol_balance, treasury.liquidity_sol);
     assert!(post_swap_oil_balance >= pre_swap_oil_balance);
     sol_log(
         &format!(
             "📈 Swapped {} SOL into {} OIL",
-            lamports_to_sol(pre_swap_sol_balance),
+            lamports_to_sol(swap_sol_amount),
             amount_to_ui_amount(total_oil, TOKEN_DECIMALS),
         )
         .as_str(),
@@ -141,7 +147,7 @@ pub fn process_buyback(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
             disc: 1,
             oil_barreled: burn_amount,
             oil_shared: 0, // No longer sharing OIL - all burned for deflation
-            sol_amount: pre_swap_sol_balance,
+            sol_amount: swap_sol_amount,
             new_circulating_supply: mint.supply(),
             ts: Clock::get()?.unix_timestamp,
         }
@@ -0,0 +1,37 @@
+use oil_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+use crate::admin_timelock::admin_action_event;
+
+/// Cancels a queued admin action, refunding its rent to the admin.
+pub fn process_cancel_admin_action(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = CancelAdminAction::try_from_bytes(data)?;
+    let action = args.action;
+
+    // Load accounts.
+    let [signer_info, config_info, pending_info, board_info, oil_program, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    config_info
+        .as_account::<Config>(&oil_api::ID)?
+        .check_role(Role::Admin, signer_info, accounts)?;
+    let pending = pending_info
+        .is_writable()?
+        .has_seeds(&[PENDING_ADMIN_ACTION, &[action]], &oil_api::ID)?
+        .as_account::<PendingAdminAction>(&oil_api::ID)?;
+    board_info.is_writable()?.has_seeds(&[BOARD], &oil_api::ID)?;
+    oil_program.is_program(&oil_api::ID)?;
+
+    // Close the pending action.
+    let clock = Clock::get()?;
+    let event = admin_action_event(OilEvent::AdminActionCancelled, pending, signer_info.key, &clock);
+    pending_info.close(signer_info)?;
+    program_log(&[board_info.clone(), oil_program.clone()], &event.to_bytes())?;
+
+    sol_log(&format!("Admin action {} cancelled", action));
+
+    Ok(())
+}
@@ -1,16 +1,36 @@
 use oil_api::prelude::*;
+use oil_api::fogo::UserContext;
 use solana_program::{log::sol_log, native_token::lamports_to_sol, rent::Rent};
 use spl_token::amount_to_ui_amount;
 use steel::*;
 
+use crate::pause::check_unpaused;
+
 /// Checkpoints a miner's rewards.
-pub fn process_checkpoint(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+pub fn process_checkpoint<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, board, config, miner, round, treasury, system_program
+    let [signer_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    checkpoint(UserContext::wallet(signer_info, signer_info, signer_info)?, accounts, data)
+}
+
+/// Checkpoints a miner's rewards (FOGO session).
+pub fn process_checkpoint_with_session<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, authority, program_signer, board, config, miner, round, treasury, system_program
+    let [signer_info, authority_info, program_signer_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    checkpoint(UserContext::session(signer_info, authority_info, program_signer_info, signer_info)?, accounts, data)
+}
+
+fn checkpoint<'a>(user: UserContext<'a>, accounts: &'a [AccountInfo<'a>], _data: &[u8]) -> ProgramResult {
     let clock = Clock::get()?;
-    let [signer_info, board_info, config_info, miner_info, round_info, treasury_info, system_program] = accounts
+    let [board_info, config_info, miner_info, round_info, treasury_info, system_program] = accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
-    signer_info.is_signer()?;
+    check_unpaused(config_info, PAUSE_MINING)?;
     
     // Allow anyone to checkpoint (like ORE) - signer can collect bot fee, rewards go to miner
     let board = board_info.as_account::<Board>(&oil_api::ID)?;
@@ -93,14 +113,13 @@ pub fn process_checkpoint(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
 
             // Calculate OIL rewards.
             if round.top_miner == SPLIT_ADDRESS {
-                // If round is split, split the reward evenly among all miners.
-                rewards_oil = ((round.top_miner_reward as u128
-                    * miner.deployed[winning_square] as u128)
-                    / round.deployed[winning_square] as u128) as u64;
+                // If round is split, split the reward among all miners, weighted by their stake boost.
+                rewards_oil = round.split_oil(winning_square, miner);
                 sol_log(
                     &format!(
-                        "Split rewards: {} OIL",
-                        amount_to_ui_amount(rewards_oil, TOKEN_DECIMALS)
+                        "Split rewards: {} OIL ({} bps stake boost)",
+                        amount_to_ui_amount(rewards_oil, TOKEN_DECIMALS),
+                        miner.stake_boost_bps
                     )
                     .as_str(),
                 );
@@ -178,7 +197,11 @@ pub fn process_checkpoint(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
                 );
                 // Pool OIL share (from pool_rewards_oil calculated in reset)
                 if round.pool_rewards_oil > 0 {
-                    let pool_oil_share = ((round.pool_rewards_oil as u128 * miner_pool_share) / total_pool) as u64;
+                    let pool_oil_share = if round.top_miner == SPLIT_ADDRESS {
+                        round.pool_split_oil(miner)
+                    } else {
+                        ((round.pool_rewards_oil as u128 * miner_pool_share) / total_pool) as u64
+                    };
                     sol_log(
                         &format!(
                             "Pool share: {} OIL",
@@ -251,7 +274,7 @@ pub fn process_checkpoint(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
     }
     
     if bot_fee > 0 {
-        miner_info.send(bot_fee, &signer_info);
+        miner_info.send(bot_fee, user.signer);
     }
 
     // Assert miner account has sufficient funds for rent and rewards.
@@ -1,11 +1,31 @@
 use oil_api::prelude::*;
 use oil_api::instruction::CheckpointAuction;
+use oil_api::fogo::UserContext;
 use solana_program::{log::sol_log, native_token::lamports_to_sol};
 use steel::*;
 
+use crate::pause::check_unpaused;
+
 /// User checkpoints their auction rewards for multiple wells/epochs
 /// Supports batch checkpointing: well_mask allows checkpointing multiple wells in a single instruction
-pub fn process_checkpoint_auction(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+pub fn process_checkpoint_auction<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, authority, config, miner, [share, micro, well for each well], oil_program
+    let [signer_info, authority_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    checkpoint_auction(UserContext::wallet(signer_info, authority_info, signer_info)?, accounts, data)
+}
+
+/// User checkpoints their auction rewards for multiple wells/epochs (Fogo session)
+pub fn process_checkpoint_auction_with_session<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, authority, program_signer, config, miner, [share, micro, well for each well], oil_program
+    let [signer_info, authority_info, program_signer_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    checkpoint_auction(UserContext::session(signer_info, authority_info, program_signer_info, signer_info)?, accounts, data)
+}
+
+fn checkpoint_auction<'a>(user: UserContext<'a>, accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
     let args = CheckpointAuction::try_from_bytes(data)?;
     let well_mask = args.well_mask;
     
@@ -18,18 +38,17 @@ pub fn process_checkpoint_auction(accounts: &[AccountInfo<'_>], data: &[u8]) ->
         return Err(ProgramError::InvalidArgument); // Invalid well_mask
     }
     
-    // Account order: signer, authority, miner, [share, micro, well for each well], oil_program
-    let expected_len = 3 + (num_wells * 3) + 1; // 3 base + 3 per well + 1 program
+    let expected_len = 2 + (num_wells * 3) + 1; // config + miner + 3 per well + 1 program
     if accounts.len() < expected_len {
         return Err(ProgramError::NotEnoughAccountKeys);
     }
     
     let mut accounts_iter = accounts.iter();
-    oil_api::extract_accounts!(accounts_iter, [s, a, m]);
-    let (signer_info, authority_info, miner_info) = (s, a, m);
+    oil_api::extract_accounts!(accounts_iter, [config_info, m]);
+    check_unpaused(config_info, PAUSE_AUCTION)?;
+    let miner_info = m;
     
-    signer_info.is_signer()?;
-    let authority = *authority_info.key;
+    let authority = user.key();
     
     // Validate miner account
     miner_info.is_writable()?.has_seeds(&[MINER, &authority.to_bytes()], &oil_api::ID)?;
@@ -62,7 +81,9 @@ pub fn process_checkpoint_auction(accounts: &[AccountInfo<'_>], data: &[u8]) ->
         }
         
         // Check if user was a contributor (has Share account) or operator (no Share account)
-        let is_contributor = !share_info.data_is_empty();
+        // A fully withdrawn contribution has no rewards, so it is checkpointed like an operator
+        let is_contributor = !share_info.data_is_empty()
+            && share_info.as_account::<Share>(&oil_api::ID)?.contribution > 0;
         
         if is_contributor {
             // User was a contributor - process Share rewards
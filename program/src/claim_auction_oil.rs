@@ -1,31 +1,58 @@
 use oil_api::prelude::*;
+use oil_api::fogo::UserContext;
 use solana_program::log::sol_log;
 use spl_token::amount_to_ui_amount;
 use steel::*;
 
+use crate::pause::check_unpaused;
+
 /// Claim auction-based OIL rewards
-pub fn process_claim_auction_oil(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+pub fn process_claim_auction_oil<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, config, miner, well_0, well_1, well_2, well_3, auction, treasury, treasury_tokens, mint,
+    // mint_authority, mint_program, recipient, token_program, associated_token_program, system_program, oil_program,
+    // [miner_referrer, referral_referrer, referral_referrer_oil_ata, referral_config, [upline_referral, upline_referral_oil_ata]]
+    let [signer_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_CLAIMS)?;
+    claim_auction_oil(UserContext::wallet(signer_info, signer_info, signer_info)?, config_info, accounts, data)
+}
+
+/// Claim auction-based OIL rewards (FOGO session)
+pub fn process_claim_auction_oil_with_session<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, authority, program_signer, payer, config, miner, well_0, well_1, well_2, well_3, auction, treasury,
+    // treasury_tokens, mint, mint_authority, mint_program, recipient, token_program, associated_token_program,
+    // system_program, oil_program, [miner_referrer, referral_referrer, referral_referrer_oil_ata, referral_config,
+    // [upline_referral, upline_referral_oil_ata]]
+    let [signer_info, authority_info, program_signer_info, payer_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_CLAIMS)?;
+    claim_auction_oil(UserContext::session(signer_info, authority_info, program_signer_info, payer_info)?, config_info, accounts, data)
+}
+
+fn claim_auction_oil<'a>(
+    user: UserContext<'a>,
+    config_info: &'a AccountInfo<'a>,
+    accounts: &'a [AccountInfo<'a>],
+    data: &[u8],
+) -> ProgramResult {
     let clock = Clock::get()?;
     let args = ClaimAuctionOIL::try_from_bytes(data)?;
     let well_mask = args.well_mask; // Which wells to claim OIL from (0-3)
 
-    // Minimum accounts required (without referral)
-    if accounts.len() < 18 {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    }
-
     // Destructure base accounts
-    let [signer_info, miner_info, well_0_info, well_1_info, well_2_info, well_3_info, auction_info, treasury_info, treasury_tokens_info, mint_info, mint_authority_info, mint_program, recipient_info, token_program, associated_token_program, system_program, oil_program] =
-        &accounts[0..17]
+    let [miner_info, well_0_info, well_1_info, well_2_info, well_3_info, auction_info, treasury_info, treasury_tokens_info, mint_info, mint_authority_info, mint_program, recipient_info, token_program, associated_token_program, system_program, oil_program] =
+        accounts.get(0..16).ok_or(ProgramError::NotEnoughAccountKeys)?
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
-    signer_info.is_signer()?;
+    let authority = user.key();
     
     let miner = miner_info
         .as_account_mut::<Miner>(&oil_api::ID)?
-        .assert_mut(|d| d.authority == *signer_info.key)?;
+        .assert_mut(|d| d.authority == authority)?;
     
     // Cooldown check: prevent spam claims
     if miner.last_claim_auction_oil_at > 0 {
@@ -36,6 +63,7 @@ pub fn process_claim_auction_oil(accounts: &[AccountInfo<'_>], data: &[u8]) -> P
         }
     }
     
+    let config = config_info.as_account::<Config>(&oil_api::ID)?;
     let auction = auction_info.as_account_mut::<Auction>(&oil_api::ID)?;
     let treasury = treasury_info.as_account_mut::<Treasury>(&oil_api::ID)?;
     mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
@@ -51,8 +79,8 @@ pub fn process_claim_auction_oil(accounts: &[AccountInfo<'_>], data: &[u8]) -> P
     // Create recipient OIL ATA if it doesn't exist
     if recipient_info.data_is_empty() {
         create_associated_token_account(
-            signer_info,
-            signer_info,
+            user.payer,
+            user.authority,
             recipient_info,
             mint_info,
             system_program,
@@ -60,7 +88,7 @@ pub fn process_claim_auction_oil(accounts: &[AccountInfo<'_>], data: &[u8]) -> P
             associated_token_program,
         )?;
     } else {
-        recipient_info.as_associated_token_account(signer_info.key, mint_info.key)?;
+        recipient_info.as_associated_token_account(&authority, mint_info.key)?;
     }
 
     miner.update_auction_rewards(treasury);
@@ -89,7 +117,7 @@ pub fn process_claim_auction_oil(accounts: &[AccountInfo<'_>], data: &[u8]) -> P
         well.check_and_apply_halving(auction, &clock);
 
         // Only solo owners can claim
-        let is_solo_owner = well.current_bidder == *signer_info.key;
+        let is_solo_owner = well.current_bidder == authority;
 
         if is_solo_owner {
             // Solo owner: get full accumulated OIL
@@ -127,10 +155,11 @@ pub fn process_claim_auction_oil(accounts: &[AccountInfo<'_>], data: &[u8]) -> P
             treasury.auction_total_unclaimed += current_ownership_oil;
         }
 
-        // Apply refining fees (10% fee, shared with other auction miners)
+        // Apply refining fees (shared with other auction miners)
         claimable_oil = total_auction_oil;
         if treasury.auction_total_unclaimed > 0 && total_auction_oil > 0 {
-            refining_fee = total_auction_oil / 10;
+            refining_fee = (total_auction_oil as u128 * config.auction_refining_fee_bps as u128
+                / DENOMINATOR_BPS as u128) as u64;
             claimable_oil -= refining_fee;
             treasury.auction_rewards_factor += Numeric::from_fraction(refining_fee, treasury.auction_total_unclaimed);
             treasury.auction_total_refined += refining_fee;
@@ -148,28 +177,14 @@ pub fn process_claim_auction_oil(accounts: &[AccountInfo<'_>], data: &[u8]) -> P
         
         miner.auction_rewards_factor = treasury.auction_rewards_factor;
 
-        let referral_amount = if miner.referrer != Pubkey::default() {
-            if accounts.len() < 19 {
-                return Err(ProgramError::NotEnoughAccountKeys);
-            }
-            
-            let miner_referrer_idx = 17;
-            let miner_referrer_info = &accounts[miner_referrer_idx];
-            miner_referrer_info
-                .has_seeds(&[MINER, &miner.referrer.to_bytes()], &oil_api::ID)?;
-            
-            let referral_referrer_idx = 18;
-            let referral_referrer_info = &accounts[referral_referrer_idx];
-            referral_referrer_info
-                .has_seeds(&[REFERRAL, &miner.referrer.to_bytes()], &oil_api::ID)?;
-            
-            let referral_referrer = referral_referrer_info
-                .as_account_mut::<Referral>(&oil_api::ID)?;
-            
-            referral_referrer.credit_oil_referral(claimable_oil)
+        let referral = if miner.referrer != Pubkey::default() {
+            let referral = ReferralAccounts::load(&accounts[16..], miner.referrer, authority, true)?;
+            let split = referral.credit_oil(claimable_oil, &clock)?;
+            Some((referral, split))
         } else {
-            0
+            None
         };
+        let referral_amount = referral.as_ref().map_or(0, |(_, split)| split.total());
 
         signer_amount = claimable_oil.saturating_sub(referral_amount);
 
@@ -185,41 +200,18 @@ pub fn process_claim_auction_oil(accounts: &[AccountInfo<'_>], data: &[u8]) -> P
             )?;
         }
         
-        // Transfer referral OIL directly to referral account's OIL ATA
-        if referral_amount > 0 {
-            let referral_referrer_info = &accounts[18];
-            let referral_referrer_oil_ata_info = &accounts[19];
-                        
-            // Create referral OIL ATA if it doesn't exist
-            if referral_referrer_oil_ata_info.data_is_empty() {
-                create_associated_token_account(
-                    signer_info,
-                    referral_referrer_info,
-                    referral_referrer_oil_ata_info,
-                    mint_info,
-                    system_program,
-                    token_program,
-                    associated_token_program,
-                )?;
-            } else {
-                referral_referrer_oil_ata_info.as_associated_token_account(referral_referrer_info.key, mint_info.key)?;
-            }
-                        
-            // Transfer OIL from treasury to referral account's OIL ATA
-            transfer_signed(
+        // Transfer referral OIL directly to the referral accounts' OIL ATAs
+        if let Some((referral, split)) = referral {
+            referral.send_oil(
                 treasury_info,
                 treasury_tokens_info,
-                referral_referrer_oil_ata_info,
+                user.payer,
+                mint_info,
+                system_program,
                 token_program,
-                referral_amount,
-                &[TREASURY],
+                associated_token_program,
+                split,
             )?;
-        
-            sol_log(&format!(
-                "Referral bonus: {} OIL to {}",
-                amount_to_ui_amount(referral_amount, TOKEN_DECIMALS),
-                miner.referrer
-            ));
         }
     }
 
@@ -228,7 +220,7 @@ pub fn process_claim_auction_oil(accounts: &[AccountInfo<'_>], data: &[u8]) -> P
         &[auction_info.clone(), oil_program.clone()],
         ClaimAuctionOILEvent {
             disc: 6,
-            authority: *signer_info.key,
+            authority,
             oil_claimed: signer_amount,
             refining_fee,
             ts: clock.unix_timestamp as u64,
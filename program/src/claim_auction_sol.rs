@@ -1,29 +1,48 @@
 use oil_api::prelude::*;
+use oil_api::fogo::UserContext;
 use solana_program::{log::sol_log, native_token::lamports_to_sol};
 use steel::*;
 
+use crate::pause::check_unpaused;
+
 /// Claim auction-based SOL rewards
-pub fn process_claim_auction_sol(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
-    let clock = Clock::get()?;
+pub fn process_claim_auction_sol<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, config, miner, treasury, auction, system_program, oil_program, [miner_referrer, referral_referrer,
+    // referral_config, [upline_referral]]
+    let [signer_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_CLAIMS)?;
+    claim_auction_sol(UserContext::wallet(signer_info, signer_info, signer_info)?, accounts, data)
+}
 
-    // Minimum accounts required (without referral)
-    if accounts.len() < 7 {
+/// Claim auction-based SOL rewards (FOGO session)
+pub fn process_claim_auction_sol_with_session<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, authority, program_signer, payer, config, miner, treasury, auction, system_program, oil_program,
+    // [miner_referrer, referral_referrer, referral_config, [upline_referral]]
+    let [signer_info, authority_info, program_signer_info, payer_info, config_info, accounts @ ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
-    }
+    };
+    check_unpaused(config_info, PAUSE_CLAIMS)?;
+    claim_auction_sol(UserContext::session(signer_info, authority_info, program_signer_info, payer_info)?, accounts, data)
+}
+
+fn claim_auction_sol<'a>(user: UserContext<'a>, accounts: &'a [AccountInfo<'a>], _data: &[u8]) -> ProgramResult {
+    let clock = Clock::get()?;
 
     // Destructure base accounts
-    let [signer_info, miner_info, treasury_info, auction_info, system_program, oil_program] =
-        &accounts[0..6]
+    let [miner_info, treasury_info, auction_info, system_program, oil_program] =
+        accounts.get(0..5).ok_or(ProgramError::NotEnoughAccountKeys)?
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
-    signer_info.is_signer()?;
-    signer_info.is_writable()?;
+    user.authority.is_writable()?;
+    let authority = user.key();
     
     let miner = miner_info
         .as_account_mut::<Miner>(&oil_api::ID)?
-        .assert_mut(|d| d.authority == *signer_info.key)?;
+        .assert_mut(|d| d.authority == authority)?;
     let treasury = treasury_info.as_account_mut::<Treasury>(&oil_api::ID)?;
     system_program.is_program(&system_program::ID)?;
     oil_program.is_program(&oil_api::ID)?;
@@ -33,58 +52,30 @@ pub fn process_claim_auction_sol(accounts: &[AccountInfo<'_>], _data: &[u8]) ->
     miner.auction_rewards_sol = 0;
 
     // ENFORCE referral rewards: If miner has a referrer, require referral accounts to be provided.
-    let referral_amount = if miner.referrer != Pubkey::default() {
-        // Require at least 8 accounts (base 6 + miner_referrer + referral_referrer) if miner has referrer
-        if accounts.len() < 8 {
-            return Err(ProgramError::NotEnoughAccountKeys);
-        }
-
-        // Validate referrer's miner account
-        let miner_referrer_idx = 6;
-        let miner_referrer_info = &accounts[miner_referrer_idx];
-        miner_referrer_info
-            .has_seeds(&[MINER, &miner.referrer.to_bytes()], &oil_api::ID)?;
-
-        // Validate referrer's referral account
-        let referral_referrer_idx = 7;
-        let referral_referrer_info = &accounts[referral_referrer_idx];
-        referral_referrer_info
-            .has_seeds(&[REFERRAL, &miner.referrer.to_bytes()], &oil_api::ID)?;
-        
-        // Get referral account and calculate/credit referral bonus
-        let referral_referrer = referral_referrer_info
-            .as_account_mut::<Referral>(&oil_api::ID)?;
-        
-        // Calculate and credit referral bonus (0.5% of total claim)
-        referral_referrer.credit_sol_referral(total_sol_claimed)
+    let referral = if miner.referrer != Pubkey::default() {
+        let referral = ReferralAccounts::load(&accounts[5..], miner.referrer, authority, false)?;
+        let split = referral.credit_sol(total_sol_claimed, &clock)?;
+        Some((referral, split))
     } else {
-        0
+        None
     };
+    let referral_amount = referral.as_ref().map_or(0, |(_, split)| split.total());
 
     // Calculate amount to send to authority (after referral deduction)
     let authority_amount = total_sol_claimed.saturating_sub(referral_amount);
 
-    // Transfer authority's portion from treasury to signer (user's wallet)
+    // Transfer authority's portion from treasury to the authority (user's wallet)
     if authority_amount > 0 {
-        treasury_info.send(authority_amount, signer_info);
+        treasury_info.send(authority_amount, user.authority);
         // Subtract from treasury.auction_rewards_sol (tracked separately from treasury.balance)
         treasury.auction_rewards_sol = treasury.auction_rewards_sol.saturating_sub(authority_amount);
     }
     
-    // Transfer referral SOL directly to referral account PDA from treasury
-    if referral_amount > 0 {
-        let referral_referrer_info = &accounts[7];
-        
-        // Transfer SOL from treasury to referral account
-        treasury_info.send(referral_amount, referral_referrer_info);
+    // Transfer referral SOL directly to the referral account PDAs from treasury
+    if let Some((referral, split)) = referral {
+        referral.send_sol(treasury_info, split);
         // Subtract from treasury.auction_rewards_sol
         treasury.auction_rewards_sol = treasury.auction_rewards_sol.saturating_sub(referral_amount);
-        
-        sol_log(&format!(
-            "Referral bonus: {} SOL to {}",
-            lamports_to_sol(referral_amount),
-            miner.referrer
-        ));
     }
 
     // Update miner timestamps and lifetime stats
@@ -101,7 +92,7 @@ pub fn process_claim_auction_sol(accounts: &[AccountInfo<'_>], _data: &[u8]) ->
         &[auction_info.clone(), oil_program.clone()],
         ClaimAuctionSOLEvent {
             disc: 7,
-            authority: *signer_info.key,
+            authority,
             sol_claimed: total_sol_claimed,
             rewards_sol,
             refunds_sol: 0,
@@ -1,32 +1,83 @@
 use oil_api::prelude::*;
+use oil_api::fogo::UserContext;
 use solana_program::log::sol_log;
 use spl_token::amount_to_ui_amount;
 use steel::*;
 
-/// Claims OIL rewards with single-tier referral system.
-pub fn process_claim_oil(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+use crate::pause::check_unpaused;
+
+/// Claims OIL rewards with tiered referral system.
+pub fn process_claim_oil<'a>(accounts: &'a [AccountInfo<'a>], _data: &[u8]) -> ProgramResult {
+    // Account order: signer, config, miner, mint, recipient, treasury, treasury_tokens, system_program, token_program,
+    // associated_token_program, [miner_referrer, referral_referrer, referral_referrer_oil_ata, referral_config,
+    // [upline_referral, upline_referral_oil_ata]]
+    let [signer_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_CLAIMS)?;
+    claim_oil(UserContext::wallet(signer_info, signer_info, signer_info)?, config_info, accounts, false)
+}
+
+/// Claims OIL rewards with tiered referral system (FOGO session)
+pub fn process_claim_oil_with_session<'a>(accounts: &'a [AccountInfo<'a>], _data: &[u8]) -> ProgramResult {
+    // Account order: signer, authority, program_signer, payer, config, miner, mint, recipient, treasury, treasury_tokens,
+    // system_program, token_program, associated_token_program, [miner_referrer, referral_referrer, referral_referrer_oil_ata,
+    // referral_config, [upline_referral, upline_referral_oil_ata]]
+    let [signer_info, authority_info, program_signer_info, payer_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_CLAIMS)?;
+    claim_oil(UserContext::session(signer_info, authority_info, program_signer_info, payer_info)?, config_info, accounts, false)
+}
+
+/// Claims OIL rewards through the vesting schedule, without a refining fee.
+pub fn process_vest_oil<'a>(accounts: &'a [AccountInfo<'a>], _data: &[u8]) -> ProgramResult {
+    // Account order: same as ClaimOIL.
+    let [signer_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_CLAIMS)?;
+    claim_oil(UserContext::wallet(signer_info, signer_info, signer_info)?, config_info, accounts, true)
+}
+
+/// Claims OIL rewards through the vesting schedule, without a refining fee (FOGO session)
+pub fn process_vest_oil_with_session<'a>(accounts: &'a [AccountInfo<'a>], _data: &[u8]) -> ProgramResult {
+    // Account order: same as ClaimOILWithSession.
+    let [signer_info, authority_info, program_signer_info, payer_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_CLAIMS)?;
+    claim_oil(UserContext::session(signer_info, authority_info, program_signer_info, payer_info)?, config_info, accounts, true)
+}
+
+fn claim_oil<'a>(
+    user: UserContext<'a>,
+    config_info: &'a AccountInfo<'a>,
+    accounts: &'a [AccountInfo<'a>],
+    vest: bool,
+) -> ProgramResult {
     // Load accounts.
     let clock = Clock::get()?;
     
-    if accounts.len() < 9 {
+    if accounts.len() < 8 {
         return Err(ProgramError::NotEnoughAccountKeys);
     }
     
-    let signer_info = &accounts[0];
-    let miner_info = &accounts[1];
-    let mint_info = &accounts[2];
-    let recipient_info = &accounts[3];
-    let treasury_info = &accounts[4];
-    let treasury_tokens_info = &accounts[5];
-    let system_program = &accounts[6];
-    let token_program = &accounts[7];
-    let associated_token_program = &accounts[8];
+    let miner_info = &accounts[0];
+    let mint_info = &accounts[1];
+    let recipient_info = &accounts[2];
+    let treasury_info = &accounts[3];
+    let treasury_tokens_info = &accounts[4];
+    let system_program = &accounts[5];
+    let token_program = &accounts[6];
+    let associated_token_program = &accounts[7];
     
-    signer_info.is_signer()?;
+    let authority = user.key();
     
+    let config = config_info.as_account::<Config>(&oil_api::ID)?;
     let miner = miner_info
         .as_account_mut::<Miner>(&oil_api::ID)?
-        .assert_mut(|m| m.authority == *signer_info.key)?;
+        .assert_mut(|m| m.authority == authority)?;
     mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
     recipient_info.is_writable()?;
     let treasury = treasury_info.as_account_mut::<Treasury>(&oil_api::ID)?;
@@ -38,8 +89,8 @@ pub fn process_claim_oil(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramR
     // Load recipient.
     if recipient_info.data_is_empty() {
         create_associated_token_account(
-            signer_info,
-            signer_info,
+            user.payer,
+            user.authority,
             recipient_info,
             mint_info,
             system_program,
@@ -47,40 +98,27 @@ pub fn process_claim_oil(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramR
             associated_token_program,
         )?;
     } else {
-        recipient_info.as_associated_token_account(signer_info.key, mint_info.key)?;
+        recipient_info.as_associated_token_account(&authority, mint_info.key)?;
     }
 
-    // Calculate total claimable amount.
-    let total_amount = miner.claim_oil(&clock, treasury);
+    // Calculate total claimable amount. Vested claims lock the unrefined OIL instead of paying the
+    // refining fee (Seeker miners and rig levels pay a discounted fee).
+    let total_amount = if vest {
+        miner.vest_oil(&clock, treasury, config.vesting_period)
+    } else {
+        let refining_fee_bps = config.refining_fee_bps(miner);
+        miner.claim_oil(&clock, treasury, refining_fee_bps)
+    };
 
     // ENFORCE referral rewards: If miner has a referrer, require referral accounts to be provided.
-    let referral_amount = if miner.referrer != Pubkey::default() {
-        // Require at least 12 accounts (base 9 + miner_referrer + referral_referrer + referral_referrer_oil_ata)
-        if accounts.len() < 12 {
-            return Err(ProgramError::NotEnoughAccountKeys);
-        }
-        
-        // Validate referrer's miner account
-        let miner_referrer_idx = 9;
-        let miner_referrer_info = &accounts[miner_referrer_idx];
-        miner_referrer_info
-            .has_seeds(&[MINER, &miner.referrer.to_bytes()], &oil_api::ID)?;
-        
-        // Validate referrer's referral account
-        let referral_referrer_idx = 10;
-        let referral_referrer_info = &accounts[referral_referrer_idx];
-        referral_referrer_info
-            .has_seeds(&[REFERRAL, &miner.referrer.to_bytes()], &oil_api::ID)?;
-        
-        // Get referral account and calculate/credit referral bonus
-        let referral_referrer = referral_referrer_info
-            .as_account_mut::<Referral>(&oil_api::ID)?;
-        
-        // Calculate and credit referral bonus (0.5% of total claim)
-        referral_referrer.credit_oil_referral(total_amount)
+    let referral = if miner.referrer != Pubkey::default() {
+        let referral = ReferralAccounts::load(&accounts[8..], miner.referrer, authority, true)?;
+        let split = referral.credit_oil(total_amount, &clock)?;
+        Some((referral, split))
     } else {
-        0
+        None
     };
+    let referral_amount = referral.as_ref().map_or(0, |(_, split)| split.total());
 
     // Calculate amount to send to signer (after referral deduction).
     let signer_amount = total_amount.saturating_sub(referral_amount);
@@ -105,43 +143,18 @@ pub fn process_claim_oil(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramR
         )?;
     }
     
-    // Transfer referral OIL directly to referral account's OIL ATA.
-    if referral_amount > 0 {
-        let referral_referrer_info = &accounts[10];
-        let referral_referrer_oil_ata_info = &accounts[11];
-                    
-        // Create referral OIL ATA if it doesn't exist
-        if referral_referrer_oil_ata_info.data_is_empty() {
-            create_associated_token_account(
-                signer_info,
-                referral_referrer_info,
-                referral_referrer_oil_ata_info,
-                mint_info,
-                system_program,
-                token_program,
-                associated_token_program,
-            )?;
-            } else {
-                referral_referrer_oil_ata_info.as_associated_token_account(referral_referrer_info.key, mint_info.key)?;
-            }
-                    
-            // Transfer OIL from treasury to referral account's OIL ATA
-            transfer_signed(
-                treasury_info,
-                treasury_tokens_info,
-                referral_referrer_oil_ata_info,
-                token_program,
-                referral_amount,
-                &[TREASURY],
-                )?;
-    
-            sol_log(&format!(
-                "Referral bonus: {} OIL to {}",
-                amount_to_ui_amount(referral_amount, TOKEN_DECIMALS),
-                miner.referrer
-            )
-            .as_str(),
-        );
+    // Transfer referral OIL directly to the referral accounts' OIL ATAs.
+    if let Some((referral, split)) = referral {
+        referral.send_oil(
+            treasury_info,
+            treasury_tokens_info,
+            user.payer,
+            mint_info,
+            system_program,
+            token_program,
+            associated_token_program,
+            split,
+        )?;
     }
 
     Ok(())
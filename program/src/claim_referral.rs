@@ -1,18 +1,40 @@
 use oil_api::prelude::*;
+use oil_api::fogo::UserContext;
 use solana_program::{log::sol_log, native_token::lamports_to_sol};
 use spl_token::amount_to_ui_amount;
 use steel::*;
 
+use crate::pause::check_unpaused;
+
 /// Claims pending referral rewards (both SOL and OIL).
-pub fn process_claim_referral(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
-    let [signer_info, referral_info, referral_tokens_info, mint_info, recipient_info, system_program, token_program, associated_token_program] =
+pub fn process_claim_referral<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, config, referral, referral_tokens, mint, recipient, system_program, token_program, associated_token_program
+    let [signer_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_CLAIMS)?;
+    claim_referral(UserContext::wallet(signer_info, signer_info, signer_info)?, accounts, data)
+}
+
+/// Claims pending referral rewards (both SOL and OIL) (FOGO session).
+pub fn process_claim_referral_with_session<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, authority, program_signer, payer, config, referral, referral_tokens, mint, recipient,
+    // system_program, token_program, associated_token_program
+    let [signer_info, authority_info, program_signer_info, payer_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_CLAIMS)?;
+    claim_referral(UserContext::session(signer_info, authority_info, program_signer_info, payer_info)?, accounts, data)
+}
+
+fn claim_referral<'a>(user: UserContext<'a>, accounts: &'a [AccountInfo<'a>], _data: &[u8]) -> ProgramResult {
+    let [referral_info, referral_tokens_info, mint_info, recipient_info, system_program, token_program, associated_token_program] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
-    signer_info.is_signer()?;
     
-    let authority = *signer_info.key;
+    let authority = user.key();
     
     let referral = referral_info
         .as_account_mut::<Referral>(&oil_api::ID)?
@@ -26,8 +48,8 @@ pub fn process_claim_referral(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Pro
 
     if recipient_info.data_is_empty() {
         create_associated_token_account(
-            signer_info,
-            signer_info,
+            user.payer,
+            user.authority,
             recipient_info,
             mint_info,
             system_program,
@@ -35,7 +57,7 @@ pub fn process_claim_referral(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Pro
             associated_token_program,
         )?;
     } else {
-        recipient_info.as_associated_token_account(&signer_info.key, mint_info.key)?;
+        recipient_info.as_associated_token_account(&authority, mint_info.key)?;
     }
 
     // Normalize amount
@@ -53,17 +75,16 @@ pub fn process_claim_referral(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Pro
     sol_log(&format!("Claiming {} SOL", lamports_to_sol(pending_sol)).as_str());
 
     // Do CPI first (token transfer), then direct lamport modification (SOL transfer).
-        transfer_signed(
-            referral_info,
-            referral_tokens_info,
-            recipient_info,
-            token_program,
-            pending_oil,
-            &[REFERRAL, &authority.to_bytes()],
-        )?;
+    transfer_signed(
+        referral_info,
+        referral_tokens_info,
+        recipient_info,
+        token_program,
+        pending_oil,
+        &[REFERRAL, &authority.to_bytes()],
+    )?;
 
-    referral_info.send(pending_sol, signer_info);
+    referral_info.send(pending_sol, user.authority);
 
     Ok(())
 }
-
@@ -0,0 +1,87 @@
+use oil_api::prelude::*;
+use solana_program::log::sol_log;
+use solana_program::program_option::COption;
+use spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+use spl_token_group_interface::state::TokenGroupMember;
+use steel::*;
+
+use crate::pause::check_unpaused;
+
+/// Claims a Seeker genesis token for a miner, marking it as a Seeker miner.
+pub fn process_claim_seeker(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Account order: signer, config, miner, mint, token_account, seeker, system_program
+    let clock = Clock::get()?;
+    let [signer_info, config_info, miner_info, mint_info, token_account_info, seeker_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    check_unpaused(config_info, PAUSE_MINING)?;
+    let authority = *signer_info.key;
+    let miner = miner_info
+        .is_writable()?
+        .has_seeds(&[MINER, &authority.to_bytes()], &oil_api::ID)?
+        .as_account_mut::<Miner>(&oil_api::ID)?
+        .assert_mut(|m| m.authority == authority)?;
+    mint_info.has_owner(&spl_token_2022::ID)?;
+    token_account_info.has_address(&spl_associated_token_account::get_associated_token_address_with_program_id(
+        &authority,
+        mint_info.key,
+        &spl_token_2022::ID,
+    ))?;
+    token_account_info.has_owner(&spl_token_2022::ID)?;
+    seeker_info
+        .is_writable()?
+        .has_seeds(&[SEEKER, &mint_info.key.to_bytes()], &oil_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+
+    // The mint must be a member of the Seeker genesis token group.
+    {
+        let mint_data = mint_info.try_borrow_data()?;
+        let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)
+            .map_err(|_| OilError::InvalidSeekerToken)?;
+        if mint.base.mint_authority != COption::Some(SEEKER_MINT_AUTHORITY) {
+            return Err(OilError::InvalidSeekerToken.into());
+        }
+        let member = mint
+            .get_extension::<TokenGroupMember>()
+            .map_err(|_| OilError::InvalidSeekerToken)?;
+        if member.group != SEEKER_GROUP || member.mint != *mint_info.key {
+            return Err(OilError::InvalidSeekerToken.into());
+        }
+    }
+
+    // The miner must hold the token.
+    {
+        let token_data = token_account_info.try_borrow_data()?;
+        let token_account = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&token_data)
+            .map_err(|_| OilError::InvalidSeekerToken)?;
+        if token_account.base.owner != authority
+            || token_account.base.mint != *mint_info.key
+            || token_account.base.amount == 0
+        {
+            return Err(OilError::InvalidSeekerToken.into());
+        }
+    }
+
+    // Each token can only ever be claimed once.
+    if !seeker_info.data_is_empty() {
+        sol_log("Seeker token already claimed");
+        return Err(OilError::InvalidSeekerToken.into());
+    }
+    create_versioned_account::<Seeker>(
+        seeker_info,
+        system_program,
+        signer_info,
+        &oil_api::ID,
+        &[SEEKER, &mint_info.key.to_bytes()],
+    )?;
+    let seeker = seeker_info.as_account_mut::<Seeker>(&oil_api::ID)?;
+    seeker.mint = *mint_info.key;
+    seeker.authority = authority;
+    seeker.claimed_at = clock.unix_timestamp;
+
+    miner.is_seeker = 1;
+    sol_log(&format!("Seeker token {} claimed", mint_info.key));
+
+    Ok(())
+}
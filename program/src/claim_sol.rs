@@ -1,81 +1,79 @@
 use oil_api::prelude::*;
+use oil_api::fogo::UserContext;
 use solana_program::{log::sol_log, native_token::lamports_to_sol};
 use steel::*;
 
-/// Claims SOL rewards with single-tier referral system.
-pub fn process_claim_sol(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+use crate::pause::check_unpaused;
+
+/// Claims SOL rewards with tiered referral system.
+pub fn process_claim_sol<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, config, miner, system_program, [miner_referrer, referral_referrer,
+    // referral_config, [upline_referral]]
+    let [signer_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_CLAIMS)?;
+    claim_sol(UserContext::wallet(signer_info, signer_info, signer_info)?, accounts, data)
+}
+
+/// Claims SOL rewards with tiered referral system (FOGO session)
+pub fn process_claim_sol_with_session<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, authority, program_signer, payer, config, miner, system_program, [miner_referrer, referral_referrer,
+    // referral_config, [upline_referral]]
+    let [signer_info, authority_info, program_signer_info, payer_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_CLAIMS)?;
+    claim_sol(UserContext::session(signer_info, authority_info, program_signer_info, payer_info)?, accounts, data)
+}
+
+fn claim_sol<'a>(user: UserContext<'a>, accounts: &'a [AccountInfo<'a>], _data: &[u8]) -> ProgramResult {
     // Load accounts.
     let clock = Clock::get()?;
     
-    if accounts.len() < 3 {
+    if accounts.len() < 2 {
         return Err(ProgramError::NotEnoughAccountKeys);
     }
     
-    let signer_info = &accounts[0];
-    let miner_info = &accounts[1];
-    let system_program = &accounts[2];
+    let miner_info = &accounts[0];
+    let system_program = &accounts[1];
     
-    signer_info.is_signer()?;
-    signer_info.is_writable()?;
+    user.authority.is_writable()?;
+    let authority = user.key();
     
     let miner = miner_info
         .as_account_mut::<Miner>(&oil_api::ID)?
-        .assert_mut(|m| m.authority == *signer_info.key)?;
+        .assert_mut(|m| m.authority == authority)?;
     system_program.is_program(&system_program::ID)?;
 
     // Get claimable amount (includes both regular SOL and gusher SOL).
     let total_amount = miner.claim_sol(&clock);
 
-    let referral_amount = if miner.referrer != Pubkey::default() {
-        if accounts.len() < 5 {
-            return Err(ProgramError::NotEnoughAccountKeys);
-        }
-
-        // Validate referrer's miner account
-        let miner_referrer_idx = 3;
-        let miner_referrer_info = &accounts[miner_referrer_idx];
-        miner_referrer_info
-            .has_seeds(&[MINER, &miner.referrer.to_bytes()], &oil_api::ID)?;
-
-        // Validate referrer's referral account
-        let referral_referrer_idx = 4;
-        let referral_referrer_info = &accounts[referral_referrer_idx];
-        referral_referrer_info
-            .has_seeds(&[REFERRAL, &miner.referrer.to_bytes()], &oil_api::ID)?;
-        
-        // Get referral account and calculate/credit referral bonus
-        let referral_referrer = referral_referrer_info
-            .as_account_mut::<Referral>(&oil_api::ID)?;
-        
-        // Calculate and credit referral bonus (0.5% of total claim)
-        referral_referrer.credit_sol_referral(total_amount)
+    // ENFORCE referral rewards: If miner has a referrer, require referral accounts to be provided.
+    let referral = if miner.referrer != Pubkey::default() {
+        let referral = ReferralAccounts::load(&accounts[2..], miner.referrer, authority, false)?;
+        let split = referral.credit_sol(total_amount, &clock)?;
+        Some((referral, split))
     } else {
-        0
+        None
     };
+    let referral_amount = referral.as_ref().map_or(0, |(_, split)| split.total());
 
     // Calculate amount to send to authority (after referral deduction).
     let authority_amount = total_amount.saturating_sub(referral_amount);
 
     sol_log(&format!("Claiming {} SOL", lamports_to_sol(total_amount)).as_str());
 
-    // Transfer authority's portion from miner account to signer (user's wallet).
+    // Transfer authority's portion from miner account to the authority (user's wallet).
     if authority_amount > 0 {
-        miner_info.send(authority_amount, signer_info);
+        miner_info.send(authority_amount, user.authority);
     }
     
-    // Transfer referral SOL directly to referral account PDA from miner account.
-    if referral_amount > 0 {
-        let referral_referrer_info = &accounts[4];
-        
-        // Transfer SOL from miner to referral account
-        miner_info.send(referral_amount, referral_referrer_info);
-        
-        sol_log(&format!(
-            "Referral bonus: {} SOL to {}",
-            lamports_to_sol(referral_amount),
-            miner.referrer
-        ));
+    // Transfer referral SOL directly to the referral account PDAs from miner account.
+    if let Some((referral, split)) = referral {
+        referral.send_sol(miner_info, split);
     }
     
     Ok(())
 }
+
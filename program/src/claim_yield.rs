@@ -1,26 +1,46 @@
 use oil_api::prelude::*;
+use oil_api::fogo::UserContext;
 use solana_program::log::sol_log;
 use solana_program::native_token::lamports_to_sol;
 use steel::*;
 
+use crate::pause::check_unpaused;
+
 /// Claims SOL yield from the staking contract. Stakers earn SOL rewards (2% of round winnings), not OIL.
-pub fn process_claim_yield(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+pub fn process_claim_yield<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, config, stake, pool, system_program
+    let [signer_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_CLAIMS)?;
+    claim_yield(UserContext::wallet(signer_info, signer_info, signer_info)?, accounts, data)
+}
+
+/// Claims SOL yield from the staking contract (FOGO session).
+pub fn process_claim_yield_with_session<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, authority, program_signer, config, stake, pool, system_program
+    let [signer_info, authority_info, program_signer_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_CLAIMS)?;
+    claim_yield(UserContext::session(signer_info, authority_info, program_signer_info, signer_info)?, accounts, data)
+}
+
+fn claim_yield<'a>(user: UserContext<'a>, accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
     // Parse data.
     let args = ClaimYield::try_from_bytes(data)?;
     let amount = u64::from_le_bytes(args.amount);
 
     let clock = Clock::get()?;
-    let [signer_info, stake_info, pool_info, system_program] =
-        accounts
-    else {
+    let [stake_info, pool_info, system_program] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
-    signer_info.is_signer()?;
-    signer_info.is_writable()?;
+    user.authority.is_writable()?;
     
+    let authority = user.key();
     let stake = stake_info
         .as_account_mut::<Stake>(&oil_api::ID)?
-        .assert_mut(|s| s.authority == *signer_info.key)?;
+        .assert_mut(|s| s.authority == authority)?;
     let pool = pool_info.as_account_mut::<Pool>(&oil_api::ID)?;
     system_program.is_program(&system_program::ID)?;
 
@@ -34,9 +54,9 @@ pub fn process_claim_yield(accounts: &[AccountInfo<'_>], data: &[u8]) -> Program
     stake.rewards -= claimable_amount;
     stake.last_claim_at = clock.unix_timestamp;
     
-    // Transfer SOL from pool to signer (user's wallet)
+    // Transfer SOL from pool to the authority (user's wallet)
     pool.balance -= claimable_amount;
-    pool_info.send(claimable_amount, signer_info);
+    pool_info.send(claimable_amount, user.authority);
     sol_log(
         &format!(
             "Claiming {} SOL",
@@ -0,0 +1,40 @@
+use oil_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+/// Removes a miner's referrer so the miner can attach a new one (admin only)
+pub fn process_clear_referrer(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Account order: signer, config, miner, referral
+    let [signer_info, config_info, miner_info, referral_info, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    signer_info.is_signer()?;
+
+    // Verify signer is admin
+    config_info
+        .as_account::<Config>(&oil_api::ID)?
+        .check_role(Role::Admin, signer_info, accounts)?;
+
+    let miner = miner_info
+        .is_writable()?
+        .as_account_mut::<Miner>(&oil_api::ID)?
+        .assert_mut(|m| m.referrer != Pubkey::default())?;
+    let referrer = miner.referrer;
+
+    // Decrement total_referred on the old referrer's referral account
+    let referral = referral_info
+        .is_writable()?
+        .has_seeds(&[REFERRAL, &referrer.to_bytes()], &oil_api::ID)?
+        .as_account_mut::<Referral>(&oil_api::ID)?;
+    referral.total_referred = referral.total_referred.saturating_sub(1);
+
+    miner.referrer = Pubkey::default();
+
+    sol_log(&format!(
+        "Cleared referrer {} from miner {}, {} now has {} referrals",
+        referrer, miner.authority, referrer, referral.total_referred
+    ));
+
+    Ok(())
+}
@@ -1,16 +1,50 @@
 use oil_api::prelude::*;
 use oil_api::consts::{POOL_ADDRESS, SOL_MINT};
 use oil_api::instruction::Contribute;
+use oil_api::fogo::UserContext;
 use oil_api::utils::create_or_validate_wrapped_sol_ata;
-use solana_program::{log::sol_log, native_token::lamports_to_sol, program::invoke};
+use solana_program::{log::sol_log, native_token::lamports_to_sol};
 use steel::*;
 
+use crate::pause::check_unpaused;
+use crate::rig::{credit_xp, load_rig};
+
 /// User contributes FOGO to the pool for a specific well
-pub fn process_contribute(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+pub fn process_contribute<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, authority, config, well, auction, treasury, miner, rig, share,
+    // treasury_wrapped_sol_ata, user_wrapped_sol_ata, token_program, mint, associated_token_program, system_program, oil_program,
+    // whitelist (optional)
+    let [signer_info, authority_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_AUCTION)?;
+    contribute(UserContext::wallet(signer_info, authority_info, signer_info)?, config_info, accounts, data)
+}
+
+/// User contributes FOGO to the pool for a specific well (Fogo session)
+pub fn process_contribute_with_session<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, authority, program_signer, payer, config, well, auction, treasury, miner, rig, share,
+    // treasury_wrapped_sol_ata, user_wrapped_sol_ata, token_program, mint, associated_token_program, system_program, oil_program,
+    // whitelist (optional)
+    let [signer_info, authority_info, program_signer_info, payer_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_AUCTION)?;
+    contribute(UserContext::session(signer_info, authority_info, program_signer_info, payer_info)?, config_info, accounts, data)
+}
+
+fn contribute<'a>(
+    user: UserContext<'a>,
+    config_info: &'a AccountInfo<'a>,
+    accounts: &'a [AccountInfo<'a>],
+    data: &[u8],
+) -> ProgramResult {
     let clock = Clock::get()?;
     let args = Contribute::try_from_bytes(data)?;
     let well_id = u64::from_le_bytes(args.well_id) as usize;
     let amount = u64::from_le_bytes(args.amount);
+    let max_price = u64::from_le_bytes(args.max_price);
+    let has_access_code = args.access_code != [0; 32];
     
     if well_id >= 4 {
         return Err(ProgramError::InvalidArgument);
@@ -20,22 +54,30 @@ pub fn process_contribute(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramR
         return Err(ProgramError::InvalidArgument);
     }
     
-    // Account order: signer, authority, well, auction, treasury, miner, share, 
-    // treasury_wrapped_sol_ata, user_wrapped_sol_ata, token_program, mint, associated_token_program, system_program, oil_program
-    let expected_len = 14;
+    let expected_len = 13 + if has_access_code { 1 } else { 0 };
     if accounts.len() < expected_len {
         return Err(ProgramError::NotEnoughAccountKeys);
     }
     
     let mut accounts_iter = accounts.iter();
-    oil_api::extract_accounts!(accounts_iter, [s, a, w, au, t, m, sh, tws, uws, tp, mint, atap, sys, op]);
-    let (signer_info, authority_info, well_info, auction_info, treasury_info, 
-         miner_info, share_info, treasury_wrapped_sol_info, user_wrapped_sol_info, 
-         token_program_info, mint_info, ata_program_info, system_program, oil_program) = 
-         (s, a, w, au, t, m, sh, tws, uws, tp, mint, atap, sys, op);
+    oil_api::extract_accounts!(accounts_iter, [w, au, t, m, rg, sh, tws, uws, tp, mint, atap, sys, op]);
+    let (well_info, auction_info, treasury_info, miner_info, rig_info, share_info, treasury_wrapped_sol_info,
+         user_wrapped_sol_info, token_program_info, mint_info, ata_program_info, system_program, oil_program) = 
+         (w, au, t, m, rg, sh, tws, uws, tp, mint, atap, sys, op);
+    let whitelist_info_opt = if has_access_code { accounts_iter.next() } else { None };
     
-    signer_info.is_signer()?;
-    let authority = *authority_info.key;
+    let authority = user.key();
+    
+    // Before the TGE, contributing requires a usable access code
+    let config = config_info.as_account::<Config>(&oil_api::ID)?;
+    Whitelist::validate_premine_code(
+        oil_api::utils::is_premine_active(config, &clock),
+        has_access_code,
+        args.access_code,
+        whitelist_info_opt,
+        authority,
+        &clock,
+    )?;
     
     // Validate accounts
     let well = well_info.is_writable()?
@@ -62,6 +104,13 @@ pub fn process_contribute(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramR
     let current_price = well.current_price(auction, &clock);
     let bid_amount = current_price.checked_add(1).ok_or(ProgramError::ArithmeticOverflow)?;
     
+    // Conditional contribution: the pool only bids while contributing, and the price only decays
+    // within an epoch, so accepting the contribution at or below max_price guarantees the pool
+    // never spends it above max_price
+    if max_price > 0 && bid_amount > max_price {
+        return Err(OilError::PriceExceedsMax.into());
+    }
+    
     // Calculate actual amount to take (partial contribution logic)
     let required_funds = bid_amount; // Fees are deducted from bid, not added
     let current_total = well.total_contributed;
@@ -76,10 +125,10 @@ pub fn process_contribute(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramR
     miner_info.is_writable()?.has_seeds(&[MINER, &authority.to_bytes()], &oil_api::ID)?;
     let is_new_miner = miner_info.data_is_empty();
     if is_new_miner {
-        create_program_account::<Miner>(
+        create_versioned_account::<Miner>(
             miner_info,
             system_program,
-            signer_info,
+            user.payer,
             &oil_api::ID,
             &[MINER, &authority.to_bytes()],
         )?;
@@ -99,10 +148,10 @@ pub fn process_contribute(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramR
     // Create or load Share account
     let share = if share_info.data_is_empty() {
         share_info.is_writable()?.has_seeds(&[SHARE, &authority.to_bytes(), &well_id.to_le_bytes(), &well.epoch_id.to_le_bytes()], &oil_api::ID)?;
-        create_program_account::<Share>(
+        create_versioned_account::<Share>(
             share_info,
             system_program,
-            signer_info,
+            user.payer,
             &oil_api::ID,
             &[SHARE, &authority.to_bytes(), &well_id.to_le_bytes(), &well.epoch_id.to_le_bytes()],
         )?;
@@ -116,66 +165,48 @@ pub fn process_contribute(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramR
         s
     };
     
-    // Validate user wrapped SOL ATA
-    if user_wrapped_sol_info.data_is_empty() {
-        return Err(ProgramError::InvalidAccountData);
+    // Top-ups keep the conditions the funds already in the share were contributed under
+    if share.contribution > 0 && share.max_price != max_price {
+        sol_log("Contribution conditions differ from the existing share");
+        return Err(ProgramError::InvalidArgument);
     }
-    user_wrapped_sol_info.as_associated_token_account(authority_info.key, mint_info.key)?;
+    share.max_price = max_price;
     
-    // Create or validate Treasury wrapped SOL ATA
+    // Create or validate wrapped SOL ATAs
+    create_or_validate_wrapped_sol_ata(
+        user_wrapped_sol_info,
+        user.authority,
+        mint_info,
+        user.payer,
+        system_program,
+        token_program_info,
+        ata_program_info,
+        None,
+    )?;
     create_or_validate_wrapped_sol_ata(
         treasury_wrapped_sol_info,
         treasury_info,
         mint_info,
-        signer_info,
+        user.payer,
         system_program,
         token_program_info,
         ata_program_info,
         None,
     )?;
     
-    // Transfer wrapped SOL from user to Treasury ATA
+    // Transfer wrapped SOL from user to Treasury ATA and unwrap to native SOL in Treasury's system account
     let user_token_account = user_wrapped_sol_info.as_token_account()?;
     if user_token_account.amount() < actual_amount {
         return Err(ProgramError::InsufficientFunds);
     }
-    
-    invoke(
-        &spl_token::instruction::transfer(
-            token_program_info.key,
-            user_wrapped_sol_info.key,
-            treasury_wrapped_sol_info.key,
-            signer_info.key,
-            &[],
-            actual_amount,
-        )?,
-        &[
-            user_wrapped_sol_info.clone(),
-            treasury_wrapped_sol_info.clone(),
-            signer_info.clone(),
-            token_program_info.clone(),
-        ],
-    )?;
-    
-    // Close Treasury's ATA to unwrap to native SOL in Treasury's system account
-    let treasury_seeds: &[&[u8]] = &[TREASURY];
-    let close_ix = spl_token::instruction::close_account(
-        token_program_info.key,
-        treasury_wrapped_sol_info.key,
-        treasury_info.key,
-        treasury_info.key,
-        &[],
-    )?;
-    invoke_signed(
-        &close_ix,
-        &[
-            treasury_wrapped_sol_info.clone(),
-            treasury_info.clone(),
-            treasury_info.clone(),
-            token_program_info.clone(),
-        ],
-        &oil_api::ID,
-        treasury_seeds,
+    user.pay_wrapped(
+        actual_amount,
+        user_wrapped_sol_info,
+        treasury_wrapped_sol_info,
+        treasury_info,
+        mint_info,
+        token_program_info,
+        &[TREASURY],
     )?;
     
     // Update Share contribution
@@ -183,6 +214,10 @@ pub fn process_contribute(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramR
         .checked_add(actual_amount)
         .ok_or(ProgramError::ArithmeticOverflow)?;
     
+    // Only contributions above the share's high-water mark earn XP
+    let xp_amount = share.contribution.saturating_sub(share.max_contribution);
+    share.max_contribution = share.max_contribution.max(share.contribution);
+    
     // Update Well total_contributed (for backward compatibility/well-specific tracking)
     well.total_contributed = well.total_contributed
         .checked_add(actual_amount)
@@ -197,6 +232,10 @@ pub fn process_contribute(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramR
     let miner_mut = miner_info.as_account_mut::<Miner>(&oil_api::ID)?;
     miner_mut.current_epoch_id[well_id] = well.epoch_id;
     
+    // Credit XP for the contribution
+    let rig = load_rig(rig_info, authority, user.payer, system_program)?;
+    let level_up = credit_xp(rig, miner_mut, XpSource::Contribution, sol_xp(xp_amount), &clock);
+    
     // Check if pool can bid now (immediate path)
     if well.current_bidder != POOL_ADDRESS && well.total_contributed >= bid_amount {
         // Execute pool bid
@@ -253,5 +292,9 @@ pub fn process_contribute(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramR
         lamports_to_sol(actual_amount)
     ));
     
+    if let Some(event) = level_up {
+        auction_program_log(&[auction_info.clone(), oil_program.clone()], &event.to_bytes())?;
+    }
+    
     Ok(())
 }
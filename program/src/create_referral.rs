@@ -1,16 +1,37 @@
 use oil_api::prelude::*;
+use oil_api::fogo::UserContext;
 use solana_program::log::sol_log;
 use steel::*;
 
+use crate::pause::check_unpaused;
+
 /// Creates a referral account for a user to become a referrer.
-pub fn process_create_referral(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
-    let [signer_info, payer_info, referral_info, system_program] = accounts else {
+pub fn process_create_referral<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, payer, config, referral, system_program
+    let [signer_info, payer_info, config_info, accounts @ ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
-    signer_info.is_signer()?;
+    check_unpaused(config_info, PAUSE_REFERRALS)?;
     payer_info.is_signer()?;
+    create_referral(UserContext::wallet(signer_info, signer_info, payer_info)?, accounts, data)
+}
+
+/// Creates a referral account for a user to become a referrer (FOGO session).
+pub fn process_create_referral_with_session<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, authority, program_signer, payer, config, referral, system_program
+    let [signer_info, authority_info, program_signer_info, payer_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_REFERRALS)?;
+    create_referral(UserContext::session(signer_info, authority_info, program_signer_info, payer_info)?, accounts, data)
+}
+
+fn create_referral<'a>(user: UserContext<'a>, accounts: &'a [AccountInfo<'a>], _data: &[u8]) -> ProgramResult {
+    let [referral_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
     
-    let authority = *signer_info.key;
+    let authority = user.key();
     
     referral_info
         .is_writable()?
@@ -18,10 +39,10 @@ pub fn process_create_referral(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Pr
     system_program.is_program(&system_program::ID)?;
 
     if referral_info.data_is_empty() {
-        create_program_account::<Referral>(
+        create_versioned_account::<Referral>(
             referral_info,
             system_program,
-            payer_info,
+            user.payer,
             &oil_api::ID,
             &[REFERRAL, &authority.to_bytes()],
         )?;
@@ -32,6 +53,8 @@ pub fn process_create_referral(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Pr
         referral.total_oil_earned = 0;
         referral.pending_sol = 0;
         referral.pending_oil = 0;
+        referral.referred_volume_sol = 0;
+        referral.rebate_bps = 0;
 
         sol_log("Created referral account");
     } else {
@@ -40,4 +63,3 @@ pub fn process_create_referral(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Pr
 
     Ok(())
 }
-
@@ -0,0 +1,68 @@
+use oil_api::prelude::*;
+use oil_api::fogo::UserContext;
+use solana_program::log::sol_log;
+use steel::*;
+
+use crate::pause::check_unpaused;
+
+/// Creates a human-readable referral code that resolves to the signer's referral account.
+pub fn process_create_referral_code<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, config, referral, referral_code, system_program
+    let [signer_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_REFERRALS)?;
+    create_referral_code(UserContext::wallet(signer_info, signer_info, signer_info)?, accounts, data)
+}
+
+/// Creates a human-readable referral code that resolves to the authority's referral account (FOGO session).
+pub fn process_create_referral_code_with_session<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, authority, program_signer, payer, config, referral, referral_code, system_program
+    let [signer_info, authority_info, program_signer_info, payer_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_REFERRALS)?;
+    create_referral_code(UserContext::session(signer_info, authority_info, program_signer_info, payer_info)?, accounts, data)
+}
+
+fn create_referral_code<'a>(user: UserContext<'a>, accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    let args = CreateReferralCode::try_from_bytes(data)?;
+    let code_hash = args.code_hash;
+
+    let [referral_info, referral_code_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let authority = user.key();
+
+    // Only referrers can register a code
+    referral_info
+        .has_seeds(&[REFERRAL, &authority.to_bytes()], &oil_api::ID)?
+        .as_account::<Referral>(&oil_api::ID)?
+        .assert(|r| r.authority == authority)?;
+    referral_code_info
+        .is_writable()?
+        .has_seeds(&[REFERRAL_CODE, &code_hash], &oil_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Codes are first come, first served
+    if !referral_code_info.data_is_empty() {
+        sol_log("Referral code already exists");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    create_versioned_account::<ReferralCode>(
+        referral_code_info,
+        system_program,
+        user.payer,
+        &oil_api::ID,
+        &[REFERRAL_CODE, &code_hash],
+    )?;
+    let referral_code = referral_code_info.as_account_mut::<ReferralCode>(&oil_api::ID)?;
+    referral_code.code_hash = code_hash;
+    referral_code.authority = authority;
+
+    sol_log(&format!("Created referral code for {}", authority));
+
+    Ok(())
+}
@@ -3,13 +3,18 @@ use oil_api::state::Whitelist;
 use solana_program::log::sol_log;
 use steel::*;
 
-/// Creates a Whitelist account for a shared access code.
+use crate::migrate_account::migrate_account;
+
+/// Creates or updates a Whitelist account for a shared access code.
 pub fn process_create_whitelist(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
     let args = CreateWhitelist::try_from_bytes(data)?;
     let code_hash = args.code_hash;
+    let max_uses = u64::from_le_bytes(args.max_uses);
+    let expires_at = i64::from_le_bytes(args.expires_at);
+    let bound_wallet = Pubkey::new_from_array(args.bound_wallet);
 
     // Load accounts: [signer, config, whitelist, system_program]
-    let [signer_info, config_info, whitelist_info, system_program_info] = accounts else {
+    let [signer_info, config_info, whitelist_info, system_program_info, ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
@@ -17,34 +22,43 @@ pub fn process_create_whitelist(accounts: &[AccountInfo<'_>], data: &[u8]) -> Pr
     whitelist_info.is_writable()?;
     system_program_info.is_program(&system_program::ID)?;
 
-    // Verify signer is admin
+    // Verify signer holds the whitelist issuer role
     config_info
         .as_account::<Config>(&oil_api::ID)?
-        .assert(|c| c.admin == *signer_info.key)?;
+        .check_role(Role::WhitelistIssuer, signer_info, accounts)?;
+
+    // Expiry must be in the future (0 = never)
+    if expires_at < 0 || (expires_at > 0 && expires_at <= Clock::get()?.unix_timestamp) {
+        return Err(ProgramError::InvalidArgument);
+    }
 
     // Derive expected PDA (no authority needed - codes are shared)
     let (whitelist_pda, _) = Whitelist::pda(code_hash);
     whitelist_info.has_address(&whitelist_pda)?;
 
-    // Check if whitelist entry already exists
-    if !whitelist_info.data_is_empty() {
-        sol_log("Whitelist entry already exists");
-        return Err(ProgramError::InvalidArgument);
+    if whitelist_info.data_is_empty() {
+        // Create the Whitelist account
+        create_versioned_account::<Whitelist>(
+            whitelist_info,
+            system_program_info,
+            signer_info,
+            &oil_api::ID,
+            &[b"whitelist", &code_hash],
+        )?;
+        let whitelist = whitelist_info.as_account_mut::<Whitelist>(&oil_api::ID)?;
+        whitelist.code_hash = code_hash;
+        whitelist.usage_count = 0;
+    } else {
+        // Upgrade legacy whitelist accounts to hold the usage limits
+        migrate_account(whitelist_info, signer_info, system_program_info)?;
+        sol_log("Whitelist entry exists - updating limits");
     }
 
-    // Create the Whitelist account
-    create_program_account::<Whitelist>(
-        whitelist_info,
-        system_program_info,
-        signer_info,
-        &oil_api::ID,
-        &[b"whitelist", &code_hash],
-    )?;
-
-    // Initialize the Whitelist account
+    // Set the usage limits (usage_count is kept on update)
     let whitelist = whitelist_info.as_account_mut::<Whitelist>(&oil_api::ID)?;
-    whitelist.code_hash = code_hash;
-    whitelist.usage_count = 0;
+    whitelist.max_uses = max_uses;
+    whitelist.expires_at = expires_at;
+    whitelist.bound_wallet = bound_wallet;
 
     Ok(())
 }
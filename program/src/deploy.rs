@@ -1,20 +1,56 @@
 use entropy_rng_api::state::Var;
 use oil_api::prelude::*;
+use oil_api::fogo::{UserContext, WrappedSolAccounts};
 use solana_program::{keccak::hashv, log::sol_log, native_token::lamports_to_sol};
 use steel::*;
 
+use crate::pause::check_unpaused;
+use crate::rig::{credit_xp, load_rig};
+
 /// Deploys capital to prospect on a square.
-pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+pub fn process_deploy<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, authority, config, automation, board, miner, rig, round, pool, system_program, oil_program,
+    // referral (optional), whitelist (optional), var, entropy_program
+    let [signer_info, authority_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_MINING)?;
+    deploy(UserContext::wallet(signer_info, authority_info, signer_info)?, config_info, accounts, data)
+}
+
+/// Deploys capital to prospect on a square (FOGO session).
+pub fn process_deploy_with_session<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, authority, program_signer, payer, config, automation, board, miner, rig, round, pool,
+    // system_program, oil_program, user_wrapped_sol, round_wrapped_sol, token_program, sol_mint, associated_token_program,
+    // referral (optional), whitelist (optional), var, entropy_program
+    let [signer_info, authority_info, program_signer_info, payer_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_MINING)?;
+    deploy(UserContext::session(signer_info, authority_info, program_signer_info, payer_info)?, config_info, accounts, data)
+}
+
+fn deploy<'a>(
+    user: UserContext<'a>,
+    config_info: &'a AccountInfo<'a>,
+    accounts: &'a [AccountInfo<'a>],
+    data: &[u8],
+) -> ProgramResult {
     let args = Deploy::try_from_bytes(data)?;
     let mut amount = u64::from_le_bytes(args.amount);
     let mask = u32::from_le_bytes(args.squares);
     let referrer = Pubkey::new_from_array(args.referrer);
     let pooled = args.pooled != 0;
+    let has_access_code = args.access_code != [0; 32];
 
     let clock = Clock::get()?;
-    let has_referrer = referrer != Pubkey::default() && referrer != *accounts[1].key;
+    let authority = user.key();
+    let has_referrer = referrer != Pubkey::default() && referrer != authority;
     
-    let oil_accounts_count = 8 + if has_referrer { 1 } else { 0 };
+    // Sessions pay with wrapped SOL (accounts precede the optional referral and whitelist)
+    let wrapped_accounts_count = if user.is_session() { 5 } else { 0 };
+    let oil_accounts_count =
+        8 + wrapped_accounts_count + if has_referrer { 1 } else { 0 } + if has_access_code { 1 } else { 0 };
     
     if accounts.len() != oil_accounts_count + 2 {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -22,20 +58,33 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
     
     let (oil_accounts, entropy_accounts) = accounts.split_at(oil_accounts_count);
     
-    let expected_len = 8 + if has_referrer { 1 } else { 0 };
-    if oil_accounts.len() != expected_len {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    }
     let mut accounts_iter = oil_accounts.iter();
-    oil_api::extract_accounts!(accounts_iter, [s, a, aut, b, m, r, sp, op]);
+    oil_api::extract_accounts!(accounts_iter, [aut, b, m, rg, r, pl, sp, op]);
+    let wrapped = if user.is_session() {
+        Some(WrappedSolAccounts::from_iter(&mut accounts_iter)?)
+    } else {
+        None
+    };
     let ref_info = if has_referrer { accounts_iter.next() } else { None };
-    let (signer_info, authority_info, automation_info, board_info, miner_info, 
-         round_info, system_program, oil_program, 
-         referral_info_opt) = (s, a, aut, b, m, r, sp, op, ref_info);
+    let whitelist_info_opt = if has_access_code { accounts_iter.next() } else { None };
+    let (automation_info, board_info, miner_info, rig_info,
+         round_info, pool_info, system_program, oil_program, 
+         referral_info_opt) = (aut, b, m, rg, r, pl, sp, op, ref_info);
         
-    signer_info.is_signer()?;
-    authority_info.is_writable()?;
-    automation_info.is_writable()?.has_seeds(&[AUTOMATION, &authority_info.key.to_bytes()], &oil_api::ID)?;
+    user.authority.is_writable()?;
+
+    // Before the TGE, deploying requires a usable access code
+    let config = config_info.as_account::<Config>(&oil_api::ID)?;
+    Whitelist::validate_premine_code(
+        oil_api::utils::is_premine_active(config, &clock),
+        has_access_code,
+        args.access_code,
+        whitelist_info_opt,
+        authority,
+        &clock,
+    )?;
+
+    automation_info.is_writable()?.has_seeds(&[AUTOMATION, &authority.to_bytes()], &oil_api::ID)?;
     let board = board_info.as_account_mut::<Board>(&oil_api::ID)?;
     
     if board.end_slot != u64::MAX {
@@ -46,8 +95,8 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
     let automation = if !automation_info.data_is_empty() {
         let automation = automation_info
             .as_account_mut::<Automation>(&oil_api::ID)?
-            .assert_mut(|a| a.executor == *signer_info.key)?
-            .assert_mut(|a| a.authority == *authority_info.key)?;
+            .assert_mut(|a| a.executor == *user.signer.key)?
+            .assert_mut(|a| a.authority == authority)?;
         strategy = automation.strategy as u64;
         Some(automation)
     } else {
@@ -60,7 +109,11 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
         .as_account_mut::<Round>(&oil_api::ID)?
         .assert_mut(|r| r.id == board.round_id)?;
     
-    miner_info.is_writable()?.has_seeds(&[MINER, &authority_info.key.to_bytes()], &oil_api::ID)?;
+    let pool = pool_info
+        .has_seeds(&[POOL], &oil_api::ID)?
+        .as_account::<Pool>(&oil_api::ID)?;
+    
+    miner_info.is_writable()?.has_seeds(&[MINER, &authority.to_bytes()], &oil_api::ID)?;
     system_program.is_program(&system_program::ID)?;
 
     if board.end_slot == u64::MAX {
@@ -109,6 +162,15 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
                     squares = oil_api::utils::generate_random_mask(num_squares, &r);
                 }
             }
+            AutomationStrategy::Contrarian => {
+                // Deploy to the squares with the least SOL deployed so far this round.
+                let num_squares = ((automation.mask & 0xFF) as usize).min(25);
+                let mut order: [usize; 25] = core::array::from_fn(|i| i);
+                order.sort_by_key(|&i| (round.deployed[i], i));
+                for &i in order.iter().take(num_squares) {
+                    squares[i] = true;
+                }
+            }
         }
     } else {
         for i in 0..25 {
@@ -118,22 +180,22 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
 
     let is_new_miner = miner_info.data_is_empty();
     let miner = if is_new_miner {
-        create_program_account::<Miner>(
+        create_versioned_account::<Miner>(
             miner_info,
             system_program,
-            signer_info,
+            user.payer,
             &oil_api::ID,
-            &[MINER, &authority_info.key.to_bytes()],
+            &[MINER, &authority.to_bytes()],
         )?;
         let miner = miner_info.as_account_mut::<Miner>(&oil_api::ID)?;
-        miner.initialize(*authority_info.key);
+        miner.initialize(authority);
         
-        if referrer != Pubkey::default() && referrer != *authority_info.key {
+        if referrer != Pubkey::default() && referrer != authority {
             miner.referrer = referrer;
             Referral::process_new_miner_referral(
                 referral_info_opt,
                 referrer,
-                *authority_info.key,
+                authority,
             )?;
         }
         miner
@@ -142,7 +204,7 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
             if let Some(automation) = &automation {
                 m.authority == automation.authority
             } else {
-                m.authority == *authority_info.key
+                m.authority == authority
             }
         })?
     };
@@ -153,6 +215,9 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
         miner.cumulative = round.deployed;
         miner.round_id = round.id;
         miner.pooled_deployed = 0;
+
+        // Stakers weigh more in split OIL, at the stake they hold when joining the round.
+        miner.stake_boost_bps = config.stake_boost_bps(miner.total_stake_score, pool.total_staked_score);
     }
 
     let is_first_deploy = miner.deployed.iter().sum::<u64>() == 0;
@@ -173,6 +238,9 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
         miner.cumulative[square_id] = round.deployed[square_id];
         miner.deployed[square_id] = amount;
         round.deployed[square_id] += amount;
+        if !pooled {
+            round.boosted_deployed[square_id] += miner.boosted(amount);
+        }
         round.total_deployed += amount;
         round.count[square_id] += 1;
         total_amount += amount;
@@ -190,7 +258,9 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
         if miner.pooled_deployed == 0 {
             round.pool_members += 1;
         }
+        let boosted_before = miner.boosted(miner.pooled_deployed);
         miner.pooled_deployed += total_amount;
+        round.boosted_pooled += miner.boosted(miner.pooled_deployed) - boosted_before;
         for (square_id, &should_deploy) in squares.iter().enumerate() {
             if square_id > 24 {
                 break;
@@ -212,14 +282,24 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
     miner.lifetime_deployed += total_amount;
 
     if miner.checkpoint_fee == 0 {
-        miner.checkpoint_fee = CHECKPOINT_FEE;
-        miner_info.collect(CHECKPOINT_FEE, signer_info)?;
+        let checkpoint_fee = rig_checkpoint_fee(miner.level);
+        miner.checkpoint_fee = checkpoint_fee;
+        miner_info.collect(checkpoint_fee, user.payer)?;
     }
 
+    // Credit XP for the deploy. Executors don't pay for rigs, so automated deploys only earn XP
+    // once the miner's rig exists.
+    let level_up = if automation.is_some() && rig_info.data_is_empty() {
+        None
+    } else {
+        let rig = load_rig(rig_info, authority, user.payer, system_program)?;
+        credit_xp(rig, miner, XpSource::Deploy, sol_xp(total_amount), &clock)
+    };
+
     if let Some(automation) = automation {
         automation.balance -= total_amount + automation.fee;
         automation_info.send(total_amount, &round_info);
-        automation_info.send(automation.fee, &signer_info);
+        automation_info.send(automation.fee, user.signer);
         
         if AutomationStrategy::from_u64(automation.strategy as u64) == AutomationStrategy::Repeat {
             let mut new_mask = 0u64;
@@ -232,10 +312,11 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
         }
         
         if automation.balance < automation.amount + automation.fee {
-            automation_info.close(authority_info)?;
+            automation_info.close(user.authority)?;
         }
     } else {
-        round_info.collect(total_amount, signer_info)?;
+        let round_id_bytes = round.id.to_le_bytes();
+        user.pay(total_amount, round_info, &[ROUND, &round_id_bytes], system_program, wrapped.as_ref())?;
     }
 
     program_log(
@@ -246,13 +327,16 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
             amount,
             mask: mask as u64,
             round_id: round.id,
-            signer: *signer_info.key,
+            signer: *user.signer.key,
             strategy,
             total_squares,
             ts: clock.unix_timestamp,
         }
         .to_bytes(),
     )?;
+    if let Some(event) = level_up {
+        program_log(&[board_info.clone(), oil_program.clone()], &event.to_bytes())?;
+    }
 
     sol_log(&format!(
         "Round #{}: deploying {} SOL to {} squares{}",
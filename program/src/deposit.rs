@@ -1,12 +1,36 @@
 use oil_api::prelude::*;
-use solana_program::program::invoke;
+use oil_api::fogo::UserContext;
 use solana_program::log::sol_log;
-use spl_token::instruction::transfer;
 use spl_token::amount_to_ui_amount;
 use steel::*;
 
+use crate::pause::check_unpaused;
+use crate::rig::{accrue_stake_xp, load_rig};
+
 /// Deposits OIL into the staking contract. Stakers earn SOL rewards from protocol revenue (2% of round winnings).
-pub fn process_deposit(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+pub fn process_deposit<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, payer, config, mint, sender, stake, stake_tokens, pool, pool_tokens, miner, rig,
+    // system_program, token_program, associated_token_program
+    let [signer_info, payer_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_STAKING)?;
+    payer_info.is_signer()?;
+    deposit(UserContext::wallet(signer_info, signer_info, payer_info)?, accounts, data)
+}
+
+/// Deposits OIL into the staking contract (FOGO session).
+pub fn process_deposit_with_session<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, authority, program_signer, payer, config, mint, sender, stake, stake_tokens, pool, pool_tokens,
+    // miner, rig, system_program, token_program, associated_token_program
+    let [signer_info, authority_info, program_signer_info, payer_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_STAKING)?;
+    deposit(UserContext::session(signer_info, authority_info, program_signer_info, payer_info)?, accounts, data)
+}
+
+fn deposit<'a>(user: UserContext<'a>, accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
     let args = Deposit::try_from_bytes(data)?;
     let amount = u64::from_le_bytes(args.amount);
     let lock_duration_days = u64::from_le_bytes(args.lock_duration_days);
@@ -18,23 +42,20 @@ pub fn process_deposit(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
 
     let clock = Clock::get()?;
     
-    let [signer_info, payer_info, mint_info, sender_info, stake_info, stake_tokens_info, pool_info, pool_tokens_info, miner_info, system_program, token_program, associated_token_program] =
+    let [mint_info, sender_info, stake_info, stake_tokens_info, pool_info, pool_tokens_info, miner_info, rig_info, system_program, token_program, associated_token_program] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     
-    signer_info.is_signer()?;
-    payer_info.is_signer()?;
-    
-    let authority = *signer_info.key;
+    let authority = user.key();
     
     // Validate mint
     mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
     
     let sender = sender_info
         .is_writable()?
-        .as_associated_token_account(&signer_info.key, &MINT_ADDRESS)?;
+        .as_associated_token_account(&authority, &MINT_ADDRESS)?;
     
     stake_info.is_writable()?;
     miner_info.is_writable()?;
@@ -48,10 +69,10 @@ pub fn process_deposit(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
     associated_token_program.is_program(&spl_associated_token_account::ID)?;
 
     let stake = if stake_info.data_is_empty() {
-        create_program_account::<Stake>(
+        create_versioned_account::<Stake>(
             stake_info,
             system_program,
-            payer_info,
+            user.payer,
             &oil_api::ID,
             &[STAKE, &authority.to_bytes(), &stake_id.to_le_bytes()],
         )?;
@@ -103,7 +124,7 @@ pub fn process_deposit(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
 
     if stake_tokens_info.data_is_empty() {
         create_associated_token_account(
-            payer_info,
+            user.payer,
             stake_info,
             stake_tokens_info,
             mint_info,
@@ -115,6 +136,22 @@ pub fn process_deposit(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
         stake_tokens_info.as_associated_token_account(stake_info.key, mint_info.key)?;
     }
 
+    // Load the miner whose stake score tracks this stake, if it exists.
+    let mut miner = if miner_info.data_is_empty() {
+        None
+    } else {
+        miner_info
+            .as_account_mut::<Miner>(&oil_api::ID)
+            .ok()
+            .filter(|miner| miner.authority == authority)
+    };
+    
+    // Accrue stake XP for the stake held so far, before the deposit raises the stake score
+    if let Some(miner) = miner.as_deref_mut() {
+        let rig = load_rig(rig_info, authority, user.payer, system_program)?;
+        accrue_stake_xp(rig, miner, &clock);
+    }
+    
     let old_stake_score = stake.score();
     
     let amount = stake.deposit(amount, &clock, pool, &sender);
@@ -130,32 +167,11 @@ pub fn process_deposit(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
     let new_stake_score = stake.score();
     let stake_score_delta = new_stake_score.saturating_sub(old_stake_score);
     
-    if !miner_info.data_is_empty() {
-        if let Ok(miner) = miner_info.as_account_mut::<Miner>(&oil_api::ID) {
-            if miner.authority == authority {
-                miner.total_stake_score = miner.total_stake_score.saturating_add(stake_score_delta);
-            }
-        }
+    if let Some(miner) = miner {
+        miner.total_stake_score = miner.total_stake_score.saturating_add(stake_score_delta);
     }
 
-    let transfer_ix = transfer(
-        token_program.key,
-        sender_info.key,
-        pool_tokens_info.key,
-        signer_info.key,
-        &[],
-        amount,
-    )?;
-    
-    invoke(
-        &transfer_ix,
-        &[
-            signer_info.clone(),
-            sender_info.clone(),
-            pool_tokens_info.clone(),
-            token_program.clone(),
-        ],
-    )?;
+    user.transfer_token(token_program, sender_info, mint_info, pool_tokens_info, amount)?;
 
     // Safety check: Verify pool has enough tokens to cover all stakes.
     let pool_tokens = pool_tokens_info.as_associated_token_account(pool_info.key, mint_info.key)?;
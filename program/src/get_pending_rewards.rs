@@ -0,0 +1,45 @@
+use oil_api::prelude::*;
+use solana_program::program::set_return_data;
+use steel::*;
+
+/// Returns a miner's pending rewards through return data. Does not modify any account.
+pub fn process_get_pending_rewards(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    let clock = Clock::get()?;
+
+    // Account order: miner, treasury, config
+    let [miner_info, treasury_info, config_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Work on copies so the view never writes to the accounts.
+    let mut miner = *miner_info.as_account::<Miner>(&oil_api::ID)?;
+    let mut treasury = *treasury_info
+        .has_seeds(&[TREASURY], &oil_api::ID)?
+        .as_account::<Treasury>(&oil_api::ID)?;
+    let config = config_info
+        .has_seeds(&[CONFIG], &oil_api::ID)?
+        .as_account::<Config>(&oil_api::ID)?;
+
+    miner.update_rewards(&treasury);
+    miner.update_auction_rewards(&treasury);
+    let block_refined_oil = miner.block_refined_oil;
+    let block_rewards_oil = miner.block_rewards_oil;
+    let block_claimable_oil = miner.claim_oil(&clock, &mut treasury, config.refining_fee_bps(&miner));
+
+    set_return_data(
+        PendingRewards {
+            authority: miner.authority,
+            block_rewards_sol: miner.block_rewards_sol,
+            block_rewards_oil,
+            block_refined_oil,
+            block_claimable_oil,
+            auction_rewards_sol: miner.auction_rewards_sol,
+            auction_rewards_oil: miner.auction_rewards_oil,
+            auction_refined_oil: miner.auction_refined_oil,
+            ts: clock.unix_timestamp,
+        }
+        .to_bytes(),
+    );
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use oil_api::prelude::*;
+use solana_program::program::set_return_data;
+use steel::*;
+
+/// Returns a stake account's lock and yield state through return data. Does not modify any account.
+pub fn process_get_stake_info(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    let clock = Clock::get()?;
+
+    // Account order: stake, pool
+    let [stake_info, pool_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Work on copies so the view never writes to the accounts.
+    let mut stake = *stake_info.as_account::<Stake>(&oil_api::ID)?;
+    let pool = pool_info
+        .has_seeds(&[POOL], &oil_api::ID)?
+        .as_account::<Pool>(&oil_api::ID)?;
+
+    stake.update_rewards(pool);
+
+    let penalty_percent = if stake.is_locked(&clock) {
+        Stake::calculate_penalty_percent(stake.lock_duration_days)
+    } else {
+        0
+    };
+
+    set_return_data(
+        StakeInfo {
+            authority: stake.authority,
+            balance: stake.balance,
+            score: stake.score(),
+            lock_duration_days: stake.lock_duration_days,
+            lock_ends_at: stake.lock_ends_at,
+            remaining_lock_seconds: stake.remaining_lock_seconds(&clock),
+            penalty_percent,
+            pending_rewards: stake.rewards,
+            lifetime_rewards: stake.lifetime_rewards,
+            ts: clock.unix_timestamp,
+        }
+        .to_bytes(),
+    );
+
+    Ok(())
+}
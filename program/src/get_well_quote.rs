@@ -0,0 +1,51 @@
+use oil_api::prelude::*;
+use solana_program::program::set_return_data;
+use steel::*;
+
+/// Returns the current price and mining state of a well through return data. Does not modify any account.
+pub fn process_get_well_quote(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    let clock = Clock::get()?;
+    let args = GetWellQuote::try_from_bytes(data)?;
+    let well_id = u64::from_le_bytes(args.well_id);
+
+    if well_id >= 4 {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Account order: well, auction
+    let [well_info, auction_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Work on copies so the view never writes to the accounts.
+    let mut well = *well_info
+        .has_seeds(&[WELL, &well_id.to_le_bytes()], &oil_api::ID)?
+        .as_account::<Well>(&oil_api::ID)?;
+    let mut auction = *auction_info
+        .has_seeds(&[AUCTION], &oil_api::ID)?
+        .as_account::<Auction>(&oil_api::ID)?;
+
+    well.update_accumulated_oil(&auction, &clock);
+    well.check_and_apply_halving(&mut auction, &clock);
+
+    let current_price = well.current_price(&auction, &clock);
+    let bid_amount = current_price.checked_add(1).ok_or(ProgramError::ArithmeticOverflow)?;
+
+    set_return_data(
+        WellQuote {
+            well_id,
+            epoch_id: well.epoch_id,
+            current_bidder: well.current_bidder,
+            current_price,
+            bid_amount,
+            accumulated_oil: well.accumulated_oil,
+            mps: well.mps,
+            total_contributed: well.total_contributed,
+            pool_needed: bid_amount.saturating_sub(well.total_contributed),
+            ts: clock.unix_timestamp,
+        }
+        .to_bytes(),
+    );
+
+    Ok(())
+}
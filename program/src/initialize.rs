@@ -40,9 +40,17 @@ pub fn process_initialize(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramR
     let token_program = &accounts[14];
     let associated_token_program = &accounts[15];
     
-    signer_info.is_signer()?.has_address(&ADMIN_ADDRESS)?;
+    signer_info.is_signer()?;
     board_info.has_seeds(&[BOARD], &oil_api::ID)?;
     config_info.has_seeds(&[CONFIG], &oil_api::ID)?;
+    // The program is bootstrapped by ADMIN_ADDRESS; once the config exists, the admin role is required.
+    if config_info.data_is_empty() {
+        signer_info.has_address(&ADMIN_ADDRESS)?;
+    } else {
+        config_info
+            .as_account::<Config>(&oil_api::ID)?
+            .check_role(Role::Admin, signer_info, accounts)?;
+    }
     mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
     treasury_info.has_seeds(&[TREASURY], &oil_api::ID)?;
     // Only validate treasury_tokens_info address if account is not empty
@@ -63,7 +71,7 @@ pub fn process_initialize(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramR
 
     // Create board account.
     if board_info.data_is_empty() {
-        create_program_account::<Board>(
+        create_versioned_account::<Board>(
             board_info,
             system_program,
             signer_info,
@@ -81,7 +89,7 @@ pub fn process_initialize(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramR
 
     // Create config account.
     if config_info.data_is_empty() {
-        create_program_account::<Config>(
+        create_versioned_account::<Config>(
             config_info,
             system_program,
             signer_info,
@@ -96,17 +104,27 @@ pub fn process_initialize(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramR
         config.var_address = var_address;
         // Cap admin fee at 1% (100 basis points).
         config.admin_fee = admin_fee.min(100);
-        // Initialize emission schedule (starts at week 0 = 50 OIL per round)
+        // Initialize emission schedule (week 0 starts on the first reset)
         config.emission_week = 0;
-        config.last_emission_week_update = 0; // Will be set on first reset
+        config.last_emission_week_update = 0;
         config.tge_timestamp = 0; // 0 = pre-mine disabled by default
+        // Seed the roles (unassigned roles fall back to the admin)
+        config.roles[Role::Admin as usize] = RoleSet::single(*signer_info.key);
+        config.roles[Role::TreasuryOperator as usize] = RoleSet::single(BURY_AUTHORITY);
+        config.roles[Role::LiquidityManager as usize] = RoleSet::single(barrel_authority);
+        config.stake_boost_max_bps = DEFAULT_STAKE_BOOST_MAX_BPS;
+        config.stake_boost_share_bps = DEFAULT_STAKE_BOOST_SHARE_BPS;
+        config.seeker_refining_discount_bps = DEFAULT_SEEKER_REFINING_DISCOUNT_BPS;
+        config.refining_fee_bps = DEFAULT_REFINING_FEE_BPS;
+        config.auction_refining_fee_bps = DEFAULT_AUCTION_REFINING_FEE_BPS;
+        config.vesting_period = DEFAULT_VESTING_PERIOD;
     } else {
         config_info.as_account::<Config>(&oil_api::ID)?;
     }
 
     // Create treasury account.
     if treasury_info.data_is_empty() {
-        create_program_account::<Treasury>(
+        create_versioned_account::<Treasury>(
             treasury_info,
             system_program,
             signer_info,
@@ -131,7 +149,7 @@ pub fn process_initialize(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramR
 
     // Create pool account.
     if pool_info.data_is_empty() {
-        create_program_account::<Pool>(
+        create_versioned_account::<Pool>(
             pool_info,
             system_program,
             signer_info,
@@ -143,9 +161,10 @@ pub fn process_initialize(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramR
         pool.stake_rewards_factor = Numeric::ZERO;
         pool.total_staked_score = 0;
         pool.total_staked = 0;
-        pool.buffer_a = Numeric::ZERO;
+        pool.liquid_rewards_factor = Numeric::ZERO;
         pool.total_burned_penalties = 0;
-        pool.buffer_c = 0;
+        pool.liquid_staked = 0;
+        pool.liquid_rewards = 0;
     } else {
         pool_info.as_account::<Pool>(&oil_api::ID)?;
     }
@@ -198,7 +217,7 @@ pub fn process_initialize(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramR
 
     // Create Auction account (idempotent - only if empty)
     if auction_info.data_is_empty() {
-        create_program_account::<Auction>(
+        create_versioned_account::<Auction>(
             auction_info,
             system_program,
             signer_info,
@@ -206,14 +225,22 @@ pub fn process_initialize(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramR
             &[AUCTION],
         )?;
         let auction = auction_info.as_account_mut::<Auction>(&oil_api::ID)?;
-        // Time-based halving: 
+        // Time-based halving (reschedule via ScheduleHalvings):
         // - First halving: 14 days (50% reduction)
         // - Subsequent halvings: 28 days (25% reduction each)
-        auction.halving_period_seconds = if halving_period_seconds > 0 {
-            halving_period_seconds
-        } else {
-            28 * 24 * 60 * 60 // Default to 28 days for subsequent halvings
-        };
+        auction.set_halving_schedule(&HalvingSchedule {
+            anchor_time: current_timestamp,
+            anchor_count: 0,
+            anchor_rate: HALVING_RATE_SCALE,
+            first_delay_seconds: DEFAULT_FIRST_HALVING_DELAY_SECONDS,
+            period_seconds: if halving_period_seconds > 0 {
+                halving_period_seconds
+            } else {
+                DEFAULT_HALVING_PERIOD_SECONDS
+            },
+            first_decay_bps: DEFAULT_FIRST_HALVING_DECAY_BPS,
+            decay_bps: DEFAULT_HALVING_DECAY_BPS,
+        });
         auction.last_halving_time = current_timestamp; // Set to current time (first halving will be 14 days from now)
         auction.halving_count = 0; // No halvings yet
         auction.base_mining_rates = base_mining_rates;
@@ -221,9 +248,12 @@ pub fn process_initialize(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramR
         auction.starting_prices = starting_prices;
         auction.buffer_a = Numeric::ZERO;
         auction.halving_count = 0; // No halvings yet
-        // Buffer fields (for future use)
-        auction.buffer_c = 0;
-        auction.buffer_d = 0;
+        // Price curves: linear decay to AUCTION_FLOOR_PRICE, next epoch at AUCTION_PRICE_MULTIPLIER_BPS (set via SetAuction)
+        auction.price_curves = [PriceCurve::Linear as u64; 4];
+        auction.floor_prices = [0; 4];
+        auction.price_multipliers_bps = [0; 4];
+        // No pending halving schedule change
+        auction.pending_halving_effective_time = 0;
     } else {
         auction_info.as_account::<Auction>(&oil_api::ID)?;
     }
@@ -236,7 +266,7 @@ pub fn process_initialize(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramR
             .has_seeds(&[WELL, &well_id.to_le_bytes()], &oil_api::ID)?;
         
         if well_info.data_is_empty() {
-            create_program_account::<Well>(
+            create_versioned_account::<Well>(
                 well_info,
                 system_program,
                 signer_info,
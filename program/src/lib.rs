@@ -1,102 +1,119 @@
+mod accept_admin;
+mod admin_timelock;
 mod initialize;
 mod automate;
 mod buyback;
+mod cancel_admin_action;
 mod wrap;
 mod checkpoint;
-mod checkpoint_with_session;
 mod claim_oil;
 mod claim_referral;
-mod claim_referral_with_session;
+mod claim_seeker;
 mod claim_sol;
 mod claim_yield;
-mod claim_yield_with_session;
+mod clear_referrer;
 mod close;
 mod create_referral;
-mod create_referral_with_session;
+mod create_referral_code;
 mod create_whitelist;
 mod deploy;
-mod deploy_with_session;
-mod automate_with_session;
-mod place_bid_with_session;
-mod claim_auction_oil_with_session;
-mod claim_auction_sol_with_session;
-mod claim_sol_with_session;
-mod claim_oil_with_session;
-mod withdraw_with_session;
 mod deposit;
-mod deposit_with_session;
 mod log;
-mod migrate;
+mod migrate_account;
 mod new_var;
 mod reload_sol;
 mod reset;
+mod revoke_whitelist;
+mod rig;
 mod set_admin;
 mod set_admin_fee;
+mod set_refining_fee;
+mod set_seeker_bonus;
+mod set_stake_boost;
 mod set_fee_collector;
+mod set_lp_mint;
 mod set_swap_program;
 mod set_var_address;
+mod set_referral_config;
+mod set_referral_rebate;
+mod pause;
+mod set_pause;
+mod set_referrer;
+mod set_role;
+mod update_rig;
 mod withdraw;
 mod place_bid;
 mod claim_auction_oil;
 mod claim_auction_sol;
 mod set_auction;
+mod schedule_halvings;
 mod set_tge_timestamp;
-mod liq;
+mod liquid_stake;
+mod liquidity;
 mod barrel;
 mod contribute;
-mod contribute_with_session;
+mod withdraw_contribution;
+mod get_pending_rewards;
+mod get_well_quote;
+mod get_stake_info;
 mod checkpoint_auction;
-mod checkpoint_auction_with_session;
 
+use accept_admin::*;
 use initialize::*;
 use automate::*;
 use buyback::*;
+use cancel_admin_action::*;
 use wrap::*;
 use checkpoint::*;
-use checkpoint_with_session::*;
 use claim_oil::*;
 use claim_referral::*;
-use claim_referral_with_session::*;
+use claim_seeker::*;
 use claim_sol::*;
 use claim_yield::*;
-use claim_yield_with_session::*;
+use clear_referrer::*;
 use close::*;
 use create_referral::*;
-use create_referral_with_session::*;
+use create_referral_code::*;
 use create_whitelist::*;
 use deploy::*;
-use deploy_with_session::*;
-use automate_with_session::*;
 use deposit::*;
 use log::*;
-use migrate::*;
+use migrate_account::*;
 use new_var::*;
 use reload_sol::*;
 use reset::*;
+use revoke_whitelist::*;
 use set_admin::*;
 use set_admin_fee::*;
+use set_refining_fee::*;
+use set_seeker_bonus::*;
+use set_stake_boost::*;
 use set_fee_collector::*;
+use set_lp_mint::*;
 use set_swap_program::*;
 use set_var_address::*;
+use set_referral_config::*;
+use set_referral_rebate::*;
+use set_pause::*;
+use set_referrer::*;
+use set_role::*;
+use update_rig::*;
 use withdraw::*;
 use place_bid::*;
-use place_bid_with_session::*;
-use claim_auction_oil_with_session::*;
-use claim_auction_sol_with_session::*;
-use claim_sol_with_session::*;
-use claim_oil_with_session::*;
-use withdraw_with_session::*;
-use deposit_with_session::*;
 use claim_auction_oil::*;
 use claim_auction_sol::*;
 use set_auction::*;
+use schedule_halvings::*;
 use set_tge_timestamp::*;
-use liq::*;
+use liquid_stake::*;
+use liquidity::*;
 use barrel::*;
 use contribute::*;
-use contribute_with_session::*;
+use withdraw_contribution::*;
+use get_pending_rewards::*;
+use get_well_quote::*;
+use get_stake_info::*;
 use checkpoint_auction::*;
-use checkpoint_auction_with_session::*;
 use oil_api::instruction::*;
 use steel::*;
 
@@ -118,12 +135,16 @@ pub fn process_instruction<'a>(
         OilInstruction::ClaimSOLWithSession => process_claim_sol_with_session(accounts, data)?,
         OilInstruction::ClaimOIL => process_claim_oil(accounts, data)?,
         OilInstruction::ClaimOILWithSession => process_claim_oil_with_session(accounts, data)?,
+        OilInstruction::VestOIL => process_vest_oil(accounts, data)?,
+        OilInstruction::VestOILWithSession => process_vest_oil_with_session(accounts, data)?,
         OilInstruction::Deploy => process_deploy(accounts, data)?,
         OilInstruction::DeployWithSession => process_deploy_with_session(accounts, data)?,
         OilInstruction::Log => process_log(accounts, data)?,
         OilInstruction::Close => process_close(accounts, data)?,
         OilInstruction::Reset => process_reset(accounts, data)?,
         OilInstruction::ReloadSOL => process_reload_sol(accounts, data)?,
+        OilInstruction::ClaimSeeker => process_claim_seeker(accounts, data)?,
+        OilInstruction::UpdateRig => process_update_rig(accounts, data)?,
 
         // Staker
         OilInstruction::Deposit => process_deposit(accounts, data)?,
@@ -132,28 +153,53 @@ pub fn process_instruction<'a>(
         OilInstruction::WithdrawWithSession => process_withdraw_with_session(accounts, data)?,
         OilInstruction::ClaimYield => process_claim_yield(accounts, data)?,
         OilInstruction::ClaimYieldWithSession => process_claim_yield_with_session(accounts, data)?,
+        OilInstruction::DepositLiquid => process_deposit_liquid(accounts, data)?,
+        OilInstruction::DepositLiquidWithSession => process_deposit_liquid_with_session(accounts, data)?,
+        OilInstruction::RedeemLiquid => process_redeem_liquid(accounts, data)?,
+        OilInstruction::RedeemLiquidWithSession => process_redeem_liquid_with_session(accounts, data)?,
 
         // Admin
         OilInstruction::Initialize => process_initialize(accounts, data)?,
         OilInstruction::Buyback => process_buyback(accounts, data)?,
+        OilInstruction::CompoundLiquid => process_compound_liquid(accounts, data)?,
         OilInstruction::Wrap => process_wrap(accounts, data)?,
-        OilInstruction::Liq => process_liq(accounts, data)?,
+        OilInstruction::AddLiquidity => process_add_liquidity(accounts, data)?,
+        OilInstruction::RemoveLiquidity => process_remove_liquidity(accounts, data)?,
+        OilInstruction::HarvestLiquidity => process_harvest_liquidity(accounts, data)?,
+        OilInstruction::RebalanceLiquidity => process_rebalance_liquidity(accounts, data)?,
+        OilInstruction::SetLpMint => process_set_lp_mint(accounts, data)?,
         OilInstruction::SetAdmin => process_set_admin(accounts, data)?,
+        OilInstruction::AcceptAdmin => process_accept_admin(accounts, data)?,
+        OilInstruction::CancelAdminAction => process_cancel_admin_action(accounts, data)?,
+        OilInstruction::SetRole => process_set_role(accounts, data)?,
+        OilInstruction::SetPause => process_set_pause(accounts, data)?,
         OilInstruction::SetFeeCollector => process_set_fee_collector(accounts, data)?,
         OilInstruction::SetSwapProgram => process_set_swap_program(accounts, data)?,
         OilInstruction::SetVarAddress => process_set_var_address(accounts, data)?,
         OilInstruction::NewVar => process_new_var(accounts, data)?,
         OilInstruction::SetAdminFee => process_set_admin_fee(accounts, data)?,
-        OilInstruction::Migrate => process_migrate(accounts, data)?,
+        OilInstruction::SetStakeBoost => process_set_stake_boost(accounts, data)?,
+        OilInstruction::SetSeekerBonus => process_set_seeker_bonus(accounts, data)?,
+        OilInstruction::SetRefiningFee => process_set_refining_fee(accounts, data)?,
+        OilInstruction::MigrateAccount => process_migrate_account(accounts, data)?,
+        OilInstruction::SetReferralConfig => process_set_referral_config(accounts, data)?,
         
         // Referral
         OilInstruction::CreateReferral => process_create_referral(accounts, data)?,
         OilInstruction::CreateReferralWithSession => process_create_referral_with_session(accounts, data)?,
         OilInstruction::ClaimReferral => process_claim_referral(accounts, data)?,
         OilInstruction::ClaimReferralWithSession => process_claim_referral_with_session(accounts, data)?,
+        OilInstruction::CreateReferralCode => process_create_referral_code(accounts, data)?,
+        OilInstruction::CreateReferralCodeWithSession => process_create_referral_code_with_session(accounts, data)?,
+        OilInstruction::SetReferralRebate => process_set_referral_rebate(accounts, data)?,
+        OilInstruction::SetReferralRebateWithSession => process_set_referral_rebate_with_session(accounts, data)?,
+        OilInstruction::SetReferrer => process_set_referrer(accounts, data)?,
+        OilInstruction::SetReferrerWithSession => process_set_referrer_with_session(accounts, data)?,
+        OilInstruction::ClearReferrer => process_clear_referrer(accounts, data)?,
 
         // Pre-mine
         OilInstruction::CreateWhitelist => process_create_whitelist(accounts, data)?,
+        OilInstruction::RevokeWhitelist => process_revoke_whitelist(accounts, data)?,
         OilInstruction::SetTgeTimestamp => process_set_tge_timestamp(accounts, data)?,
 
         // Auction-based mining
@@ -164,12 +210,20 @@ pub fn process_instruction<'a>(
         OilInstruction::ClaimAuctionSOL => process_claim_auction_sol(accounts, data)?,
         OilInstruction::ClaimAuctionSOLWithSession => process_claim_auction_sol_with_session(accounts, data)?,
         OilInstruction::SetAuction => process_set_auction(accounts, data)?,
+        OilInstruction::ScheduleHalvings => process_schedule_halvings(accounts, data)?,
         OilInstruction::Barrel => process_barrel(accounts, data)?,
         OilInstruction::Contribute => process_contribute(accounts, data)?,
         OilInstruction::ContributeWithSession => process_contribute_with_session(accounts, data)?,
+        OilInstruction::WithdrawContribution => process_withdraw_contribution(accounts, data)?,
+        OilInstruction::WithdrawContributionWithSession => process_withdraw_contribution_with_session(accounts, data)?,
         OilInstruction::CheckpointAuction => process_checkpoint_auction(accounts, data)?,
         OilInstruction::CheckpointAuctionWithSession => process_checkpoint_auction_with_session(accounts, data)?,
 
+        // View
+        OilInstruction::GetPendingRewards => process_get_pending_rewards(accounts, data)?,
+        OilInstruction::GetWellQuote => process_get_well_quote(accounts, data)?,
+        OilInstruction::GetStakeInfo => process_get_stake_info(accounts, data)?,
+
     }
 
     Ok(())
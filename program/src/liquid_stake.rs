@@ -0,0 +1,348 @@
+use oil_api::prelude::*;
+use oil_api::fogo::UserContext;
+use solana_program::log::sol_log;
+use solana_program::native_token::lamports_to_sol;
+use solana_program::program::invoke;
+use solana_program::program_pack::Pack;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+use spl_token::amount_to_ui_amount;
+use steel::*;
+
+use crate::pause::check_unpaused;
+
+/// Stakes OIL in the liquid stake, minting stOIL at the pool exchange rate.
+pub fn process_deposit_liquid<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, config, mint, sender, pool, pool_tokens, stoil_mint, recipient, system_program,
+    // token_program, associated_token_program
+    let [signer_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_STAKING)?;
+    deposit_liquid(UserContext::wallet(signer_info, signer_info, signer_info)?, accounts, data)
+}
+
+/// Stakes OIL in the liquid stake, minting stOIL at the pool exchange rate (FOGO session).
+pub fn process_deposit_liquid_with_session<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, authority, program_signer, payer, config, mint, sender, pool, pool_tokens, stoil_mint,
+    // recipient, system_program, token_program, associated_token_program
+    let [signer_info, authority_info, program_signer_info, payer_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_STAKING)?;
+    deposit_liquid(UserContext::session(signer_info, authority_info, program_signer_info, payer_info)?, accounts, data)
+}
+
+/// Burns stOIL, redeeming the OIL it backs from the pool.
+pub fn process_redeem_liquid<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, config, mint, recipient, pool, pool_tokens, stoil_mint, sender, pool_stoil, system_program,
+    // token_program, associated_token_program
+    let [signer_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_WITHDRAWALS)?;
+    redeem_liquid(UserContext::wallet(signer_info, signer_info, signer_info)?, accounts, data)
+}
+
+/// Burns stOIL, redeeming the OIL it backs from the pool (FOGO session).
+pub fn process_redeem_liquid_with_session<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, authority, program_signer, payer, config, mint, recipient, pool, pool_tokens, stoil_mint,
+    // sender, pool_stoil, system_program, token_program, associated_token_program
+    let [signer_info, authority_info, program_signer_info, payer_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_WITHDRAWALS)?;
+    redeem_liquid(UserContext::session(signer_info, authority_info, program_signer_info, payer_info)?, accounts, data)
+}
+
+fn deposit_liquid<'a>(user: UserContext<'a>, accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = DepositLiquid::try_from_bytes(data)?;
+    let amount = u64::from_le_bytes(args.amount);
+    let min_shares = u64::from_le_bytes(args.min_shares);
+
+    // Load accounts.
+    let [mint_info, sender_info, pool_info, pool_tokens_info, stoil_mint_info, recipient_info, system_program, token_program, associated_token_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let authority = user.key();
+    mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
+    let sender = sender_info
+        .is_writable()?
+        .as_associated_token_account(&authority, &MINT_ADDRESS)?;
+    let pool = pool_info.is_writable()?.as_account_mut::<Pool>(&oil_api::ID)?;
+    pool_tokens_info.as_associated_token_account(pool_info.key, mint_info.key)?;
+    stoil_mint_info.is_writable()?.has_seeds(&[STOIL_MINT], &oil_api::ID)?;
+    recipient_info.is_writable()?;
+    system_program.is_program(&system_program::ID)?;
+    token_program.is_program(&spl_token::ID)?;
+    associated_token_program.is_program(&spl_associated_token_account::ID)?;
+
+    // The first liquid deposit creates the stOIL mint, with the pool as mint authority.
+    if stoil_mint_info.data_is_empty() {
+        create_stoil_mint(stoil_mint_info, pool_info, user.payer, system_program, token_program)?;
+    }
+    let supply = stoil_mint_info.as_mint()?.supply();
+
+    // Load recipient.
+    if recipient_info.data_is_empty() {
+        create_associated_token_account(
+            user.payer,
+            user.authority,
+            recipient_info,
+            stoil_mint_info,
+            system_program,
+            token_program,
+            associated_token_program,
+        )?;
+    } else {
+        recipient_info.as_associated_token_account(&authority, stoil_mint_info.key)?;
+    }
+
+    // Stake the OIL and mint stOIL at the current exchange rate.
+    let amount = sender.amount().min(amount);
+    let shares = pool.deposit_liquid(amount, supply, min_shares)?;
+    user.transfer_token(token_program, sender_info, mint_info, pool_tokens_info, amount)?;
+    invoke_signed(
+        &spl_token::instruction::mint_to(
+            &spl_token::ID,
+            stoil_mint_info.key,
+            recipient_info.key,
+            pool_info.key,
+            &[],
+            shares,
+        )?,
+        &[
+            stoil_mint_info.clone(),
+            recipient_info.clone(),
+            pool_info.clone(),
+            token_program.clone(),
+        ],
+        &oil_api::ID,
+        &[POOL],
+    )?;
+
+    sol_log(&format!(
+        "Depositing {} OIL for {} stOIL",
+        amount_to_ui_amount(amount, TOKEN_DECIMALS),
+        amount_to_ui_amount(shares, TOKEN_DECIMALS)
+    ));
+
+    Ok(())
+}
+
+fn redeem_liquid<'a>(user: UserContext<'a>, accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = RedeemLiquid::try_from_bytes(data)?;
+    let amount = u64::from_le_bytes(args.amount);
+
+    // Load accounts.
+    let [mint_info, recipient_info, pool_info, pool_tokens_info, stoil_mint_info, sender_info, pool_stoil_info, system_program, token_program, associated_token_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let authority = user.key();
+    mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
+    recipient_info.is_writable()?;
+    let pool = pool_info.is_writable()?.as_account_mut::<Pool>(&oil_api::ID)?;
+    pool_tokens_info.as_associated_token_account(pool_info.key, mint_info.key)?;
+    let supply = stoil_mint_info
+        .is_writable()?
+        .has_seeds(&[STOIL_MINT], &oil_api::ID)?
+        .as_mint()?
+        .supply();
+    let sender = sender_info
+        .is_writable()?
+        .as_associated_token_account(&authority, stoil_mint_info.key)?;
+    pool_stoil_info.is_writable()?;
+    system_program.is_program(&system_program::ID)?;
+    token_program.is_program(&spl_token::ID)?;
+    associated_token_program.is_program(&spl_associated_token_account::ID)?;
+
+    // Load token accounts.
+    if recipient_info.data_is_empty() {
+        create_associated_token_account(
+            user.payer,
+            user.authority,
+            recipient_info,
+            mint_info,
+            system_program,
+            token_program,
+            associated_token_program,
+        )?;
+    } else {
+        recipient_info.as_associated_token_account(&authority, mint_info.key)?;
+    }
+    if pool_stoil_info.data_is_empty() {
+        create_associated_token_account(
+            user.payer,
+            pool_info,
+            pool_stoil_info,
+            stoil_mint_info,
+            system_program,
+            token_program,
+            associated_token_program,
+        )?;
+    } else {
+        pool_stoil_info.as_associated_token_account(pool_info.key, stoil_mint_info.key)?;
+    }
+
+    // Burn the stOIL (moved to the pool first, so sessions can redeem) and send the OIL it backs.
+    let shares = sender.amount().min(amount);
+    let amount = pool.redeem_liquid(shares, supply);
+    user.transfer_token(token_program, sender_info, stoil_mint_info, pool_stoil_info, shares)?;
+    burn_signed(pool_stoil_info, stoil_mint_info, pool_info, token_program, shares, &[POOL])?;
+    transfer_signed(pool_info, pool_tokens_info, recipient_info, token_program, amount, &[POOL])?;
+
+    sol_log(&format!(
+        "Redeeming {} stOIL for {} OIL",
+        amount_to_ui_amount(shares, TOKEN_DECIMALS),
+        amount_to_ui_amount(amount, TOKEN_DECIMALS)
+    ));
+
+    Ok(())
+}
+
+/// Creates the stOIL mint PDA, with the pool as mint authority.
+fn create_stoil_mint<'a>(
+    stoil_mint_info: &'a AccountInfo<'a>,
+    pool_info: &'a AccountInfo<'a>,
+    payer_info: &'a AccountInfo<'a>,
+    system_program: &'a AccountInfo<'a>,
+    token_program: &'a AccountInfo<'a>,
+) -> ProgramResult {
+    let space = spl_token::state::Mint::LEN;
+    invoke_signed(
+        &system_instruction::create_account(
+            payer_info.key,
+            stoil_mint_info.key,
+            Rent::get()?.minimum_balance(space),
+            space as u64,
+            &spl_token::ID,
+        ),
+        &[payer_info.clone(), stoil_mint_info.clone(), system_program.clone()],
+        &oil_api::ID,
+        &[STOIL_MINT],
+    )?;
+    invoke(
+        &spl_token::instruction::initialize_mint2(&spl_token::ID, stoil_mint_info.key, pool_info.key, None, TOKEN_DECIMALS)?,
+        &[stoil_mint_info.clone(), token_program.clone()],
+    )?;
+    Ok(())
+}
+
+/// Swaps the SOL rewards earned by liquid stake into OIL and adds it to the liquid stake, raising
+/// the stOIL exchange rate.
+pub fn process_compound_liquid(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data. The swap data follows the args.
+    let (args, swap_data) = data.split_at(data.len().min(std::mem::size_of::<CompoundLiquid>()));
+    let args = CompoundLiquid::try_from_bytes(args)?;
+    let min_oil_out = u64::from_le_bytes(args.min_oil_out);
+
+    // Load accounts.
+    if accounts.len() < 10 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (oil_accounts, swap_accounts) = accounts.split_at(10);
+    let [signer_info, config_info, mint_info, pool_info, pool_tokens_info, pool_sol_info, sol_mint_info, system_program, token_program, associated_token_program] =
+        oil_accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    config_info
+        .as_account::<Config>(&oil_api::ID)?
+        .check_role(Role::TreasuryOperator, signer_info, accounts)?;
+    let oil_mint = mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
+    let pool = pool_info
+        .is_writable()?
+        .has_seeds(&[POOL], &oil_api::ID)?
+        .as_account_mut::<Pool>(&oil_api::ID)?;
+    pool_tokens_info.as_associated_token_account(pool_info.key, &MINT_ADDRESS)?;
+    pool_sol_info.is_writable()?;
+    sol_mint_info.has_address(&SOL_MINT)?;
+    system_program.is_program(&system_program::ID)?;
+    token_program.is_program(&spl_token::ID)?;
+    associated_token_program.is_program(&spl_associated_token_account::ID)?;
+
+    if pool_sol_info.data_is_empty() {
+        create_associated_token_account(
+            signer_info,
+            pool_info,
+            pool_sol_info,
+            sol_mint_info,
+            system_program,
+            token_program,
+            associated_token_program,
+        )?;
+    } else {
+        pool_sol_info.as_associated_token_account(pool_info.key, &SOL_MINT)?;
+    }
+
+    // Wrap the liquid stake's SOL rewards.
+    pool.update_liquid_rewards();
+    let sol_amount = pool.liquid_rewards.min(pool.balance);
+    assert!(sol_amount > 0);
+    pool.liquid_rewards -= sol_amount;
+    pool.balance -= sol_amount;
+    pool_info.send(sol_amount, pool_sol_info);
+    sync_native(pool_sol_info)?;
+
+    // Record pre-swap balances.
+    let pre_oil = pool_tokens_info
+        .as_associated_token_account(pool_info.key, &MINT_ADDRESS)?
+        .amount();
+    let pre_mint_supply = oil_mint.supply();
+    let pre_pool_lamports = pool_info.lamports();
+
+    // Build swap accounts.
+    let cpi_accounts: Vec<AccountMeta> = swap_accounts
+        .iter()
+        .map(|acc| AccountMeta {
+            pubkey: *acc.key,
+            is_signer: acc.key == pool_info.key || acc.is_signer,
+            is_writable: acc.is_writable,
+        })
+        .collect();
+    let cpi_account_infos: Vec<AccountInfo> = swap_accounts.to_vec();
+
+    // Invoke swap program.
+    invoke_signed(
+        &Instruction {
+            program_id: SWAP_PROGRAM,
+            accounts: cpi_accounts,
+            data: swap_data.to_vec(),
+        },
+        &cpi_account_infos,
+        &oil_api::ID,
+        &[POOL],
+    )?;
+
+    // The swap must spend all the wrapped SOL, and not touch the pool's lamports or the OIL supply.
+    assert_eq!(pool_info.lamports(), pre_pool_lamports, "Pool lamports changed during swap");
+    assert_eq!(mint_info.as_mint()?.supply(), pre_mint_supply, "Mint supply changed during swap");
+    let post_sol = pool_sol_info
+        .as_associated_token_account(pool_info.key, &SOL_MINT)?
+        .amount();
+    assert_eq!(post_sol, 0);
+    let post_oil = pool_tokens_info
+        .as_associated_token_account(pool_info.key, &MINT_ADDRESS)?
+        .amount();
+    assert!(post_oil >= pre_oil);
+
+    // Stake the OIL for stOIL holders, provided the swap met the operator's minimum.
+    let oil_amount = post_oil - pre_oil;
+    pool.compound_liquid(oil_amount, min_oil_out)?;
+    sol_log(&format!(
+        "Compounded {} SOL into {} OIL ({} OIL liquid staked)",
+        lamports_to_sol(sol_amount),
+        amount_to_ui_amount(oil_amount, TOKEN_DECIMALS),
+        amount_to_ui_amount(pool.liquid_staked, TOKEN_DECIMALS)
+    ));
+
+    Ok(())
+}
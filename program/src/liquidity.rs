@@ -0,0 +1,160 @@
+use oil_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+/// Deposits SOL and OIL held for liquidity into the pool, for LP tokens held by the treasury.
+pub fn process_add_liquidity(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    manage_liquidity(LiquidityAction::Add, accounts, data)
+}
+
+/// Burns LP tokens held by the treasury for SOL and OIL held for liquidity.
+pub fn process_remove_liquidity(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    manage_liquidity(LiquidityAction::Remove, accounts, data)
+}
+
+/// Collects the pool fees earned by the treasury's position.
+pub fn process_harvest_liquidity(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    manage_liquidity(LiquidityAction::Harvest, accounts, data)
+}
+
+/// Swaps between the SOL and OIL held for liquidity.
+pub fn process_rebalance_liquidity(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    manage_liquidity(LiquidityAction::Rebalance, accounts, data)
+}
+
+/// Invokes the liquidity program with the treasury as signer, and records the resulting balance
+/// changes of the treasury's SOL, OIL and LP token accounts.
+fn manage_liquidity(action: LiquidityAction, accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    if accounts.len() < 13 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (oil_accounts, pool_accounts) = accounts.split_at(13);
+    let [signer_info, board_info, config_info, mint_info, lp_mint_info, treasury_info, treasury_oil_info, treasury_sol_info, treasury_lp_info, system_program, token_program, associated_token_program, oil_program] =
+        oil_accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    config_info
+        .as_account::<Config>(&oil_api::ID)?
+        .check_role(Role::LiquidityManager, signer_info, accounts)?;
+    board_info.as_account_mut::<Board>(&oil_api::ID)?;
+    let oil_mint = mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
+    let treasury = treasury_info.as_account_mut::<Treasury>(&oil_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+    token_program.is_program(&spl_token::ID)?;
+    associated_token_program.is_program(&spl_associated_token_account::ID)?;
+    oil_program.is_program(&oil_api::ID)?;
+
+    // The LP mint of the treasury's position is set by the admin (see SetLpMint).
+    if treasury.lp_mint == Pubkey::default() {
+        return Err(ProgramError::UninitializedAccount);
+    }
+    lp_mint_info.has_address(&treasury.lp_mint)?;
+
+    // Create the treasury's LP token account on first use.
+    if treasury_lp_info.data_is_empty() {
+        create_associated_token_account(
+            signer_info,
+            treasury_info,
+            treasury_lp_info,
+            lp_mint_info,
+            system_program,
+            token_program,
+            associated_token_program,
+        )?;
+    }
+
+    // Sync native token balance.
+    sync_native(treasury_sol_info)?;
+
+    // Record pre-operation balances.
+    let pre_sol = treasury_sol_info
+        .as_associated_token_account(treasury_info.key, &SOL_MINT)?
+        .amount();
+    let pre_oil = treasury_oil_info
+        .as_associated_token_account(treasury_info.key, &MINT_ADDRESS)?
+        .amount();
+    let pre_lp = treasury_lp_info
+        .as_associated_token_account(treasury_info.key, &treasury.lp_mint)?
+        .amount();
+    let pre_mint_supply = oil_mint.supply();
+    let pre_treasury_lamports = treasury_info.lamports();
+
+    // Build liquidity program accounts.
+    let cpi_accounts: Vec<AccountMeta> = pool_accounts
+        .iter()
+        .map(|acc| AccountMeta {
+            pubkey: *acc.key,
+            is_signer: acc.key == treasury_info.key || acc.is_signer,
+            is_writable: acc.is_writable,
+        })
+        .collect();
+    let cpi_account_infos: Vec<AccountInfo> = pool_accounts.to_vec();
+
+    // Invoke liquidity program.
+    invoke_signed(
+        &Instruction {
+            program_id: LIQUIDITY_PROGRAM,
+            accounts: cpi_accounts,
+            data: data.to_vec(),
+        },
+        &cpi_account_infos,
+        &oil_api::ID,
+        &[TREASURY],
+    )?;
+
+    // The liquidity program must not touch the treasury's lamports or the OIL supply.
+    assert_eq!(
+        treasury_info.lamports(),
+        pre_treasury_lamports,
+        "Treasury lamports changed during liquidity operation"
+    );
+    assert_eq!(
+        mint_info.as_mint()?.supply(),
+        pre_mint_supply,
+        "Mint supply changed during liquidity operation"
+    );
+
+    // Record post-operation balances.
+    let post_sol = treasury_sol_info
+        .as_associated_token_account(treasury_info.key, &SOL_MINT)?
+        .amount();
+    let post_oil = treasury_oil_info
+        .as_associated_token_account(treasury_info.key, &MINT_ADDRESS)?
+        .amount();
+    let post_lp = treasury_lp_info
+        .as_associated_token_account(treasury_info.key, &treasury.lp_mint)?
+        .amount();
+    let change = LiquidityChange {
+        sol: post_sol as i128 - pre_sol as i128,
+        oil: post_oil as i128 - pre_oil as i128,
+        lp: post_lp as i128 - pre_lp as i128,
+    };
+    treasury.apply_liquidity_change(action, change)?;
+
+    sol_log(&format!(
+        "💧 {:?} liquidity: {} SOL, {} OIL, {} LP ({} LP held)",
+        action, change.sol, change.oil, change.lp, treasury.lp_tokens
+    ));
+
+    // Emit event.
+    program_log(
+        &[board_info.clone(), oil_program.clone()],
+        LiquidityEvent {
+            disc: OilEvent::Liquidity as u64,
+            action: action as u64,
+            sol_change: change.sol as i64,
+            oil_change: change.oil as i64,
+            lp_change: change.lp as i64,
+            lp_tokens: treasury.lp_tokens,
+            liquidity_sol: treasury.liquidity_sol,
+            liquidity_oil: treasury.liquidity_oil,
+            ts: Clock::get()?.unix_timestamp,
+        }
+        .to_bytes(),
+    )?;
+
+    Ok(())
+}
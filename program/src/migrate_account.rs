@@ -0,0 +1,217 @@
+use oil_api::prelude::*;
+use solana_program::{
+    log::sol_log,
+    program::invoke,
+    system_instruction,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+use steel::*;
+
+/// Upgrades an account from one layout version to the next.
+struct Upgrade {
+    /// The account size (including the discriminator) after the upgrade.
+    size: usize,
+
+    /// Transforms the account data once it has been resized (new bytes are zero-initialized).
+    transform: fn(&mut [u8]) -> ProgramResult,
+}
+
+impl Upgrade {
+    /// Extends the account to `size` bytes, leaving the new fields zeroed.
+    fn resize(size: usize) -> Self {
+        Self {
+            size,
+            transform: |_| Ok(()),
+        }
+    }
+
+    /// Extends the account to the layout of `T`, leaving the new fields zeroed.
+    fn extend<T>() -> Self {
+        Self::resize(8 + std::mem::size_of::<T>())
+    }
+}
+
+/// Returns the upgrade of an account type from `version` to `version + 1`.
+///
+/// The last upgrade of each type targets the current struct. When adding a version, pin the size of
+/// the previous upgrade to a literal and bump `OilAccount::version`.
+fn upgrade(kind: OilAccount, version: u8) -> Option<Upgrade> {
+    match (kind, version) {
+        // Version 1: extend legacy (unversioned) accounts to the layout at the time versioning was
+        // introduced, seeding the config roles from the legacy authorities.
+        (OilAccount::Config, 0) => Some(Upgrade {
+            size: 1216,
+            transform: seed_config_roles,
+        }),
+        (OilAccount::Automation, 0) => Some(Upgrade::extend::<Automation>()),
+        (OilAccount::Miner, 0) => Some(Upgrade::resize(736)),
+        (OilAccount::Treasury, 0) => Some(Upgrade::resize(144)),
+        (OilAccount::Board, 0) => Some(Upgrade::extend::<Board>()),
+        (OilAccount::Stake, 0) => Some(Upgrade::extend::<Stake>()),
+        (OilAccount::Round, 0) => Some(Upgrade::resize(1000)),
+        (OilAccount::Referral, 0) => Some(Upgrade::extend::<Referral>()),
+        (OilAccount::Pool, 0) => Some(Upgrade::resize(80)),
+        (OilAccount::Auction, 0) => Some(Upgrade::extend::<Auction>()),
+        (OilAccount::Well, 0) => Some(Upgrade::extend::<Well>()),
+        (OilAccount::Whitelist, 0) => Some(Upgrade::extend::<Whitelist>()),
+        (OilAccount::Micro, 0) => Some(Upgrade::extend::<Micro>()),
+        (OilAccount::Share, 0) => Some(Upgrade::extend::<Share>()),
+        (OilAccount::ReferralConfig, 0) => Some(Upgrade::extend::<ReferralConfig>()),
+        (OilAccount::ReferralCode, 0) => Some(Upgrade::extend::<ReferralCode>()),
+        (OilAccount::PendingAdminAction, 0) => Some(Upgrade::extend::<PendingAdminAction>()),
+
+        // Treasury version 2: protocol-owned liquidity accounting.
+        (OilAccount::Treasury, 1) => Some(Upgrade::extend::<Treasury>()),
+
+        // Config version 2: stake-weighted mining boost.
+        (OilAccount::Config, 1) => Some(Upgrade::resize(1232)),
+
+        // Miner version 2: stake boost snapshot.
+        (OilAccount::Miner, 1) => Some(Upgrade::resize(744)),
+
+        // Round version 2: stake-boosted deployment weights.
+        (OilAccount::Round, 1) => Some(Upgrade::extend::<Round>()),
+
+        // Config version 3: Seeker miner bonus.
+        (OilAccount::Config, 2) => Some(Upgrade::resize(1240)),
+
+        // Config version 4: configurable refining fees and the vesting period.
+        (OilAccount::Config, 3) => Some(Upgrade {
+            size: 8 + std::mem::size_of::<Config>(),
+            transform: seed_refining_fees,
+        }),
+
+        // Miner version 3: OIL vesting schedule.
+        (OilAccount::Miner, 2) => Some(Upgrade::extend::<Miner>()),
+
+        // Pool version 2: liquid staking (stOIL) rewards.
+        (OilAccount::Pool, 1) => Some(Upgrade::extend::<Pool>()),
+        _ => None,
+    }
+}
+
+/// Seeds the role table of a config that predates it (unassigned roles fall back to the admin).
+fn seed_config_roles(data: &mut [u8]) -> ProgramResult {
+    let config = Config::try_from_bytes_mut(data)?;
+    if config.roles[Role::Admin as usize].threshold > 0 {
+        return Ok(());
+    }
+    config.roles[Role::Admin as usize] = RoleSet::single(config.admin);
+    config.roles[Role::TreasuryOperator as usize] = RoleSet::single(BURY_AUTHORITY);
+    config.roles[Role::LiquidityManager as usize] = RoleSet::single(LIQ_MANAGER);
+    Ok(())
+}
+
+/// Seeds the refining fees and vesting period of a config that predates them with the defaults.
+fn seed_refining_fees(data: &mut [u8]) -> ProgramResult {
+    let config = Config::try_from_bytes_mut(data)?;
+    config.refining_fee_bps = DEFAULT_REFINING_FEE_BPS;
+    config.auction_refining_fee_bps = DEFAULT_AUCTION_REFINING_FEE_BPS;
+    config.vesting_period = DEFAULT_VESTING_PERIOD;
+    Ok(())
+}
+
+/// Migrate Account: Upgrades any program account to the current layout version of its type.
+/// Anyone can migrate an account: upgrades are deterministic and the signer only pays the rent top-up.
+pub fn process_migrate_account(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Account order: signer, account, system_program
+    let [signer_info, account_info, system_program_info, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    system_program_info.is_program(&system_program::ID)?;
+
+    migrate_account(account_info, signer_info, system_program_info)
+}
+
+/// Applies the registered upgrades of an account until it reaches the current version of its type,
+/// topping up rent from the payer as it grows.
+pub fn migrate_account<'a>(
+    account_info: &AccountInfo<'a>,
+    payer_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+) -> ProgramResult {
+    account_info.is_writable()?.has_owner(&oil_api::ID)?;
+
+    let (kind, mut version) = {
+        let data = account_info.try_borrow_data()?;
+        let kind = data
+            .first()
+            .and_then(|d| OilAccount::try_from(*d).ok())
+            .ok_or(ProgramError::InvalidAccountData)?;
+        (kind, account_version(&data))
+    };
+    let current_version = kind.version();
+    if version >= current_version {
+        sol_log(&format!("{:?} already at version {}", kind, version));
+        return Ok(());
+    }
+
+    let rent = Rent::get()?;
+    while version < current_version {
+        let upgrade = upgrade(kind, version).ok_or(ProgramError::InvalidAccountData)?;
+        let old_size = account_info.data_len();
+        let new_size = upgrade.size.max(old_size);
+
+        // The account must keep rent for the new size plus any SOL it holds above its old rent
+        let held = account_info.lamports().saturating_sub(rent.minimum_balance(old_size));
+        let required_balance = rent.minimum_balance(new_size).saturating_add(held);
+        let additional_rent = required_balance.saturating_sub(account_info.lamports());
+        if additional_rent > 0 {
+            invoke(
+                &system_instruction::transfer(payer_info.key, account_info.key, additional_rent),
+                &[payer_info.clone(), account_info.clone(), system_program_info.clone()],
+            )?;
+        }
+
+        // Reallocate the account (new bytes are automatically zero-initialized)
+        if new_size > old_size {
+            account_info.realloc(new_size, false)?;
+        }
+
+        let mut data = account_info.try_borrow_mut_data()?;
+        (upgrade.transform)(&mut data[..])?;
+        version += 1;
+        data[ACCOUNT_VERSION_OFFSET] = version;
+
+        sol_log(&format!(
+            "{:?} migrated to version {}: {} -> {} bytes",
+            kind, version, old_size, new_size
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem::{offset_of, size_of};
+
+    use super::*;
+
+    fn size(kind: OilAccount, version: u8) -> usize {
+        upgrade(kind, version).unwrap().size
+    }
+
+    #[test]
+    fn test_pinned_sizes_match_legacy_layouts() {
+        // Fields are only ever appended, so each pinned size ends where the next version's fields begin.
+        assert_eq!(size(OilAccount::Treasury, 0), 8 + 136);
+        assert_eq!(size(OilAccount::Treasury, 0), 8 + offset_of!(Treasury, lp_mint));
+        assert_eq!(size(OilAccount::Miner, 0), 8 + offset_of!(Miner, stake_boost_bps));
+        assert_eq!(size(OilAccount::Miner, 1), 8 + offset_of!(Miner, vesting_oil));
+        assert_eq!(size(OilAccount::Round, 0), 8 + offset_of!(Round, boosted_deployed));
+        assert_eq!(size(OilAccount::Pool, 0), 8 + offset_of!(Pool, liquid_rewards));
+        assert_eq!(size(OilAccount::Config, 0), 8 + offset_of!(Config, stake_boost_max_bps));
+        assert_eq!(size(OilAccount::Config, 1), 8 + offset_of!(Config, seeker_refining_discount_bps));
+        assert_eq!(size(OilAccount::Config, 2), 8 + offset_of!(Config, refining_fee_bps));
+
+        // The last upgrade of each type targets the current struct.
+        assert_eq!(size(OilAccount::Treasury, 1), 8 + size_of::<Treasury>());
+        assert_eq!(size(OilAccount::Miner, 2), 8 + size_of::<Miner>());
+        assert_eq!(size(OilAccount::Round, 1), 8 + size_of::<Round>());
+        assert_eq!(size(OilAccount::Pool, 1), 8 + size_of::<Pool>());
+        assert_eq!(size(OilAccount::Config, 3), 8 + size_of::<Config>());
+    }
+}
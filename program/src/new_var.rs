@@ -10,7 +10,7 @@ pub fn process_new_var(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
     let samples = u64::from_le_bytes(args.samples);
 
     // Load accounts.
-    let [signer_info, board_info, config_info, provider_info, var_info, system_program, entropy_program] =
+    let [signer_info, board_info, config_info, provider_info, var_info, system_program, entropy_program, ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -20,11 +20,8 @@ pub fn process_new_var(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
     let board = board_info.as_account_mut::<Board>(&oil_api::ID)?;
     
     config_info
-        .as_account_mut::<Config>(&oil_api::ID)?
-        .assert_mut_err(
-            |c| c.admin == *signer_info.key,
-            OilError::NotAuthorized.into(),
-        )?;
+        .as_account::<Config>(&oil_api::ID)?
+        .check_role(Role::Admin, signer_info, accounts)?;
     
     entropy_program.is_program(&entropy_rng_api::ID)?;
     system_program.is_program(&system_program::ID)?;
@@ -0,0 +1,10 @@
+use oil_api::prelude::*;
+use steel::*;
+
+/// Fails with `OilError::Paused` if any subsystem in `flags` is paused.
+pub fn check_unpaused(config_info: &AccountInfo<'_>, flags: u64) -> ProgramResult {
+    config_info
+        .has_seeds(&[CONFIG], &oil_api::ID)?
+        .as_account::<Config>(&oil_api::ID)?
+        .check_unpaused(flags)
+}
@@ -1,41 +1,78 @@
 use oil_api::prelude::*;
-use oil_api::consts::{AUCTION_FLOOR_PRICE, POOL_ADDRESS};
+use oil_api::consts::POOL_ADDRESS;
 use oil_api::instruction::PlaceBid;
+use oil_api::fogo::{UserContext, WrappedSolAccounts};
 use solana_program::{log::sol_log, native_token::lamports_to_sol};
 use steel::*;
 
+use crate::rig::{credit_xp, load_rig};
+
 /// Direct solo bid on an auction well (seize ownership)
-pub fn process_place_bid(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+pub fn process_place_bid<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, authority, well, auction, treasury, treasury_tokens, mint, mint_authority, mint_program,
+    // staking_pool, fee_collector, config, token_program, system_program, oil_program, bidder_miner, previous_owner_miner,
+    // micro, rig, referral (optional), whitelist (optional)
+    let [signer_info, authority_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    place_bid(UserContext::wallet(signer_info, authority_info, signer_info)?, accounts, data)
+}
+
+/// Direct solo bid on an auction well (Fogo session)
+pub fn process_place_bid_with_session<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, authority, program_signer, payer, well, auction, treasury, treasury_tokens, mint, mint_authority,
+    // mint_program, staking_pool, fee_collector, config, token_program, system_program, oil_program, bidder_miner,
+    // previous_owner_miner, micro, rig, referral (optional), whitelist (optional), user_wrapped_sol, treasury_wrapped_sol, token_program, sol_mint,
+    // associated_token_program
+    let [signer_info, authority_info, program_signer_info, payer_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    place_bid(UserContext::session(signer_info, authority_info, program_signer_info, payer_info)?, accounts, data)
+}
+
+fn place_bid<'a>(user: UserContext<'a>, accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
     let clock = Clock::get()?;
     let args = PlaceBid::try_from_bytes(data)?;
     let well_id = u64::from_le_bytes(args.square_id) as usize;
     let referrer = Pubkey::new_from_array(args.referrer);
+    let max_price = u64::from_le_bytes(args.max_price);
+    let expires_at = i64::from_le_bytes(args.expires_at);
     
     if well_id >= 4 {
         return Err(ProgramError::InvalidArgument);
     }
 
+    // Reject stale bids before touching any accounts
+    if expires_at > 0 && clock.unix_timestamp > expires_at {
+        return Err(OilError::BidExpired.into());
+    }
+
     let has_referral = referrer != Pubkey::default();
-    // Account order: signer, authority, well, auction, treasury, treasury_tokens, mint, mint_authority, mint_program,
-    // staking_pool, fee_collector, config, token_program, system_program, oil_program, bidder_miner, previous_owner_miner,
-    // micro, referral (optional)
-    let expected_len = 18 + if has_referral { 1 } else { 0 };
+    let has_access_code = args.access_code != [0; 32];
+    let expected_len = 17 + if has_referral { 1 } else { 0 } + if has_access_code { 1 } else { 0 };
     
     if accounts.len() < expected_len {
         return Err(ProgramError::NotEnoughAccountKeys);
     }
     
     let mut accounts_iter = accounts.iter();
-    oil_api::extract_accounts!(accounts_iter, [s, a, w, au, t, tt, m, ma, mp, sp, fc, c, tp, sys, op, bm, pom, mic]);
+    oil_api::extract_accounts!(accounts_iter, [w, au, t, tt, m, ma, mp, sp, fc, c, tp, sys, op, bm, pom, mic, rg]);
     let ref_info = if has_referral { accounts_iter.next() } else { None };
-    let (signer_info, authority_info, well_info, auction_info, 
+    let whitelist_info_opt = if has_access_code { accounts_iter.next() } else { None };
+    let (well_info, auction_info, 
          treasury_info, treasury_tokens_info, mint_info, mint_authority_info, mint_program, staking_pool_info, 
          fee_collector_info, config_info, token_program, system_program, oil_program, bidder_miner_info, 
-         previous_owner_miner_info, micro_info, referral_info_opt) = 
-         (s, a, w, au, t, tt, m, ma, mp, sp, fc, c, tp, sys, op, bm, pom, mic, ref_info);
+         previous_owner_miner_info, micro_info, rig_info, referral_info_opt) = 
+         (w, au, t, tt, m, ma, mp, sp, fc, c, tp, sys, op, bm, pom, mic, rg, ref_info);
+    
+    // Sessions pay with wrapped SOL (accounts follow the optional referral and whitelist)
+    let wrapped = if user.is_session() {
+        Some(WrappedSolAccounts::from_iter(&mut accounts_iter)?)
+    } else {
+        None
+    };
 
-    signer_info.is_signer()?;
-    let authority = *authority_info.key;
+    let authority = user.key();
     
     // Validate accounts
     let well = well_info.is_writable()?
@@ -46,8 +83,19 @@ pub fn process_place_bid(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRe
     treasury_info.is_writable()?.has_seeds(&[TREASURY], &oil_api::ID)?;
     let treasury = treasury_info.as_account_mut::<Treasury>(&oil_api::ID)?;
     let config = config_info.as_account::<Config>(&oil_api::ID)?;
+    config.check_unpaused(PAUSE_AUCTION)?;
     let is_premine = oil_api::utils::is_premine_active(&config, &clock);
     
+    // Before the TGE, bidding requires a usable access code
+    Whitelist::validate_premine_code(
+        is_premine,
+        has_access_code,
+        args.access_code,
+        whitelist_info_opt,
+        user.key(),
+        &clock,
+    )?;
+    
     mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
     treasury_tokens_info.as_associated_token_account(&treasury_info.key, &mint_info.key)?;
     mint_authority_info.as_account::<oil_mint_api::state::Authority>(&oil_mint_api::ID)?;
@@ -63,10 +111,10 @@ pub fn process_place_bid(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRe
     bidder_miner_info.is_writable()?.has_seeds(&[MINER, &authority.to_bytes()], &oil_api::ID)?;
     let is_new_miner = bidder_miner_info.data_is_empty();
     if is_new_miner {
-        create_program_account::<Miner>(
+        create_versioned_account::<Miner>(
             bidder_miner_info,
             system_program,
-            signer_info,
+            user.payer,
             &oil_api::ID,
             &[MINER, &authority.to_bytes()],
         )?;
@@ -96,9 +144,18 @@ pub fn process_place_bid(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRe
     well.check_and_apply_halving(auction, &clock);
 
     let current_price = well.current_price(auction, &clock);
-    let is_at_floor = current_price == AUCTION_FLOOR_PRICE;
     
     let bid_amount = current_price.checked_add(1).ok_or(ProgramError::ArithmeticOverflow)?;
+    
+    // Slippage protection: the price may have moved (e.g. another bid landed first)
+    if bid_amount > max_price {
+        sol_log(&format!(
+            "Bid amount {} exceeds max price {}",
+            bid_amount,
+            max_price
+        ));
+        return Err(OilError::PriceExceedsMax.into());
+    }
 
     let previous_owner = well.current_bidder;
     let has_previous_owner = previous_owner != Pubkey::default();
@@ -113,7 +170,7 @@ pub fn process_place_bid(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRe
     let total_protocol_revenue = buyback_amount + liquidity_amount + staking_amount + dev_fee_amount;
     let previous_owner_amount = bid_amount.saturating_sub(total_protocol_revenue);
 
-    treasury_info.collect(bid_amount, signer_info)?;
+    user.pay(bid_amount, treasury_info, &[TREASURY], system_program, wrapped.as_ref())?;
     
     treasury.balance += buyback_amount;
     treasury.liquidity += liquidity_amount;
@@ -167,10 +224,10 @@ pub fn process_place_bid(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRe
         micro_info.is_writable()?.has_seeds(&[MICRO, &well_id.to_le_bytes(), &previous_epoch_id.to_le_bytes()], &oil_api::ID)?;
         
         if micro_info.data_is_empty() {
-            create_program_account::<Micro>(
+            create_versioned_account::<Micro>(
                 micro_info,
                 system_program,
-                signer_info,
+                user.payer,
                 &oil_api::ID,
                 &[MICRO, &well_id.to_le_bytes(), &previous_epoch_id.to_le_bytes()],
             )?;
@@ -241,20 +298,17 @@ pub fn process_place_bid(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRe
     // Update Miner current_epoch_id
     let bidder_miner_mut = bidder_miner_info.as_account_mut::<Miner>(&oil_api::ID)?;
     bidder_miner_mut.current_epoch_id[well_id] = well.epoch_id;
+    
+    // Credit XP for the bid
+    let rig = load_rig(rig_info, authority, user.payer, system_program)?;
+    let level_up = credit_xp(rig, bidder_miner_mut, XpSource::Bid, sol_xp(bid_amount), &clock);
     well.current_bidder = authority;
-    well.init_price = if is_at_floor {
-        auction.starting_prices[well_id]
-    } else {
-        current_price * 2
-    };
+    well.init_price = auction.next_init_price(well_id, current_price);
     well.epoch_start_time = clock.unix_timestamp as u64;
     well.accumulated_oil = 0;
     well.operator_total_oil_mined = 0;
     well.last_update_time = clock.unix_timestamp as u64;
-    well.mps = auction.base_mining_rates[well_id];
-    // Apply all halvings that have already occurred
-    well.apply_existing_halvings(auction);
-    // Check for and apply any new halvings that should occur now
+    // Set mps to the current rate of the halving schedule
     well.check_and_apply_halving(auction, &clock);
     
     // Validate auction account right before auction_program_log (matching pattern from claim_auction_sol.rs)
@@ -277,6 +331,9 @@ pub fn process_place_bid(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRe
         }
         .to_bytes(),
     )?;
+    if let Some(event) = level_up {
+        auction_program_log(&[auction_info.clone(), oil_program.clone()], &event.to_bytes())?;
+    }
 
     if bid_amount > 0 {
         let pool_final_amount = if has_previous_owner {
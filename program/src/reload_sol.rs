@@ -2,21 +2,27 @@ use oil_api::prelude::*;
 use solana_program::{log::sol_log, native_token::lamports_to_sol};
 use steel::*;
 
-/// Reloads SOL from miner account to automation balance with single-tier referral system.
-pub fn process_reload_sol(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+use crate::pause::check_unpaused;
+
+/// Reloads SOL from miner account to automation balance with tiered referral system.
+pub fn process_reload_sol<'a>(accounts: &'a [AccountInfo<'a>], _data: &[u8]) -> ProgramResult {
+    // Account order: signer, config, automation, miner, system_program, [miner_referrer, referral_referrer,
+    // referral_config, [upline_referral]]
     // Load accounts.
     let clock = Clock::get()?;
     
-    if accounts.len() < 4 {
+    if accounts.len() < 5 {
         return Err(ProgramError::NotEnoughAccountKeys);
     }
     
     let signer_info = &accounts[0];
-    let automation_info = &accounts[1];
-    let miner_info = &accounts[2];
-    let system_program = &accounts[3];
+    let config_info = &accounts[1];
+    let automation_info = &accounts[2];
+    let miner_info = &accounts[3];
+    let system_program = &accounts[4];
     
     signer_info.is_signer()?;
+    check_unpaused(config_info, PAUSE_CLAIMS)?;
     let automation = automation_info
         .as_account_mut::<Automation>(&oil_api::ID)?
         .assert_mut(|a| a.executor == *signer_info.key)?
@@ -29,33 +35,14 @@ pub fn process_reload_sol(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
     // Get claimable amount (includes both regular SOL and gusher SOL).
     let total_amount = miner.claim_sol(&clock);
 
-    let referral_amount = if miner.referrer != Pubkey::default() {
-        if accounts.len() < 6 {
-            return Err(ProgramError::NotEnoughAccountKeys);
-        }
-
-        // Validate referrer's miner account
-        let miner_referrer_idx = 4;
-        let miner_referrer_info = &accounts[miner_referrer_idx];
-        miner_referrer_info
-            .has_seeds(&[MINER, &miner.referrer.to_bytes()], &oil_api::ID)?;
-
-        // Validate referrer's referral account
-        let referral_referrer_idx = 5;
-        let referral_referrer_info = &accounts[referral_referrer_idx];
-        referral_referrer_info
-            .has_seeds(&[REFERRAL, &miner.referrer.to_bytes()], &oil_api::ID)?;
-        
-        // Get referral account and calculate/credit referral bonus
-        let referral_referrer_info = &accounts[referral_referrer_idx];
-        let referral_referrer = referral_referrer_info
-            .as_account_mut::<Referral>(&oil_api::ID)?;
-        
-        // Calculate and credit referral bonus (0.5% of total claim)
-        referral_referrer.credit_sol_referral(total_amount)
+    let referral = if miner.referrer != Pubkey::default() {
+        let referral = ReferralAccounts::load(&accounts[5..], miner.referrer, automation.authority, false)?;
+        let split = referral.credit_sol(total_amount, &clock)?;
+        Some((referral, split))
     } else {
-        0
+        None
     };
+    let referral_amount = referral.as_ref().map_or(0, |(_, split)| split.total());
 
     // Calculate amount to add to automation balance (after referral deduction).
     let automation_amount = total_amount.saturating_sub(referral_amount);
@@ -70,18 +57,9 @@ pub fn process_reload_sol(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
         miner_info.send(automation_amount, automation_info);
     }
     
-    // Transfer referral SOL directly to referral account PDA from miner account.
-    if referral_amount > 0 {
-        let referral_referrer_info = &accounts[5];
-        
-        // Transfer SOL from miner to referral account
-        miner_info.send(referral_amount, referral_referrer_info);
-        
-        sol_log(&format!(
-            "Referral bonus: {} SOL to {}",
-            lamports_to_sol(referral_amount),
-            miner.referrer
-        ));
+    // Transfer referral SOL directly to the referral account PDAs from miner account.
+    if let Some((referral, split)) = referral {
+        referral.send_sol(miner_info, split);
     }
 
     Ok(())
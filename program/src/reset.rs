@@ -2,8 +2,11 @@ use entropy_rng_api::state::Var;
 use oil_api::prelude::*;
 use oil_api::consts::{POOL_ADDRESS, SOL_MINT};
 use solana_program::{keccak, log::sol_log, native_token::lamports_to_sol};
+use spl_token::amount_to_ui_amount;
 use steel::*;
 
+use crate::pause::check_unpaused;
+
 /// Pays out the winners and block reward.
 pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
     // Load accounts.
@@ -17,6 +20,7 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
+    check_unpaused(config_info, PAUSE_MINING)?;
     let board = board_info
         .as_account_mut::<Board>(&oil_api::ID)?
         .assert_mut(|b| {
@@ -49,8 +53,11 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
     slot_hashes_sysvar.is_sysvar(&sysvar::slot_hashes::ID)?;
     sol_mint_info.has_address(&SOL_MINT)?;
 
+    // Advance the emission schedule.
+    config.update_emission_week(clock.unix_timestamp);
+
     // Open next round account.
-    create_program_account::<Round>(
+    create_versioned_account::<Round>(
         round_next_info,
         oil_program,
         signer_info,
@@ -77,6 +84,8 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
     round_next.pool_rewards_oil = 0;
     round_next.pool_members = 0;
     round_next.pool_cumulative = [0; 25];
+    round_next.boosted_deployed = [0; 25];
+    round_next.boosted_pooled = 0;
 
     // Sample random variable
     let (entropy_accounts, mint_accounts) = other_accounts.split_at(2);
@@ -129,6 +138,7 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
                 total_minted: 0,
                 ts: clock.unix_timestamp,
                 gusher_sol: round.gusher_sol,
+                emission_week: config.emission_week,
             }
             .to_bytes(),
         )?;
@@ -175,6 +185,7 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
                 total_minted: 0,
                 ts: clock.unix_timestamp,
                 gusher_sol: 0, // No gusher if no winners
+                emission_week: config.emission_week,
             }
             .to_bytes(),
         )?;
@@ -241,13 +252,15 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
     let mint_supply = mint.supply();
     let remaining_supply = MAX_SUPPLY.saturating_sub(mint_supply);
     
-    // Emission per round (capped by remaining supply)
-    let mint_amount = remaining_supply.min(EMISSION_PER_ROUND * ONE_OIL);
+    // Scheduled emission for the current week (capped by remaining supply)
+    let emission = emission_per_round(config.emission_week);
+    let mint_amount = remaining_supply.min(emission);
     
     sol_log(&format!(
-        "Emission: per_round={} OIL, total_mint={} OIL",
-        EMISSION_PER_ROUND,
-        mint_amount / ONE_OIL
+        "Emission: week={}, per_round={} OIL, total_mint={} OIL",
+        config.emission_week,
+        amount_to_ui_amount(emission, TOKEN_DECIMALS),
+        amount_to_ui_amount(mint_amount, TOKEN_DECIMALS)
     ));
 
     // Reward OIL for the winning miner(s).
@@ -368,6 +381,7 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
             total_minted: mint_amount,
             ts: clock.unix_timestamp,
             gusher_sol: round.gusher_sol,
+            emission_week: config.emission_week,
         }
         .to_bytes(),
     )?;
@@ -0,0 +1,27 @@
+use oil_api::prelude::*;
+use steel::*;
+
+/// Revokes an access code by closing its Whitelist account, refunding rent to the admin.
+pub fn process_revoke_whitelist(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts: [signer, config, whitelist]
+    let [signer_info, config_info, whitelist_info, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    signer_info.is_signer()?;
+    whitelist_info.is_writable()?;
+
+    // Verify signer holds the whitelist issuer role
+    config_info
+        .as_account::<Config>(&oil_api::ID)?
+        .check_role(Role::WhitelistIssuer, signer_info, accounts)?;
+
+    // Verify the whitelist entry is derived from its own code hash
+    let code_hash = whitelist_info.as_account::<Whitelist>(&oil_api::ID)?.code_hash;
+    whitelist_info.has_address(&Whitelist::pda(code_hash).0)?;
+
+    // Close the account
+    whitelist_info.close(signer_info)?;
+
+    Ok(())
+}
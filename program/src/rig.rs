@@ -0,0 +1,59 @@
+use oil_api::prelude::*;
+use steel::*;
+
+/// Loads the rig of `authority`, creating it (paid by `payer`) if it does not exist yet.
+pub fn load_rig<'a, 'info>(
+    rig_info: &'a AccountInfo<'info>,
+    authority: Pubkey,
+    payer: &'a AccountInfo<'info>,
+    system_program: &'a AccountInfo<'info>,
+) -> Result<&'a mut Rig, ProgramError> {
+    rig_info
+        .is_writable()?
+        .has_seeds(&[RIG, &authority.to_bytes()], &oil_api::ID)?;
+    if rig_info.data_is_empty() {
+        create_versioned_account::<Rig>(
+            rig_info,
+            system_program,
+            payer,
+            &oil_api::ID,
+            &[RIG, &authority.to_bytes()],
+        )?;
+        let rig = rig_info.as_account_mut::<Rig>(&oil_api::ID)?;
+        rig.authority = authority;
+        return Ok(rig);
+    }
+    rig_info
+        .as_account_mut::<Rig>(&oil_api::ID)?
+        .assert_mut(|r| r.authority == authority)
+}
+
+/// Accrues a miner's stake XP before its stake score changes, so XP is only earned for stake held
+/// the whole time, and mirrors the rig's level to the miner. Staking leaves the board out of its
+/// accounts, so a level reached here is not logged as a `LevelUpEvent`.
+pub fn accrue_stake_xp(rig: &mut Rig, miner: &mut Miner, clock: &Clock) {
+    rig.accrue_stake_xp(miner.total_stake_score, clock.unix_timestamp);
+    miner.level = rig.level;
+}
+
+/// Credits `amount` XP from `source` to a miner's rig, after accruing the rig's stake XP, and
+/// mirrors the rig's level to the miner. Returns the event to log if the rig leveled up.
+pub fn credit_xp(
+    rig: &mut Rig,
+    miner: &mut Miner,
+    source: XpSource,
+    amount: u64,
+    clock: &Clock,
+) -> Option<LevelUpEvent> {
+    let previous_level = rig.level;
+    rig.accrue_stake_xp(miner.total_stake_score, clock.unix_timestamp);
+    rig.add_xp(source, amount);
+    miner.level = rig.level;
+    (rig.level > previous_level).then(|| LevelUpEvent {
+        disc: OilEvent::LevelUp as u64,
+        authority: rig.authority,
+        level: rig.level,
+        xp: rig.xp,
+        ts: clock.unix_timestamp,
+    })
+}
@@ -0,0 +1,124 @@
+use oil_api::prelude::*;
+use solana_program::{
+    log::sol_log,
+    program::invoke,
+    system_instruction,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+use steel::*;
+
+/// Schedules a change to the halving schedule in advance, or cancels a pending change (auction manager only)
+pub fn process_schedule_halvings(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data
+    let args = ScheduleHalvings::try_from_bytes(data)?;
+    let effective_time = i64::from_le_bytes(args.effective_time);
+    let first_halving_delay_seconds = u64::from_le_bytes(args.first_halving_delay_seconds);
+    let halving_period_seconds = u64::from_le_bytes(args.halving_period_seconds);
+    let first_halving_decay_bps = u64::from_le_bytes(args.first_halving_decay_bps);
+    let halving_decay_bps = u64::from_le_bytes(args.halving_decay_bps);
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp as u64;
+    
+    // Account order: signer, config, auction, system_program, well_0, well_1, well_2, well_3
+    let [signer_info, config_info, auction_info, system_program, well_0_info, well_1_info, well_2_info, well_3_info, ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    
+    signer_info.is_signer()?;
+    system_program.is_program(&system_program::ID)?;
+    
+    // Verify signer holds the auction manager role
+    config_info
+        .as_account::<Config>(&oil_api::ID)?
+        .check_role(Role::AuctionManager, signer_info, accounts)?;
+    
+    // A 0 effective time cancels the pending change
+    if effective_time != 0 && effective_time <= clock.unix_timestamp {
+        return Err(ProgramError::InvalidArgument);
+    }
+    
+    auction_info
+        .is_writable()?
+        .has_seeds(&[AUCTION], &oil_api::ID)?;
+    
+    // Extend auction account to hold the halving schedule (new bytes are zero-initialized)
+    let auction_size = 8 + std::mem::size_of::<Auction>();
+    if auction_info.data_len() < auction_size {
+        let required_balance = Rent::get()?.minimum_balance(auction_size);
+        let additional_rent = required_balance.saturating_sub(auction_info.lamports());
+        if additional_rent > 0 {
+            invoke(
+                &system_instruction::transfer(signer_info.key, auction_info.key, additional_rent),
+                &[signer_info.clone(), auction_info.clone(), system_program.clone()],
+            )?;
+        }
+        auction_info.realloc(auction_size, false)?;
+        sol_log(&format!("ℹ️  Reallocated auction account to {} bytes", auction_size));
+    }
+    let auction = auction_info.as_account_mut::<Auction>(&oil_api::ID)?;
+    
+    // Validate new schedule
+    if effective_time != 0 {
+        auction.check_halving_schedule(
+            first_halving_delay_seconds,
+            halving_period_seconds,
+            first_halving_decay_bps,
+            halving_decay_bps,
+        )?;
+    }
+    
+    // Load wells
+    let wells_info = [well_0_info, well_1_info, well_2_info, well_3_info];
+    let mut wells = Vec::with_capacity(4);
+    for (well_id, well_info) in wells_info.into_iter().enumerate() {
+        let well = well_info
+            .is_writable()?
+            .has_seeds(&[WELL, &(well_id as u64).to_le_bytes()], &oil_api::ID)?
+            .as_account_mut::<Well>(&oil_api::ID)?;
+        
+        // Accumulate OIL under the schedule in effect so far
+        well.update_accumulated_oil(auction, &clock);
+        wells.push(well);
+    }
+    
+    // Apply a pending change that has already taken effect before replacing it
+    auction.sync_halvings(current_time);
+    if auction.promote_pending_halving_schedule(current_time) {
+        sol_log("ℹ️  Applied pending halving schedule");
+    }
+    
+    auction.pending_halving_effective_time = effective_time.max(0) as u64;
+    if effective_time == 0 {
+        auction.pending_first_halving_delay_seconds = 0;
+        auction.pending_halving_period_seconds = 0;
+        auction.pending_first_halving_decay_bps = 0;
+        auction.pending_halving_decay_bps = 0;
+        sol_log("✅ Cancelled pending halving schedule");
+    } else {
+        auction.pending_first_halving_delay_seconds = first_halving_delay_seconds;
+        auction.pending_halving_period_seconds = halving_period_seconds;
+        auction.pending_first_halving_decay_bps = first_halving_decay_bps;
+        auction.pending_halving_decay_bps = halving_decay_bps;
+        sol_log(&format!("✅ Scheduled halvings: effective_time={}, first_delay={}s ({} bps), period={}s ({} bps)",
+            effective_time,
+            first_halving_delay_seconds,
+            first_halving_decay_bps,
+            halving_period_seconds,
+            halving_decay_bps));
+    }
+    
+    // Sync well mps to the current rate
+    for well in wells {
+        well.check_and_apply_halving(auction, &clock);
+    }
+    
+    sol_log(&format!("   halving_count={}, last_halving={}, next_halving={}",
+        auction.halving_count,
+        auction.last_halving_time,
+        auction.next_halving_time(current_time)));
+    
+    Ok(())
+}
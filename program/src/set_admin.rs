@@ -1,27 +1,28 @@
 use oil_api::prelude::*;
 use steel::*;
 
-/// Sets the admin.
+use crate::admin_timelock::queue_admin_action;
+
+/// Proposes a new admin. The new admin must accept the proposal once the timelock has passed.
 pub fn process_set_admin(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
     // Parse data.
     let args = SetAdmin::try_from_bytes(data)?;
     let new_admin = Pubkey::new_from_array(args.admin);
 
     // Load accounts.
-    let [signer_info, config_info, system_program] = accounts else {
+    let [signer_info, config_info, timelock_accounts @ ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
-    let config = config_info
-        .as_account_mut::<Config>(&oil_api::ID)?
-        .assert_mut_err(
-            |c| c.admin == *signer_info.key,
-            OilError::NotAuthorized.into(),
-        )?;
-    system_program.is_program(&system_program::ID)?;
+    config_info
+        .as_account::<Config>(&oil_api::ID)?
+        .check_role(Role::Admin, signer_info, accounts)?;
+    if new_admin == Pubkey::default() {
+        return Err(ProgramError::InvalidArgument);
+    }
 
-    // Set admin.
-    config.admin = new_admin;
+    // Queue the proposal.
+    queue_admin_action(signer_info, timelock_accounts, OilInstruction::SetAdmin, data, new_admin)?;
 
     Ok(())
 }
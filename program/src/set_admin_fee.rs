@@ -1,24 +1,28 @@
 use oil_api::prelude::*;
 use steel::*;
 
-/// Sets the admin fee.
+use crate::admin_timelock::admin_timelock;
+
+/// Sets the admin fee (timelocked).
 pub fn process_set_admin_fee(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
     // Parse data.
     let args = SetAdminFee::try_from_bytes(data)?;
     let new_admin_fee = u64::from_le_bytes(args.admin_fee);
 
     // Load accounts.
-    let [signer_info, config_info, system_program] = accounts else {
+    let [signer_info, config_info, timelock_accounts @ ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
     let config = config_info
-        .as_account_mut::<Config>(&oil_api::ID)?
-        .assert_mut_err(
-            |c| c.admin == *signer_info.key,
-            OilError::NotAuthorized.into(),
-        )?;
-    system_program.is_program(&system_program::ID)?;
+        .is_writable()?
+        .as_account_mut::<Config>(&oil_api::ID)?;
+    config.check_role(Role::Admin, signer_info, accounts)?;
+
+    // Queue the change, or apply it once the timelock has passed.
+    if !admin_timelock(signer_info, timelock_accounts, OilInstruction::SetAdminFee, data, Pubkey::default())? {
+        return Ok(());
+    }
 
     // Cap admin fee at 1%.
     let new_admin_fee = new_admin_fee.min(100);
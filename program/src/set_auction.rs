@@ -1,8 +1,16 @@
 use oil_api::prelude::*;
-use solana_program::log::sol_log;
+use solana_program::{
+    log::sol_log,
+    program::invoke,
+    system_instruction,
+    rent::Rent,
+    sysvar::Sysvar,
+};
 use steel::*;
 
-/// Sets the auction state (admin only)
+use crate::admin_timelock::admin_timelock;
+
+/// Sets the auction state (auction manager only, timelocked)
 pub fn process_set_auction(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
     sol_log("🔧 Set Auction instruction started");
     
@@ -10,28 +18,78 @@ pub fn process_set_auction(accounts: &[AccountInfo<'_>], data: &[u8]) -> Program
     let args = SetAuction::try_from_bytes(data)?;
     let well_id = u64::from_le_bytes(args.well_id) as usize;
     
-    // Load accounts: [signer, config, auction, well?]
-    if accounts.len() < 3 {
+    // Load accounts: [signer, config, auction, pending_admin_action, system_program, board, oil_program, well?]
+    if accounts.len() < 7 {
         return Err(ProgramError::NotEnoughAccountKeys);
     }
     
-    let [signer_info, config_info, auction_info] =
-        &accounts[0..3]
+    let [signer_info, config_info, auction_info, _pending_admin_action_info, system_program, _board_info, _oil_program] =
+        &accounts[0..7]
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
+    let timelock_accounts = &accounts[3..7];
     
     signer_info.is_signer()?;
+    system_program.is_program(&system_program::ID)?;
     
-    // Verify signer is admin
+    // Verify signer holds the auction manager role
     config_info
         .as_account::<Config>(&oil_api::ID)?
-        .assert(|c| c.admin == *signer_info.key)?;
+        .check_role(Role::AuctionManager, signer_info, accounts)?;
+    
+    // Parse per-well price curve settings
+    let mut starting_prices = [0u64; 4];
+    let mut price_curves = [0u64; 4];
+    let mut floor_prices = [0u64; 4];
+    let mut price_multipliers_bps = [0u64; 4];
+    for i in 0..4 {
+        starting_prices[i] = u64::from_le_bytes(args.starting_prices[i]);
+        price_curves[i] = u64::from_le_bytes(args.price_curves[i]);
+        floor_prices[i] = u64::from_le_bytes(args.floor_prices[i]);
+        price_multipliers_bps[i] = u64::from_le_bytes(args.price_multipliers_bps[i]);
+        
+        // Curve must be a known PriceCurve variant
+        if price_curves[i] > u8::MAX as u64 || PriceCurve::try_from(price_curves[i] as u8).is_err() {
+            return Err(ProgramError::InvalidArgument);
+        }
+        
+        // Prices decay from the starting price down to the floor (0 = default floor)
+        let floor_price = if floor_prices[i] == 0 { AUCTION_FLOOR_PRICE } else { floor_prices[i] };
+        if floor_price > starting_prices[i] {
+            return Err(ProgramError::InvalidArgument);
+        }
+        
+        // Next epoch must never start below the winning price (0 = default multiplier)
+        if price_multipliers_bps[i] != 0 && price_multipliers_bps[i] < DENOMINATOR_BPS {
+            return Err(ProgramError::InvalidArgument);
+        }
+    }
+    
+    // Queue the change, or apply it once the timelock has passed
+    if !admin_timelock(signer_info, timelock_accounts, OilInstruction::SetAuction, data, Pubkey::default())? {
+        return Ok(());
+    }
     
     // Load and update auction account
     auction_info
         .is_writable()?
         .has_seeds(&[AUCTION], &oil_api::ID)?;
+    
+    // Extend auction account to hold per-well price curve settings (new bytes are zero-initialized)
+    let auction_size = 8 + std::mem::size_of::<Auction>();
+    if auction_info.data_len() < auction_size {
+        let required_balance = Rent::get()?.minimum_balance(auction_size);
+        let additional_rent = required_balance.saturating_sub(auction_info.lamports());
+        if additional_rent > 0 {
+            invoke(
+                &system_instruction::transfer(signer_info.key, auction_info.key, additional_rent),
+                &[signer_info.clone(), auction_info.clone(), system_program.clone()],
+            )?;
+        }
+        auction_info.realloc(auction_size, false)?;
+        sol_log(&format!("ℹ️  Reallocated auction account to {} bytes", auction_size));
+    }
     let auction = auction_info.as_account_mut::<Auction>(&oil_api::ID)?;
     
     // Update auction account with new base_mining_rates
@@ -42,12 +100,10 @@ pub fn process_set_auction(accounts: &[AccountInfo<'_>], data: &[u8]) -> Program
         u64::from_le_bytes(args.base_mining_rates[3]),
     ];
     auction.auction_duration_seconds = u64::from_le_bytes(args.auction_duration_seconds);
-    auction.starting_prices = [
-        u64::from_le_bytes(args.starting_prices[0]),
-        u64::from_le_bytes(args.starting_prices[1]),
-        u64::from_le_bytes(args.starting_prices[2]),
-        u64::from_le_bytes(args.starting_prices[3]),
-    ];
+    auction.starting_prices = starting_prices;
+    auction.price_curves = price_curves;
+    auction.floor_prices = floor_prices;
+    auction.price_multipliers_bps = price_multipliers_bps;
     
     // Initialize halving_period_seconds and last_halving_time if they're 0 (not yet set)
     let clock = Clock::get()?;
@@ -73,17 +129,24 @@ pub fn process_set_auction(accounts: &[AccountInfo<'_>], data: &[u8]) -> Program
         sol_log(&format!("ℹ️  Initialized last_halving_time to current timestamp: {}", current_timestamp));
     }
     
+    auction.sync_halvings(current_timestamp);
+    
     sol_log(&format!("✅ Auction account updated: base_mining_rates={:?}, auction_duration={}", 
         auction.base_mining_rates, auction.auction_duration_seconds));
-    sol_log(&format!("   Time-based halving: first=14 days (50%), subsequent={}s (28 days, 25%), halving_count={}, last_halving={}, next_halving={}", 
+    sol_log(&format!("   Price curves: curves={:?}, floor_prices={:?}, multipliers_bps={:?}", 
+        auction.price_curves, auction.floor_prices, auction.price_multipliers_bps));
+    sol_log(&format!("   Time-based halving: first_delay={}s ({} bps), period={}s ({} bps), halving_count={}, last_halving={}, next_halving={}", 
+        auction.first_halving_delay_seconds,
+        auction.first_halving_decay_bps,
         auction.halving_period_seconds,
+        auction.halving_decay_bps,
         auction.halving_count,
         auction.last_halving_time,
-        auction.next_halving_time()));
+        auction.next_halving_time(current_timestamp)));
     
     // Sync well mps if well_id < 4 and well account is provided
-    if well_id < 4 && accounts.len() >= 4 {
-        let well_info = &accounts[3];
+    if well_id < 4 && accounts.len() >= 8 {
+        let well_info = &accounts[7];
         let well_id_u64 = well_id as u64;
         
         // Load well account
@@ -97,11 +160,8 @@ pub fn process_set_auction(accounts: &[AccountInfo<'_>], data: &[u8]) -> Program
                 let new_base_mps = auction.base_mining_rates[well_id];
                 well.mps = new_base_mps;
                 
-                // Update accumulated OIL and apply halvings
+                // Update accumulated OIL and set mps to the current rate of the halving schedule
                 well.update_accumulated_oil(auction, &clock);
-                // Apply all halvings that have already occurred
-                well.apply_existing_halvings(auction);
-                // Check for and apply any new halvings that should occur now
                 well.check_and_apply_halving(auction, &clock);
                 
                 sol_log(&format!("✅ Synced well {} mps to {} (after halvings: {})", 
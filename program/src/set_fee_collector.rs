@@ -1,24 +1,26 @@
 use oil_api::prelude::*;
 use steel::*;
 
-/// Sets the fee collector.
+use crate::admin_timelock::admin_timelock;
+
+/// Sets the fee collector (timelocked).
 pub fn process_set_fee_collector(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
     // Parse data.
     let args = SetFeeCollector::try_from_bytes(data)?;
     let new_fee_collector = Pubkey::new_from_array(args.fee_collector);
 
     // Load accounts.
-    let [signer_info, config_info, system_program] = accounts else {
+    let [signer_info, config_info, timelock_accounts @ ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
-    let config = config_info
-        .as_account_mut::<Config>(&oil_api::ID)?
-        .assert_mut_err(
-            |c| c.admin == *signer_info.key,
-            OilError::NotAuthorized.into(),
-        )?;
-    system_program.is_program(&system_program::ID)?;
+    let config = config_info.as_account_mut::<Config>(&oil_api::ID)?;
+    config.check_role(Role::Admin, signer_info, accounts)?;
+
+    // Queue the change, or apply it once the timelock has passed.
+    if !admin_timelock(signer_info, timelock_accounts, OilInstruction::SetFeeCollector, data, Pubkey::default())? {
+        return Ok(());
+    }
 
     // Set fee collector.
     config.fee_collector = new_fee_collector;
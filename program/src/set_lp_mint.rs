@@ -0,0 +1,41 @@
+use oil_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+use crate::admin_timelock::admin_timelock;
+
+/// Sets the LP token mint of the treasury's pool position (timelocked).
+pub fn process_set_lp_mint(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let [signer_info, config_info, treasury_info, lp_mint_info, timelock_accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    config_info
+        .as_account::<Config>(&oil_api::ID)?
+        .check_role(Role::Admin, signer_info, accounts)?;
+    let treasury = treasury_info
+        .is_writable()?
+        .as_account_mut::<Treasury>(&oil_api::ID)?;
+    if *lp_mint_info.key == MINT_ADDRESS || *lp_mint_info.key == SOL_MINT {
+        return Err(ProgramError::InvalidArgument);
+    }
+    lp_mint_info.as_mint()?;
+
+    // The position can only move to another pool once it has been fully withdrawn.
+    if treasury.lp_tokens > 0 {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Queue the change, or apply it once the timelock has passed.
+    if !admin_timelock(signer_info, timelock_accounts, OilInstruction::SetLpMint, data, *lp_mint_info.key)? {
+        return Ok(());
+    }
+
+    // Set LP mint.
+    treasury.lp_mint = *lp_mint_info.key;
+
+    sol_log(&format!("LP mint set: {}", treasury.lp_mint));
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use oil_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+/// Sets the paused subsystems. The admin can pause and unpause, the guardian can only pause.
+pub fn process_set_pause(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetPause::try_from_bytes(data)?;
+    let paused = u64::from_le_bytes(args.paused);
+
+    // Load accounts.
+    let [signer_info, config_info, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .is_writable()?
+        .has_seeds(&[CONFIG], &oil_api::ID)?
+        .as_account_mut::<Config>(&oil_api::ID)?;
+    if paused & !PAUSE_ALL != 0 {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // The guardian can only add pause flags.
+    if config.check_role(Role::Admin, signer_info, accounts).is_err() {
+        config.check_role(Role::Guardian, signer_info, accounts)?;
+        if paused & config.paused != config.paused {
+            return Err(OilError::NotAuthorized.into());
+        }
+    }
+
+    sol_log(&format!("Paused subsystems: {:#08b} -> {:#08b}", config.paused, paused));
+
+    // Set paused subsystems.
+    config.paused = paused;
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use oil_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+/// Sets the referral tiers and upline share, creating the referral config if needed (admin only)
+pub fn process_set_referral_config(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data
+    let args = SetReferralConfig::try_from_bytes(data)?;
+    let tier_thresholds = args.tier_thresholds.map(u64::from_le_bytes);
+    let tier_rates_bps = args.tier_rates_bps.map(u64::from_le_bytes);
+    let upline_share_bps = u64::from_le_bytes(args.upline_share_bps);
+
+    // Account order: signer, config, referral_config, system_program
+    let [signer_info, config_info, referral_config_info, system_program, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    signer_info.is_signer()?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Verify signer is admin
+    config_info
+        .as_account::<Config>(&oil_api::ID)?
+        .check_role(Role::Admin, signer_info, accounts)?;
+
+    // Tier thresholds must ascend (a 0 threshold disables the tier and every tier after it)
+    for i in 2..4 {
+        if tier_thresholds[i] != 0 && tier_thresholds[i] <= tier_thresholds[i - 1] {
+            return Err(ProgramError::InvalidArgument);
+        }
+    }
+
+    // Referrer and upline together can never take more than the max rate of a claim
+    for rate_bps in tier_rates_bps {
+        if rate_bps.saturating_add(upline_share_bps) > MAX_REFERRAL_RATE_BPS {
+            return Err(ProgramError::InvalidArgument);
+        }
+    }
+
+    referral_config_info
+        .is_writable()?
+        .has_seeds(&[REFERRAL_CONFIG], &oil_api::ID)?;
+    if referral_config_info.data_is_empty() {
+        create_versioned_account::<ReferralConfig>(
+            referral_config_info,
+            system_program,
+            signer_info,
+            &oil_api::ID,
+            &[REFERRAL_CONFIG],
+        )?;
+    }
+    let referral_config = referral_config_info.as_account_mut::<ReferralConfig>(&oil_api::ID)?;
+    referral_config.tier_thresholds = tier_thresholds;
+    referral_config.tier_thresholds[0] = 0;
+    referral_config.tier_rates_bps = tier_rates_bps;
+    referral_config.upline_share_bps = upline_share_bps;
+
+    sol_log(&format!(
+        "Referral config: thresholds={:?}, rates_bps={:?}, upline_share_bps={}",
+        referral_config.tier_thresholds, referral_config.tier_rates_bps, referral_config.upline_share_bps
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use oil_api::prelude::*;
+use oil_api::fogo::UserContext;
+use solana_program::log::sol_log;
+use steel::*;
+
+use crate::pause::check_unpaused;
+
+/// Sets the share of the referral bonus a referrer gives back to their referees.
+pub fn process_set_referral_rebate<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, config, referral
+    let [signer_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_REFERRALS)?;
+    set_referral_rebate(UserContext::wallet(signer_info, signer_info, signer_info)?, accounts, data)
+}
+
+/// Sets the share of the referral bonus a referrer gives back to their referees (FOGO session).
+pub fn process_set_referral_rebate_with_session<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, authority, program_signer, config, referral
+    let [signer_info, authority_info, program_signer_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_REFERRALS)?;
+    set_referral_rebate(UserContext::session(signer_info, authority_info, program_signer_info, signer_info)?, accounts, data)
+}
+
+fn set_referral_rebate<'a>(user: UserContext<'a>, accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    let args = SetReferralRebate::try_from_bytes(data)?;
+    let rebate_bps = u64::from_le_bytes(args.rebate_bps);
+    if rebate_bps > DENOMINATOR_BPS {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let [referral_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Only the referrer (or their own session) may change their rebate
+    user.assert_authority()?;
+    let authority = user.key();
+
+    let referral = referral_info
+        .is_writable()?
+        .has_seeds(&[REFERRAL, &authority.to_bytes()], &oil_api::ID)?
+        .as_account_mut::<Referral>(&oil_api::ID)?
+        .assert_mut(|r| r.authority == authority)?;
+    referral.rebate_bps = rebate_bps;
+
+    sol_log(&format!("Referral rebate set to {} bps", rebate_bps));
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use oil_api::prelude::*;
+use oil_api::fogo::UserContext;
+use solana_program::log::sol_log;
+use steel::*;
+
+use crate::pause::check_unpaused;
+
+/// Attaches a referrer to an existing miner that has none.
+pub fn process_set_referrer<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, config, miner, referral
+    let [signer_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_REFERRALS)?;
+    set_referrer(UserContext::wallet(signer_info, signer_info, signer_info)?, accounts, data)
+}
+
+/// Attaches a referrer to an existing miner that has none (FOGO session).
+pub fn process_set_referrer_with_session<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, authority, program_signer, config, miner, referral
+    let [signer_info, authority_info, program_signer_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_REFERRALS)?;
+    set_referrer(UserContext::session(signer_info, authority_info, program_signer_info, signer_info)?, accounts, data)
+}
+
+fn set_referrer<'a>(user: UserContext<'a>, accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    let args = SetReferrer::try_from_bytes(data)?;
+    let referrer = Pubkey::new_from_array(args.referrer);
+
+    let [miner_info, referral_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Only the miner (or their own session) may choose its referrer
+    user.assert_authority()?;
+    let authority = user.key();
+
+    // A referrer can only be attached once, and never to yourself
+    if referrer == Pubkey::default() || referrer == authority {
+        return Err(ProgramError::InvalidArgument);
+    }
+    let miner = miner_info
+        .is_writable()?
+        .has_seeds(&[MINER, &authority.to_bytes()], &oil_api::ID)?
+        .as_account_mut::<Miner>(&oil_api::ID)?
+        .assert_mut(|m| m.authority == authority)?;
+    if miner.referrer != Pubkey::default() {
+        sol_log("Miner already has a referrer");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    miner.referrer = referrer;
+    Referral::process_new_miner_referral(Some(referral_info), referrer, authority)?;
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use oil_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+use crate::admin_timelock::admin_timelock;
+
+/// Sets the refining fees and the vesting period of fee-free claims (timelocked).
+pub fn process_set_refining_fee(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetRefiningFee::try_from_bytes(data)?;
+    let refining_fee_bps = u64::from_le_bytes(args.refining_fee_bps);
+    let auction_refining_fee_bps = u64::from_le_bytes(args.auction_refining_fee_bps);
+    let vesting_period = i64::from_le_bytes(args.vesting_period);
+    if refining_fee_bps > MAX_REFINING_FEE_BPS
+        || auction_refining_fee_bps > MAX_REFINING_FEE_BPS
+        || vesting_period <= 0
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Load accounts.
+    let [signer_info, config_info, timelock_accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .is_writable()?
+        .as_account_mut::<Config>(&oil_api::ID)?;
+    config.check_role(Role::Admin, signer_info, accounts)?;
+
+    // Queue the change, or apply it once the timelock has passed.
+    if !admin_timelock(signer_info, timelock_accounts, OilInstruction::SetRefiningFee, data, Pubkey::default())? {
+        return Ok(());
+    }
+
+    // Set the fees (already vesting OIL keeps its schedule).
+    config.refining_fee_bps = refining_fee_bps;
+    config.auction_refining_fee_bps = auction_refining_fee_bps;
+    config.vesting_period = vesting_period;
+    sol_log(&format!(
+        "Refining fee: {} bps, auction refining fee: {} bps, vesting period: {}s",
+        refining_fee_bps, auction_refining_fee_bps, vesting_period
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use oil_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+use crate::admin_timelock::admin_timelock;
+
+/// Sets the members and signing threshold of a role (timelocked).
+pub fn process_set_role(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetRole::try_from_bytes(data)?;
+    let role = Role::try_from(args.role).map_err(|_| ProgramError::InvalidArgument)?;
+    let threshold = args.threshold as u64;
+    let members = args.members.map(Pubkey::new_from_array);
+
+    // Load accounts.
+    let [signer_info, config_info, timelock_accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .is_writable()?
+        .as_account_mut::<Config>(&oil_api::ID)?;
+    config.check_role(Role::Admin, signer_info, accounts)?;
+
+    // Members must be distinct
+    for (i, member) in members.iter().enumerate() {
+        if *member != Pubkey::default() && members[i + 1..].contains(member) {
+            return Err(ProgramError::InvalidArgument);
+        }
+    }
+
+    // Threshold must be reachable (0 unassigns the role, which the admin role can't be)
+    let member_count = members.iter().filter(|m| **m != Pubkey::default()).count() as u64;
+    if threshold > member_count || (threshold == 0 && (member_count > 0 || role == Role::Admin)) {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // The admin key only changes through set_admin/accept_admin, so an admin multisig must seat it
+    if role == Role::Admin && !members.contains(&config.admin) {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Queue the change, or apply it once the timelock has passed.
+    if !admin_timelock(signer_info, timelock_accounts, OilInstruction::SetRole, data, Pubkey::default())? {
+        return Ok(());
+    }
+
+    // Set role.
+    config.roles[role as usize] = RoleSet { members, threshold };
+
+    sol_log(&format!("Role {:?} set: {}-of-{}", role, threshold, member_count));
+
+    Ok(())
+}
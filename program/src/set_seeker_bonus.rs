@@ -0,0 +1,36 @@
+use oil_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+use crate::admin_timelock::admin_timelock;
+
+/// Sets the Seeker miner bonus (timelocked).
+pub fn process_set_seeker_bonus(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetSeekerBonus::try_from_bytes(data)?;
+    let refining_discount_bps = u64::from_le_bytes(args.refining_discount_bps);
+    if refining_discount_bps > DENOMINATOR_BPS {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Load accounts.
+    let [signer_info, config_info, timelock_accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .is_writable()?
+        .as_account_mut::<Config>(&oil_api::ID)?;
+    config.check_role(Role::Admin, signer_info, accounts)?;
+
+    // Queue the change, or apply it once the timelock has passed.
+    if !admin_timelock(signer_info, timelock_accounts, OilInstruction::SetSeekerBonus, data, Pubkey::default())? {
+        return Ok(());
+    }
+
+    // Set the bonus (a discount of 0 disables it).
+    config.seeker_refining_discount_bps = refining_discount_bps;
+    sol_log(&format!("Seeker refining discount: {} bps", refining_discount_bps));
+
+    Ok(())
+}
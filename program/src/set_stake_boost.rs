@@ -0,0 +1,38 @@
+use oil_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+use crate::admin_timelock::admin_timelock;
+
+/// Sets the stake-weighted mining boost (timelocked).
+pub fn process_set_stake_boost(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetStakeBoost::try_from_bytes(data)?;
+    let max_bps = u64::from_le_bytes(args.max_bps);
+    let share_bps = u64::from_le_bytes(args.share_bps);
+    if max_bps > MAX_STAKE_BOOST_BPS || share_bps > DENOMINATOR_BPS {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Load accounts.
+    let [signer_info, config_info, timelock_accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .is_writable()?
+        .as_account_mut::<Config>(&oil_api::ID)?;
+    config.check_role(Role::Admin, signer_info, accounts)?;
+
+    // Queue the change, or apply it once the timelock has passed.
+    if !admin_timelock(signer_info, timelock_accounts, OilInstruction::SetStakeBoost, data, Pubkey::default())? {
+        return Ok(());
+    }
+
+    // Set the boost (a share of 0 disables it).
+    config.stake_boost_max_bps = max_bps;
+    config.stake_boost_share_bps = share_bps;
+    sol_log(&format!("Stake boost: up to {} bps at {} bps of the total stake", max_bps, share_bps));
+
+    Ok(())
+}
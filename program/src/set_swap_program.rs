@@ -1,21 +1,24 @@
 use oil_api::prelude::*;
 use steel::*;
 
-/// Sets the swap program.
-pub fn process_set_swap_program(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+use crate::admin_timelock::admin_timelock;
+
+/// Sets the swap program (timelocked).
+pub fn process_set_swap_program(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
     // Load accounts.
-    let [signer_info, config_info, new_swap_program_info] = accounts else {
+    let [signer_info, config_info, new_swap_program_info, timelock_accounts @ ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
-    let config = config_info
-        .as_account_mut::<Config>(&oil_api::ID)?
-        .assert_mut_err(
-            |c| c.admin == *signer_info.key,
-            OilError::NotAuthorized.into(),
-        )?;
+    let config = config_info.as_account_mut::<Config>(&oil_api::ID)?;
+    config.check_role(Role::Admin, signer_info, accounts)?;
     new_swap_program_info.is_executable()?;
 
+    // Queue the change, or apply it once the timelock has passed.
+    if !admin_timelock(signer_info, timelock_accounts, OilInstruction::SetSwapProgram, data, *new_swap_program_info.key)? {
+        return Ok(());
+    }
+
     // Set swap program.
     config.swap_program = *new_swap_program_info.key;
 
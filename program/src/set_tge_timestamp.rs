@@ -2,27 +2,29 @@ use oil_api::prelude::*;
 use solana_program::log::sol_log;
 use steel::*;
 
-/// Sets the TGE (Token Generation Event) timestamp.
+use crate::admin_timelock::admin_timelock;
+
+/// Sets the TGE (Token Generation Event) timestamp (timelocked).
 pub fn process_set_tge_timestamp(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
     // Parse data.
     let args = SetTgeTimestamp::try_from_bytes(data)?;
     let new_tge_timestamp = i64::from_le_bytes(args.tge_timestamp);
 
     // Load accounts.
-    let [signer_info, config_info, system_program] = accounts else {
+    let [signer_info, config_info, timelock_accounts @ ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
     config_info.is_writable()?;
-    system_program.is_program(&system_program::ID)?;
 
     // Verify signer is admin
-    let config = config_info
-        .as_account_mut::<Config>(&oil_api::ID)?
-        .assert_mut_err(
-            |c| c.admin == *signer_info.key,
-            OilError::NotAuthorized.into(),
-        )?;
+    let config = config_info.as_account_mut::<Config>(&oil_api::ID)?;
+    config.check_role(Role::Admin, signer_info, accounts)?;
+
+    // Queue the change, or apply it once the timelock has passed.
+    if !admin_timelock(signer_info, timelock_accounts, OilInstruction::SetTgeTimestamp, data, Pubkey::default())? {
+        return Ok(());
+    }
 
     // Set TGE timestamp
     let old_tge_timestamp = config.tge_timestamp;
@@ -0,0 +1,32 @@
+use oil_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+use crate::pause::check_unpaused;
+use crate::rig::{credit_xp, load_rig};
+
+/// Accrues stake XP on a miner's rig and mirrors its level to the miner. Anyone can update a rig;
+/// the signer pays for it if it does not exist yet.
+pub fn process_update_rig(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Account order: signer, board, config, miner, rig, system_program, oil_program
+    let clock = Clock::get()?;
+    let [signer_info, board_info, config_info, miner_info, rig_info, system_program, oil_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    check_unpaused(config_info, PAUSE_STAKING)?;
+    board_info.is_writable()?.has_seeds(&[BOARD], &oil_api::ID)?;
+    let miner = miner_info
+        .is_writable()?
+        .as_account_mut::<Miner>(&oil_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+    oil_program.is_program(&oil_api::ID)?;
+    let rig = load_rig(rig_info, miner.authority, signer_info, system_program)?;
+
+    if let Some(event) = credit_xp(rig, miner, XpSource::Stake, 0, &clock) {
+        program_log(&[board_info.clone(), oil_program.clone()], &event.to_bytes())?;
+    }
+    sol_log(&format!("Rig level {} ({} XP)", rig.level, rig.xp));
+
+    Ok(())
+}
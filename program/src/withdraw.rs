@@ -1,10 +1,35 @@
 use oil_api::prelude::*;
+use oil_api::fogo::UserContext;
 use solana_program::log::sol_log;
 use spl_token::amount_to_ui_amount;
 use steel::*;
 
+use crate::pause::check_unpaused;
+use crate::rig::{accrue_stake_xp, load_rig};
+
 /// Withdraws OIL from the staking contract.
-pub fn process_withdraw(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+pub fn process_withdraw<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, config, mint, recipient, stake, stake_tokens, pool, pool_tokens, miner, rig, treasury,
+    // treasury_oil, system_program, token_program, associated_token_program
+    let [signer_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_WITHDRAWALS)?;
+    withdraw(UserContext::wallet(signer_info, signer_info, signer_info)?, accounts, data)
+}
+
+/// Withdraws OIL from the staking contract (FOGO session)
+pub fn process_withdraw_with_session<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, authority, program_signer, payer, config, mint, recipient, stake, stake_tokens, pool, pool_tokens,
+    // miner, rig, treasury, treasury_oil, system_program, token_program, associated_token_program
+    let [signer_info, authority_info, program_signer_info, payer_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_WITHDRAWALS)?;
+    withdraw(UserContext::session(signer_info, authority_info, program_signer_info, payer_info)?, accounts, data)
+}
+
+fn withdraw<'a>(user: UserContext<'a>, accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
     // Parse data.
     let args = Withdraw::try_from_bytes(data)?;
     let amount = u64::from_le_bytes(args.amount);
@@ -16,19 +41,18 @@ pub fn process_withdraw(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
     }
 
     let clock = Clock::get()?;
-    let [signer_info, mint_info, recipient_info, stake_info, stake_tokens_info, pool_info, pool_tokens_info, miner_info, treasury_info, treasury_oil_info, system_program, token_program, associated_token_program] =
+    let [mint_info, recipient_info, stake_info, stake_tokens_info, pool_info, pool_tokens_info, miner_info, rig_info, treasury_info, treasury_oil_info, system_program, token_program, associated_token_program] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
-    signer_info.is_signer()?;
     
-    let authority = *signer_info.key;
+    let authority = user.key();
     
     mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
     recipient_info
         .is_writable()?
-        .as_associated_token_account(&signer_info.key, &mint_info.key)?;
+        .as_associated_token_account(&authority, &mint_info.key)?;
     stake_info.has_seeds(&[STAKE, &authority.to_bytes(), &stake_id.to_le_bytes()], &oil_api::ID)?;
     let stake = stake_info
         .as_account_mut::<Stake>(&oil_api::ID)?
@@ -55,8 +79,8 @@ pub fn process_withdraw(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
 
     if recipient_info.data_is_empty() {
         create_associated_token_account(
-            signer_info,
-            signer_info,
+            user.payer,
+            user.authority,
             recipient_info,
             mint_info,
             system_program,
@@ -65,6 +89,22 @@ pub fn process_withdraw(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
         )?;
     }
 
+    // Load the miner whose stake score tracks this stake, if it exists.
+    let mut miner = if miner_info.data_is_empty() {
+        None
+    } else {
+        miner_info
+            .as_account_mut::<Miner>(&oil_api::ID)
+            .ok()
+            .filter(|miner| miner.authority == authority)
+    };
+    
+    // Accrue stake XP for the stake held so far, before the withdrawal lowers the stake score
+    if let Some(miner) = miner.as_deref_mut() {
+        let rig = load_rig(rig_info, authority, user.payer, system_program)?;
+        accrue_stake_xp(rig, miner, &clock);
+    }
+    
     // Calculate old score before withdraw (for miner account update)
     let old_stake_score = stake.score();
     
@@ -76,12 +116,8 @@ pub fn process_withdraw(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
     let stake_score_delta = old_stake_score.saturating_sub(new_stake_score);
     
     // Update miner account's total_stake_score
-    if !miner_info.data_is_empty() {
-        if let Ok(miner) = miner_info.as_account_mut::<Miner>(&oil_api::ID) {
-            if miner.authority == authority {
-                miner.total_stake_score = miner.total_stake_score.saturating_sub(stake_score_delta);
-            }
-        }
+    if let Some(miner) = miner {
+        miner.total_stake_score = miner.total_stake_score.saturating_sub(stake_score_delta);
     }
 
     // Calculate penalty amount if early withdrawal
@@ -131,14 +167,14 @@ pub fn process_withdraw(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
 
     // Transfer remaining OIL from pool tokens account to recipient.
     if user_amount > 0 {
-    transfer_signed(
-        pool_info,
-        pool_tokens_info,
-        recipient_info,
-        token_program,
+        transfer_signed(
+            pool_info,
+            pool_tokens_info,
+            recipient_info,
+            token_program,
             user_amount,
-        &[POOL],
-    )?;
+            &[POOL],
+        )?;
     }
 
     // Safety check: Verify pool has enough tokens to cover remaining stakes.
@@ -0,0 +1,99 @@
+use oil_api::prelude::*;
+use oil_api::consts::POOL_ADDRESS;
+use oil_api::instruction::WithdrawContribution;
+use oil_api::fogo::UserContext;
+use solana_program::{log::sol_log, native_token::lamports_to_sol};
+use steel::*;
+
+use crate::pause::check_unpaused;
+
+/// Withdraws un-spent FOGO from a pool contribution before the pool bids.
+pub fn process_withdraw_contribution<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, authority, config, well, treasury, share, system_program
+    let [signer_info, authority_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_WITHDRAWALS)?;
+    withdraw_contribution(UserContext::wallet(signer_info, authority_info, signer_info)?, accounts, data)
+}
+
+/// Withdraws un-spent FOGO from a pool contribution before the pool bids (Fogo session)
+pub fn process_withdraw_contribution_with_session<'a>(accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    // Account order: signer, authority, program_signer, config, well, treasury, share, system_program
+    let [signer_info, authority_info, program_signer_info, config_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_unpaused(config_info, PAUSE_WITHDRAWALS)?;
+    withdraw_contribution(UserContext::session(signer_info, authority_info, program_signer_info, signer_info)?, accounts, data)
+}
+
+fn withdraw_contribution<'a>(user: UserContext<'a>, accounts: &'a [AccountInfo<'a>], data: &[u8]) -> ProgramResult {
+    let args = WithdrawContribution::try_from_bytes(data)?;
+    let well_id = u64::from_le_bytes(args.well_id) as usize;
+    let amount = u64::from_le_bytes(args.amount);
+    
+    if well_id >= 4 {
+        return Err(ProgramError::InvalidArgument);
+    }
+    
+    let [well_info, treasury_info, share_info, system_program] =
+        accounts.get(0..4).ok_or(ProgramError::NotEnoughAccountKeys)?
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+    
+    user.assert_authority()?;
+    user.authority.is_writable()?;
+    let authority = user.key();
+    
+    // Validate accounts
+    let well = well_info.is_writable()?
+        .has_seeds(&[WELL, &(well_id as u64).to_le_bytes()], &oil_api::ID)?
+        .as_account_mut::<Well>(&oil_api::ID)?;
+    treasury_info.is_writable()?.has_seeds(&[TREASURY], &oil_api::ID)?;
+    let treasury = treasury_info.as_account_mut::<Treasury>(&oil_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+    
+    // Contributions are spent once the pool bids
+    if well.current_bidder == POOL_ADDRESS {
+        return Err(ProgramError::InvalidArgument);
+    }
+    
+    // Only contributions to the current epoch are still un-spent
+    share_info.is_writable()?.has_seeds(&[SHARE, &authority.to_bytes(), &well_id.to_le_bytes(), &well.epoch_id.to_le_bytes()], &oil_api::ID)?;
+    let share = share_info
+        .as_account_mut::<Share>(&oil_api::ID)?
+        .assert_mut(|s| s.authority == authority && s.well_id == well_id as u64 && s.epoch_id == well.epoch_id)?;
+    
+    let withdraw_amount = amount.min(share.contribution);
+    
+    if withdraw_amount == 0 {
+        return Err(OilError::AmountTooSmall.into());
+    }
+    
+    // Update Share contribution
+    share.contribution -= withdraw_amount;
+    
+    // Update Well total_contributed
+    well.total_contributed = well.total_contributed
+        .checked_sub(withdraw_amount)
+        .ok_or(ProgramError::InsufficientFunds)?;
+    
+    // Update Treasury auction_total_pooled
+    treasury.auction_total_pooled = treasury.auction_total_pooled
+        .checked_sub(withdraw_amount)
+        .ok_or(ProgramError::InsufficientFunds)?;
+    
+    // Refund native FOGO held by the Treasury
+    treasury_info.send(withdraw_amount, user.authority);
+    
+    sol_log(&format!(
+        "Withdraw contribution: well_id={}, epoch_id={}, amount={} SOL, remaining={} SOL",
+        well_id,
+        well.epoch_id,
+        lamports_to_sol(withdraw_amount),
+        lamports_to_sol(share.contribution)
+    ));
+    
+    Ok(())
+}
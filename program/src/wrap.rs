@@ -14,11 +14,14 @@ pub fn process_wrap(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult
     let requested_amount = u64::from_le_bytes(args.amount);
 
     // Load accounts.
-    let [signer_info, _config_info, treasury_info, treasury_sol_info, system_program] = accounts
+    let [signer_info, config_info, treasury_info, treasury_sol_info, system_program, ..] = accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
-    signer_info.is_signer()?.has_address(&BURY_AUTHORITY)?;
+    signer_info.is_signer()?;
+    config_info
+        .as_account::<Config>(&oil_api::ID)?
+        .check_role(Role::TreasuryOperator, signer_info, accounts)?;
     let treasury = treasury_info.as_account_mut::<Treasury>(&oil_api::ID)?;
     treasury_sol_info
         .is_writable()?
@@ -62,9 +65,10 @@ pub fn process_wrap(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult
     // Update treasury.
     treasury.balance -= wrap_amount;
     
-    // If using liquidity, also decrement liquidity tracking
+    // If using liquidity, move the liquidity tracking to the wrapped SOL held for liquidity
     if use_liquidity {
         treasury.liquidity = treasury.liquidity.saturating_sub(wrap_amount);
+        treasury.liquidity_sol += wrap_amount;
     }
 
     Ok(())
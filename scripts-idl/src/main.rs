@@ -1,9 +1,9 @@
-use std::collections::HashMap;
 use std::fs;
-use std::path::{Path, PathBuf};
+use std::path::PathBuf;
 use regex::{Regex, escape as regex_escape};
 use serde_json::{json, Value};
 use indexmap::IndexMap;
+use oil_api::idl::{IdlAccount, IdlArg, IdlInstruction, INSTRUCTIONS};
 
 const PROGRAM_ID: &str = "rigwXYKkE8rXiiyu6eFs3ZuDNH2eYHb1y87tYqwDJhk";
 
@@ -14,44 +14,10 @@ fn main() -> anyhow::Result<()> {
     let workspace_root = find_workspace_root()?;
     println!("Workspace root: {}", workspace_root.display());
     
-    let api_src = workspace_root.join("api/src/instruction.rs");
-    let program_src_dir = workspace_root.join("program/src");
     let output_path = workspace_root.join("api/idl.json");
     
-    // Read instruction definitions
-    let instruction_rs = fs::read_to_string(&api_src)?;
-    
-    // Parse instruction enum to get discriminators (preserve order)
-    let instructions = parse_instruction_enum(&instruction_rs)?;
-    
-    // Parse instruction structs to get args
-    let args_map = parse_instruction_args(&instruction_rs)?;
-    
-    // Parse account lists from instruction handlers
-    let accounts_map = parse_account_lists(&program_src_dir)?;
-    
-    // Build IDL (preserve enum definition order)
-    let mut idl_instructions = Vec::new();
-    
-    for (name, discriminator) in &instructions {
-        let instruction_name = to_camel_case(name);
-        let args = args_map.get(name).cloned().unwrap_or_default();
-        let accounts = accounts_map.get(name).cloned().unwrap_or_default();
-        let docs = get_docs_for_instruction(name)?;
-        
-        idl_instructions.push(json!({
-            "name": instruction_name,
-            "discriminant": {
-                "type": "u8",
-                "value": discriminator
-            },
-            "docs": docs,
-            "accounts": accounts,
-            "args": args
-        }));
-    }
-    
-    // Instructions are already in enum definition order, no sorting needed
+    // Instructions come from the declarations in oil_api::idl, in enum definition order
+    let idl_instructions: Vec<Value> = INSTRUCTIONS.iter().map(instruction_to_idl).collect();
     
     // Build full IDL in correct order: version, name, instructions, accounts, types, events, errors, metadata
     // Use IndexMap to preserve insertion order
@@ -88,59 +54,101 @@ fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
-fn parse_instruction_enum(content: &str) -> anyhow::Result<Vec<(String, u8)>> {
-    let mut instructions = Vec::new();
-    
-    // Pattern: InstructionName = value,
-    // Match in order to preserve enum definition order
-    let re = Regex::new(r"(\w+)\s*=\s*(\d+)")?;
-    
-    for cap in re.captures_iter(content) {
-        let name = cap.get(1).unwrap().as_str().to_string();
-        let value: u8 = cap.get(2).unwrap().as_str().parse()?;
-        instructions.push((name, value));
+fn instruction_to_idl(ix: &IdlInstruction) -> Value {
+    json!({
+        "name": to_camel_case(&format!("{:?}", ix.instruction)),
+        "discriminant": {
+            "type": "u8",
+            "value": ix.instruction as u8
+        },
+        "docs": [ix.docs],
+        "accounts": ix.accounts.iter().map(account_to_idl).collect::<Vec<_>>(),
+        "args": ix.args.iter().map(arg_to_idl).collect::<Vec<_>>()
+    })
+}
+
+fn account_to_idl(account: &IdlAccount) -> Value {
+    let mut value = json!({
+        "name": to_camel_case(account.name),
+        "isMut": account.is_writable,
+        "isSigner": account.is_signer
+    });
+    if account.is_optional {
+        value["isOptional"] = json!(true);
     }
-    
-    Ok(instructions)
+    if let Some(address) = account.address {
+        value["address"] = json!(address.to_string());
+    }
+    value
 }
 
-fn parse_instruction_args(content: &str) -> anyhow::Result<HashMap<String, Vec<Value>>> {
-    let mut args_map = HashMap::new();
-    
-    // Pattern: pub struct InstructionName { ... }
-    let struct_re = Regex::new(r"#\[repr\(C\)\]\s*#\[derive\([^)]+\)\]\s*pub struct (\w+)\s*\{([^}]+)\}")?;
-    let field_re = Regex::new(r"pub\s+(\w+):\s*([^,]+)")?;
+fn arg_to_idl(arg: &IdlArg) -> Value {
+    json!({
+        "name": arg.name,
+        "type": arg_type(arg)
+    })
+}
+
+/// Maps an argument to its IDL type. Instruction structs store integers as little-endian byte
+/// arrays, so the type is read from the element width.
+fn arg_type(arg: &IdlArg) -> Value {
+    let ty: String = arg.ty.chars().filter(|c| !c.is_whitespace()).collect();
+    match ty.as_str() {
+        "u8" => json!("u8"),
+        "[u8;4]" => json!("u32"),
+        "[u8;8]" => json!("u64"),
+        "[u8;32]" => json!("publicKey"),
+        _ if ty.starts_with("[[u8;8];") => json!({ "array": ["u64", arg.size / 8] }),
+        _ if ty.starts_with("[[u8;32];") => json!({ "array": ["publicKey", arg.size / 32] }),
+        _ => json!({ "array": ["u8", arg.size] }),
+    }
+}
+
+/// Converts PascalCase or snake_case to camelCase, treating acronyms as words
+/// (e.g. `ClaimSOLWithSession` -> `claimSolWithSession`, `treasury_tokens` -> `treasuryTokens`).
+fn to_camel_case(s: &str) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    let mut words: Vec<String> = Vec::new();
+    let mut word = String::new();
     
-    for cap in struct_re.captures_iter(content) {
-        let struct_name = cap.get(1).unwrap().as_str();
-        let fields = cap.get(2).unwrap().as_str();
-        
-        // Skip empty structs
-        if fields.trim().is_empty() {
-            args_map.insert(struct_name.to_string(), vec![]);
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '_' {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
             continue;
         }
-        
-        let mut args = Vec::new();
-        for field_cap in field_re.captures_iter(fields) {
-            let field_name = field_cap.get(1).unwrap().as_str();
-            let field_type = field_cap.get(2).unwrap().as_str().trim();
-            
-            // Convert Rust type to IDL type
-            let idl_type = rust_to_idl_type(field_type);
-            
-            args.push(json!({
-                "name": field_name,
-                "type": idl_type
-            }));
+        if c.is_uppercase() && !word.is_empty() {
+            let prev = chars[i - 1];
+            let next_is_lower = matches!(chars.get(i + 1), Some(n) if n.is_lowercase());
+            // A new word starts after a lowercase letter, or at the last capital of an acronym
+            if !prev.is_uppercase() || next_is_lower {
+                words.push(std::mem::take(&mut word));
+            }
         }
-        
-        args_map.insert(struct_name.to_string(), args);
+        word.push(c);
+    }
+    if !word.is_empty() {
+        words.push(word);
     }
     
-    Ok(args_map)
+    let mut result = String::new();
+    for (i, word) in words.iter().enumerate() {
+        let lower = word.to_lowercase();
+        if i == 0 {
+            result.push_str(&lower);
+        } else {
+            let mut chars = lower.chars();
+            if let Some(first) = chars.next() {
+                result.push(first.to_ascii_uppercase());
+                result.push_str(chars.as_str());
+            }
+        }
+    }
+    result
 }
 
+
 fn rust_to_idl_type(rust_type: &str) -> Value {
     let rust_type = rust_type.trim();
     
@@ -190,223 +198,6 @@ fn find_workspace_root() -> anyhow::Result<PathBuf> {
     }
 }
 
-fn parse_account_lists(program_dir: &Path) -> anyhow::Result<HashMap<String, Vec<Value>>> {
-    let mut accounts_map = HashMap::new();
-    
-    // Read all instruction handler files
-    for entry in fs::read_dir(&program_dir)? {
-        let entry = entry?;
-        let path = entry.path();
-        
-        if path.is_file() && path.extension().and_then(|s| s.to_str()) == Some("rs") {
-            let content = fs::read_to_string(&path)?;
-            
-            // Extract instruction name from filename (e.g., contribute.rs -> Contribute)
-            let file_stem = path.file_stem().unwrap().to_str().unwrap();
-            
-            // Handle _with_session variants
-            if file_stem.ends_with("_with_session") {
-                let base_name = file_stem.strip_suffix("_with_session").unwrap();
-                let instruction_name = format!("{}WithSession", to_pascal_case(base_name));
-                
-                if let Some(accounts) = extract_accounts_from_comment(&content) {
-                    accounts_map.insert(instruction_name, accounts);
-                }
-            } else {
-                let instruction_name = to_pascal_case(file_stem);
-                
-                // Look for account order comments
-                if let Some(accounts) = extract_accounts_from_comment(&content) {
-                    accounts_map.insert(instruction_name, accounts);
-                }
-            }
-        }
-    }
-    
-    Ok(accounts_map)
-}
-
-fn extract_accounts_from_comment(content: &str) -> Option<Vec<Value>> {
-    // Look for comment pattern: // Account order: account1, account2, ...
-    // Stop at the next non-comment line or end of comment block
-    let re = Regex::new(r"(?m)^\s*//\s*Account order:\s*([^\n]+)").ok()?;
-    
-    if let Some(cap) = re.captures(content) {
-        let account_list = cap.get(1).unwrap().as_str();
-        
-        // Split by comma only (not newlines, as the comment is on one line)
-        let accounts: Vec<&str> = account_list
-            .split(',')
-            .map(|s| s.trim())
-            .filter(|s| !s.is_empty())
-            .collect();
-        
-        let mut idl_accounts = Vec::new();
-        for account in accounts {
-            // Clean up account name - remove any trailing comments or extra whitespace
-            let account = account.split("//").next().unwrap().trim();
-            
-            // Skip if it looks like code (contains operators, keywords, etc.)
-            if account.contains('=') || account.contains('(') || account.contains(')') || 
-               account.contains('{') || account.contains('}') || account.contains(';') ||
-               account == "let" || account == "if" || account == "return" {
-                continue;
-            }
-            
-            if account.is_empty() {
-                continue;
-            }
-            
-            // Convert snake_case to camelCase for IDL
-            let account_name = to_camel_case(account);
-            
-            // Determine if account is signer, writable, etc.
-            let (is_mut, is_signer, address) = determine_account_properties(&account_name);
-            
-            let mut account_json = json!({
-                "name": account_name,
-                "isMut": is_mut,
-                "isSigner": is_signer
-            });
-            
-            if let Some(addr) = address {
-                account_json["address"] = json!(addr);
-            }
-            
-            idl_accounts.push(account_json);
-        }
-        
-        return Some(idl_accounts);
-    }
-    
-    None
-}
-
-fn determine_account_properties(account: &str) -> (bool, bool, Option<&str>) {
-    let account_lower = account.to_lowercase();
-    
-    // Common patterns
-    match account_lower.as_str() {
-        "signer" | "authority" | "payer" => (true, true, None),
-        "systemprogram" | "system_program" => (false, false, Some("11111111111111111111111111111111")),
-        "tokenprogram" | "token_program" => (false, false, Some("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")),
-        "associatedtokenprogram" | "associated_token_program" | "ataprogram" | "ata_program" => (false, false, Some("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")),
-        "mint" => (false, false, Some("oiLTuhTJc9qRDr2FcMiCUBJ3BCunNXP1LGJCG7svBSy")),
-        "programsigner" | "program_signer" => (false, true, None),
-        _ => {
-            // Default: writable if not a program, not a signer unless explicitly named
-            let is_mut = !account_lower.contains("program") && !account_lower.contains("mint") && account_lower != "executor";
-            let is_signer = account_lower == "signer" || account_lower == "authority" || account_lower == "payer";
-            (is_mut, is_signer, None)
-        }
-    }
-}
-
-fn to_camel_case(s: &str) -> String {
-    // Handle PascalCase input (e.g., "AutomateWithSession" -> "automateWithSession")
-    // or snake_case input (e.g., "automate_with_session" -> "automateWithSession")
-    let mut result = String::new();
-    let mut capitalize_next = false;
-    let mut first_char = true;
-    let mut prev_was_lowercase = false;
-    
-    for c in s.chars() {
-        if c == '_' {
-            capitalize_next = true;
-        } else if c.is_uppercase() {
-            if first_char {
-                result.push(c.to_ascii_lowercase());
-                first_char = false;
-                prev_was_lowercase = true;
-            } else {
-                // If previous char was lowercase, this uppercase starts a new word - keep it uppercase
-                if capitalize_next || prev_was_lowercase {
-                    result.push(c);
-                    capitalize_next = false;
-                    prev_was_lowercase = false;
-                } else {
-                    result.push(c.to_ascii_lowercase());
-                    prev_was_lowercase = false;
-                }
-            }
-        } else {
-            if capitalize_next {
-                result.push(c.to_ascii_uppercase());
-                capitalize_next = false;
-            } else {
-                result.push(c);
-            }
-            first_char = false;
-            prev_was_lowercase = true;
-        }
-    }
-    
-    result
-}
-
-fn to_pascal_case(s: &str) -> String {
-    let mut result = String::new();
-    let mut capitalize_next = true;
-    
-    for c in s.chars() {
-        if c == '_' {
-            capitalize_next = true;
-        } else if capitalize_next {
-            result.push(c.to_ascii_uppercase());
-            capitalize_next = false;
-        } else {
-            result.push(c.to_ascii_lowercase());
-        }
-    }
-    
-    result
-}
-
-fn get_docs_for_instruction(instruction_name: &str) -> anyhow::Result<Vec<String>> {
-    // Find workspace root
-    let workspace_root = find_workspace_root()?;
-    let program_src_dir = workspace_root.join("program/src");
-    
-    // Try to extract docs from instruction handler
-    let base_name = if instruction_name.ends_with("WithSession") {
-        instruction_name.strip_suffix("WithSession").unwrap()
-    } else {
-        instruction_name
-    };
-    
-    let handler_file = program_src_dir.join(format!("{}.rs", to_snake_case(base_name)));
-    let alt_handler_file = if !instruction_name.ends_with("WithSession") {
-        program_src_dir.join(format!("{}_with_session.rs", to_snake_case(base_name)))
-    } else {
-        PathBuf::new()
-    };
-    
-    let content = if handler_file.exists() {
-        fs::read_to_string(&handler_file)?
-    } else if alt_handler_file.exists() {
-        fs::read_to_string(&alt_handler_file)?
-    } else {
-        return Ok(vec![]);
-    };
-    
-    // Extract doc comments (look for function-level docs before process_ function)
-    let re = Regex::new(r"///\s*(.+)")?;
-    let mut docs = Vec::new();
-    
-    for cap in re.captures_iter(&content) {
-        if let Some(doc) = cap.get(1) {
-            let doc_text = doc.as_str().trim();
-            // Skip empty docs or very short ones
-            if doc_text.len() > 3 {
-                docs.push(doc_text.to_string());
-            }
-        }
-    }
-    
-    // Limit to first few meaningful docs
-    Ok(docs.into_iter().take(5).collect())
-}
-
 fn to_snake_case(s: &str) -> String {
     let mut result = String::new();
     for (i, c) in s.chars().enumerate() {
@@ -431,7 +222,7 @@ fn get_account_definitions() -> Vec<Value> {
                     let name = cap.get(1).unwrap().as_str();
                     if let Ok(discriminator_value) = cap.get(2).unwrap().as_str().parse::<u8>() {
                         // Read the account struct file
-                        let account_file = state_dir.join(format!("{}.rs", name.to_lowercase()));
+                        let account_file = state_dir.join(format!("{}.rs", to_snake_case(name)));
                         if let Ok(content) = fs::read_to_string(&account_file) {
                             if let Some(account_def) = parse_account_struct(&content, name, discriminator_value) {
                                 accounts.push(account_def);